@@ -0,0 +1,45 @@
+//! Benchmarks of the incremental construction of graphs. Run with
+//! `cargo bench --bench build`.
+
+use graphst::{DGraph, Graph, UGraph};
+use std::hint::black_box;
+use std::time::Instant;
+
+fn bench<G, F>(name: &str, mut build: F)
+where
+    G: Graph,
+    F: FnMut() -> G,
+{
+    let start = Instant::now();
+    let g = build();
+    println!("{:<32} {:>12?}", name, start.elapsed());
+    black_box(g.get_n_nodes());
+}
+
+fn main() {
+    let n_nodes = 10_000;
+    bench("UGraph add_node (10k nodes)", || {
+        let mut g = UGraph::new();
+        for _ in 0..n_nodes {
+            g.add_node();
+        }
+        g
+    });
+    bench("UGraph add_nodes (10k nodes)", || {
+        let mut g = UGraph::new();
+        g.add_nodes(n_nodes);
+        g
+    });
+    bench("DGraph add_node (10k nodes)", || {
+        let mut g = DGraph::new();
+        for _ in 0..n_nodes {
+            g.add_node();
+        }
+        g
+    });
+    bench("DGraph add_nodes (10k nodes)", || {
+        let mut g = DGraph::new();
+        g.add_nodes(n_nodes);
+        g
+    });
+}
@@ -0,0 +1,53 @@
+//! Benchmarks of the dense primitives. Run with `cargo bench --bench dense`, and with
+//! `cargo bench --bench dense --features simd` to compare against the SIMD versions.
+
+use graphst::algorithm::dijkstra_dense;
+use graphst::dense::{row_min_scan, row_min_scan_scalar};
+use graphst::UGraph;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+fn bench<F: FnMut()>(name: &str, iters: u32, mut f: F) {
+    f(); // warm up
+    let start = Instant::now();
+    for _ in 0..iters {
+        f();
+    }
+    let per_iter = start.elapsed() / iters;
+    println!("{:<28} {:>12?}/iter", name, per_iter);
+}
+
+fn main() {
+    let n = 4096;
+    let values: Vec<f32> = (0..n).map(|i| ((i * 7919) % 1009) as f32).collect();
+    let visited: Vec<bool> = (0..n).map(|i| i % 3 == 0).collect();
+
+    println!("simd feature enabled: {}", cfg!(feature = "simd"));
+    bench("row_min_scan", 10_000, || {
+        black_box(row_min_scan(black_box(&values), black_box(&visited)));
+    });
+    bench("row_min_scan_scalar", 10_000, || {
+        black_box(row_min_scan_scalar(black_box(&values), black_box(&visited)));
+    });
+
+    // Dense graph where the O(V^2) min scan dominates
+    let n_nodes = 1000;
+    let adj_mat: Vec<Vec<f32>> = (0..n_nodes)
+        .map(|i| {
+            (0..n_nodes)
+                .map(|j| {
+                    if i == j {
+                        0.0
+                    } else {
+                        1.0 + ((i * j) % 17) as f32
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let g = UGraph::from_adjacency_matrix(adj_mat);
+    let start = Instant::now();
+    black_box(dijkstra_dense(&g, 0));
+    let elapsed: Duration = start.elapsed();
+    println!("{:<28} {:>12?}", "dijkstra_dense (1000 dense)", elapsed);
+}
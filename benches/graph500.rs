@@ -0,0 +1,15 @@
+//! Graph500-style BFS benchmark. Run with `cargo bench --bench graph500`.
+
+use graphst::benchmark::bfs_teps;
+use graphst::generate::kronecker;
+
+fn main() {
+    for scale in [8, 10, 12] {
+        let g = kronecker(scale, 16, 1);
+        let result = bfs_teps(&g, 64, 2);
+        println!(
+            "scale {:>2}: harmonic mean {:>14.0} TEPS",
+            scale, result.harmonic_mean_teps
+        );
+    }
+}
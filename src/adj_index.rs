@@ -0,0 +1,129 @@
+//! Auxiliary index of the present edges for the matrix based graphs. Each row keeps the
+//! sorted destinations of the edges of a node, so the neighbors can be iterated in time
+//! proportional to the degree of the node instead of the number of nodes.
+//!
+//! The index stores every weight that is not exactly `0.0`, and the crate tolerance is
+//! only applied when it is read with `present`, so changing the epsilon with
+//! `float::set_epsilon` never leaves the index out of sync with `get_edge`. The matrix
+//! views (`present_row` and `present_matrix`) apply it the same way.
+
+use crate::float;
+use std::borrow::Cow;
+
+/// Builds the sorted index of the present edges of each row of the adjacency matrix.
+pub(crate) fn build(adj_mat: &[Vec<f32>]) -> Vec<Vec<usize>> {
+    adj_mat
+        .iter()
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, w)| **w != 0.0)
+                .map(|(idx, _)| idx)
+                .collect()
+        })
+        .collect()
+}
+
+/// Updates the index row to store (or not) the node `dest` depending on the new weight.
+pub(crate) fn update(row: &mut Vec<usize>, dest: usize, weight: f32) {
+    match row.binary_search(&dest) {
+        Ok(pos) if weight == 0.0 => {
+            row.remove(pos);
+        }
+        Err(pos) if weight != 0.0 => row.insert(pos, dest),
+        _ => {} // the index is already in the right state
+    }
+}
+
+/// Returns the nodes of the index row whose weight in the matrix row is not zero within
+/// the current crate tolerance.
+pub(crate) fn present(row: &[usize], weights: &[f32]) -> Vec<usize> {
+    row.iter()
+        .copied()
+        .filter(|idx| !float::is_zero(weights[*idx]))
+        .collect()
+}
+
+/// Returns the weight as seen by `get_edge`: `0.0` if it is zero within the current crate
+/// tolerance, the weight itself otherwise.
+pub(crate) fn present_weight(weight: f32) -> f32 {
+    if float::is_zero(weight) {
+        0.0
+    } else {
+        weight
+    }
+}
+
+/// Returns the matrix row with the weights that are zero within the current crate
+/// tolerance set to `0.0`. The row is only copied if it has some of those weights.
+pub(crate) fn present_row(row: &[f32]) -> Cow<'_, [f32]> {
+    if row.iter().all(|w| present_weight(*w) == *w || w.is_nan()) {
+        Cow::Borrowed(row)
+    } else {
+        Cow::Owned(row.iter().map(|w| present_weight(*w)).collect())
+    }
+}
+
+/// Returns a copy of the adjacency matrix with the weights that are zero within the
+/// current crate tolerance set to `0.0`, or `None` if it has none of those weights and
+/// can be returned borrowed.
+pub(crate) fn present_matrix(adj_mat: &[Vec<f32>]) -> Option<Vec<Vec<f32>>> {
+    if adj_mat
+        .iter()
+        .all(|row| matches!(present_row(row), Cow::Borrowed(_)))
+    {
+        None
+    } else {
+        Some(
+            adj_mat
+                .iter()
+                .map(|row| present_row(row).into_owned())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_check_values() {
+        let adj_mat = vec![
+            vec![0.0, 1.0, 2.0],
+            vec![0.0, 0.0, 0.0],
+            vec![3.0, 0.0, 0.0],
+        ];
+        assert_eq!(build(&adj_mat), vec![vec![1, 2], vec![], vec![0]]);
+    }
+
+    #[test]
+    fn update_check_status() {
+        let mut row = vec![1, 4];
+        update(&mut row, 2, 1.0);
+        assert_eq!(row, vec![1, 2, 4]);
+        update(&mut row, 2, 3.0); // already present
+        assert_eq!(row, vec![1, 2, 4]);
+        update(&mut row, 1, 0.0);
+        assert_eq!(row, vec![2, 4]);
+        update(&mut row, 0, 0.0); // not present
+        assert_eq!(row, vec![2, 4]);
+        update(&mut row, 3, 1e-9); // tiny weights are indexed, and filtered by `present`
+        assert_eq!(row, vec![2, 3, 4]);
+        assert_eq!(present(&row, &[0.0, 0.0, 1.0, 1e-9, 2.0]), vec![2, 4]);
+    }
+
+    #[test]
+    fn present_row_check_copies() {
+        let row = [0.0, 1.0, -2.0];
+        assert!(matches!(present_row(&row), Cow::Borrowed(_)));
+        let row = [0.0, 1e-9, -2.0, -1e-9];
+        assert_eq!(*present_row(&row), [0.0, 0.0, -2.0, 0.0]);
+        let adj_mat = vec![vec![0.0, 1.0], vec![1e-9, 0.0]];
+        assert_eq!(
+            present_matrix(&adj_mat),
+            Some(vec![vec![0.0, 1.0], vec![0.0, 0.0]])
+        );
+        assert_eq!(present_matrix(&[vec![0.0, 1.0], vec![1.0, 0.0]]), None);
+    }
+}
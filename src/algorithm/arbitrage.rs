@@ -0,0 +1,167 @@
+use crate::{DGraph, Graph};
+
+// Minimum gain (in log space) of a cycle to be an arbitrage, so the rounding errors of
+// the rates don't report cycles that gain nothing
+const MIN_LOG_GAIN: f64 = 1e-6;
+
+/// The `Arbitrage` struct is the result of `find_arbitrage`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Arbitrage {
+    /// The currencies of the cycle in trading order, starting and ending at the lowest
+    /// one (e.g. `[0, 2, 1, 0]`).
+    pub cycle: Vec<usize>,
+    /// The amount obtained after trading one unit around the cycle, greater than `1.0`.
+    pub multiplier: f32,
+}
+
+/// Given a directed graph of exchange rates, where the weight of the edge `a -> b` is the
+/// units of `b` obtained for one unit of `a`, returns a cycle of trades that ends with
+/// more units than it started, or `None` if there is no arbitrage. The rates are turned
+/// into the weights `-ln(rate)`, so a cycle with a product of rates greater than `1.0` is
+/// a negative cycle, and it is found with Bellman-Ford from every node at once. The
+/// computations use `f64` values, and the cycles that gain less than a relative `1e-6`
+/// are ignored as rounding errors.
+///
+/// # Panics
+///
+/// * If some rate is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::find_arbitrage;
+/// use graphst::DGraph;
+/// // 0: USD, 1: EUR, 2: GBP
+/// let rates = vec![
+///     (0, 1, 0.9), (1, 0, 1.1),
+///     (1, 2, 0.9), (2, 1, 1.1),
+///     (2, 0, 1.3), (0, 2, 0.7),
+/// ];
+/// let arbitrage = find_arbitrage(&DGraph::from_weighted_edges(3, rates)).unwrap();
+/// assert_eq!(arbitrage.cycle, vec![0, 1, 2, 0]);
+/// assert!((arbitrage.multiplier - 0.9 * 0.9 * 1.3).abs() < 1e-6);
+/// ```
+pub fn find_arbitrage(g: &DGraph) -> Option<Arbitrage> {
+    let n_nodes = g.get_n_nodes();
+    let mut arcs = vec![];
+    for (node1, node2, rate) in g.get_edges() {
+        if rate <= 0.0 || rate.is_nan() {
+            panic!(
+                "[algorithm::find_arbitrage] Error: The rate {} of the edge {} -> {} is not valid!",
+                rate, node1, node2
+            );
+        }
+        arcs.push((node1, node2, -(rate as f64).ln()));
+    }
+    // Every node starts at distance 0, as if a virtual source reached all of them
+    let mut dist = vec![0.0f64; n_nodes];
+    let mut predecessors = vec![None; n_nodes];
+    let mut last_relaxed = None;
+    for _ in 0..n_nodes {
+        last_relaxed = None;
+        for (node1, node2, weight) in &arcs {
+            if dist[*node1] + weight < dist[*node2] - MIN_LOG_GAIN / n_nodes as f64 {
+                dist[*node2] = dist[*node1] + weight;
+                predecessors[*node2] = Some(*node1);
+                last_relaxed = Some(*node2);
+            }
+        }
+        last_relaxed?;
+    }
+    // A node relaxed in the last round leads back to a negative cycle through its
+    // predecessors, and `n` steps back are surely inside the cycle
+    let mut node = last_relaxed?;
+    for _ in 0..n_nodes {
+        node = predecessors[node]?;
+    }
+    let mut cycle = vec![node];
+    let mut current = predecessors[node]?;
+    while current != node {
+        cycle.push(current);
+        current = predecessors[current]?;
+    }
+    cycle.reverse();
+    let start = (0..cycle.len()).min_by_key(|idx| cycle[*idx])?;
+    cycle.rotate_left(start);
+    cycle.push(cycle[0]);
+    let multiplier: f64 = cycle
+        .windows(2)
+        .map(|w| g.get_edge(w[0], w[1]).unwrap_or(0.0) as f64)
+        .product();
+    if multiplier.ln() <= MIN_LOG_GAIN {
+        return None;
+    }
+    Some(Arbitrage {
+        cycle,
+        multiplier: multiplier as f32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Best product of rates of the simple cycles through `start`, by exhaustive search
+    fn best_cycle(
+        g: &DGraph,
+        start: usize,
+        node: usize,
+        visited: &mut Vec<usize>,
+        gain: f64,
+    ) -> f64 {
+        let mut best = 0.0f64;
+        for n in g.get_successors_of(node) {
+            let rate = g.get_edge(node, n).unwrap() as f64;
+            if n == start {
+                best = best.max(gain * rate);
+            } else if !visited.contains(&n) {
+                visited.push(n);
+                best = best.max(best_cycle(g, start, n, visited, gain * rate));
+                visited.pop();
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn find_arbitrage_matches_brute_force() {
+        let mut rng = crate::rng::Rng::new(30);
+        for _ in 0..40 {
+            let n_nodes = 2 + rng.gen_range(4);
+            let mut edges = vec![];
+            for node1 in 0..n_nodes {
+                for node2 in 0..n_nodes {
+                    if node1 != node2 && rng.gen_range(4) > 0 {
+                        // Rates around a fair price, with a spread
+                        let price = (node2 as f64 + 1.0) / (node1 as f64 + 1.0);
+                        let noise = 0.85 + rng.next_f64() * 0.2;
+                        edges.push((node1, node2, (price * noise) as f32));
+                    }
+                }
+            }
+            let g = DGraph::from_weighted_edges(n_nodes, edges);
+            let best = (0..n_nodes)
+                .map(|n| best_cycle(&g, n, n, &mut vec![n], 1.0))
+                .fold(0.0, f64::max);
+            match find_arbitrage(&g) {
+                Some(arbitrage) => {
+                    assert!(arbitrage.multiplier > 1.0);
+                    let cycle = &arbitrage.cycle;
+                    assert_eq!(cycle[0], cycle[cycle.len() - 1]);
+                    let product: f64 = cycle
+                        .windows(2)
+                        .map(|w| g.get_edge(w[0], w[1]).unwrap() as f64)
+                        .product();
+                    assert!((product as f32 - arbitrage.multiplier).abs() < 1e-6);
+                }
+                None => assert!(best.ln() <= 1e-5),
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn find_arbitrage_panic_negative_rate() {
+        find_arbitrage(&DGraph::from_weighted_edges(2, vec![(0, 1, -1.0)]));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::algorithm::implicit_astar;
+use crate::graph::Graph;
+
+/// Returns the shortest path from `src` to `dest` as the sequence of its nodes and its
+/// cost, or `None` if `dest` is not reachable. The search is guided by `heuristic`, an
+/// estimate of the cost from a node to `dest`, so it explores fewer nodes than Dijkstra's
+/// algorithm when the estimate is good. The path is the shortest one if the heuristic
+/// never overestimates the remaining cost and is consistent (e.g. the straight-line
+/// distance on a map). A heuristic that always returns `0.0` gives Dijkstra's algorithm.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`, with non-negative weights.
+/// * `src` - The first node of the path.
+/// * `dest` - The last node of the path.
+/// * `heuristic` - A closure with the estimated cost from a node to `dest`.
+///
+/// # Panics
+///
+/// * If some node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::astar;
+/// use graphst::UGraph;
+/// // Nodes on a line at x = 0, 1, 2, 3 and a costly shortcut 0-3
+/// let g = UGraph::from_weighted_edges(4, vec![(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0), (0, 3, 5.0)]);
+/// let straight_line = |node: usize| (3.0 - node as f32).abs();
+/// assert_eq!(astar(&g, 0, 3, straight_line), Some((vec![0, 1, 2, 3], 3.0)));
+/// ```
+pub fn astar<G, H>(g: &G, src: usize, dest: usize, heuristic: H) -> Option<(Vec<usize>, f32)>
+where
+    G: Graph,
+    H: Fn(usize) -> f32,
+{
+    if let Some(node) = [src, dest].iter().find(|n| **n >= g.get_n_nodes()) {
+        panic!("[algorithm::astar] Error: The node {} is not valid!", node);
+    }
+    implicit_astar(g, src, |node| *node == dest, |node| heuristic(*node))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::dijkstra;
+    use crate::DGraph;
+
+    #[test]
+    fn astar_matches_dijkstra_on_grid() {
+        // 4x4 grid where moving right or down costs the sum of the coordinates
+        let width = 4;
+        let mut edges = vec![];
+        for node in 0..width * width {
+            let (row, col) = (node / width, node % width);
+            let weight = 1.0 + (row + col) as f32;
+            if col + 1 < width {
+                edges.push((node, node + 1, weight));
+            }
+            if row + 1 < width {
+                edges.push((node, node + width, weight));
+            }
+        }
+        let g = DGraph::from_weighted_edges(width * width, edges);
+        let dest = width * width - 1;
+        // Manhattan distance, each step costs at least 1
+        let manhattan =
+            |node: usize| ((width - 1 - node / width) + (width - 1 - node % width)) as f32;
+        let (path, cost) = astar(&g, 0, dest, manhattan).unwrap();
+        assert_eq!(cost, dijkstra(&g, 0)[dest]);
+        assert_eq!((path[0], path[path.len() - 1]), (0, dest));
+        assert_eq!(astar(&g, dest, 0, |_| 0.0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn astar_panic_not_valid_dest() {
+        let g = DGraph::from_edges(2, vec![(0, 1)]);
+        astar(&g, 0, 2, |_| 0.0);
+    }
+}
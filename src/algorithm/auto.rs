@@ -0,0 +1,130 @@
+use crate::algorithm::{bellman_ford, bfs, dijkstra};
+use crate::graph::Graph;
+use crate::{float, GraphError};
+
+/// The `ShortestPathMethod` enum lists the algorithms that `shortest_paths_auto` can
+/// choose.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShortestPathMethod {
+    /// Breadth-first search, for graphs where every edge has the same positive weight.
+    Bfs,
+    /// Dijkstra, for graphs with non-negative weights.
+    Dijkstra,
+    /// Bellman-Ford, for graphs with negative weights.
+    BellmanFord,
+}
+
+/// The `ShortestPaths` struct holds the result of `shortest_paths_auto`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShortestPaths {
+    /// The length of the shortest path from the source to each node, `f32::INFINITY` for
+    /// the unreachable nodes.
+    pub distances: Vec<f32>,
+    /// The algorithm used to compute the distances.
+    pub method: ShortestPathMethod,
+}
+
+/// Computes the shortest paths from the source choosing the algorithm from the weights
+/// of the graph: breadth-first search if every edge has the same positive weight,
+/// dijkstra if the weights are non-negative, and Bellman-Ford otherwise.
+///
+/// # Errors
+///
+/// * `GraphError::InvalidNode` if the source node is not valid.
+/// * `GraphError::NegativeWeight` if some weight is NaN.
+/// * `GraphError::NegativeCycle` if a negative cycle is reachable from the source.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::{shortest_paths_auto, ShortestPathMethod};
+/// use graphst::DGraph;
+/// let g = DGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+/// let paths = shortest_paths_auto(&g, 0).unwrap();
+/// assert_eq!(paths.method, ShortestPathMethod::Bfs);
+/// assert_eq!(paths.distances, vec![0.0, 1.0, 2.0]);
+///
+/// let g = DGraph::from_weighted_edges(3, vec![(0, 1, 3.0), (0, 2, 1.0), (2, 1, -1.0)]);
+/// let paths = shortest_paths_auto(&g, 0).unwrap();
+/// assert_eq!(paths.method, ShortestPathMethod::BellmanFord);
+/// assert_eq!(paths.distances, vec![0.0, 0.0, 1.0]);
+/// ```
+pub fn shortest_paths_auto<G>(g: &G, src: usize) -> Result<ShortestPaths, GraphError>
+where
+    G: Graph,
+{
+    if src >= g.get_n_nodes() {
+        return Err(GraphError::InvalidNode {
+            operation: "algorithm::shortest_paths_auto",
+            node: src,
+            n_nodes: g.get_n_nodes(),
+        });
+    }
+    let edges = g.get_edges();
+    if let Some((node1, node2, weight)) = edges.iter().find(|(_, _, w)| w.is_nan()) {
+        return Err(GraphError::NegativeWeight {
+            operation: "algorithm::shortest_paths_auto",
+            src: *node1,
+            dest: *node2,
+            weight: *weight,
+            n_nodes: g.get_n_nodes(),
+        });
+    }
+    let first_weight = edges.first().map_or(1.0, |(_, _, w)| *w);
+    if first_weight > 0.0
+        && edges
+            .iter()
+            .all(|(_, _, w)| float::approx_eq(*w, first_weight))
+    {
+        let distances = bfs(g, src)
+            .hops
+            .into_iter()
+            .map(|hops| hops.map_or(f32::INFINITY, |h| h as f32 * first_weight))
+            .collect();
+        return Ok(ShortestPaths {
+            distances,
+            method: ShortestPathMethod::Bfs,
+        });
+    }
+    if edges.iter().all(|(_, _, w)| *w >= 0.0) {
+        return Ok(ShortestPaths {
+            distances: dijkstra(g, src),
+            method: ShortestPathMethod::Dijkstra,
+        });
+    }
+    Ok(ShortestPaths {
+        distances: bellman_ford(g, src)?,
+        method: ShortestPathMethod::BellmanFord,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UGraph;
+
+    #[test]
+    fn shortest_paths_auto_check_methods() {
+        let g = UGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 2.0)]);
+        let paths = shortest_paths_auto(&g, 2).unwrap();
+        assert_eq!(paths.method, ShortestPathMethod::Bfs);
+        assert_eq!(paths.distances, vec![4.0, 2.0, 0.0]);
+        let g = UGraph::from_weighted_edges(4, vec![(0, 1, 2.0), (1, 2, 0.5)]);
+        let paths = shortest_paths_auto(&g, 0).unwrap();
+        assert_eq!(paths.method, ShortestPathMethod::Dijkstra);
+        assert_eq!(paths.distances, vec![0.0, 2.0, 2.5, f32::INFINITY]);
+    }
+
+    #[test]
+    fn shortest_paths_auto_check_errors() {
+        let g = UGraph::from_weighted_edges(2, vec![(0, 1, -1.0)]);
+        assert!(matches!(
+            shortest_paths_auto(&g, 0),
+            Err(GraphError::NegativeCycle { .. })
+        ));
+        assert!(matches!(
+            shortest_paths_auto(&g, 2),
+            Err(GraphError::InvalidNode { node: 2, .. })
+        ));
+    }
+}
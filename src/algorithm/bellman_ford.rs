@@ -0,0 +1,104 @@
+use crate::graph::Graph;
+use crate::{float, GraphError};
+
+/// Given a graph (that implements `Graph`) and a source node, returns the shortest path
+/// to each node from the source with the Bellman-Ford algorithm, which supports negative
+/// weights in `O(V * E)` time. The unreachable nodes get `f32::INFINITY`. In undirected
+/// graphs a negative edge is a negative cycle by itself (going back and forth).
+///
+/// # Errors
+///
+/// * `GraphError::InvalidNode` if the source node is not valid.
+/// * `GraphError::NegativeCycle` if a negative cycle is reachable from the source.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::bellman_ford;
+/// use graphst::{DGraph, GraphError};
+/// let g = DGraph::from_weighted_edges(3, vec![(0, 1, 4.0), (0, 2, 2.0), (2, 1, -3.0)]);
+/// assert_eq!(bellman_ford(&g, 0), Ok(vec![0.0, -1.0, 2.0]));
+/// let g = DGraph::from_weighted_edges(2, vec![(0, 1, 1.0), (1, 0, -2.0)]);
+/// assert!(matches!(bellman_ford(&g, 0), Err(GraphError::NegativeCycle { .. })));
+/// ```
+pub fn bellman_ford<G>(g: &G, src: usize) -> Result<Vec<f32>, GraphError>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if src >= n_nodes {
+        return Err(GraphError::InvalidNode {
+            operation: "algorithm::bellman_ford",
+            node: src,
+            n_nodes,
+        });
+    }
+    // Both directions of the undirected edges
+    let mut arcs = vec![];
+    for node in g.get_nodes() {
+        for n in g.get_out_neighbors_of(node) {
+            if let Some(weight) = g.get_edge(node, n) {
+                arcs.push((node, n, weight));
+            }
+        }
+    }
+    let mut dist = vec![f32::INFINITY; n_nodes];
+    dist[src] = 0.0;
+    for _ in 1..n_nodes {
+        let mut changed = false;
+        for (node1, node2, weight) in &arcs {
+            if dist[*node1] != f32::INFINITY
+                && float::greater_than(dist[*node2], dist[*node1] + weight)
+            {
+                dist[*node2] = dist[*node1] + weight;
+                changed = true;
+            }
+        }
+        if !changed {
+            return Ok(dist);
+        }
+    }
+    // Any improvement after `n - 1` rounds comes from a negative cycle
+    for (node1, node2, weight) in &arcs {
+        if dist[*node1] != f32::INFINITY && float::greater_than(dist[*node2], dist[*node1] + weight)
+        {
+            return Err(GraphError::NegativeCycle {
+                operation: "algorithm::bellman_ford",
+                node: *node2,
+                n_nodes,
+            });
+        }
+    }
+    Ok(dist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::dijkstra;
+    use crate::UGraph;
+
+    #[test]
+    fn bellman_ford_matches_dijkstra_without_negative_weights() {
+        let edges = vec![(0, 1, 2.0), (1, 2, 1.0), (0, 2, 4.0), (3, 4, 1.0)];
+        let g = UGraph::from_weighted_edges(5, edges);
+        assert_eq!(bellman_ford(&g, 0), Ok(dijkstra(&g, 0)));
+    }
+
+    #[test]
+    fn bellman_ford_check_undirected_negative_edge() {
+        let g = UGraph::from_weighted_edges(3, vec![(0, 1, 1.0), (1, 2, -1.0)]);
+        assert!(matches!(
+            bellman_ford(&g, 0),
+            Err(GraphError::NegativeCycle { .. })
+        ));
+    }
+
+    #[test]
+    fn bellman_ford_ignores_unreachable_negative_cycles() {
+        let edges = vec![(0, 1, 1.0), (2, 3, -1.0), (3, 2, -1.0)];
+        let g = crate::DGraph::from_weighted_edges(4, edges);
+        let inf = f32::INFINITY;
+        assert_eq!(bellman_ford(&g, 0), Ok(vec![0.0, 1.0, inf, inf]));
+    }
+}
@@ -0,0 +1,130 @@
+use crate::graph::Graph;
+use std::collections::VecDeque;
+
+/// The `BfsTraversal` struct is the result of a breadth-first search: the nodes in the
+/// order they were visited and the number of edges (hops) from the source to each node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BfsTraversal {
+    /// The visited nodes in the order of the search, starting with the source.
+    pub order: Vec<usize>,
+    /// The hop distance from the source to each node, `None` if not visited.
+    pub hops: Vec<Option<usize>>,
+}
+
+/// Given a graph (that implements `Graph`) and a source node, returns the nodes
+/// reachable from the source in breadth-first order and their hop distances, ignoring the
+/// weights of the edges. The neighbors of each node are visited in increasing order.
+///
+/// # Panics
+///
+/// * If the source node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::bfs;
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(5, vec![(0, 2), (0, 1), (1, 3), (2, 3)]);
+/// let traversal = bfs(&g, 0);
+/// assert_eq!(traversal.order, vec![0, 1, 2, 3]);
+/// assert_eq!(traversal.hops, vec![Some(0), Some(1), Some(1), Some(2), None]);
+/// ```
+pub fn bfs<G>(g: &G, src: usize) -> BfsTraversal
+where
+    G: Graph,
+{
+    search(g, src, None, "bfs")
+}
+
+/// Same as `bfs` but stopping as soon as `target` is visited, so only the part of the
+/// graph closer to the source than the target is explored. The target is the last node
+/// of the order if it is reachable.
+///
+/// # Panics
+///
+/// * If some node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::bfs_to;
+/// use graphst::DGraph;
+/// let g = DGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3), (0, 2)]);
+/// let traversal = bfs_to(&g, 0, 2);
+/// assert_eq!(traversal.order, vec![0, 1, 2]);
+/// assert_eq!(traversal.hops[2], Some(1));
+/// assert_eq!(traversal.hops[3], None); // not explored
+/// ```
+pub fn bfs_to<G>(g: &G, src: usize, target: usize) -> BfsTraversal
+where
+    G: Graph,
+{
+    if target >= g.get_n_nodes() {
+        panic!(
+            "[algorithm::bfs_to] Error: The node {} is not valid!",
+            target
+        );
+    }
+    search(g, src, Some(target), "bfs_to")
+}
+
+//--------- Private functions ---------
+
+fn search<G>(g: &G, src: usize, target: Option<usize>, function: &str) -> BfsTraversal
+where
+    G: Graph,
+{
+    if src >= g.get_n_nodes() {
+        panic!(
+            "[algorithm::{}] Error: The node {} is not valid!",
+            function, src
+        );
+    }
+    let mut hops = vec![None; g.get_n_nodes()];
+    let mut order = vec![src];
+    hops[src] = Some(0);
+    let mut queue = VecDeque::from(vec![src]);
+    if target != Some(src) {
+        'search: while let Some(node) = queue.pop_front() {
+            let next_hops = hops[node].map(|h| h + 1);
+            for n in g.get_out_neighbors_of(node) {
+                if hops[n].is_none() {
+                    hops[n] = next_hops;
+                    order.push(n);
+                    if target == Some(n) {
+                        break 'search;
+                    }
+                    queue.push_back(n);
+                }
+            }
+        }
+    }
+    BfsTraversal { order, hops }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DGraph;
+
+    #[test]
+    fn bfs_check_directed_reachability() {
+        let g = DGraph::from_edges(4, vec![(1, 0), (1, 2), (2, 3)]);
+        assert_eq!(bfs(&g, 0).order, vec![0]);
+        assert_eq!(bfs(&g, 1).hops, vec![Some(1), Some(0), Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn bfs_to_check_source_and_unreachable_target() {
+        let g = DGraph::from_edges(3, vec![(0, 1)]);
+        assert_eq!(bfs_to(&g, 0, 0).order, vec![0]);
+        assert_eq!(bfs_to(&g, 0, 2), bfs(&g, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn bfs_panic_not_valid_source() {
+        let g = DGraph::from_edges(2, vec![(0, 1)]);
+        bfs(&g, 2);
+    }
+}
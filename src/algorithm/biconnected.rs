@@ -0,0 +1,155 @@
+use crate::algorithm::{dfs_all, DfsEvent};
+use crate::{Graph, UGraph};
+
+/// Returns the biconnected components (blocks) of an undirected graph as the sets of
+/// their edges. The nodes of a block stay connected after removing any one of them, so a
+/// network whose links are in a single block survives the failure of any single site.
+/// Each edge is `(node1, node2)` with `node1 < node2`, the edges of each block are sorted
+/// and the blocks are sorted by their first edge. A bridge is a block of a single edge,
+/// the nodes without edges are in no block and the self-loops are ignored.
+///
+/// It runs a single depth first search that keeps the low point of each node (the
+/// earliest node reached by a back edge from its subtree) and a stack with the visited
+/// edges. When the subtree of a child can't reach above its parent, the parent is an
+/// articulation point (or the root) and the edges on the stack down to the tree edge form
+/// a block.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::biconnected_components;
+/// use graphst::UGraph;
+/// // Two triangles joined by the bridge 2-3
+/// let edges = vec![(0, 1), (1, 2), (0, 2), (2, 3), (3, 4), (4, 5), (3, 5)];
+/// let blocks = biconnected_components(&UGraph::from_edges(6, edges));
+/// assert_eq!(
+///     blocks,
+///     vec![
+///         vec![(0, 1), (0, 2), (1, 2)],
+///         vec![(2, 3)],
+///         vec![(3, 4), (3, 5), (4, 5)],
+///     ]
+/// );
+/// ```
+pub fn biconnected_components(g: &UGraph) -> Vec<Vec<(usize, usize)>> {
+    let n_nodes = g.get_n_nodes();
+    let mut discovered = vec![0; n_nodes];
+    let mut low = vec![0; n_nodes];
+    let mut parent = vec![None; n_nodes];
+    let mut n_steps = 0;
+    let mut edge_stack = vec![];
+    let mut blocks = vec![];
+    dfs_all(g, |event| match event {
+        DfsEvent::Discover(node) => {
+            discovered[node] = n_steps;
+            low[node] = n_steps;
+            n_steps += 1;
+        }
+        DfsEvent::TreeEdge(node1, node2) => {
+            parent[node2] = Some(node1);
+            edge_stack.push((node1, node2));
+        }
+        DfsEvent::BackEdge(node1, node2) if node1 != node2 => {
+            low[node1] = low[node1].min(discovered[node2]);
+            edge_stack.push((node1, node2));
+        }
+        DfsEvent::Finish(node) => {
+            if let Some(p) = parent[node] {
+                low[p] = low[p].min(low[node]);
+                if low[node] >= discovered[p] {
+                    let mut block = vec![];
+                    while let Some((node1, node2)) = edge_stack.pop() {
+                        block.push((node1.min(node2), node1.max(node2)));
+                        if (node1, node2) == (p, node) {
+                            break;
+                        }
+                    }
+                    block.sort_unstable();
+                    blocks.push(block);
+                }
+            }
+        }
+        _ => {}
+    });
+    blocks.sort_unstable();
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    // Connected, and still connected after removing any node (with 3 or more nodes)
+    fn is_biconnected(edges: &[(usize, usize)]) -> bool {
+        let nodes: BTreeSet<usize> = edges.iter().flat_map(|(a, b)| vec![*a, *b]).collect();
+        let connected_without = |removed: Option<usize>| {
+            let kept: Vec<usize> = nodes
+                .iter()
+                .copied()
+                .filter(|n| Some(*n) != removed)
+                .collect();
+            let mut reached = vec![kept[0]];
+            let mut idx = 0;
+            while idx < reached.len() {
+                for (a, b) in edges {
+                    for (x, y) in [(*a, *b), (*b, *a)] {
+                        if x == reached[idx] && Some(y) != removed && !reached.contains(&y) {
+                            reached.push(y);
+                        }
+                    }
+                }
+                idx += 1;
+            }
+            reached.len() == kept.len()
+        };
+        connected_without(None)
+            && (nodes.len() < 3 || nodes.iter().all(|n| connected_without(Some(*n))))
+    }
+
+    #[test]
+    fn biconnected_components_check_blocks_are_maximal() {
+        let mut rng = crate::rng::Rng::new(33);
+        for _ in 0..40 {
+            let n_nodes = 1 + rng.gen_range(9);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(16) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                adj_mat[node1][node2] = 1.0;
+                adj_mat[node2][node1] = 1.0;
+            }
+            let g = UGraph::from_adjacency_matrix(adj_mat);
+            let blocks = biconnected_components(&g);
+            let mut all_edges: Vec<(usize, usize)> = blocks.concat();
+            all_edges.sort_unstable();
+            let expected: Vec<(usize, usize)> = g
+                .get_edges()
+                .into_iter()
+                .filter(|(a, b, _)| a != b)
+                .map(|(a, b, _)| (a.min(b), a.max(b)))
+                .collect();
+            assert_eq!(all_edges, expected);
+            for (idx, block) in blocks.iter().enumerate() {
+                assert!(is_biconnected(block));
+                for other in &blocks[idx + 1..] {
+                    assert!(!is_biconnected(&[block.clone(), other.clone()].concat()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn biconnected_components_check_articulation_chain() {
+        // A path 0-1-2 and a square 2-3-4-5
+        let edges = vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 2), (6, 6)];
+        let blocks = biconnected_components(&UGraph::from_edges(7, edges));
+        assert_eq!(
+            blocks,
+            vec![
+                vec![(0, 1)],
+                vec![(1, 2)],
+                vec![(2, 3), (2, 5), (3, 4), (4, 5)]
+            ]
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::graph::Graph;
+use crate::UGraph;
+
+/// The `ProjectionMode` enum selects how the edges of a bipartite projection are weighted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProjectionMode {
+    /// The weight is the number of shared neighbors (co-occurrence count).
+    Count,
+    /// The weight is the sum, over the shared neighbors, of the minimum of the two
+    /// edge weights to that neighbor.
+    WeightedOverlap,
+}
+
+/// Given a bipartite graph (that implements `Graph`) and the nodes of one of its sides,
+/// returns the one-mode projection onto that side: two nodes are connected when they
+/// share at least one neighbor. The node `i` of the returned graph is `left_nodes[i]`.
+///
+/// # Arguments
+///
+/// * `g` - A bipartite graph that implements `Graph`.
+/// * `left_nodes` - The nodes of the side to project onto.
+/// * `mode` - How to weight the edges of the projection.
+///
+/// # Panics
+///
+/// * If some node of `left_nodes` is not valid or is repeated.
+/// * If there is an edge between two nodes of `left_nodes`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::{bipartite_projection, ProjectionMode};
+/// use graphst::{Graph, UGraph};
+/// // Authors 0, 1, 2 and papers 3, 4
+/// let edges = vec![(0, 3), (1, 3), (1, 4), (2, 4), (0, 4)];
+/// let g = UGraph::from_edges(5, edges);
+/// let authors = bipartite_projection(&g, &[0, 1, 2], ProjectionMode::Count);
+/// assert_eq!(authors.get_edge(0, 1), Some(2.0)); // two papers together
+/// assert_eq!(authors.get_edge(1, 2), Some(1.0));
+/// ```
+pub fn bipartite_projection<G>(g: &G, left_nodes: &[usize], mode: ProjectionMode) -> UGraph
+where
+    G: Graph,
+{
+    // position: index in the projection of each left node
+    let mut position = vec![None; g.get_n_nodes()];
+    for (idx, node) in left_nodes.iter().enumerate() {
+        if *node >= g.get_n_nodes() || position[*node].is_some() {
+            panic!(
+                "[algorithm::bipartite_projection] Error: The left node {} is not valid!",
+                node
+            );
+        }
+        position[*node] = Some(idx);
+    }
+    for node in left_nodes {
+        if g.get_out_neighbors_of(*node)
+            .iter()
+            .any(|n| position[*n].is_some())
+        {
+            panic!(
+                "[algorithm::bipartite_projection] Error: The node {} is connected to \
+                 another left node, the graph is not bipartite!",
+                node
+            );
+        }
+    }
+    let n_left = left_nodes.len();
+    let mut adj_mat = vec![vec![0.0; n_left]; n_left];
+    for right in g.get_nodes().into_iter().filter(|n| position[*n].is_none()) {
+        // Every pair of left neighbors of a right node shares that node
+        let neighbors: Vec<(usize, f32)> = g
+            .get_out_neighbors_of(right)
+            .into_iter()
+            .filter_map(|n| {
+                let w = g.get_edge(right, n)?;
+                position[n].map(|idx| (idx, w))
+            })
+            .collect();
+        for (i, (idx1, w1)) in neighbors.iter().enumerate() {
+            for (idx2, w2) in &neighbors[i + 1..] {
+                let increment = match mode {
+                    ProjectionMode::Count => 1.0,
+                    ProjectionMode::WeightedOverlap => w1.min(*w2),
+                };
+                adj_mat[*idx1][*idx2] += increment;
+                adj_mat[*idx2][*idx1] += increment;
+            }
+        }
+    }
+    UGraph::from_adjacency_matrix(adj_mat)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bipartite_projection_check_weighted_overlap() {
+        // Users 0, 1 and items 2, 3
+        let edges = vec![(0, 2, 5.0), (1, 2, 2.0), (0, 3, 1.0), (1, 3, 4.0)];
+        let g = UGraph::from_weighted_edges(4, edges);
+        let users = bipartite_projection(&g, &[1, 0], ProjectionMode::WeightedOverlap);
+        assert_eq!(users.get_edge(0, 1), Some(3.0)); // min(5, 2) + min(1, 4)
+        assert_eq!(users.get_n_nodes(), 2);
+    }
+
+    #[test]
+    fn bipartite_projection_check_isolated_left_node() {
+        let g = UGraph::from_edges(4, vec![(0, 3), (1, 3)]);
+        let left = bipartite_projection(&g, &[0, 1, 2], ProjectionMode::Count);
+        assert_eq!(left.get_neighbors_of(2), vec![]);
+        assert_eq!(left.get_neighbors_of(0), vec![1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not bipartite")]
+    fn bipartite_projection_panic_not_bipartite() {
+        let g = UGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+        bipartite_projection(&g, &[0, 1], ProjectionMode::Count);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn bipartite_projection_panic_repeated_left_node() {
+        let g = UGraph::from_edges(3, vec![(0, 2)]);
+        bipartite_projection(&g, &[0, 0], ProjectionMode::Count);
+    }
+}
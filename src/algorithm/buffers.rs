@@ -0,0 +1,86 @@
+use crate::float::FloatOrd;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The `SearchBuffers` struct holds the scratch storage used by the search algorithms
+/// (distances, visited flags, priority queue...). It can be reused between queries to avoid allocating
+/// new buffers each time, which matters when running millions of queries.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::{dijkstra_with_buffers, SearchBuffers};
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+/// let mut buffers = SearchBuffers::new();
+/// for src in 0..3 {
+///     let dist = dijkstra_with_buffers(&g, src, &mut buffers);
+///     assert_eq!(dist[src], 0.0);
+/// }
+/// ```
+pub struct SearchBuffers {
+    pub(crate) dist: Vec<f32>,
+    pub(crate) visited: Vec<bool>,
+    pub(crate) heap: BinaryHeap<Reverse<(FloatOrd, usize)>>,
+}
+
+impl SearchBuffers {
+    /// Creates an empty `SearchBuffers`. The buffers grow on the first query.
+    pub fn new() -> SearchBuffers {
+        SearchBuffers {
+            dist: vec![],
+            visited: vec![],
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    /// Creates a `SearchBuffers` with space for graphs of up to `n_nodes` nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_nodes` - An `usize` value with the number of nodes to reserve space for.
+    pub fn with_capacity(n_nodes: usize) -> SearchBuffers {
+        SearchBuffers {
+            dist: Vec::with_capacity(n_nodes),
+            visited: Vec::with_capacity(n_nodes),
+            heap: BinaryHeap::with_capacity(n_nodes),
+        }
+    }
+
+    /// Returns the distances computed in the last query.
+    pub fn get_distances(&self) -> &[f32] {
+        &self.dist
+    }
+
+    // Prepares the buffers for a new query on a graph with `n_nodes` nodes
+    pub(crate) fn reset(&mut self, n_nodes: usize) {
+        self.dist.clear();
+        self.dist.resize(n_nodes, f32::INFINITY);
+        self.visited.clear();
+        self.visited.resize(n_nodes, false);
+        self.heap.clear();
+    }
+}
+
+impl Default for SearchBuffers {
+    /// Creates an empty `SearchBuffers`, same as `SearchBuffers::new()`.
+    fn default() -> SearchBuffers {
+        SearchBuffers::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SearchBuffers;
+
+    #[test]
+    fn reset_check_status() {
+        let mut buffers = SearchBuffers::with_capacity(2);
+        buffers.reset(3);
+        buffers.dist[1] = 2.0;
+        buffers.visited[1] = true;
+        buffers.reset(2);
+        assert_eq!(buffers.dist, vec![f32::INFINITY; 2]);
+        assert_eq!(buffers.visited, vec![false; 2]);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::graph::Graph;
+use crate::UGraph;
+
+/// Returns a perfect elimination ordering of the graph if it is chordal (every cycle of
+/// four or more nodes has a chord), or `None` otherwise. In a perfect elimination
+/// ordering, the neighbors of each node that come after it in the ordering form a
+/// clique. The ordering is the reverse of a lexicographic breadth-first search (Lex-BFS),
+/// which is a perfect elimination ordering exactly when the graph is chordal. The Lex-BFS
+/// uses partition refinement, so it runs in `O(n + m)` plus the neighbor queries of the
+/// graph. Self-loops are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::is_chordal;
+/// use graphst::UGraph;
+/// // Two triangles sharing the edge 1-2
+/// let g = UGraph::from_edges(4, vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)]);
+/// assert!(is_chordal(&g).is_some());
+/// // A square without diagonals
+/// let g = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// assert_eq!(is_chordal(&g), None);
+/// ```
+pub fn is_chordal(g: &UGraph) -> Option<Vec<usize>> {
+    let neighbors = neighbor_lists(g);
+    let mut ordering = lex_bfs(&neighbors);
+    ordering.reverse();
+    let mut position = vec![0; ordering.len()];
+    for (pos, node) in ordering.iter().enumerate() {
+        position[*node] = pos;
+    }
+    for node in &ordering {
+        // The later neighbors must be adjacent to the first of them
+        let later: Vec<usize> = neighbors[*node]
+            .iter()
+            .copied()
+            .filter(|n| position[*n] > position[*node])
+            .collect();
+        if let Some(first) = later.iter().min_by_key(|n| position[**n]) {
+            let is_clique = later
+                .iter()
+                .all(|n| n == first || g.get_edge(*first, *n).is_some());
+            if !is_clique {
+                return None;
+            }
+        }
+    }
+    Some(ordering)
+}
+
+/// Returns a maximum clique of a chordal graph, sorted, or `None` if the graph is not
+/// chordal. It runs in polynomial time (the problem is NP-hard for general graphs): the
+/// maximal cliques of a chordal graph are among the sets formed by each node and its
+/// later neighbors in a perfect elimination ordering.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::chordal_max_clique;
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(5, vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (0, 3), (3, 4)]);
+/// assert_eq!(chordal_max_clique(&g), Some(vec![0, 1, 2, 3]));
+/// ```
+pub fn chordal_max_clique(g: &UGraph) -> Option<Vec<usize>> {
+    let ordering = is_chordal(g)?;
+    let neighbors = neighbor_lists(g);
+    let mut position = vec![0; ordering.len()];
+    for (pos, node) in ordering.iter().enumerate() {
+        position[*node] = pos;
+    }
+    let mut clique: Vec<usize> = ordering
+        .iter()
+        .map(|node| {
+            let mut clique = vec![*node];
+            clique.extend(
+                neighbors[*node]
+                    .iter()
+                    .filter(|n| position[**n] > position[*node]),
+            );
+            clique
+        })
+        .max_by_key(|clique| clique.len())
+        .unwrap_or_default();
+    clique.sort_unstable();
+    Some(clique)
+}
+
+/// Returns an optimal node coloring of a chordal graph (the color of each node, with
+/// the colors from `0`), or `None` if the graph is not chordal. The nodes are colored
+/// greedily in the reverse of a perfect elimination ordering, which uses as many colors
+/// as the size of the maximum clique.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::chordal_coloring;
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(4, vec![(0, 1), (0, 2), (1, 2), (2, 3)]);
+/// let colors = chordal_coloring(&g).unwrap();
+/// assert_eq!(colors.iter().max(), Some(&2)); // 3 colors for the triangle
+/// assert_ne!(colors[2], colors[3]);
+/// ```
+pub fn chordal_coloring(g: &UGraph) -> Option<Vec<usize>> {
+    let ordering = is_chordal(g)?;
+    let neighbors = neighbor_lists(g);
+    let mut colors: Vec<Option<usize>> = vec![None; ordering.len()];
+    for node in ordering.iter().rev() {
+        let used: Vec<usize> = neighbors[*node].iter().filter_map(|n| colors[*n]).collect();
+        colors[*node] = (0..).find(|c| !used.contains(c));
+    }
+    Some(colors.into_iter().map(|c| c.unwrap()).collect())
+}
+
+//--------- Private functions ---------
+
+fn neighbor_lists(g: &UGraph) -> Vec<Vec<usize>> {
+    g.get_nodes()
+        .into_iter()
+        .map(|node| {
+            g.get_neighbors_of(node)
+                .into_iter()
+                .filter(|n| *n != node)
+                .collect()
+        })
+        .collect()
+}
+
+// Returns the nodes in Lex-BFS order: each step visits the unvisited node with the
+// lexicographically largest label, where the label lists the visit steps of its visited
+// neighbors. Instead of building the labels, the unvisited nodes are kept in classes of
+// equal label sorted by decreasing label (partition refinement): visiting a node moves
+// its unvisited neighbors of each class to a new class right before it. Every visit
+// costs the degree of the node, so it runs in O(n + m)
+fn lex_bfs(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let n_nodes = neighbors.len();
+    // order: The nodes by class, the visited ones first. position: Of each node in it
+    let mut order: Vec<usize> = (0..n_nodes).collect();
+    let mut position: Vec<usize> = (0..n_nodes).collect();
+    // The range of positions [start, end) of each class and the class of each node
+    let mut classes = vec![(0, n_nodes)];
+    let mut class_of = vec![0; n_nodes];
+    // The class created from each class in the current step, if any, and that step
+    let mut split: Vec<Option<(usize, usize)>> = vec![None];
+    for step in 0..n_nodes {
+        // The first unvisited node is the first node of the first class
+        let node = order[step];
+        classes[class_of[node]].0 += 1;
+        for n in &neighbors[node] {
+            if position[*n] <= step {
+                continue; // already visited
+            }
+            let class = class_of[*n];
+            let new_class = match split[class] {
+                Some((split_step, new_class)) if split_step == step => new_class,
+                _ => {
+                    let start = classes[class].0;
+                    classes.push((start, start));
+                    split.push(None);
+                    split[class] = Some((step, classes.len() - 1));
+                    classes.len() - 1
+                }
+            };
+            // Swap the neighbor to the front of its class and move the boundary
+            let front = classes[class].0;
+            let other = order[front];
+            order.swap(position[*n], front);
+            position[other] = position[*n];
+            position[*n] = front;
+            classes[class].0 += 1;
+            classes[new_class].1 += 1;
+            class_of[*n] = new_class;
+        }
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_chordal_check_cycles_and_trees() {
+        let cycle5 = UGraph::from_edges(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+        assert_eq!(is_chordal(&cycle5), None);
+        let tree = UGraph::from_edges(6, vec![(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)]);
+        let ordering = is_chordal(&tree).unwrap();
+        let mut sorted = ordering.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(chordal_coloring(&tree).unwrap().iter().max(), Some(&1));
+    }
+
+    #[test]
+    fn is_chordal_check_big_cycles_and_triangulations() {
+        let n_nodes = 200;
+        let mut edges: Vec<(usize, usize)> = (0..n_nodes).map(|n| (n, (n + 1) % n_nodes)).collect();
+        assert_eq!(
+            is_chordal(&UGraph::from_edges(n_nodes, edges.clone())),
+            None
+        );
+        // The chords from the node 0 triangulate the cycle
+        edges.extend((2..n_nodes - 1).map(|n| (0, n)));
+        let g = UGraph::from_edges(n_nodes, edges);
+        let ordering = is_chordal(&g).unwrap();
+        assert_eq!(ordering.len(), n_nodes);
+        assert_eq!(chordal_max_clique(&g).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn chordal_functions_check_not_chordal() {
+        let g = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3), (3, 0)]);
+        assert_eq!(chordal_max_clique(&g), None);
+        assert_eq!(chordal_coloring(&g), None);
+    }
+
+    #[test]
+    fn chordal_coloring_uses_max_clique_colors() {
+        // A fan: the node 0 connected to the path 1-2-3-4-5
+        let mut edges = vec![(1, 2), (2, 3), (3, 4), (4, 5)];
+        edges.extend((1..6).map(|n| (0, n)));
+        let g = UGraph::from_edges(6, edges);
+        let colors = chordal_coloring(&g).unwrap();
+        for (node1, node2, _) in g.get_edges() {
+            assert_ne!(colors[node1], colors[node2]);
+        }
+        assert_eq!(chordal_max_clique(&g).unwrap().len(), 3);
+        assert_eq!(colors.iter().max(), Some(&2));
+    }
+}
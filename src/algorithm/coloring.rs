@@ -0,0 +1,216 @@
+use crate::graph::Graph;
+use crate::UGraph;
+
+// Marks a missing neighbor or color
+const NONE: usize = usize::MAX;
+
+/// Returns a proper edge coloring of the undirected graph, where the edges that share a
+/// node get different colors, using at most `max_degree + 1` colors (Vizing's theorem).
+/// The colors are returned as `(node1, node2, color)` triplets in the order of
+/// `get_edges`, with the colors in the range `[0, max_degree]`. Each color class is a
+/// matching, so the edges of the same color can be processed in the same round.
+/// Self-loops can't be colored, so they are ignored.
+///
+/// The coloring is built with the algorithm of Misra and Gries, in `O(n * m)`.
+///
+/// # Arguments
+///
+/// * `g` - An undirected graph.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::edge_coloring;
+/// use graphst::UGraph;
+/// // A triangle needs 3 colors
+/// let g = UGraph::from_edges(3, vec![(0, 1), (1, 2), (0, 2)]);
+/// let colors = edge_coloring(&g);
+/// let mut used: Vec<usize> = colors.iter().map(|(_, _, c)| *c).collect();
+/// used.sort();
+/// assert_eq!(used, vec![0, 1, 2]);
+/// ```
+pub fn edge_coloring(g: &UGraph) -> Vec<(usize, usize, usize)> {
+    let n_nodes = g.get_n_nodes();
+    let edges: Vec<(usize, usize)> = g
+        .get_edges()
+        .into_iter()
+        .filter(|(node1, node2, _)| node1 != node2)
+        .map(|(node1, node2, _)| (node1, node2))
+        .collect();
+    let mut degrees = vec![0; n_nodes];
+    for (node1, node2) in &edges {
+        degrees[*node1] += 1;
+        degrees[*node2] += 1;
+    }
+    let n_colors = degrees.iter().max().map_or(0, |d| d + 1);
+    let mut coloring = Coloring {
+        at: vec![vec![NONE; n_colors]; n_nodes],
+        color: vec![vec![NONE; n_nodes]; n_nodes],
+    };
+    for (u, v) in &edges {
+        coloring.color_edge(*u, *v);
+    }
+    edges
+        .into_iter()
+        .map(|(node1, node2)| (node1, node2, coloring.color[node1][node2]))
+        .collect()
+}
+
+//--------- Private functions ---------
+
+// Partial edge coloring: `at[node][c]` is the neighbor joined to `node` by the edge of
+// color `c` and `color[node1][node2]` the color of the edge
+struct Coloring {
+    at: Vec<Vec<usize>>,
+    color: Vec<Vec<usize>>,
+}
+
+impl Coloring {
+    fn is_free(&self, node: usize, c: usize) -> bool {
+        self.at[node][c] == NONE
+    }
+
+    fn free_color(&self, node: usize) -> usize {
+        (0..self.at[node].len())
+            .find(|c| self.is_free(node, *c))
+            .unwrap() // the degree is lower than the number of colors
+    }
+
+    fn set(&mut self, node1: usize, node2: usize, c: usize) {
+        self.at[node1][c] = node2;
+        self.at[node2][c] = node1;
+        self.color[node1][node2] = c;
+        self.color[node2][node1] = c;
+    }
+
+    fn unset(&mut self, node1: usize, node2: usize) {
+        let c = self.color[node1][node2];
+        if c != NONE {
+            self.at[node1][c] = NONE;
+            self.at[node2][c] = NONE;
+            self.color[node1][node2] = NONE;
+            self.color[node2][node1] = NONE;
+        }
+    }
+
+    // Colors the uncolored edge `u`-`v` without exceeding the available colors
+    fn color_edge(&mut self, u: usize, v: usize) {
+        // Maximal fan of `u` starting at `v`: the edge to each node of the fan has a color
+        // that is free on the previous node
+        let mut fan = vec![v];
+        loop {
+            let last = fan[fan.len() - 1];
+            let next = (0..self.at[u].len())
+                .filter(|c| self.is_free(last, *c))
+                .map(|c| self.at[u][c])
+                .find(|w| *w != NONE && !fan.contains(w));
+            match next {
+                Some(w) => fan.push(w),
+                None => break,
+            }
+        }
+        let c = self.free_color(u);
+        let d = self.free_color(fan[fan.len() - 1]);
+        // Invert the path from `u` with the colors `d` and `c`, so that `d` is free on `u`
+        let mut path = vec![];
+        let (mut node, mut current) = (u, d);
+        while self.at[node][current] != NONE {
+            let next = self.at[node][current];
+            path.push((node, next, current));
+            node = next;
+            current = if current == d { c } else { d };
+        }
+        for (node1, node2, _) in &path {
+            self.unset(*node1, *node2);
+        }
+        for (node1, node2, old) in path {
+            self.set(node1, node2, if old == d { c } else { d });
+        }
+        // Find the first node of the fan (still a fan after the inversion) where `d` is
+        // free, rotate the fan up to it and color its edge with `d`
+        let mut end = 0;
+        for (idx, w) in fan.iter().enumerate() {
+            if idx > 0 {
+                let prev_edge_color = self.color[u][*w];
+                if prev_edge_color == NONE || !self.is_free(fan[idx - 1], prev_edge_color) {
+                    break; // the prefix is no longer a fan
+                }
+            }
+            if self.is_free(*w, d) {
+                end = idx;
+                break;
+            }
+        }
+        let shifted: Vec<usize> = (1..=end).map(|idx| self.color[u][fan[idx]]).collect();
+        for w in &fan[1..=end] {
+            self.unset(u, *w);
+        }
+        for (idx, c) in shifted.into_iter().enumerate() {
+            self.set(u, fan[idx], c);
+        }
+        self.set(u, fan[end], d);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    fn check_proper(g: &UGraph, colors: &[(usize, usize, usize)]) {
+        let max_degree = g
+            .get_nodes()
+            .into_iter()
+            .map(|n| g.get_neighbors_of(n).len())
+            .max()
+            .unwrap_or(0);
+        for (idx, (a1, b1, c1)) in colors.iter().enumerate() {
+            assert!(*c1 <= max_degree);
+            for (a2, b2, c2) in &colors[idx + 1..] {
+                let share_node = a1 == a2 || a1 == b2 || b1 == a2 || b1 == b2;
+                assert!(!share_node || c1 != c2);
+            }
+        }
+    }
+
+    #[test]
+    fn edge_coloring_check_random_graphs() {
+        let mut rng = Rng::new(11);
+        for _ in 0..100 {
+            let n_nodes = 2 + rng.gen_range(12);
+            let mut g = UGraph::from_adjacency_matrix(vec![vec![0.0; n_nodes]; n_nodes]);
+            for node1 in 0..n_nodes {
+                for node2 in node1 + 1..n_nodes {
+                    if rng.gen_range(3) > 0 {
+                        g.add_edge(node1, node2);
+                    }
+                }
+            }
+            let colors = edge_coloring(&g);
+            assert_eq!(colors.len(), g.get_edges().len());
+            check_proper(&g, &colors);
+        }
+    }
+
+    #[test]
+    fn edge_coloring_check_petersen_graph() {
+        // The Petersen graph is 3-regular and needs 4 colors
+        let mut edges = vec![];
+        for i in 0..5 {
+            edges.push((i, (i + 1) % 5));
+            edges.push((i, i + 5));
+            edges.push((i + 5, (i + 2) % 5 + 5));
+        }
+        let g = UGraph::from_edges(10, edges);
+        let colors = edge_coloring(&g);
+        check_proper(&g, &colors);
+        let n_colors = colors.iter().map(|(_, _, c)| c).max().unwrap() + 1;
+        assert_eq!(n_colors, 4);
+    }
+
+    #[test]
+    fn edge_coloring_ignores_self_loops() {
+        let g = UGraph::from_edges(2, vec![(0, 0), (0, 1)]);
+        assert_eq!(edge_coloring(&g), vec![(0, 1, 0)]);
+    }
+}
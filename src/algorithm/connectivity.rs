@@ -0,0 +1,160 @@
+use crate::algorithm::disjoint_paths::{edge_network, split_network};
+use crate::algorithm::max_flow;
+use crate::graph::Graph;
+
+/// Returns the edge connectivity of the graph: the minimum number of edges that have to
+/// be removed to disconnect it (for directed graphs, to make it not strongly connected).
+/// It is computed with `n - 1` maximum flows from the node `0` (twice for directed
+/// graphs). Graphs with less than two nodes have connectivity `0`. The weights of the
+/// edges are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::edge_connectivity;
+/// use graphst::UGraph;
+/// let cycle = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// assert_eq!(edge_connectivity(&cycle), 2);
+/// let path = UGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+/// assert_eq!(edge_connectivity(&path), 1);
+/// ```
+pub fn edge_connectivity<G>(g: &G) -> usize
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if n_nodes < 2 {
+        return 0;
+    }
+    let net = edge_network(g);
+    let mut connectivity = usize::MAX;
+    for node in 1..n_nodes {
+        let mut pairs = vec![(0, node)];
+        if g.is_directed() {
+            pairs.push((node, 0));
+        }
+        for (src, dest) in pairs {
+            let mut net = net.clone();
+            connectivity = connectivity.min(max_flow(&mut net, src, dest).round() as usize);
+            if connectivity == 0 {
+                return 0;
+            }
+        }
+    }
+    connectivity
+}
+
+/// Returns the vertex connectivity of the graph: the minimum number of nodes that have to
+/// be removed to disconnect it (for directed graphs, to make it not strongly connected).
+/// Complete graphs can't be disconnected, so their connectivity is `n - 1`. It is
+/// computed with a maximum flow over the split nodes for each pair of non-adjacent nodes.
+/// Graphs with less than two nodes have connectivity `0`. The weights of the edges are
+/// ignored.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::vertex_connectivity;
+/// use graphst::UGraph;
+/// // Two triangles sharing the node 2
+/// let g = UGraph::from_edges(5, vec![(0, 1), (1, 2), (0, 2), (2, 3), (3, 4), (2, 4)]);
+/// assert_eq!(vertex_connectivity(&g), 1);
+/// let k4 = UGraph::from_edges(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+/// assert_eq!(vertex_connectivity(&k4), 3);
+/// ```
+pub fn vertex_connectivity<G>(g: &G) -> usize
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if n_nodes < 2 {
+        return 0;
+    }
+    let mut connectivity = n_nodes - 1; // value for complete graphs
+    for src in 0..n_nodes {
+        for dest in 0..n_nodes {
+            let skip = src == dest || (!g.is_directed() && dest < src);
+            if skip || g.get_edge(src, dest).is_some() {
+                continue;
+            }
+            // The output of `src` to the input of `dest`, the terminals can't be cut
+            let mut net = split_network(g, src, dest, n_nodes as f32);
+            let flow = max_flow(&mut net, src + n_nodes, dest).round() as usize;
+            connectivity = connectivity.min(flow);
+            if connectivity == 0 {
+                return 0;
+            }
+        }
+    }
+    connectivity
+}
+
+/// Returns `true` if the graph is `k`-vertex-connected: it has more than `k` nodes and it
+/// stays connected after removing any `k - 1` nodes.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::is_k_connected;
+/// use graphst::UGraph;
+/// let cycle = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// assert!(is_k_connected(&cycle, 2));
+/// assert!(!is_k_connected(&cycle, 3));
+/// ```
+pub fn is_k_connected<G>(g: &G, k: usize) -> bool
+where
+    G: Graph,
+{
+    g.get_n_nodes() > k && vertex_connectivity(g) >= k
+}
+
+/// Returns `true` if the graph is `k`-edge-connected: it has at least two nodes and it
+/// stays connected after removing any `k - 1` edges.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::is_k_edge_connected;
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(5, vec![(0, 1), (1, 2), (0, 2), (2, 3), (3, 4), (2, 4)]);
+/// assert!(is_k_edge_connected(&g, 2));
+/// ```
+pub fn is_k_edge_connected<G>(g: &G, k: usize) -> bool
+where
+    G: Graph,
+{
+    g.get_n_nodes() > 1 && edge_connectivity(g) >= k
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DGraph, UGraph};
+
+    #[test]
+    fn edge_connectivity_check_directed() {
+        // A directed cycle is strongly connected, but one edge breaks it
+        let cycle = DGraph::from_edges(3, vec![(0, 1), (1, 2), (2, 0)]);
+        assert_eq!(edge_connectivity(&cycle), 1);
+        let path = DGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+        assert_eq!(edge_connectivity(&path), 0);
+    }
+
+    #[test]
+    fn connectivity_check_disconnected_and_small_graphs() {
+        let g = UGraph::from_edges(4, vec![(0, 1), (2, 3)]);
+        assert_eq!(edge_connectivity(&g), 0);
+        assert_eq!(vertex_connectivity(&g), 0);
+        assert_eq!(vertex_connectivity(&UGraph::from_edges(1, vec![])), 0);
+        assert!(!is_k_connected(&UGraph::from_edges(2, vec![(0, 1)]), 2));
+    }
+
+    #[test]
+    fn vertex_connectivity_check_bipartite() {
+        // K(2,3): removing the two nodes of one side disconnects it
+        let edges = vec![(0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4)];
+        let g = UGraph::from_edges(5, edges);
+        assert_eq!(vertex_connectivity(&g), 2);
+        assert_eq!(edge_connectivity(&g), 2);
+    }
+}
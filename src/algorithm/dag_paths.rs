@@ -0,0 +1,285 @@
+use crate::algorithm::resolve_dependencies;
+use crate::{DagGraph, Graph};
+
+/// Given a directed acyclic graph and a source node, returns the shortest path to each
+/// node from the source. The nodes are relaxed once in topological order, so it runs in
+/// linear time and, unlike `dijkstra`, supports negative weights. The unreachable nodes
+/// get `f32::INFINITY`.
+///
+/// # Panics
+///
+/// * If the source node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::dag_shortest_paths;
+/// use graphst::DagGraph;
+/// let dag = DagGraph::from_weighted_edges(4, vec![(0, 1, 2.0), (1, 2, -3.0), (0, 2, 1.0)]).unwrap();
+/// assert_eq!(dag_shortest_paths(&dag, 0), vec![0.0, 2.0, -1.0, f32::INFINITY]);
+/// ```
+pub fn dag_shortest_paths(g: &DagGraph, src: usize) -> Vec<f32> {
+    relax_in_order(g, src, "dag_shortest_paths", |new, old| new < old)
+}
+
+/// Given a directed acyclic graph and a source node, returns the longest path to each node
+/// from the source, processing the nodes in topological order in linear time. The
+/// unreachable nodes get `f32::NEG_INFINITY`.
+///
+/// # Panics
+///
+/// * If the source node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::dag_longest_paths;
+/// use graphst::DagGraph;
+/// let dag = DagGraph::from_weighted_edges(4, vec![(0, 1, 2.0), (1, 2, 3.0), (0, 2, 1.0)]).unwrap();
+/// assert_eq!(dag_longest_paths(&dag, 0), vec![0.0, 2.0, 5.0, f32::NEG_INFINITY]);
+/// ```
+pub fn dag_longest_paths(g: &DagGraph, src: usize) -> Vec<f32> {
+    relax_in_order(g, src, "dag_longest_paths", |new, old| new > old)
+}
+
+/// Returns the critical path of a directed acyclic graph: the path with the largest total
+/// weight between any pair of nodes, as the sequence of its nodes and its total weight.
+/// When the nodes are tasks and each edge weight is the duration of the task at its
+/// source, it is the chain of tasks that determines the length of the schedule. The
+/// path is a single node with weight `0.0` if the graph has no positive path, and empty
+/// if the graph has no nodes.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::critical_path;
+/// use graphst::DagGraph;
+/// // 0: design (3 days), 1: backend (5 days), 2: frontend (2 days), 3: release
+/// let edges = vec![(0, 1, 3.0), (0, 2, 3.0), (1, 3, 5.0), (2, 3, 2.0)];
+/// let dag = DagGraph::from_weighted_edges(4, edges).unwrap();
+/// assert_eq!(critical_path(&dag), (vec![0, 1, 3], 8.0));
+/// ```
+pub fn critical_path(g: &DagGraph) -> (Vec<usize>, f32) {
+    let dgraph = g.get_graph();
+    let n_nodes = dgraph.get_n_nodes();
+    if n_nodes == 0 {
+        return (vec![], 0.0);
+    }
+    // Longest path ending at each node, starting anywhere (every node is a source)
+    let mut dist = vec![0.0; n_nodes];
+    let mut predecessors = vec![None; n_nodes];
+    for node in topological_order(g) {
+        for n in dgraph.get_successors_of(node) {
+            let new_dist = dist[node] + dgraph.get_edge(node, n).unwrap_or(0.0);
+            if new_dist > dist[n] {
+                dist[n] = new_dist;
+                predecessors[n] = Some(node);
+            }
+        }
+    }
+    let mut last = 0;
+    for node in 1..n_nodes {
+        if dist[node] > dist[last] {
+            last = node;
+        }
+    }
+    let mut path = vec![last];
+    while let Some(prev) = predecessors[path[path.len() - 1]] {
+        path.push(prev);
+    }
+    path.reverse();
+    (path, dist[last])
+}
+
+/// The `CriticalPathSchedule` struct is the result of `critical_path_schedule`, with the
+/// times of each node (task) indexed by node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CriticalPathSchedule {
+    /// The earliest time each task can start, after all its predecessors.
+    pub earliest_start: Vec<f32>,
+    /// The latest time each task can start without delaying the end of the project.
+    pub latest_start: Vec<f32>,
+    /// The time each task can be delayed without delaying the project (its slack):
+    /// `latest_start - earliest_start`. The tasks of the critical path have `0.0`.
+    pub total_float: Vec<f32>,
+    /// The critical path, the same as the one of `critical_path`.
+    pub critical_path: Vec<usize>,
+    /// The length of the project, the total weight of the critical path.
+    pub duration: f32,
+}
+
+/// Returns the schedule of the Critical Path Method (CPM) of a directed acyclic graph of
+/// tasks, where each edge weight is the duration of the task at its source (as in
+/// `critical_path`). The earliest starts are computed forwards in topological order and
+/// the latest starts backwards from the end of the project, so the tasks with no float
+/// are the ones that can't be delayed.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::critical_path_schedule;
+/// use graphst::DagGraph;
+/// // 0: design (3 days), 1: backend (5 days), 2: frontend (2 days), 3: release
+/// let edges = vec![(0, 1, 3.0), (0, 2, 3.0), (1, 3, 5.0), (2, 3, 2.0)];
+/// let dag = DagGraph::from_weighted_edges(4, edges).unwrap();
+/// let schedule = critical_path_schedule(&dag);
+/// assert_eq!(schedule.earliest_start, vec![0.0, 3.0, 3.0, 8.0]);
+/// assert_eq!(schedule.latest_start, vec![0.0, 3.0, 6.0, 8.0]);
+/// assert_eq!(schedule.total_float, vec![0.0, 0.0, 3.0, 0.0]); // The frontend can wait 3 days
+/// assert_eq!((schedule.critical_path, schedule.duration), (vec![0, 1, 3], 8.0));
+/// ```
+pub fn critical_path_schedule(g: &DagGraph) -> CriticalPathSchedule {
+    let dgraph = g.get_graph();
+    let order = topological_order(g);
+    let mut earliest_start = vec![0.0f32; dgraph.get_n_nodes()];
+    for node in &order {
+        for n in dgraph.get_successors_of(*node) {
+            let end = earliest_start[*node] + dgraph.get_edge(*node, n).unwrap_or(0.0);
+            earliest_start[n] = earliest_start[n].max(end);
+        }
+    }
+    let (critical_path, duration) = critical_path(g);
+    // Every task must start early enough for its successors, and the rest can wait until
+    // the end of the project
+    let mut latest_start = vec![duration; dgraph.get_n_nodes()];
+    for node in order.iter().rev() {
+        for n in dgraph.get_successors_of(*node) {
+            let start = latest_start[n] - dgraph.get_edge(*node, n).unwrap_or(0.0);
+            latest_start[*node] = latest_start[*node].min(start);
+        }
+    }
+    let total_float = latest_start
+        .iter()
+        .zip(&earliest_start)
+        .map(|(latest, earliest)| latest - earliest)
+        .collect();
+    CriticalPathSchedule {
+        earliest_start,
+        latest_start,
+        total_float,
+        critical_path,
+        duration,
+    }
+}
+
+//--------- Private functions ---------
+
+fn topological_order(g: &DagGraph) -> Vec<usize> {
+    resolve_dependencies(g.get_graph())
+        .expect("A DagGraph can't have cycles")
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+// Relaxes the edges of the nodes reachable from `src` in topological order, keeping the
+// distances that are `better` than the current ones
+fn relax_in_order<F>(g: &DagGraph, src: usize, caller: &str, better: F) -> Vec<f32>
+where
+    F: Fn(f32, f32) -> bool,
+{
+    let dgraph = g.get_graph();
+    let n_nodes = dgraph.get_n_nodes();
+    if src >= n_nodes {
+        panic!(
+            "[algorithm::{}] Error: The node {} is not valid!",
+            caller, src
+        );
+    }
+    let mut dist: Vec<Option<f32>> = vec![None; n_nodes];
+    dist[src] = Some(0.0);
+    for node in topological_order(g) {
+        let node_dist = match dist[node] {
+            Some(d) => d,
+            None => continue, // Not reachable from the source
+        };
+        for n in dgraph.get_successors_of(node) {
+            let new_dist = node_dist + dgraph.get_edge(node, n).unwrap_or(0.0);
+            if dist[n].map_or(true, |old| better(new_dist, old)) {
+                dist[n] = Some(new_dist);
+            }
+        }
+    }
+    // The unreachable nodes get the worst value of the comparison
+    let unreachable = if better(0.0, f32::INFINITY) {
+        f32::INFINITY
+    } else {
+        f32::NEG_INFINITY
+    };
+    dist.into_iter().map(|d| d.unwrap_or(unreachable)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::bellman_ford;
+
+    #[test]
+    fn dag_shortest_paths_matches_bellman_ford() {
+        let mut rng = crate::rng::Rng::new(8);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(12);
+            let mut dag = DagGraph::with_nodes(n_nodes);
+            for _ in 0..rng.gen_range(30) {
+                // Edges from lower to higher nodes can't create cycles
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                if node1 < node2 {
+                    let weight = rng.gen_range(10) as f32 - 3.0;
+                    dag.add_weighted_edge(node1, node2, weight).unwrap();
+                }
+            }
+            let src = rng.gen_range(n_nodes);
+            let expected = bellman_ford(dag.get_graph(), src).unwrap();
+            assert_eq!(dag_shortest_paths(&dag, src), expected);
+        }
+    }
+
+    #[test]
+    fn critical_path_check_values() {
+        let edges = vec![
+            (3, 1, 2.0),
+            (1, 0, 4.0),
+            (3, 2, 1.0),
+            (2, 0, 1.0),
+            (4, 0, 6.5),
+        ];
+        let dag = DagGraph::from_weighted_edges(5, edges).unwrap();
+        assert_eq!(critical_path(&dag), (vec![4, 0], 6.5));
+        assert_eq!(dag_longest_paths(&dag, 3)[0], 6.0);
+        assert_eq!(critical_path(&DagGraph::with_nodes(2)), (vec![0], 0.0));
+        assert_eq!(critical_path(&DagGraph::new()), (vec![], 0.0));
+    }
+
+    #[test]
+    fn critical_path_schedule_check_float() {
+        let mut rng = crate::rng::Rng::new(25);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(12);
+            let mut dag = DagGraph::with_nodes(n_nodes);
+            for _ in 0..rng.gen_range(30) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                if node1 < node2 {
+                    dag.add_weighted_edge(node1, node2, 1.0 + rng.gen_range(5) as f32)
+                        .unwrap();
+                }
+            }
+            let schedule = critical_path_schedule(&dag);
+            for node in &schedule.critical_path {
+                assert_eq!(schedule.total_float[*node], 0.0);
+            }
+            // Every edge fits between the earliest start of its source and the latest
+            // start of its destination
+            for (src, dest, weight) in dag.get_graph().get_edges() {
+                assert!(schedule.earliest_start[src] + weight <= schedule.earliest_start[dest]);
+                assert!(schedule.latest_start[src] + weight <= schedule.latest_start[dest]);
+            }
+            assert!(schedule.total_float.iter().all(|f| *f >= 0.0));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn dag_longest_paths_panic_not_valid_src() {
+        dag_longest_paths(&DagGraph::with_nodes(2), 2);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::algorithm::scc::tarjan_scc;
+use crate::{DGraph, Graph, GraphError};
+use std::collections::HashSet;
+
+// Limit of cycles to report, the number of elementary cycles can grow exponentially
+const MAX_REPORTED_CYCLES: usize = 1000;
+
+/// Given a dependency graph, where an edge `src -> dest` means that `src` must be
+/// executed before `dest`, returns the nodes grouped in ordered execution batches. The
+/// nodes of a batch only depend on nodes of previous batches, so each batch can be
+/// executed in parallel. The nodes of each batch are sorted.
+///
+/// If the graph has cycles, returns a `GraphError::DependencyCycles` error listing every
+/// elementary cycle (up to 1000), each one starting at its lowest node.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::resolve_dependencies;
+/// use graphst::{DGraph, GraphError};
+/// // 0: libc, 1: libssl (needs libc), 2: curl (needs libc and libssl), 3: zlib
+/// let g = DGraph::from_edges(4, vec![(0, 1), (0, 2), (1, 2)]);
+/// assert_eq!(resolve_dependencies(&g), Ok(vec![vec![0, 3], vec![1], vec![2]]));
+///
+/// let g = DGraph::from_edges(4, vec![(0, 1), (1, 0), (2, 3), (3, 2)]);
+/// assert_eq!(
+///     resolve_dependencies(&g),
+///     Err(GraphError::DependencyCycles {
+///         operation: "algorithm::resolve_dependencies",
+///         cycles: vec![vec![0, 1], vec![2, 3]],
+///     })
+/// );
+/// ```
+pub fn resolve_dependencies(g: &DGraph) -> Result<Vec<Vec<usize>>, GraphError> {
+    // Kahn's algorithm, layer by layer
+    let mut in_degree: Vec<usize> = g
+        .get_nodes()
+        .iter()
+        .map(|n| g.get_predecessors_of(*n).len())
+        .collect();
+    let mut batch: Vec<usize> = g
+        .get_nodes()
+        .into_iter()
+        .filter(|n| in_degree[*n] == 0)
+        .collect();
+    let mut batches = vec![];
+    let mut n_resolved = 0;
+    while !batch.is_empty() {
+        let mut next_batch = vec![];
+        for node in &batch {
+            for n in g.get_successors_of(*node) {
+                in_degree[n] -= 1;
+                if in_degree[n] == 0 {
+                    next_batch.push(n);
+                }
+            }
+        }
+        next_batch.sort_unstable();
+        n_resolved += batch.len();
+        batches.push(batch);
+        batch = next_batch;
+    }
+    if n_resolved == g.get_n_nodes() {
+        Ok(batches)
+    } else {
+        Err(GraphError::DependencyCycles {
+            operation: "algorithm::resolve_dependencies",
+            cycles: find_cycles(g),
+        })
+    }
+}
+
+// Returns the elementary cycles of the graph (up to MAX_REPORTED_CYCLES), each one
+// starting at its lowest node, with Johnson's algorithm inside each strongly connected
+// component. A node is blocked while it is on the path or can't reach the start without
+// going through the path, and the `blocked_by` lists unblock it once that changes, so no
+// branch without cycles is explored twice and it runs in O((n + m)(c + 1)) for c cycles
+fn find_cycles(g: &DGraph) -> Vec<Vec<usize>> {
+    let n_nodes = g.get_n_nodes();
+    let mut component_of = vec![0; n_nodes];
+    let mut components = tarjan_scc(g);
+    components
+        .iter_mut()
+        .for_each(|component| component.sort_unstable());
+    components.sort();
+    for (idx, component) in components.iter().enumerate() {
+        for node in component {
+            component_of[*node] = idx;
+        }
+    }
+    let mut cycles = vec![];
+    let mut blocked = vec![false; n_nodes];
+    let mut blocked_by: Vec<HashSet<usize>> = vec![HashSet::new(); n_nodes];
+    for component in &components {
+        for start in component {
+            // Search the cycles through `start` using only the higher nodes of its
+            // component, so each cycle is found once (from its lowest node)
+            let valid = |n: &usize| component_of[*n] == component_of[*start] && n >= start;
+            for node in component {
+                blocked[*node] = false;
+                blocked_by[*node].clear();
+            }
+            // Each frame is a node of the path with its successors, the position of the
+            // next one to explore and whether a cycle was found through it
+            let successors = |n: usize| g.get_successors_of(n).into_iter().filter(valid);
+            let mut path = vec![*start];
+            let mut stack = vec![(successors(*start).collect::<Vec<_>>(), 0, false)];
+            blocked[*start] = true;
+            while let Some((next_nodes, pos, found)) = stack.last_mut() {
+                if cycles.len() == MAX_REPORTED_CYCLES {
+                    return cycles;
+                }
+                if *pos < next_nodes.len() {
+                    let next = next_nodes[*pos];
+                    *pos += 1;
+                    if next == *start {
+                        cycles.push(path.clone());
+                        *found = true;
+                    } else if !blocked[next] {
+                        blocked[next] = true;
+                        path.push(next);
+                        stack.push((successors(next).collect(), 0, false));
+                    }
+                    continue;
+                }
+                let (next_nodes, _, found) = stack.pop().expect("The stack is not empty");
+                let node = path.pop().expect("The path has a node per frame");
+                if found {
+                    unblock(node, &mut blocked, &mut blocked_by);
+                    if let Some(parent) = stack.last_mut() {
+                        parent.2 = true;
+                    }
+                } else {
+                    // Keep it blocked until one of its successors is unblocked
+                    for n in next_nodes {
+                        blocked_by[n].insert(node);
+                    }
+                }
+            }
+        }
+    }
+    cycles
+}
+
+// Unblocks the node and, recursively, the nodes that were waiting for it
+fn unblock(node: usize, blocked: &mut [bool], blocked_by: &mut [HashSet<usize>]) {
+    let mut pending = vec![node];
+    while let Some(current) = pending.pop() {
+        blocked[current] = false;
+        for n in blocked_by[current].drain() {
+            if blocked[n] {
+                pending.push(n);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_dependencies_check_empty_graph() {
+        assert_eq!(resolve_dependencies(&DGraph::new()), Ok(vec![]));
+    }
+
+    #[test]
+    fn resolve_dependencies_reports_every_cycle() {
+        // Two cycles sharing the node 1, a self-loop, and an acyclic part
+        let edges = vec![(0, 1), (1, 0), (1, 2), (2, 1), (3, 3), (4, 5)];
+        let g = DGraph::from_edges(6, edges);
+        assert_eq!(
+            resolve_dependencies(&g),
+            Err(GraphError::DependencyCycles {
+                operation: "algorithm::resolve_dependencies",
+                cycles: vec![vec![0, 1], vec![1, 2], vec![3]]
+            })
+        );
+    }
+
+    #[test]
+    fn find_cycles_check_complete_graph() {
+        // The complete graph of 5 nodes has C(5, k) * (k - 1)! elementary cycles of each
+        // length k: 10 + 20 + 30 + 24
+        let edges = (0..5)
+            .flat_map(|n1| (0..5).filter(move |n2| *n2 != n1).map(move |n2| (n1, n2)))
+            .collect();
+        let g = DGraph::from_edges(5, edges);
+        let cycles = find_cycles(&g);
+        assert_eq!(cycles.len(), 84);
+        let unique: HashSet<&Vec<usize>> = cycles.iter().collect();
+        assert_eq!(unique.len(), 84);
+        for cycle in &cycles {
+            assert_eq!(cycle.iter().min(), Some(&cycle[0]));
+            assert_eq!(cycle.iter().collect::<HashSet<_>>().len(), cycle.len());
+        }
+    }
+
+    #[test]
+    fn resolve_dependencies_check_diamond() {
+        let g = DGraph::from_edges(4, vec![(0, 1), (0, 2), (1, 3), (2, 3)]);
+        assert_eq!(
+            resolve_dependencies(&g),
+            Ok(vec![vec![0], vec![1, 2], vec![3]])
+        );
+    }
+}
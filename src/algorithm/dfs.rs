@@ -0,0 +1,225 @@
+use crate::graph::Graph;
+
+/// The `DfsEvent` enum lists the events that `dfs` reports to its visitor. The edges
+/// are classified as in the classic DFS edge classification; undirected graphs only have
+/// tree and back edges, each edge reported once.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DfsEvent {
+    /// The node is reached for the first time.
+    Discover(usize),
+    /// All the descendants of the node have been explored.
+    Finish(usize),
+    /// The edge `(node1, node2)` discovers `node2`.
+    TreeEdge(usize, usize),
+    /// The edge `(node1, node2)` goes to an ancestor of `node1` that is still being
+    /// explored, so it closes a cycle.
+    BackEdge(usize, usize),
+    /// The edge `(node1, node2)` goes to a finished descendant of `node1` (directed graphs
+    /// only).
+    ForwardEdge(usize, usize),
+    /// The edge `(node1, node2)` goes to a finished node that is not a descendant of
+    /// `node1` (directed graphs only).
+    CrossEdge(usize, usize),
+}
+
+/// Runs a depth-first search from `src` and reports each step to `visitor` as a
+/// `DfsEvent`, so algorithms like cycle detection or topological sorting can be built on
+/// top of the traversal. The neighbors of each node are explored in increasing order. The
+/// search is iterative, so deep graphs don't overflow the stack.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+/// * `src` - The node where the search starts.
+/// * `visitor` - A closure called with each event.
+///
+/// # Panics
+///
+/// * If the source node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::{dfs, DfsEvent};
+/// use graphst::DGraph;
+/// let g = DGraph::from_edges(3, vec![(0, 1), (1, 2), (2, 0)]);
+/// let mut back_edges = vec![];
+/// dfs(&g, 0, |event| {
+///     if let DfsEvent::BackEdge(node1, node2) = event {
+///         back_edges.push((node1, node2));
+///     }
+/// });
+/// assert_eq!(back_edges, vec![(2, 0)]); // the cycle 0-1-2
+/// ```
+pub fn dfs<G, F>(g: &G, src: usize, mut visitor: F)
+where
+    G: Graph,
+    F: FnMut(DfsEvent),
+{
+    if src >= g.get_n_nodes() {
+        panic!("[algorithm::dfs] Error: The node {} is not valid!", src);
+    }
+    let mut state = DfsState::new(g.get_n_nodes());
+    state.visit(g, src, &mut visitor);
+}
+
+/// Same as `dfs` but covering the whole graph: a search starts from each node that is
+/// still undiscovered, in increasing order.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::{dfs_all, DfsEvent};
+/// use graphst::DGraph;
+/// // Topological order: the reverse of the finishing order
+/// let g = DGraph::from_edges(4, vec![(2, 0), (0, 1), (3, 2)]);
+/// let mut order = vec![];
+/// dfs_all(&g, |event| {
+///     if let DfsEvent::Finish(node) = event {
+///         order.push(node);
+///     }
+/// });
+/// order.reverse();
+/// assert_eq!(order, vec![3, 2, 0, 1]);
+/// ```
+pub fn dfs_all<G, F>(g: &G, mut visitor: F)
+where
+    G: Graph,
+    F: FnMut(DfsEvent),
+{
+    let mut state = DfsState::new(g.get_n_nodes());
+    for node in g.get_nodes() {
+        if state.discovered[node].is_none() {
+            state.visit(g, node, &mut visitor);
+        }
+    }
+}
+
+//--------- Private functions ---------
+
+struct DfsState {
+    // Discovery step of each node
+    discovered: Vec<Option<usize>>,
+    finished: Vec<bool>,
+    n_steps: usize,
+}
+
+impl DfsState {
+    fn new(n_nodes: usize) -> DfsState {
+        DfsState {
+            discovered: vec![None; n_nodes],
+            finished: vec![false; n_nodes],
+            n_steps: 0,
+        }
+    }
+
+    fn discover<F>(&mut self, node: usize, visitor: &mut F)
+    where
+        F: FnMut(DfsEvent),
+    {
+        self.discovered[node] = Some(self.n_steps);
+        self.n_steps += 1;
+        visitor(DfsEvent::Discover(node));
+    }
+
+    fn visit<G, F>(&mut self, g: &G, root: usize, visitor: &mut F)
+    where
+        G: Graph,
+        F: FnMut(DfsEvent),
+    {
+        let directed = g.is_directed();
+        self.discover(root, visitor);
+        // Stack of (node, parent, neighbors, index of the next neighbor)
+        let mut stack = vec![(root, None, g.get_out_neighbors_of(root), 0)];
+        while let Some((node, parent, neighbors, idx)) = stack.last_mut() {
+            let node = *node;
+            if *idx == neighbors.len() {
+                self.finished[node] = true;
+                visitor(DfsEvent::Finish(node));
+                stack.pop();
+                continue;
+            }
+            let n = neighbors[*idx];
+            *idx += 1;
+            match self.discovered[n] {
+                None => {
+                    visitor(DfsEvent::TreeEdge(node, n));
+                    self.discover(n, visitor);
+                    stack.push((n, Some(node), g.get_out_neighbors_of(n), 0));
+                }
+                Some(_) if !directed && *parent == Some(n) => {
+                    // The tree edge seen from the child, skip it only once
+                    *parent = None;
+                }
+                Some(_) if !self.finished[n] => visitor(DfsEvent::BackEdge(node, n)),
+                Some(_) if !directed => {} // already reported as a back edge from `n`
+                Some(step) if step > self.discovered[node].unwrap() => {
+                    visitor(DfsEvent::ForwardEdge(node, n))
+                }
+                Some(_) => visitor(DfsEvent::CrossEdge(node, n)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DGraph, UGraph};
+
+    fn events<G: Graph>(g: &G, src: usize) -> Vec<DfsEvent> {
+        let mut events = vec![];
+        dfs(g, src, |e| events.push(e));
+        events
+    }
+
+    #[test]
+    fn dfs_check_directed_edge_classification() {
+        let g = DGraph::from_edges(4, vec![(0, 1), (1, 2), (0, 2), (3, 1)]);
+        use DfsEvent::*;
+        assert_eq!(
+            events(&g, 0),
+            vec![
+                Discover(0),
+                TreeEdge(0, 1),
+                Discover(1),
+                TreeEdge(1, 2),
+                Discover(2),
+                Finish(2),
+                Finish(1),
+                ForwardEdge(0, 2),
+                Finish(0)
+            ]
+        );
+        let mut cross = vec![];
+        dfs_all(&g, |e| {
+            if let CrossEdge(a, b) = e {
+                cross.push((a, b));
+            }
+        });
+        assert_eq!(cross, vec![(3, 1)]);
+    }
+
+    #[test]
+    fn dfs_check_undirected_cycle_reported_once() {
+        let g = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 0), (2, 3)]);
+        let evs = events(&g, 0);
+        let back: Vec<_> = evs
+            .iter()
+            .filter(|e| matches!(e, DfsEvent::BackEdge(..)))
+            .collect();
+        assert_eq!(back, vec![&DfsEvent::BackEdge(2, 0)]);
+        let n_tree = evs
+            .iter()
+            .filter(|e| matches!(e, DfsEvent::TreeEdge(..)))
+            .count();
+        assert_eq!(n_tree, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn dfs_panic_not_valid_source() {
+        let g = UGraph::from_edges(2, vec![(0, 1)]);
+        dfs(&g, 5, |_| {});
+    }
+}
@@ -1,25 +1,22 @@
+use crate::algorithm::{DijkstraIter, SearchBuffers};
+use crate::dense;
+use crate::float::{self, FloatOrd};
 use crate::graph::Graph;
-
-fn min_distance_node<G>(g: &G, dist: &Vec<f32>, visited: &Vec<bool>) -> usize
-where
-    G: Graph,
-{
-    let mut min_dist = f32::INFINITY;
-    let mut min_idx = g.get_n_nodes(); // default is an invalid node
-
-    for node in g.get_nodes() {
-        if dist[node] < min_dist && visited[node] == false {
-            min_dist = dist[node];
-            min_idx = node;
-        }
-    }
-
-    return min_idx;
-}
+use crate::path;
+use crate::GraphError;
+use std::cmp::Reverse;
 
 /// Given a graph (that implements `Graph`) and a source node, returns the
 /// shortest path to each node from the source provided.
 ///
+/// The nodes are selected with a binary heap and only the actual neighbors of each node
+/// are relaxed, so it runs in `O((n + m) log n)` on graphs with efficient neighbor
+/// queries. For dense graphs `dijkstra_dense` avoids the heap overhead.
+///
+/// The unreachable nodes get `f32::INFINITY`, and the distances that overflow `f32` also
+/// saturate to `f32::INFINITY`. The weights must be non-negative, otherwise the result is
+/// not defined; use `try_dijkstra` to validate them.
+///
 /// # Examples
 ///
 /// ```
@@ -33,28 +30,481 @@ pub fn dijkstra<G>(g: &G, src: usize) -> Vec<f32>
 where
     G: Graph,
 {
+    let mut buffers = SearchBuffers::new();
+    dijkstra_with_buffers(g, src, &mut buffers);
+    buffers.dist
+}
+
+/// Same as `dijkstra` but using the scratch storage of `buffers` instead of allocating
+/// new vectors. Returns the shortest path to each node, borrowed from `buffers`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::{dijkstra_with_buffers, SearchBuffers};
+/// let g = graphst::UGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+/// let mut buffers = SearchBuffers::with_capacity(3);
+/// assert_eq!(dijkstra_with_buffers(&g, 0, &mut buffers), &[0.0, 1.0, 2.0]);
+/// assert_eq!(dijkstra_with_buffers(&g, 2, &mut buffers), &[2.0, 1.0, 0.0]);
+/// ```
+pub fn dijkstra_with_buffers<'a, G>(g: &G, src: usize, buffers: &'a mut SearchBuffers) -> &'a [f32]
+where
+    G: Graph,
+{
+    buffers.reset(g.get_n_nodes());
     // dist: For keeping track of the current closest distance to
     //       each node during the algorithm iterations
-    let mut dist = vec![f32::INFINITY; g.get_n_nodes()];
     // visited: To know which nodes we have visited and we already have a minimum path
-    let mut visited = vec![false; g.get_n_nodes()];
+    // heap: The candidate nodes ordered by their tentative distance
+    let SearchBuffers {
+        dist,
+        visited,
+        heap,
+    } = buffers;
 
     dist[src] = 0.0; // Initialize with distance to src
+    heap.push(Reverse((FloatOrd(0.0), src)));
 
-    for _ in g.get_nodes() {
-        // Select the closest not visited node
-        let current = min_distance_node(g, &dist, &visited);
+    // Select the closest not visited node
+    while let Some(Reverse((_, current))) = heap.pop() {
+        if visited[current] {
+            continue; // Outdated entry, the node was reached with a shorter distance
+        }
         visited[current] = true;
-        for n in g.get_nodes() {
+        for n in g.get_out_neighbors_of(current) {
             let edge_weight = match g.get_edge(current, n) {
                 Some(edge) => edge, // The edge exists, take the weight
                 None => continue,   // There is no edge, skip to the next node
             };
-            if visited[n] == false && dist[n] > dist[current] + edge_weight {
+            if !visited[n] && float::greater_than(dist[n], dist[current] + edge_weight) {
                 dist[n] = dist[current] + edge_weight; // Set the new best distance
+                heap.push(Reverse((FloatOrd(dist[n]), n)));
+            }
+        }
+    }
+
+    &buffers.dist
+}
+
+/// Same as `dijkstra` but selecting the closest node with a linear scan of the distances
+/// instead of a heap, in `O(n^2)`. It is faster on dense graphs, where most of the
+/// matrix has to be read anyway.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::dijkstra_dense;
+/// let g = graphst::UGraph::from_weighted_edges(3, vec![(0, 1, 1.0), (1, 2, 1.0), (0, 2, 3.0)]);
+/// assert_eq!(dijkstra_dense(&g, 0), vec![0.0, 1.0, 2.0]);
+/// ```
+pub fn dijkstra_dense<G>(g: &G, src: usize) -> Vec<f32>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    let mut dist = vec![f32::INFINITY; n_nodes];
+    let mut visited = vec![false; n_nodes];
+    dist[src] = 0.0;
+    while let Some(current) = dense::row_min_scan(&dist, &visited) {
+        visited[current] = true;
+        for n in g.get_out_neighbors_of(current) {
+            if let Some(edge_weight) = g.get_edge(current, n) {
+                if !visited[n] && float::greater_than(dist[n], dist[current] + edge_weight) {
+                    dist[n] = dist[current] + edge_weight;
+                }
+            }
+        }
+    }
+    dist
+}
+
+/// Same as `dijkstra` but for graphs with small integer weights, selecting the nodes with
+/// Dial's bucket queue instead of a heap: the nodes are kept in a circular array of
+/// `max_weight + 1` buckets indexed by their distance, so it runs in
+/// `O(E + V * max_weight)`. It is the fastest option for grids and unit weight graphs.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+/// * `src` - The source node.
+/// * `max_weight` - The maximum weight of the edges of the graph.
+///
+/// # Panics
+///
+/// * If the source node is not valid.
+/// * If some weight is not an integer from `1` to `max_weight`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::dijkstra_dial;
+/// let g = graphst::DGraph::from_weighted_edges(3, vec![(0, 1, 3.0), (1, 2, 1.0), (0, 2, 5.0)]);
+/// assert_eq!(dijkstra_dial(&g, 0, 5), vec![0.0, 3.0, 4.0]);
+/// ```
+pub fn dijkstra_dial<G>(g: &G, src: usize, max_weight: usize) -> Vec<f32>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if src >= n_nodes {
+        panic!(
+            "[algorithm::dijkstra_dial] Error: The node {} is not valid!",
+            src
+        );
+    }
+    let mut dist: Vec<Option<usize>> = vec![None; n_nodes];
+    let mut visited = vec![false; n_nodes];
+    // The bucket `d % n_buckets` holds the nodes with tentative distance `d`
+    let n_buckets = max_weight + 1;
+    let mut buckets: Vec<Vec<usize>> = vec![vec![]; n_buckets];
+    let mut n_pending = 1;
+    dist[src] = Some(0);
+    buckets[0].push(src);
+    let mut current_dist = 0;
+    while n_pending > 0 {
+        let bucket = current_dist % n_buckets;
+        while let Some(current) = buckets[bucket].pop() {
+            n_pending -= 1;
+            if visited[current] || dist[current] != Some(current_dist) {
+                continue; // Outdated entry
+            }
+            visited[current] = true;
+            for n in g.get_out_neighbors_of(current) {
+                let weight = match g.get_edge(current, n) {
+                    Some(weight) => weight,
+                    None => continue,
+                };
+                if weight < 1.0 || weight > max_weight as f32 || weight.fract() != 0.0 {
+                    panic!(
+                        "[algorithm::dijkstra_dial] Error: The weight {} is not valid!",
+                        weight
+                    );
+                }
+                let new_dist = current_dist + weight as usize;
+                if !visited[n] && dist[n].map_or(true, |d| new_dist < d) {
+                    dist[n] = Some(new_dist);
+                    buckets[new_dist % n_buckets].push(n);
+                    n_pending += 1;
+                }
+            }
+        }
+        current_dist += 1;
+    }
+    dist.into_iter()
+        .map(|d| d.map_or(f32::INFINITY, |d| d as f32))
+        .collect()
+}
+
+/// The `ShortestPathTree` struct is the result of `dijkstra_with_paths`: the distances
+/// from the source and the predecessor of each node in a shortest path, which together
+/// describe a shortest path to every reachable node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShortestPathTree {
+    /// The source node of the paths.
+    pub src: usize,
+    /// The distance from the source to each node, `f32::INFINITY` if unreachable.
+    pub distances: Vec<f32>,
+    /// The previous node in the shortest path to each node, `None` for the source and
+    /// the unreachable nodes.
+    pub predecessors: Vec<Option<usize>>,
+}
+
+impl ShortestPathTree {
+    /// Returns the nodes of the shortest path from the source to `dest`, both included,
+    /// or `None` if `dest` is not reachable.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::algorithm::dijkstra_with_paths;
+    /// let g = graphst::DGraph::from_weighted_edges(4, vec![(0, 1, 1.0), (1, 2, 1.0), (0, 2, 5.0)]);
+    /// let tree = dijkstra_with_paths(&g, 0);
+    /// assert_eq!(tree.path_to(2), Some(vec![0, 1, 2]));
+    /// assert_eq!(tree.path_to(0), Some(vec![0]));
+    /// assert_eq!(tree.path_to(3), None);
+    /// ```
+    pub fn path_to(&self, dest: usize) -> Option<Vec<usize>> {
+        if dest >= self.distances.len() {
+            panic!(
+                "[ShortestPathTree::path_to] Error: The node {} is not valid!",
+                dest
+            );
+        }
+        if self.distances[dest] == f32::INFINITY && dest != self.src {
+            return None;
+        }
+        Some(path::from_predecessors(&self.predecessors, dest))
+    }
+}
+
+/// Same as `dijkstra` but also keeping the predecessor of each node, so the shortest
+/// paths can be recovered with `ShortestPathTree::path_to`. When several shortest paths
+/// exist, the first one found is kept.
+///
+/// # Panics
+///
+/// * If the source node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::dijkstra_with_paths;
+/// let g = graphst::UGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 1.0)]);
+/// let tree = dijkstra_with_paths(&g, 2);
+/// assert_eq!(tree.distances, vec![3.0, 1.0, 0.0]);
+/// assert_eq!(tree.predecessors, vec![Some(1), Some(2), None]);
+/// ```
+pub fn dijkstra_with_paths<G>(g: &G, src: usize) -> ShortestPathTree
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if src >= n_nodes {
+        panic!(
+            "[algorithm::dijkstra_with_paths] Error: The node {} is not valid!",
+            src
+        );
+    }
+    let mut steps = DijkstraIter::new(g, src);
+    steps.by_ref().for_each(drop);
+    let (distances, predecessors) = steps.into_parts();
+    ShortestPathTree {
+        src,
+        distances,
+        predecessors,
+    }
+}
+
+/// Returns the cost and the nodes of the shortest path from `src` to `dest`, or `None` if
+/// `dest` is not reachable. The search stops as soon as `dest` is settled, so only the
+/// nodes closer to the source than the destination are explored.
+///
+/// # Panics
+///
+/// * If some node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::dijkstra_to;
+/// let g = graphst::DGraph::from_weighted_edges(4, vec![(0, 1, 1.0), (1, 2, 1.0), (0, 2, 5.0)]);
+/// assert_eq!(dijkstra_to(&g, 0, 2), Some((2.0, vec![0, 1, 2])));
+/// assert_eq!(dijkstra_to(&g, 0, 3), None);
+/// ```
+pub fn dijkstra_to<G>(g: &G, src: usize, dest: usize) -> Option<(f32, Vec<usize>)>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if let Some(node) = [src, dest].iter().find(|n| **n >= n_nodes) {
+        panic!(
+            "[algorithm::dijkstra_to] Error: The node {} is not valid!",
+            node
+        );
+    }
+    let mut steps = DijkstraIter::new(g, src);
+    let settled = steps.find(|step| step.node == dest)?;
+    let path = path::from_predecessors(steps.get_predecessors(), dest);
+    Some((settled.distance, path))
+}
+
+/// Same as `dijkstra` but validating the input first: returns
+/// `GraphError::InvalidNode` if the source node is not valid, and
+/// `GraphError::NegativeWeight` with the first edge (in the order of `get_edges`) that
+/// has a negative or NaN weight.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::try_dijkstra;
+/// use graphst::{DGraph, GraphError};
+/// let g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, -1.0)]);
+/// assert_eq!(
+///     try_dijkstra(&g, 0),
+///     Err(GraphError::NegativeWeight {
+///         operation: "algorithm::try_dijkstra",
+///         src: 1,
+///         dest: 2,
+///         weight: -1.0,
+///         n_nodes: 3,
+///     })
+/// );
+/// assert!(matches!(try_dijkstra(&g, 3), Err(GraphError::InvalidNode { node: 3, .. })));
+/// ```
+pub fn try_dijkstra<G>(g: &G, src: usize) -> Result<Vec<f32>, GraphError>
+where
+    G: Graph,
+{
+    if src >= g.get_n_nodes() {
+        return Err(GraphError::InvalidNode {
+            operation: "algorithm::try_dijkstra",
+            node: src,
+            n_nodes: g.get_n_nodes(),
+        });
+    }
+    if let Some((node1, node2, weight)) = g
+        .get_edges()
+        .into_iter()
+        .find(|(_, _, w)| w.is_nan() || *w < 0.0)
+    {
+        return Err(GraphError::NegativeWeight {
+            operation: "algorithm::try_dijkstra",
+            src: node1,
+            dest: node2,
+            weight,
+            n_nodes: g.get_n_nodes(),
+        });
+    }
+    Ok(dijkstra(g, src))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DGraph;
+
+    #[test]
+    fn dijkstra_check_unreachable_nodes() {
+        let n_nodes = 4;
+        let edges = vec![(0, 1, 2.0), (2, 3, 1.0)];
+        let g = DGraph::from_weighted_edges(n_nodes, edges);
+        let inf = f32::INFINITY;
+        assert_eq!(dijkstra(&g, 0), vec![0.0, 2.0, inf, inf]);
+        assert_eq!(dijkstra(&g, 2), vec![inf, inf, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn dijkstra_with_buffers_reuse_different_graphs() {
+        let mut buffers = SearchBuffers::new();
+        let g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 1.0)]);
+        assert_eq!(dijkstra_with_buffers(&g, 0, &mut buffers), &[0.0, 2.0, 3.0]);
+        let g = DGraph::from_weighted_edges(2, vec![(1, 0, 4.0)]);
+        assert_eq!(dijkstra_with_buffers(&g, 1, &mut buffers), &[4.0, 0.0]);
+        assert_eq!(buffers.get_distances(), &[4.0, 0.0]);
+    }
+
+    #[test]
+    fn dijkstra_matches_dijkstra_dense() {
+        let mut rng = crate::rng::Rng::new(5);
+        for _ in 0..50 {
+            let n_nodes = 1 + rng.gen_range(20);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(60) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                adj_mat[node1][node2] = 1.0 + rng.gen_range(9) as f32;
+            }
+            let g = DGraph::from_adjacency_matrix(adj_mat);
+            let src = rng.gen_range(n_nodes);
+            assert_eq!(dijkstra(&g, src), dijkstra_dense(&g, src));
+        }
+    }
+
+    #[test]
+    fn dijkstra_dial_matches_dijkstra() {
+        let mut rng = crate::rng::Rng::new(17);
+        for _ in 0..50 {
+            let n_nodes = 1 + rng.gen_range(20);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(60) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                adj_mat[node1][node2] = 1.0 + rng.gen_range(4) as f32;
+            }
+            let g = DGraph::from_adjacency_matrix(adj_mat);
+            let src = rng.gen_range(n_nodes);
+            assert_eq!(dijkstra_dial(&g, src, 4), dijkstra(&g, src));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The weight 1.5 is not valid")]
+    fn dijkstra_dial_panic_not_integer_weight() {
+        let g = DGraph::from_weighted_edges(2, vec![(0, 1, 1.5)]);
+        dijkstra_dial(&g, 0, 2);
+    }
+
+    #[test]
+    fn dijkstra_with_paths_check_paths_match_distances() {
+        let edges = vec![
+            (0, 1, 4.0),
+            (0, 2, 1.0),
+            (2, 1, 2.0),
+            (1, 3, 1.0),
+            (3, 4, 3.0),
+        ];
+        let g = DGraph::from_weighted_edges(6, edges);
+        let tree = dijkstra_with_paths(&g, 0);
+        assert_eq!(tree.distances, dijkstra(&g, 0));
+        assert_eq!(tree.path_to(4), Some(vec![0, 2, 1, 3, 4]));
+        assert_eq!(tree.path_to(5), None);
+        let cost: f32 = tree
+            .path_to(3)
+            .unwrap()
+            .windows(2)
+            .map(|w| g.get_edge(w[0], w[1]).unwrap())
+            .sum();
+        assert_eq!(cost, tree.distances[3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "[algorithm::dijkstra_with_paths] Error: The node 2 is not valid!")]
+    fn dijkstra_with_paths_panic_not_valid_src() {
+        let g = DGraph::from_edges(2, vec![(0, 1)]);
+        dijkstra_with_paths(&g, 2);
+    }
+
+    #[test]
+    fn dijkstra_to_matches_dijkstra() {
+        let mut rng = crate::rng::Rng::new(23);
+        for _ in 0..50 {
+            let n_nodes = 1 + rng.gen_range(15);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(40) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                adj_mat[node1][node2] = 1.0 + rng.gen_range(9) as f32;
+            }
+            let g = DGraph::from_adjacency_matrix(adj_mat);
+            let (src, dest) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+            let expected = dijkstra(&g, src)[dest];
+            match dijkstra_to(&g, src, dest) {
+                Some((cost, path)) => {
+                    assert_eq!(cost, expected);
+                    let path_cost: f32 = path
+                        .windows(2)
+                        .map(|w| g.get_edge(w[0], w[1]).unwrap())
+                        .sum();
+                    assert_eq!((path[0], path_cost), (src, cost));
+                }
+                None => assert_eq!(expected, f32::INFINITY),
             }
         }
     }
 
-    return dist;
+    #[test]
+    fn dijkstra_saturates_to_infinity() {
+        let edges = vec![(0, 1, f32::MAX), (1, 2, f32::MAX), (0, 3, f32::INFINITY)];
+        let g = DGraph::from_weighted_edges(4, edges);
+        assert_eq!(
+            dijkstra(&g, 0),
+            vec![0.0, f32::MAX, f32::INFINITY, f32::INFINITY]
+        );
+    }
+
+    #[test]
+    fn try_dijkstra_check_valid_and_nan_weights() {
+        let g = DGraph::from_weighted_edges(2, vec![(0, 1, 1.5)]);
+        assert_eq!(try_dijkstra(&g, 0), Ok(vec![0.0, 1.5]));
+        let g = DGraph::from_weighted_edges(2, vec![(1, 0, f32::NAN)]);
+        assert!(matches!(
+            try_dijkstra(&g, 0),
+            Err(GraphError::NegativeWeight {
+                src: 1,
+                dest: 0,
+                ..
+            })
+        ));
+    }
 }
@@ -0,0 +1,175 @@
+use crate::float::{self, FloatOrd};
+use crate::graph::Graph;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The `DijkstraStep` struct describes one iteration of `DijkstraIter`: the node settled
+/// (its shortest distance is final) and the neighbors whose distance was improved.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DijkstraStep {
+    /// The node settled in this step.
+    pub node: usize,
+    /// The shortest distance from the source to the node.
+    pub distance: f32,
+    /// The previous node in the shortest path to the node, `None` for the source.
+    pub predecessor: Option<usize>,
+    /// The neighbors of the node whose tentative distance was improved in this step.
+    pub relaxed: Vec<usize>,
+}
+
+/// The `DijkstraIter` struct runs Dijkstra's algorithm one node at a time. Each call to
+/// `next` settles the closest node not settled yet and returns a `DijkstraStep`, and the
+/// intermediate state (tentative distances, settled nodes and the frontier) can be
+/// inspected between the steps. The iterator ends when every reachable node is settled,
+/// and the final distances are the same as the ones of `dijkstra`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::DijkstraIter;
+/// use graphst::DGraph;
+/// let g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (0, 2, 5.0), (1, 2, 1.0)]);
+/// let mut steps = DijkstraIter::new(&g, 0);
+/// let first = steps.next().unwrap();
+/// assert_eq!((first.node, first.relaxed), (0, vec![1, 2]));
+/// assert_eq!(steps.get_distances(), &[0.0, 2.0, 5.0]); // 2 is still tentative
+/// assert_eq!(steps.get_frontier(), vec![1, 2]);
+/// let order: Vec<usize> = steps.map(|step| step.node).collect();
+/// assert_eq!(order, vec![1, 2]);
+/// ```
+#[derive(Debug)]
+pub struct DijkstraIter<'a, G> {
+    g: &'a G,
+    dist: Vec<f32>,
+    predecessors: Vec<Option<usize>>,
+    settled: Vec<bool>,
+    heap: BinaryHeap<Reverse<(FloatOrd, usize)>>,
+}
+
+impl<'a, G> DijkstraIter<'a, G>
+where
+    G: Graph,
+{
+    /// Creates the iterator from a graph (that implements `Graph`, with non-negative
+    /// weights) and a source node. No node is settled until the first call to `next`.
+    ///
+    /// # Panics
+    ///
+    /// * If the source node is not valid.
+    pub fn new(g: &'a G, src: usize) -> DijkstraIter<'a, G> {
+        let n_nodes = g.get_n_nodes();
+        if src >= n_nodes {
+            panic!("[DijkstraIter::new] Error: The node {} is not valid!", src);
+        }
+        let mut dist = vec![f32::INFINITY; n_nodes];
+        dist[src] = 0.0;
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((FloatOrd(0.0), src)));
+        DijkstraIter {
+            g,
+            dist,
+            predecessors: vec![None; n_nodes],
+            settled: vec![false; n_nodes],
+            heap,
+        }
+    }
+
+    /// Returns the current distance to each node: final for the settled nodes, tentative
+    /// for the frontier and `f32::INFINITY` for the nodes not reached yet.
+    pub fn get_distances(&self) -> &[f32] {
+        &self.dist
+    }
+
+    /// Returns the current predecessor of each node in the shortest path tree.
+    pub fn get_predecessors(&self) -> &[Option<usize>] {
+        &self.predecessors
+    }
+
+    /// Returns `true` if the shortest distance to the node passed as a parameter is final.
+    pub fn is_settled(&self, node: usize) -> bool {
+        self.settled[node]
+    }
+
+    /// Returns the nodes reached but not settled yet, in increasing order.
+    pub fn get_frontier(&self) -> Vec<usize> {
+        (0..self.dist.len())
+            .filter(|n| !self.settled[*n] && self.dist[*n] < f32::INFINITY)
+            .collect()
+    }
+
+    // Consumes the iterator and returns the distances and the predecessors
+    pub(crate) fn into_parts(self) -> (Vec<f32>, Vec<Option<usize>>) {
+        (self.dist, self.predecessors)
+    }
+}
+
+impl<'a, G> Iterator for DijkstraIter<'a, G>
+where
+    G: Graph,
+{
+    type Item = DijkstraStep;
+
+    fn next(&mut self) -> Option<DijkstraStep> {
+        while let Some(Reverse((_, current))) = self.heap.pop() {
+            if self.settled[current] {
+                continue; // Outdated entry
+            }
+            self.settled[current] = true;
+            let mut relaxed = vec![];
+            for n in self.g.get_out_neighbors_of(current) {
+                if let Some(edge_weight) = self.g.get_edge(current, n) {
+                    let new_dist = self.dist[current] + edge_weight;
+                    if !self.settled[n] && float::greater_than(self.dist[n], new_dist) {
+                        self.dist[n] = new_dist;
+                        self.predecessors[n] = Some(current);
+                        self.heap.push(Reverse((FloatOrd(new_dist), n)));
+                        relaxed.push(n);
+                    }
+                }
+            }
+            return Some(DijkstraStep {
+                node: current,
+                distance: self.dist[current],
+                predecessor: self.predecessors[current],
+                relaxed,
+            });
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::dijkstra;
+    use crate::DGraph;
+
+    #[test]
+    fn dijkstra_iter_matches_dijkstra() {
+        let mut rng = crate::rng::Rng::new(11);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(15);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(40) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                adj_mat[node1][node2] = 1.0 + rng.gen_range(9) as f32;
+            }
+            let g = DGraph::from_adjacency_matrix(adj_mat);
+            let src = rng.gen_range(n_nodes);
+            let mut steps = DijkstraIter::new(&g, src);
+            let mut last = 0.0;
+            for step in steps.by_ref() {
+                assert!(step.distance >= last); // Settled in increasing distance
+                last = step.distance;
+            }
+            assert_eq!(steps.get_distances(), &dijkstra(&g, src)[..]);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn dijkstra_iter_panic_not_valid_src() {
+        let g = DGraph::from_edges(2, vec![(0, 1)]);
+        DijkstraIter::new(&g, 2);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::algorithm::max_flow;
+use crate::graph::Graph;
+use crate::FlowNetwork;
+
+/// Returns up to `k` paths from `src` to `dest` that don't share any edge, as the
+/// sequences of their nodes. Fewer paths are returned if there are not `k` edge-disjoint
+/// paths. The paths are found with a unit capacity maximum flow, so the weights of the
+/// edges are ignored.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+/// * `src` - The first node of the paths.
+/// * `dest` - The last node of the paths.
+/// * `k` - The maximum number of paths to find.
+///
+/// # Panics
+///
+/// * If some node is not valid or `src` and `dest` are the same node.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::edge_disjoint_paths;
+/// use graphst::UGraph;
+/// // Two triangles sharing the node 2
+/// let g = UGraph::from_edges(5, vec![(0, 1), (1, 2), (0, 2), (2, 3), (3, 4), (2, 4)]);
+/// let paths = edge_disjoint_paths(&g, 0, 4, 3);
+/// assert_eq!(paths.len(), 2);
+/// assert!(paths.iter().all(|p| p[0] == 0 && p[p.len() - 1] == 4));
+/// ```
+pub fn edge_disjoint_paths<G>(g: &G, src: usize, dest: usize, k: usize) -> Vec<Vec<usize>>
+where
+    G: Graph,
+{
+    check_nodes(g, src, dest, "edge_disjoint_paths");
+    disjoint_paths(edge_network(g), src, dest, k)
+}
+
+/// Returns up to `k` paths from `src` to `dest` that don't share any node apart from
+/// `src` and `dest`, as the sequences of their nodes. Fewer paths are returned if there
+/// are not `k` node-disjoint paths. Each node is split in an input and an output node
+/// joined by an arc of capacity `1`, and the paths are found with a maximum flow, so the
+/// weights of the edges are ignored.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+/// * `src` - The first node of the paths.
+/// * `dest` - The last node of the paths.
+/// * `k` - The maximum number of paths to find.
+///
+/// # Panics
+///
+/// * If some node is not valid or `src` and `dest` are the same node.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::node_disjoint_paths;
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(5, vec![(0, 1), (1, 2), (0, 2), (2, 3), (3, 4), (2, 4)]);
+/// assert_eq!(node_disjoint_paths(&g, 0, 4, 3).len(), 1); // every path crosses 2
+/// ```
+pub fn node_disjoint_paths<G>(g: &G, src: usize, dest: usize, k: usize) -> Vec<Vec<usize>>
+where
+    G: Graph,
+{
+    check_nodes(g, src, dest, "node_disjoint_paths");
+    let n_nodes = g.get_n_nodes();
+    disjoint_paths(split_network(g, src, dest, k as f32), src, dest, k)
+        .into_iter()
+        .map(|path| path.into_iter().filter(|n| *n < n_nodes).collect())
+        .collect()
+}
+
+// Returns the network with an arc of capacity `1` for each edge of the graph (two
+// opposite arcs for undirected graphs)
+pub(crate) fn edge_network<G>(g: &G) -> FlowNetwork
+where
+    G: Graph,
+{
+    let mut net = FlowNetwork::with_nodes(g.get_n_nodes());
+    for (node1, node2, _) in g.get_edges() {
+        net.add_arc(node1, node2, 1.0);
+        if !g.is_directed() {
+            net.add_arc(node2, node1, 1.0);
+        }
+    }
+    net
+}
+
+// Returns the network where the node `v` is split in the input `v` and the output
+// `v + n_nodes`, joined by an arc of capacity `1` (`terminal_capacity` for `src` and
+// `dest`), and each edge is an arc of capacity `1` from an output to an input
+pub(crate) fn split_network<G>(
+    g: &G,
+    src: usize,
+    dest: usize,
+    terminal_capacity: f32,
+) -> FlowNetwork
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    let mut net = FlowNetwork::with_nodes(2 * n_nodes);
+    for node in g.get_nodes() {
+        let capacity = if node == src || node == dest {
+            terminal_capacity
+        } else {
+            1.0
+        };
+        net.add_arc(node, node + n_nodes, capacity);
+    }
+    for (node1, node2, _) in g.get_edges() {
+        net.add_arc(node1 + n_nodes, node2, 1.0);
+        if !g.is_directed() {
+            net.add_arc(node2 + n_nodes, node1, 1.0);
+        }
+    }
+    net
+}
+
+//--------- Private functions ---------
+
+fn check_nodes<G>(g: &G, src: usize, dest: usize, caller: &str)
+where
+    G: Graph,
+{
+    if let Some(node) = [src, dest].iter().find(|n| **n >= g.get_n_nodes()) {
+        panic!(
+            "[algorithm::{}] Error: The node {} is not valid!",
+            caller, node
+        );
+    }
+    if src == dest {
+        panic!(
+            "[algorithm::{}] Error: The source and the destination are the same node!",
+            caller
+        );
+    }
+}
+
+// Runs a maximum flow limited to `k` units (with an extra source node) and decomposes it
+// in paths from `src` to `dest`
+fn disjoint_paths(mut net: FlowNetwork, src: usize, dest: usize, k: usize) -> Vec<Vec<usize>> {
+    let super_src = net.add_node();
+    net.add_arc(super_src, src, k as f32);
+    let n_paths = max_flow(&mut net, super_src, dest).round() as usize;
+    // Remaining units of flow of each arc, canceling the opposite flows between the
+    // same nodes
+    let mut flow: Vec<usize> = net
+        .get_arcs()
+        .iter()
+        .map(|arc| arc.flow.round() as usize)
+        .collect();
+    for (idx, arc) in net.get_arcs().iter().enumerate() {
+        let opposite = net
+            .get_out_arcs_of(arc.dest)
+            .iter()
+            .find(|a| flow[**a] > 0 && net.get_arc(**a).dest == arc.src);
+        if let Some(opposite) = opposite.copied() {
+            let canceled = flow[idx].min(flow[opposite]);
+            flow[idx] -= canceled;
+            flow[opposite] -= canceled;
+        }
+    }
+    let mut paths = vec![];
+    for _ in 0..n_paths {
+        let mut path = vec![src];
+        let mut current = src;
+        while current != dest {
+            let arc = match net.get_out_arcs_of(current).iter().find(|a| flow[**a] > 0) {
+                Some(arc) => *arc,
+                None => break, // the flow was fully decomposed
+            };
+            flow[arc] -= 1;
+            current = net.get_arc(arc).dest;
+            // Remove the cycles of the flow from the path
+            if let Some(pos) = path.iter().position(|n| *n == current) {
+                path.truncate(pos);
+            }
+            path.push(current);
+        }
+        if current != dest {
+            break;
+        }
+        paths.push(path);
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DGraph, UGraph};
+
+    #[test]
+    fn edge_disjoint_paths_check_directed() {
+        let g = DGraph::from_edges(4, vec![(0, 1), (1, 3), (0, 2), (2, 3), (3, 0)]);
+        let mut paths = edge_disjoint_paths(&g, 0, 3, 5);
+        paths.sort();
+        assert_eq!(paths, vec![vec![0, 1, 3], vec![0, 2, 3]]);
+        assert_eq!(edge_disjoint_paths(&g, 0, 3, 1).len(), 1);
+        assert!(edge_disjoint_paths(&g, 0, 3, 0).is_empty());
+    }
+
+    #[test]
+    fn edge_disjoint_paths_cancels_opposite_flows() {
+        // The shortest augmenting path uses the bridge 1-2 in one direction and the
+        // second one in the other, so the final paths can't use it
+        let edges = vec![(0, 1), (1, 2), (2, 5), (0, 3), (3, 2), (1, 4), (4, 5)];
+        let g = UGraph::from_edges(6, edges);
+        let paths = edge_disjoint_paths(&g, 0, 5, 2);
+        assert_eq!(paths.len(), 2);
+        let mut used = vec![];
+        for path in &paths {
+            for pair in path.windows(2) {
+                let edge = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+                assert!(!used.contains(&edge));
+                used.push(edge);
+            }
+        }
+    }
+
+    #[test]
+    fn node_disjoint_paths_check_values() {
+        let edges = vec![(0, 1), (1, 4), (0, 2), (2, 4), (0, 3), (3, 2)];
+        let g = UGraph::from_edges(5, edges);
+        let paths = node_disjoint_paths(&g, 0, 4, 3);
+        assert_eq!(paths.len(), 2); // 1 and 2 are a cut
+        let mut inner: Vec<usize> = paths
+            .iter()
+            .flat_map(|p| p[1..p.len() - 1].to_vec())
+            .collect();
+        let n_inner = inner.len();
+        inner.sort();
+        inner.dedup();
+        assert_eq!(inner.len(), n_inner);
+    }
+
+    #[test]
+    #[should_panic(expected = "are the same node")]
+    fn node_disjoint_paths_panic_same_node() {
+        node_disjoint_paths(&UGraph::from_edges(2, vec![(0, 1)]), 1, 1, 1);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::algorithm::{dijkstra_with_buffers, SearchBuffers};
+use crate::graph::Graph;
+use crate::parallel;
+use std::thread;
+
+/// Returns the matrix of shortest path distances between the nodes of `nodes` (or all
+/// the nodes if it is `None`), where `matrix[i][j]` is the distance from `nodes[i]` to
+/// `nodes[j]` and `f32::INFINITY` means that there is no path. It runs a dijkstra from
+/// each node reusing the same `SearchBuffers`.
+///
+/// # Panics
+///
+/// * If some node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::distance_matrix;
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(distance_matrix(&g, Some(&[3, 0])), vec![vec![0.0, 3.0], vec![3.0, 0.0]]);
+/// assert_eq!(distance_matrix(&g, None)[1], vec![1.0, 0.0, 1.0, 2.0]);
+/// ```
+pub fn distance_matrix<G>(g: &G, nodes: Option<&[usize]>) -> Vec<Vec<f32>>
+where
+    G: Graph,
+{
+    let nodes = subset(g, nodes, "distance_matrix");
+    let mut buffers = SearchBuffers::with_capacity(g.get_n_nodes());
+    distance_rows(g, &nodes, &nodes, &mut buffers)
+}
+
+/// Same as `distance_matrix` but splitting the sources between the available threads.
+///
+/// # Panics
+///
+/// * If some node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::{distance_matrix, par_distance_matrix};
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(par_distance_matrix(&g, None), distance_matrix(&g, None));
+/// ```
+pub fn par_distance_matrix<G>(g: &G, nodes: Option<&[usize]>) -> Vec<Vec<f32>>
+where
+    G: Graph + Sync,
+{
+    let nodes = subset(g, nodes, "par_distance_matrix");
+    let chunk_size = parallel::chunk_size(nodes.len());
+    if chunk_size == 0 {
+        return vec![];
+    }
+    let targets = &nodes;
+    thread::scope(|s| {
+        let handles: Vec<_> = nodes
+            .chunks(chunk_size)
+            .map(|sources| {
+                s.spawn(move || {
+                    let mut buffers = SearchBuffers::with_capacity(g.get_n_nodes());
+                    distance_rows(g, sources, targets, &mut buffers)
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| {
+                h.join()
+                    .expect("[algorithm::par_distance_matrix] Error: A thread panicked!")
+            })
+            .collect()
+    })
+}
+
+/// Same as `distance_matrix` but computing the matrix in blocks of up to `block_size`
+/// rows, so only one block is kept in memory. The function `f` is called with the index
+/// of the first row of each block and its rows, in order.
+///
+/// # Panics
+///
+/// * If some node is not valid or `block_size` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::distance_matrix_blocks;
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+/// let mut row_sums = vec![];
+/// distance_matrix_blocks(&g, None, 2, |_, rows| {
+///     row_sums.extend(rows.iter().map(|row| row.iter().sum::<f32>()));
+/// });
+/// assert_eq!(row_sums, vec![3.0, 2.0, 3.0]);
+/// ```
+pub fn distance_matrix_blocks<G, F>(g: &G, nodes: Option<&[usize]>, block_size: usize, mut f: F)
+where
+    G: Graph,
+    F: FnMut(usize, &[Vec<f32>]),
+{
+    if block_size == 0 {
+        panic!("[algorithm::distance_matrix_blocks] Error: The block size can't be 0!");
+    }
+    let nodes = subset(g, nodes, "distance_matrix_blocks");
+    let mut buffers = SearchBuffers::with_capacity(g.get_n_nodes());
+    for (idx, sources) in nodes.chunks(block_size).enumerate() {
+        let rows = distance_rows(g, sources, &nodes, &mut buffers);
+        f(idx * block_size, &rows);
+    }
+}
+
+//--------- Private functions ---------
+
+// Returns the nodes of the subset, or all the nodes, checking that they are valid
+fn subset<G>(g: &G, nodes: Option<&[usize]>, caller: &str) -> Vec<usize>
+where
+    G: Graph,
+{
+    match nodes {
+        Some(nodes) => {
+            if let Some(node) = nodes.iter().find(|n| **n >= g.get_n_nodes()) {
+                panic!(
+                    "[algorithm::{}] Error: The node {} is not valid!",
+                    caller, node
+                );
+            }
+            nodes.to_vec()
+        }
+        None => g.get_nodes(),
+    }
+}
+
+fn distance_rows<G>(
+    g: &G,
+    sources: &[usize],
+    targets: &[usize],
+    buffers: &mut SearchBuffers,
+) -> Vec<Vec<f32>>
+where
+    G: Graph,
+{
+    sources
+        .iter()
+        .map(|src| {
+            let dist = dijkstra_with_buffers(g, *src, buffers);
+            targets.iter().map(|t| dist[*t]).collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DGraph;
+
+    #[test]
+    fn distance_matrix_check_directed_subset() {
+        let g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 0.5)]);
+        let inf = f32::INFINITY;
+        assert_eq!(
+            distance_matrix(&g, Some(&[2, 0, 1])),
+            vec![
+                vec![0.0, inf, inf],
+                vec![2.5, 0.0, 2.0],
+                vec![0.5, inf, 0.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn distance_matrix_blocks_matches_full_matrix() {
+        let edges = (0..9).map(|n| (n, n + 1, 1.0 + n as f32)).collect();
+        let g = DGraph::from_weighted_edges(10, edges);
+        let mut blocks = vec![];
+        distance_matrix_blocks(&g, None, 3, |start, rows| {
+            blocks.push((start, rows.to_vec()))
+        });
+        assert_eq!(
+            blocks.iter().map(|b| b.0).collect::<Vec<_>>(),
+            vec![0, 3, 6, 9]
+        );
+        let rows: Vec<Vec<f32>> = blocks.into_iter().flat_map(|b| b.1).collect();
+        assert_eq!(rows, distance_matrix(&g, None));
+        assert_eq!(par_distance_matrix(&g, None), rows);
+    }
+
+    #[test]
+    #[should_panic(expected = "node 7 is not valid")]
+    fn par_distance_matrix_panic_not_valid_node() {
+        par_distance_matrix(&DGraph::from_edges(2, vec![(0, 1)]), Some(&[7]));
+    }
+}
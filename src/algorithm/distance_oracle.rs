@@ -0,0 +1,250 @@
+use crate::algorithm::multi_source_dijkstra;
+use crate::float::FloatOrd;
+use crate::graph::Graph;
+use crate::rng::Rng;
+use crate::UGraph;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+// Seed of the samples of `DistanceOracle::build`
+const DEFAULT_SEED: u64 = 0x7a0c;
+
+/// The `DistanceOracle` struct is a Thorup-Zwick approximate distance oracle of an
+/// undirected graph. It samples a hierarchy of `k` node sets, each one `n^(1/k)` times
+/// smaller than the previous one, and stores for each node its nearest sample of every
+/// level and its bunch: the samples closer to it than the nearest sample of the next
+/// level. With `O(k n^(1 + 1/k))` expected space, a query takes `O(k)` steps and returns
+/// an estimation `d'` of the distance `d` with `d <= d' <= (2k - 1) d`. With `k = 1` the
+/// distances are exact (all the pairs are stored).
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::DistanceOracle;
+/// use graphst::UGraph;
+/// // A ring of 8 nodes
+/// let edges = (0..8).map(|n| (n, (n + 1) % 8, 1.0)).collect();
+/// let g = UGraph::from_weighted_edges(8, edges);
+/// let oracle = DistanceOracle::build(&g, 2);
+/// let estimation = oracle.query(0, 3);
+/// assert!(3.0 <= estimation && estimation <= 3.0 * 3.0);
+/// assert_eq!(DistanceOracle::build(&g, 1).query(0, 5), 3.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistanceOracle {
+    // pivots[i][v]: The nearest sample of the level `i` to `v`, and its distance
+    pivots: Vec<Vec<Option<(usize, f32)>>>,
+    // bunches[v]: The samples of the bunch of `v`, with their distances to `v`
+    bunches: Vec<HashMap<usize, f32>>,
+}
+
+impl DistanceOracle {
+    /// Builds the oracle of the graph (with non-negative weights) with `k` levels of
+    /// samples, taking the samples with a fixed seed (see `build_with_seed`).
+    ///
+    /// # Panics
+    ///
+    /// * If `k` is `0`.
+    pub fn build(g: &UGraph, k: usize) -> DistanceOracle {
+        DistanceOracle::build_with_seed(g, k, DEFAULT_SEED)
+    }
+
+    /// Same as `build` but taking the samples with the random seed passed as a parameter.
+    /// The guarantee of the queries holds for any seed, only the size of the bunches
+    /// changes.
+    ///
+    /// # Panics
+    ///
+    /// * If `k` is `0`.
+    pub fn build_with_seed(g: &UGraph, k: usize, seed: u64) -> DistanceOracle {
+        if k == 0 {
+            panic!("[DistanceOracle::build] Error: The number of levels must be positive!");
+        }
+        let n_nodes = g.get_n_nodes();
+        let levels = sample_levels(n_nodes, k, seed);
+        let mut pivots = vec![];
+        for samples in &levels {
+            let nearest = multi_source_dijkstra(g, samples);
+            let level_pivots = nearest
+                .nearest
+                .iter()
+                .zip(nearest.distances)
+                .map(|(pivot, dist)| pivot.map(|p| (p, dist)))
+                .collect();
+            pivots.push(level_pivots);
+        }
+        // The last level has no next level, so its clusters are not bounded
+        let no_pivots = vec![None; n_nodes];
+        let mut bunches = vec![HashMap::new(); n_nodes];
+        for i in 0..k {
+            let next_pivots = pivots.get(i + 1).unwrap_or(&no_pivots);
+            // Membership mask of the next level, to skip its samples in constant time
+            let mut in_next_level = vec![false; n_nodes];
+            for w in levels.get(i + 1).map(|l| l.as_slice()).unwrap_or(&[]) {
+                in_next_level[*w] = true;
+            }
+            for w in &levels[i] {
+                if !in_next_level[*w] {
+                    grow_cluster(g, *w, next_pivots, &mut bunches);
+                }
+            }
+        }
+        DistanceOracle { pivots, bunches }
+    }
+
+    /// Returns the number of levels of the oracle (`k`).
+    pub fn get_k(&self) -> usize {
+        self.pivots.len()
+    }
+
+    /// Returns the number of distances stored in the bunches of all the nodes, which
+    /// dominates the memory used by the oracle.
+    pub fn get_size(&self) -> usize {
+        self.bunches.iter().map(|bunch| bunch.len()).sum()
+    }
+
+    /// Returns an estimation of the distance between the nodes passed as parameters, at
+    /// least the distance and at most `2k - 1` times it. Returns `f32::INFINITY` if the
+    /// nodes are not connected.
+    ///
+    /// # Panics
+    ///
+    /// * If some node is not valid.
+    pub fn query(&self, node1: usize, node2: usize) -> f32 {
+        if let Some(node) = [node1, node2].iter().find(|n| **n >= self.bunches.len()) {
+            panic!(
+                "[DistanceOracle::query] Error: The node {} is not valid!",
+                node
+            );
+        }
+        let (mut u, mut v) = (node1, node2);
+        let (mut w, mut dist_wu) = (u, 0.0);
+        // Climb the levels alternating the nodes until the pivot is in the other bunch
+        for i in 1..=self.get_k() {
+            if let Some(dist_wv) = self.bunches[v].get(&w) {
+                return dist_wu + dist_wv;
+            }
+            if i == self.get_k() {
+                break;
+            }
+            std::mem::swap(&mut u, &mut v);
+            match self.pivots[i][u] {
+                Some((pivot, dist)) => {
+                    w = pivot;
+                    dist_wu = dist;
+                }
+                None => break, // No sample of the level is connected to the nodes
+            }
+        }
+        f32::INFINITY
+    }
+}
+
+//--------- Private functions ---------
+
+// Samples the node sets A_0 (all the nodes) ⊇ A_1 ⊇ ... ⊇ A_{k-1}, keeping each node of
+// a level with probability n^(-1/k). The last level is sampled again until it isn't empty
+fn sample_levels(n_nodes: usize, k: usize, seed: u64) -> Vec<Vec<usize>> {
+    let mut rng = Rng::new(seed);
+    let p = (n_nodes as f64).powf(-1.0 / k as f64);
+    loop {
+        let mut levels = vec![(0..n_nodes).collect::<Vec<usize>>()];
+        for _ in 1..k {
+            let last = &levels[levels.len() - 1];
+            let next = last
+                .iter()
+                .copied()
+                .filter(|_| rng.next_f64() < p)
+                .collect();
+            levels.push(next);
+        }
+        if n_nodes == 0 || !levels[k - 1].is_empty() {
+            return levels;
+        }
+    }
+}
+
+// Dijkstra from `w` restricted to the nodes closer to `w` than to their pivot of the next
+// level (the cluster of `w`), adding `w` to the bunch of each of them
+fn grow_cluster(
+    g: &UGraph,
+    w: usize,
+    next_pivots: &[Option<(usize, f32)>],
+    bunches: &mut [HashMap<usize, f32>],
+) {
+    let bound = |node: usize| next_pivots[node].map_or(f32::INFINITY, |(_, d)| d);
+    let mut dist: HashMap<usize, f32> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    dist.insert(w, 0.0);
+    heap.push(Reverse((FloatOrd(0.0), w)));
+    while let Some(Reverse((FloatOrd(d), current))) = heap.pop() {
+        if d > dist[&current] || bunches[current].contains_key(&w) {
+            continue;
+        }
+        bunches[current].insert(w, d);
+        for n in g.get_neighbors_of(current) {
+            let new_dist = d + g.get_edge(current, n).unwrap_or(0.0);
+            if new_dist < bound(n) && dist.get(&n).map_or(true, |old| new_dist < *old) {
+                dist.insert(n, new_dist);
+                heap.push(Reverse((FloatOrd(new_dist), n)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::floyd_warshall;
+    use crate::float;
+
+    #[test]
+    fn query_check_stretch() {
+        let mut rng = Rng::new(23);
+        for _ in 0..20 {
+            let n_nodes = 2 + rng.gen_range(30);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(60) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                if node1 != node2 {
+                    let weight = 1.0 + rng.gen_range(9) as f32;
+                    adj_mat[node1][node2] = weight;
+                    adj_mat[node2][node1] = weight;
+                }
+            }
+            let g = UGraph::from_adjacency_matrix(adj_mat);
+            let dist = floyd_warshall(&g);
+            for k in 1..4 {
+                let oracle = DistanceOracle::build_with_seed(&g, k, rng.next_u64());
+                let stretch = (2 * k - 1) as f32;
+                for (node1, row) in dist.iter().enumerate() {
+                    for (node2, d) in row.iter().enumerate() {
+                        let estimation = oracle.query(node1, node2);
+                        if d.is_finite() {
+                            assert!(!float::less_than(estimation, *d));
+                            assert!(!float::greater_than(estimation, stretch * d));
+                        } else {
+                            assert_eq!(estimation, f32::INFINITY);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn build_check_exact_level() {
+        let g = UGraph::from_weighted_edges(4, vec![(0, 1, 2.0), (1, 2, 0.5)]);
+        let oracle = DistanceOracle::build(&g, 1);
+        assert_eq!(oracle.get_k(), 1);
+        assert_eq!(oracle.get_size(), 9 + 1); // The component {0, 1, 2} and the node 3
+        assert_eq!(oracle.query(2, 0), 2.5);
+        assert_eq!(oracle.query(0, 3), f32::INFINITY);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be positive")]
+    fn build_panic_zero_levels() {
+        DistanceOracle::build(&UGraph::new(), 0);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::backend::{CpuBackend, DenseBackend};
+use crate::graph::Graph;
+
+/// Given a graph (that implements `Graph`), returns the matrix with the length of the
+/// shortest path between each pair of nodes, where `f32::INFINITY` means that there is
+/// no path. Negative edges are allowed, and a negative cycle shows up as a negative
+/// value in the diagonal.
+///
+/// # Examples
+///
+/// ```
+/// let edges = vec![(0, 1, 4.0), (1, 2, 1.0), (0, 2, 6.0)];
+/// let g = graphst::DGraph::from_weighted_edges(3, edges);
+/// let dist = graphst::algorithm::floyd_warshall(&g);
+/// assert_eq!(dist[0], vec![0.0, 4.0, 5.0]);
+/// assert_eq!(dist[2], vec![f32::INFINITY, f32::INFINITY, 0.0]);
+/// ```
+pub fn floyd_warshall<G>(g: &G) -> Vec<Vec<f32>>
+where
+    G: Graph,
+{
+    floyd_warshall_with(g, &CpuBackend)
+}
+
+/// Same as `floyd_warshall` but running the computation in the `backend` provided.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::backend::CpuBackend;
+/// let g = graphst::UGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+/// let dist = graphst::algorithm::floyd_warshall_with(&g, &CpuBackend);
+/// assert_eq!(dist[0], vec![0.0, 1.0, 2.0]);
+/// ```
+pub fn floyd_warshall_with<G, B>(g: &G, backend: &B) -> Vec<Vec<f32>>
+where
+    G: Graph,
+    B: DenseBackend,
+{
+    let n_nodes = g.get_n_nodes();
+    let mut dist = vec![vec![f32::INFINITY; n_nodes]; n_nodes];
+    for node in g.get_nodes() {
+        dist[node][node] = 0.0;
+    }
+    for node1 in g.get_nodes() {
+        for node2 in g.get_out_neighbors_of(node1) {
+            if let Some(weight) = g.get_edge(node1, node2) {
+                if weight < dist[node1][node2] {
+                    dist[node1][node2] = weight;
+                }
+            }
+        }
+    }
+    backend.floyd_warshall(&mut dist);
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::dijkstra;
+    use crate::{DGraph, UGraph};
+
+    #[test]
+    fn floyd_warshall_matches_dijkstra() {
+        let edges = vec![
+            (0, 1, 4.0),
+            (0, 7, 8.0),
+            (1, 2, 8.0),
+            (1, 7, 11.0),
+            (2, 3, 7.0),
+            (2, 5, 4.0),
+            (2, 8, 2.0),
+            (3, 4, 9.0),
+            (3, 5, 14.0),
+            (4, 5, 10.0),
+            (5, 6, 2.0),
+            (6, 7, 1.0),
+            (6, 8, 6.0),
+            (7, 8, 8.0),
+        ];
+        let g = UGraph::from_weighted_edges(9, edges);
+        let dist = floyd_warshall(&g);
+        for src in g.get_nodes() {
+            assert_eq!(dist[src], dijkstra(&g, src));
+        }
+    }
+
+    #[test]
+    fn floyd_warshall_check_negative_edges_and_cycles() {
+        let g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, -1.0)]);
+        assert_eq!(floyd_warshall(&g)[0], vec![0.0, 2.0, 1.0]);
+        let g = DGraph::from_weighted_edges(2, vec![(0, 1, 1.0), (1, 0, -2.0)]);
+        assert!(floyd_warshall(&g)[0][0] < 0.0);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::algorithm::max_flow;
+use crate::graph::Graph;
+use crate::{FlowNetwork, UGraph};
+
+/// The `GomoryHuTree` struct is the cut tree returned by `gomory_hu_tree`. The tree has
+/// the nodes of the graph, and the minimum cut between two nodes of the graph is the
+/// lightest edge of the path between them in the tree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GomoryHuTree {
+    parents: Vec<Option<usize>>,
+    cut_values: Vec<f32>,
+}
+
+impl GomoryHuTree {
+    /// Returns the parent of the node in the tree, `None` for the root (the node `0`).
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    pub fn get_parent_of(&self, node: usize) -> Option<usize> {
+        if node >= self.parents.len() {
+            panic!(
+                "[GomoryHuTree::get_parent_of] Error: The node {} is not valid!",
+                node
+            );
+        }
+        self.parents[node]
+    }
+
+    /// Returns the edges of the tree as `(node, parent, cut_value)` triplets, in the order
+    /// of the nodes.
+    pub fn get_edges(&self) -> Vec<(usize, usize, f32)> {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter_map(|(node, parent)| parent.map(|p| (node, p, self.cut_values[node])))
+            .collect()
+    }
+
+    /// Returns the value of the minimum cut between the nodes passed as parameters in the
+    /// original graph. The cut between a node and itself is `f32::INFINITY`.
+    ///
+    /// # Panics
+    ///
+    /// * If some node is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::algorithm::gomory_hu_tree;
+    /// use graphst::UGraph;
+    /// let g = UGraph::from_weighted_edges(3, vec![(0, 1, 3.0), (1, 2, 1.0), (0, 2, 1.0)]);
+    /// let tree = gomory_hu_tree(&g);
+    /// assert_eq!(tree.min_cut_value(0, 1), 4.0);
+    /// assert_eq!(tree.min_cut_value(2, 0), 2.0);
+    /// ```
+    pub fn min_cut_value(&self, node1: usize, node2: usize) -> f32 {
+        if let Some(node) = [node1, node2].iter().find(|n| **n >= self.parents.len()) {
+            panic!(
+                "[GomoryHuTree::min_cut_value] Error: The node {} is not valid!",
+                node
+            );
+        }
+        // Lightest edge from node1 to each of its ancestors
+        let mut up_min = vec![None; self.parents.len()];
+        let mut current = node1;
+        let mut min = f32::INFINITY;
+        up_min[current] = Some(min);
+        while let Some(parent) = self.parents[current] {
+            min = min.min(self.cut_values[current]);
+            up_min[parent] = Some(min);
+            current = parent;
+        }
+        // Climb from node2 until reaching an ancestor of node1
+        let mut current = node2;
+        let mut min = f32::INFINITY;
+        loop {
+            if let Some(ancestor_min) = up_min[current] {
+                return min.min(ancestor_min);
+            }
+            match self.parents[current] {
+                Some(parent) => {
+                    min = min.min(self.cut_values[current]);
+                    current = parent;
+                }
+                None => unreachable!("The root is an ancestor of every node"),
+            }
+        }
+    }
+}
+
+/// Returns the Gomory-Hu tree of the graph, built with `n - 1` maximum flows (Gusfield's
+/// algorithm), using the weights of the edges as capacities. Then the minimum cut
+/// between any pair of nodes can be queried with `GomoryHuTree::min_cut_value` without
+/// computing more flows.
+///
+/// # Panics
+///
+/// * If some edge has a negative weight.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::gomory_hu_tree;
+/// use graphst::UGraph;
+/// // Two triangles joined by the edge 2-3
+/// let edges = vec![(0, 1), (1, 2), (0, 2), (2, 3), (3, 4), (4, 5), (3, 5)];
+/// let tree = gomory_hu_tree(&UGraph::from_edges(6, edges));
+/// assert_eq!(tree.min_cut_value(0, 5), 1.0);
+/// assert_eq!(tree.min_cut_value(0, 1), 2.0);
+/// ```
+pub fn gomory_hu_tree(g: &UGraph) -> GomoryHuTree {
+    let n_nodes = g.get_n_nodes();
+    let mut net = FlowNetwork::with_nodes(n_nodes);
+    for (node1, node2, weight) in g.get_edges() {
+        if weight < 0.0 {
+            panic!(
+                "[algorithm::gomory_hu_tree] Error: The weight {} is not valid!",
+                weight
+            );
+        }
+        if node1 != node2 {
+            net.add_arc(node1, node2, weight);
+            net.add_arc(node2, node1, weight);
+        }
+    }
+    let mut parents: Vec<Option<usize>> = (0..n_nodes)
+        .map(|n| if n == 0 { None } else { Some(0) })
+        .collect();
+    let mut cut_values = vec![0.0; n_nodes];
+    for src in 1..n_nodes {
+        let dest = parents[src].expect("Only the root has no parent");
+        net.reset_flows();
+        let value = max_flow(&mut net, src, dest);
+        let mut src_side = vec![false; n_nodes];
+        for node in net.get_residual_reachable_from(src) {
+            src_side[node] = true;
+        }
+        cut_values[src] = value;
+        for node in src + 1..n_nodes {
+            if src_side[node] && parents[node] == Some(dest) {
+                parents[node] = Some(src);
+            }
+        }
+        // Swap src and dest positions if the parent of dest is in the side of src
+        if let Some(dest_parent) = parents[dest] {
+            if src_side[dest_parent] {
+                parents[src] = Some(dest_parent);
+                parents[dest] = Some(src);
+                cut_values[src] = cut_values[dest];
+                cut_values[dest] = value;
+            }
+        }
+    }
+    GomoryHuTree {
+        parents,
+        cut_values,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::float;
+
+    #[test]
+    fn gomory_hu_tree_matches_max_flow() {
+        let edges = vec![
+            (0, 1, 1.0),
+            (0, 2, 7.0),
+            (1, 2, 1.0),
+            (1, 3, 3.0),
+            (1, 4, 2.0),
+            (2, 4, 4.0),
+            (3, 4, 1.0),
+            (3, 5, 6.0),
+            (4, 5, 2.0),
+        ];
+        let g = UGraph::from_weighted_edges(6, edges.clone());
+        let tree = gomory_hu_tree(&g);
+        assert_eq!(tree.get_edges().len(), 5);
+        for node1 in 0..6 {
+            for node2 in node1 + 1..6 {
+                let mut net = FlowNetwork::with_nodes(6);
+                for (a, b, w) in &edges {
+                    net.add_arc(*a, *b, *w);
+                    net.add_arc(*b, *a, *w);
+                }
+                let expected = max_flow(&mut net, node1, node2);
+                assert!(float::approx_eq(tree.min_cut_value(node1, node2), expected));
+            }
+        }
+    }
+
+    #[test]
+    fn gomory_hu_tree_check_disconnected_graph() {
+        let g = UGraph::from_edges(4, vec![(0, 1), (2, 3)]);
+        let tree = gomory_hu_tree(&g);
+        assert_eq!(tree.min_cut_value(0, 1), 1.0);
+        assert_eq!(tree.min_cut_value(1, 3), 0.0);
+        assert_eq!(tree.min_cut_value(2, 2), f32::INFINITY);
+    }
+
+    #[test]
+    #[should_panic(expected = "weight -1 is not valid")]
+    fn gomory_hu_tree_panic_negative_weight() {
+        gomory_hu_tree(&UGraph::from_weighted_edges(2, vec![(0, 1, -1.0)]));
+    }
+}
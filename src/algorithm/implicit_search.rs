@@ -0,0 +1,156 @@
+use crate::float::{self, FloatOrd};
+use crate::implicit::ImplicitGraph;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Returns the cheapest path from `start` to the first state that satisfies `is_goal`,
+/// exploring the graph from `start` with Dijkstra's algorithm. The result is the
+/// sequence of states of the path and its cost, or `None` if no goal is reachable. The
+/// states are generated on demand, so the search ends for infinite graphs as long as a
+/// goal is reachable.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `ImplicitGraph` (e.g. any `Graph`).
+/// * `start` - The initial state.
+/// * `is_goal` - A closure that returns `true` for the goal states.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::implicit_dijkstra;
+/// use graphst::UGraph;
+/// let g = UGraph::from_weighted_edges(4, vec![(0, 1, 1.0), (1, 2, 1.0), (0, 2, 3.0)]);
+/// assert_eq!(implicit_dijkstra(&g, 0, |n| *n == 2), Some((vec![0, 1, 2], 2.0)));
+/// assert_eq!(implicit_dijkstra(&g, 0, |n| *n == 3), None);
+/// ```
+pub fn implicit_dijkstra<G, F>(g: &G, start: G::State, is_goal: F) -> Option<(Vec<G::State>, f32)>
+where
+    G: ImplicitGraph,
+    F: Fn(&G::State) -> bool,
+{
+    implicit_astar(g, start, is_goal, |_| 0.0)
+}
+
+/// Same as `implicit_dijkstra` but guided by `heuristic`, an estimate of the remaining
+/// cost from a state to the closest goal (A* search). The returned path is the cheapest
+/// one if the heuristic never overestimates the remaining cost and is consistent.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `ImplicitGraph` (e.g. any `Graph`).
+/// * `start` - The initial state.
+/// * `is_goal` - A closure that returns `true` for the goal states.
+/// * `heuristic` - A closure with the estimated cost from a state to a goal.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::implicit_astar;
+/// use graphst::ImplicitGraph;
+/// // An unbounded grid where each step costs 1.0
+/// struct Grid;
+/// impl ImplicitGraph for Grid {
+///     type State = (i32, i32);
+///     fn successors(&self, (x, y): &(i32, i32)) -> Vec<((i32, i32), f32)> {
+///         vec![((x + 1, *y), 1.0), ((x - 1, *y), 1.0), ((*x, y + 1), 1.0), ((*x, y - 1), 1.0)]
+///     }
+/// }
+/// let goal = (3, -2);
+/// let manhattan = |(x, y): &(i32, i32)| ((goal.0 - x).abs() + (goal.1 - y).abs()) as f32;
+/// let (path, cost) = implicit_astar(&Grid, (0, 0), |s| *s == goal, manhattan).unwrap();
+/// assert_eq!(cost, 5.0);
+/// assert_eq!(path.len(), 6);
+/// ```
+pub fn implicit_astar<G, F, H>(
+    g: &G,
+    start: G::State,
+    is_goal: F,
+    heuristic: H,
+) -> Option<(Vec<G::State>, f32)>
+where
+    G: ImplicitGraph,
+    F: Fn(&G::State) -> bool,
+    H: Fn(&G::State) -> f32,
+{
+    // The states get an index when discovered
+    let mut index: HashMap<G::State, usize> = HashMap::new();
+    let mut states = vec![start.clone()];
+    let mut dist = vec![0.0];
+    let mut parent: Vec<Option<usize>> = vec![None];
+    let mut closed = vec![false];
+    index.insert(start.clone(), 0);
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((FloatOrd(heuristic(&start)), 0)));
+
+    while let Some(Reverse((_, current))) = heap.pop() {
+        if closed[current] {
+            continue; // Outdated entry of an already expanded state
+        }
+        closed[current] = true;
+        if is_goal(&states[current]) {
+            let cost = dist[current];
+            let mut path = vec![];
+            let mut node = Some(current);
+            while let Some(idx) = node {
+                path.push(states[idx].clone());
+                node = parent[idx];
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+        for (state, cost) in g.successors(&states[current]) {
+            let new_dist = dist[current] + cost;
+            let idx = match index.get(&state) {
+                Some(idx) => *idx,
+                None => {
+                    states.push(state.clone());
+                    dist.push(f32::INFINITY);
+                    parent.push(None);
+                    closed.push(false);
+                    index.insert(state, states.len() - 1);
+                    states.len() - 1
+                }
+            };
+            if !closed[idx] && float::greater_than(dist[idx], new_dist) {
+                dist[idx] = new_dist;
+                parent[idx] = Some(current);
+                let priority = new_dist + heuristic(&states[idx]);
+                heap.push(Reverse((FloatOrd(priority), idx)));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::dijkstra;
+    use crate::DGraph;
+
+    #[test]
+    fn implicit_dijkstra_matches_dijkstra_on_graphs() {
+        let edges = vec![
+            (0, 1, 4.0),
+            (0, 2, 1.0),
+            (2, 1, 2.0),
+            (1, 3, 1.0),
+            (2, 3, 5.0),
+        ];
+        let g = DGraph::from_weighted_edges(5, edges);
+        for (dest, dist) in dijkstra(&g, 0).into_iter().enumerate().take(4) {
+            let (path, cost) = implicit_dijkstra(&g, 0, |n| *n == dest).unwrap();
+            assert_eq!(cost, dist);
+            assert_eq!((path[0], path[path.len() - 1]), (0, dest));
+        }
+        assert_eq!(implicit_dijkstra(&g, 0, |n| *n == 4), None);
+    }
+
+    #[test]
+    fn implicit_astar_check_start_is_goal() {
+        let g = DGraph::from_edges(2, vec![(0, 1)]);
+        let result = implicit_astar(&g, 1, |_| true, |_| 10.0);
+        assert_eq!(result, Some((vec![1], 0.0)));
+    }
+}
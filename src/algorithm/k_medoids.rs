@@ -0,0 +1,158 @@
+use crate::algorithm::dijkstra;
+use crate::graph::Graph;
+
+/// The `KMedoids` struct holds the result of `k_medoids_on_graph`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KMedoids {
+    /// The sorted medoid nodes, one for each cluster.
+    pub medoids: Vec<usize>,
+    /// The cluster of each node, as the position of its medoid in `medoids`.
+    pub assignments: Vec<usize>,
+    /// The sum of the distances from each node to its medoid, ignoring the nodes that
+    /// can't reach any medoid.
+    pub cost: f32,
+}
+
+/// Clusters the nodes of the graph in `k` groups by their shortest path distances, with
+/// the PAM algorithm (greedy BUILD followed by SWAP steps until no swap improves the
+/// cost). Each cluster is represented by its medoid, the node that minimizes the
+/// distance from the other nodes of the cluster, and each node is assigned to its
+/// closest medoid (the first one in case of a tie). For directed graphs the distance is
+/// measured from the node to the medoid. Reaching every node always takes priority over
+/// the total distance, so disconnected graphs get a medoid in each component when `k`
+/// allows it.
+///
+/// # Panics
+///
+/// * If `k` is `0` or greater than the number of nodes.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::k_medoids_on_graph;
+/// use graphst::UGraph;
+/// // Two paths 0-1-2 and 3-4-5 joined by a long edge 2-3
+/// let edges = vec![(0, 1, 1.0), (1, 2, 1.0), (2, 3, 10.0), (3, 4, 1.0), (4, 5, 1.0)];
+/// let g = UGraph::from_weighted_edges(6, edges);
+/// let clusters = k_medoids_on_graph(&g, 2);
+/// assert_eq!(clusters.medoids, vec![1, 4]);
+/// assert_eq!(clusters.assignments, vec![0, 0, 0, 1, 1, 1]);
+/// assert_eq!(clusters.cost, 4.0);
+/// ```
+pub fn k_medoids_on_graph<G>(g: &G, k: usize) -> KMedoids
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if k == 0 || k > n_nodes {
+        panic!(
+            "[algorithm::k_medoids_on_graph] Error: The number of clusters {} is not valid!",
+            k
+        );
+    }
+    let dist: Vec<Vec<f32>> = g.get_nodes().into_iter().map(|n| dijkstra(g, n)).collect();
+    // BUILD: add the medoids one by one, each time the one that reduces the cost the most
+    let mut medoids: Vec<usize> = vec![];
+    for _ in 0..k {
+        let best = (0..n_nodes)
+            .filter(|n| !medoids.contains(n))
+            .map(|candidate| {
+                let mut with_candidate = medoids.clone();
+                with_candidate.push(candidate);
+                (candidate, total_cost(&dist, &with_candidate))
+            })
+            .min_by(|a, b| a.1.partial_cmp(&b.1).expect("The costs are not NaN"));
+        medoids.push(best.expect("There are nodes left").0);
+    }
+    // SWAP: replace a medoid by a non-medoid while it improves the cost
+    let mut cost = total_cost(&dist, &medoids);
+    loop {
+        let mut best_swap = None;
+        for pos in 0..k {
+            for candidate in (0..n_nodes).filter(|n| !medoids.contains(n)) {
+                let mut swapped = medoids.clone();
+                swapped[pos] = candidate;
+                let swapped_cost = total_cost(&dist, &swapped);
+                if swapped_cost < best_swap.map_or(cost, |(_, _, c)| c) {
+                    best_swap = Some((pos, candidate, swapped_cost));
+                }
+            }
+        }
+        match best_swap {
+            Some((pos, candidate, swapped_cost)) => {
+                medoids[pos] = candidate;
+                cost = swapped_cost;
+            }
+            None => break,
+        }
+    }
+    medoids.sort_unstable();
+    let assignments = (0..n_nodes)
+        .map(|node| closest_medoid(&dist[node], &medoids))
+        .collect();
+    KMedoids {
+        assignments,
+        cost: cost.1,
+        medoids,
+    }
+}
+
+//--------- Private functions ---------
+
+// Returns the position of the closest medoid to the node with the distances `row`
+fn closest_medoid(row: &[f32], medoids: &[usize]) -> usize {
+    let mut best = 0;
+    for (pos, medoid) in medoids.iter().enumerate() {
+        if row[*medoid] < row[medoids[best]] {
+            best = pos;
+        }
+    }
+    best
+}
+
+// Returns the cost of the medoids as (number of nodes without a reachable medoid, sum of
+// the distances of the other nodes to their closest medoid)
+fn total_cost(dist: &[Vec<f32>], medoids: &[usize]) -> (usize, f32) {
+    let mut n_unreachable = 0;
+    let mut sum = 0.0;
+    for row in dist {
+        let closest = row[medoids[closest_medoid(row, medoids)]];
+        if closest == f32::INFINITY {
+            n_unreachable += 1;
+        } else {
+            sum += closest;
+        }
+    }
+    (n_unreachable, sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UGraph;
+
+    #[test]
+    fn k_medoids_on_graph_covers_components() {
+        // Two triangles without any edge between them
+        let edges = vec![(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5)];
+        let g = UGraph::from_edges(6, edges);
+        let clusters = k_medoids_on_graph(&g, 2);
+        assert_eq!(clusters.assignments, vec![0, 0, 0, 1, 1, 1]);
+        assert_eq!(clusters.cost, 4.0);
+    }
+
+    #[test]
+    fn k_medoids_on_graph_check_every_node_is_a_medoid() {
+        let g = UGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+        let clusters = k_medoids_on_graph(&g, 3);
+        assert_eq!(clusters.medoids, vec![0, 1, 2]);
+        assert_eq!(clusters.assignments, vec![0, 1, 2]);
+        assert_eq!(clusters.cost, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "clusters 0 is not valid")]
+    fn k_medoids_on_graph_panic_zero_clusters() {
+        k_medoids_on_graph(&UGraph::from_edges(2, vec![(0, 1)]), 0);
+    }
+}
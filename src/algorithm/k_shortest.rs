@@ -0,0 +1,228 @@
+use crate::float::{self, FloatOrd};
+use crate::graph::Graph;
+use crate::path;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+/// Returns up to `k` shortest loopless paths from `src` to `dest` with Yen's algorithm, as
+/// the sequences of their nodes and their total costs, sorted by increasing cost. Fewer
+/// paths are returned if there are not `k` loopless paths. Each path is found with a
+/// Dijkstra search from a node of a previous path, avoiding the edges already used by the
+/// paths that share the same prefix, so it runs `O(k n)` searches.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`, with non-negative weights.
+/// * `src` - The first node of the paths.
+/// * `dest` - The last node of the paths.
+/// * `k` - The maximum number of paths to find.
+///
+/// # Panics
+///
+/// * If some node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::k_shortest_paths;
+/// use graphst::DGraph;
+/// let edges = vec![(0, 1, 1.0), (1, 3, 1.0), (0, 2, 2.0), (2, 3, 1.0), (1, 2, 0.5)];
+/// let g = DGraph::from_weighted_edges(4, edges);
+/// let paths = k_shortest_paths(&g, 0, 3, 5);
+/// assert_eq!(
+///     paths,
+///     vec![
+///         (vec![0, 1, 3], 2.0),
+///         (vec![0, 1, 2, 3], 2.5),
+///         (vec![0, 2, 3], 3.0),
+///     ]
+/// );
+/// ```
+pub fn k_shortest_paths<G>(g: &G, src: usize, dest: usize, k: usize) -> Vec<(Vec<usize>, f32)>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if let Some(node) = [src, dest].iter().find(|n| **n >= n_nodes) {
+        panic!(
+            "[algorithm::k_shortest_paths] Error: The node {} is not valid!",
+            node
+        );
+    }
+    let mut paths: Vec<(Vec<usize>, f32)> = vec![];
+    if k == 0 {
+        return paths;
+    }
+    match restricted_dijkstra(g, src, dest, &vec![false; n_nodes], &HashSet::new()) {
+        Some(path) => paths.push((path.clone(), path_cost(g, &path))),
+        None => return paths,
+    }
+    // The candidates found so far, to be selected by cost
+    let mut candidates: Vec<(Vec<usize>, f32)> = vec![];
+    while paths.len() < k {
+        let last = paths[paths.len() - 1].0.clone();
+        for j in 0..last.len() - 1 {
+            let (spur, root) = (last[j], &last[..=j]);
+            // Forbid the next edge of every path with the same root, and the root nodes
+            let mut banned_edges = HashSet::new();
+            for (path, _) in &paths {
+                if path.len() > j + 1 && &path[..=j] == root {
+                    banned_edges.insert((path[j], path[j + 1]));
+                    if !g.is_directed() {
+                        banned_edges.insert((path[j + 1], path[j]));
+                    }
+                }
+            }
+            let mut banned_nodes = vec![false; n_nodes];
+            root[..j].iter().for_each(|node| banned_nodes[*node] = true);
+            if let Some(spur_path) =
+                restricted_dijkstra(g, spur, dest, &banned_nodes, &banned_edges)
+            {
+                let mut path = root[..j].to_vec();
+                path.extend(spur_path);
+                if !candidates.iter().any(|(p, _)| *p == path) {
+                    let cost = path_cost(g, &path);
+                    candidates.push((path, cost));
+                }
+            }
+        }
+        // Select the cheapest candidate, the shortest one on ties
+        let best = candidates
+            .iter()
+            .enumerate()
+            .min_by(|(_, (p1, c1)), (_, (p2, c2))| {
+                c1.total_cmp(c2)
+                    .then(p1.len().cmp(&p2.len()))
+                    .then(p1.cmp(p2))
+            })
+            .map(|(idx, _)| idx);
+        match best {
+            Some(idx) => paths.push(candidates.swap_remove(idx)),
+            None => break,
+        }
+    }
+    paths
+}
+
+//--------- Private functions ---------
+
+// Dijkstra from `src` to `dest` without the banned nodes and edges, returning the path
+fn restricted_dijkstra<G>(
+    g: &G,
+    src: usize,
+    dest: usize,
+    banned_nodes: &[bool],
+    banned_edges: &HashSet<(usize, usize)>,
+) -> Option<Vec<usize>>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    let mut dist = vec![f32::INFINITY; n_nodes];
+    let mut predecessors = vec![None; n_nodes];
+    let mut visited = vec![false; n_nodes];
+    let mut heap = BinaryHeap::new();
+    dist[src] = 0.0;
+    heap.push(Reverse((FloatOrd(0.0), src)));
+    while let Some(Reverse((_, current))) = heap.pop() {
+        if visited[current] {
+            continue;
+        }
+        if current == dest {
+            return Some(path::from_predecessors(&predecessors, dest));
+        }
+        visited[current] = true;
+        for n in g.get_out_neighbors_of(current) {
+            if banned_nodes[n] || banned_edges.contains(&(current, n)) {
+                continue;
+            }
+            if let Some(edge_weight) = g.get_edge(current, n) {
+                if !visited[n] && float::greater_than(dist[n], dist[current] + edge_weight) {
+                    dist[n] = dist[current] + edge_weight;
+                    predecessors[n] = Some(current);
+                    heap.push(Reverse((FloatOrd(dist[n]), n)));
+                }
+            }
+        }
+    }
+    None
+}
+
+fn path_cost<G>(g: &G, path: &[usize]) -> f32
+where
+    G: Graph,
+{
+    path.windows(2)
+        .map(|w| g.get_edge(w[0], w[1]).unwrap_or(f32::INFINITY))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DGraph, UGraph};
+
+    // Costs of every loopless path from `node` to `dest`, by exhaustive search
+    fn all_path_costs(
+        g: &UGraph,
+        node: usize,
+        dest: usize,
+        path: &mut Vec<usize>,
+        costs: &mut Vec<f32>,
+    ) {
+        if node == dest {
+            costs.push(path_cost(g, path));
+            return;
+        }
+        for n in g.get_out_neighbors_of(node) {
+            if !path.contains(&n) {
+                path.push(n);
+                all_path_costs(g, n, dest, path, costs);
+                path.pop();
+            }
+        }
+    }
+
+    #[test]
+    fn k_shortest_paths_matches_brute_force() {
+        let mut rng = crate::rng::Rng::new(3);
+        for _ in 0..30 {
+            let n_nodes = 2 + rng.gen_range(6);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(12) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                if node1 != node2 {
+                    let weight = 1.0 + rng.gen_range(5) as f32;
+                    adj_mat[node1][node2] = weight;
+                    adj_mat[node2][node1] = weight;
+                }
+            }
+            let g = UGraph::from_adjacency_matrix(adj_mat);
+            let mut expected = vec![];
+            all_path_costs(&g, 0, n_nodes - 1, &mut vec![0], &mut expected);
+            expected.sort_by(|a, b| a.total_cmp(b));
+            expected.truncate(6);
+            let paths = k_shortest_paths(&g, 0, n_nodes - 1, 6);
+            let costs: Vec<f32> = paths.iter().map(|(_, cost)| *cost).collect();
+            assert_eq!(costs, expected);
+            for (path, _) in &paths {
+                let unique: HashSet<_> = path.iter().collect();
+                assert_eq!(unique.len(), path.len()); // loopless
+            }
+        }
+    }
+
+    #[test]
+    fn k_shortest_paths_check_unreachable_and_same_node() {
+        let g = DGraph::from_edges(3, vec![(0, 1), (2, 1)]);
+        assert_eq!(k_shortest_paths(&g, 0, 2, 3), vec![]);
+        assert_eq!(k_shortest_paths(&g, 1, 1, 3), vec![(vec![1], 0.0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn k_shortest_paths_panic_not_valid_node() {
+        let g = DGraph::from_edges(2, vec![(0, 1)]);
+        k_shortest_paths(&g, 0, 2, 1);
+    }
+}
@@ -0,0 +1,819 @@
+use crate::graph::Graph;
+use crate::{DGraph, UGraph};
+
+// Marks an unset vertex, blossom or endpoint (the `-1` of the original formulation)
+const NONE: usize = usize::MAX;
+
+/// Returns a maximum weight matching of the undirected graph: a set of edges without
+/// common nodes with the maximum total weight. The matching is returned as the list of
+/// its edges `(node1, node2)`, with `node1 < node2`, sorted. The edges with a weight
+/// lower than or equal to `0.0` never improve a matching, so they are not used, and
+/// self-loops are ignored. Unlike the bipartite matchers, the graph can have odd cycles.
+///
+/// This is Edmonds' blossom algorithm with the primal-dual method of Galil, in
+/// `O(n^3)`, following the well-tested implementation by Joris van Rantwijk (also used by
+/// NetworkX).
+///
+/// # Arguments
+///
+/// * `g` - An undirected graph.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::max_weight_matching;
+/// use graphst::UGraph;
+/// // A triangle with a pendant node: two light edges beat the heaviest one
+/// let edges = vec![(0, 1, 2.0), (1, 2, 3.0), (0, 2, 2.0), (2, 3, 2.0)];
+/// let g = UGraph::from_weighted_edges(4, edges);
+/// assert_eq!(max_weight_matching(&g), vec![(0, 1), (2, 3)]);
+/// ```
+pub fn max_weight_matching(g: &UGraph) -> Vec<(usize, usize)> {
+    let edges: Vec<(usize, usize, f64)> = g
+        .get_edges()
+        .into_iter()
+        .filter(|(node1, node2, _)| node1 != node2)
+        .map(|(node1, node2, weight)| (node1, node2, weight as f64))
+        .collect();
+    let mate = Matcher::new(g.get_n_nodes(), edges).run();
+    let mut matching: Vec<(usize, usize)> = mate
+        .iter()
+        .enumerate()
+        .filter_map(|(node, m)| m.filter(|m| node < *m).map(|m| (node, m)))
+        .collect();
+    matching.sort_unstable();
+    matching
+}
+
+/// Returns a stable matching between the members of two groups `A` and `B`, given the
+/// preferences of each member over the other group (Gale-Shapley algorithm, with the
+/// members of `A` proposing). The matching is stable: no pair `a`, `b` would both prefer
+/// each other to their current partners. Among the stable matchings, it is the best one
+/// for every member of `A`.
+///
+/// The preferences are lists of members of the other group, from the most to the least
+/// preferred. The missing members are unacceptable partners, so with incomplete lists
+/// some members can stay unmatched.
+///
+/// The result is a bipartite `DGraph` with `n_a + n_b` nodes, where the member `a` of `A`
+/// is the node `a` and the member `b` of `B` is the node `n_a + b`. Each matched pair is
+/// an edge from its member of `A` to its member of `B`.
+///
+/// # Arguments
+///
+/// * `preferences_a` - The preferences of each member of `A` over the members of `B`.
+/// * `preferences_b` - The preferences of each member of `B` over the members of `A`.
+///
+/// # Panics
+///
+/// * If some preference list has an invalid or repeated member.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::stable_matching;
+/// use graphst::Graph;
+/// let preferences_a = vec![vec![0, 1], vec![0, 1]];
+/// let preferences_b = vec![vec![1, 0], vec![0, 1]];
+/// let g = stable_matching(&preferences_a, &preferences_b);
+/// // Both members of A want the member 0 of B (node 2), who prefers the member 1
+/// assert_eq!(g.get_edges(), vec![(0, 3, 1.0), (1, 2, 1.0)]);
+/// ```
+pub fn stable_matching(preferences_a: &[Vec<usize>], preferences_b: &[Vec<usize>]) -> DGraph {
+    let (n_a, n_b) = (preferences_a.len(), preferences_b.len());
+    check_preferences(preferences_a, n_b, "A");
+    check_preferences(preferences_b, n_a, "B");
+    // rank[b][a]: position of a in the preferences of b, `None` if unacceptable
+    let mut rank = vec![vec![None; n_a]; n_b];
+    for (b, preferences) in preferences_b.iter().enumerate() {
+        for (pos, a) in preferences.iter().enumerate() {
+            rank[b][*a] = Some(pos);
+        }
+    }
+    let mut partner_of_b: Vec<Option<usize>> = vec![None; n_b];
+    let mut next_proposal = vec![0; n_a];
+    let mut free: Vec<usize> = (0..n_a).rev().collect();
+    while let Some(a) = free.pop() {
+        // a proposes to the next member of its list until someone accepts
+        while let Some(b) = preferences_a[a].get(next_proposal[a]).copied() {
+            next_proposal[a] += 1;
+            let rank_a = match rank[b][a] {
+                Some(rank_a) => rank_a,
+                None => continue,
+            };
+            match partner_of_b[b] {
+                None => {
+                    partner_of_b[b] = Some(a);
+                    break;
+                }
+                Some(current) if rank_a < rank[b][current].unwrap() => {
+                    partner_of_b[b] = Some(a);
+                    free.push(current);
+                    break;
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    let mut g = DGraph::from_adjacency_matrix(vec![vec![0.0; n_a + n_b]; n_a + n_b]);
+    for (b, a) in partner_of_b.iter().enumerate() {
+        if let Some(a) = a {
+            g.add_edge(*a, n_a + b);
+        }
+    }
+    g
+}
+
+//--------- Private functions ---------
+
+fn check_preferences(preferences: &[Vec<usize>], n_other: usize, group: &str) {
+    for (member, list) in preferences.iter().enumerate() {
+        let mut seen = vec![false; n_other];
+        for other in list {
+            if *other >= n_other || seen[*other] {
+                panic!(
+                    "[algorithm::stable_matching] Error: The preferences of the member {} \
+                     of {} are not valid!",
+                    member, group
+                );
+            }
+            seen[*other] = true;
+        }
+    }
+}
+
+// State of the blossom algorithm. The vertices are `0..n` and the non-trivial blossoms
+// `n..2n`. The endpoint `p` of the edge `p / 2` is the vertex `endpoint[p]`, and `p ^ 1`
+// is the other endpoint of the same edge.
+struct Matcher {
+    n: usize,
+    edges: Vec<(usize, usize, f64)>,
+    endpoint: Vec<usize>,
+    neighbend: Vec<Vec<usize>>,
+    // Remote endpoint of the matched edge of each vertex
+    mate: Vec<usize>,
+    // 0: unlabeled, 1: S, 2: T (5 marks the S-blossoms visited by `scan_blossom`)
+    label: Vec<u8>,
+    labelend: Vec<usize>,
+    inblossom: Vec<usize>,
+    blossomparent: Vec<usize>,
+    blossomchilds: Vec<Vec<usize>>,
+    blossombase: Vec<usize>,
+    blossomendps: Vec<Vec<usize>>,
+    bestedge: Vec<usize>,
+    blossombestedges: Vec<Option<Vec<usize>>>,
+    unusedblossoms: Vec<usize>,
+    dualvar: Vec<f64>,
+    allowedge: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+impl Matcher {
+    fn new(n: usize, edges: Vec<(usize, usize, f64)>) -> Matcher {
+        let max_weight = edges.iter().map(|(_, _, w)| *w).fold(0.0, f64::max);
+        let endpoint = (0..2 * edges.len())
+            .map(|p| {
+                let (i, j, _) = edges[p / 2];
+                if p % 2 == 0 {
+                    i
+                } else {
+                    j
+                }
+            })
+            .collect();
+        let mut neighbend = vec![vec![]; n];
+        for (k, (i, j, _)) in edges.iter().enumerate() {
+            neighbend[*i].push(2 * k + 1);
+            neighbend[*j].push(2 * k);
+        }
+        let n_edges = edges.len();
+        Matcher {
+            n,
+            edges,
+            endpoint,
+            neighbend,
+            mate: vec![NONE; n],
+            label: vec![0; 2 * n],
+            labelend: vec![NONE; 2 * n],
+            inblossom: (0..n).collect(),
+            blossomparent: vec![NONE; 2 * n],
+            blossomchilds: vec![vec![]; 2 * n],
+            blossombase: (0..2 * n).map(|b| if b < n { b } else { NONE }).collect(),
+            blossomendps: vec![vec![]; 2 * n],
+            bestedge: vec![NONE; 2 * n],
+            blossombestedges: vec![None; 2 * n],
+            unusedblossoms: (n..2 * n).collect(),
+            dualvar: (0..2 * n)
+                .map(|b| if b < n { max_weight } else { 0.0 })
+                .collect(),
+            allowedge: vec![false; n_edges],
+            queue: vec![],
+        }
+    }
+
+    // Returns the mate of each vertex
+    fn run(mut self) -> Vec<Option<usize>> {
+        let n = self.n;
+        for _ in 0..n {
+            // Start a new stage
+            self.label.iter_mut().for_each(|l| *l = 0);
+            self.bestedge.iter_mut().for_each(|e| *e = NONE);
+            self.blossombestedges[n..]
+                .iter_mut()
+                .for_each(|e| *e = None);
+            self.allowedge.iter_mut().for_each(|a| *a = false);
+            self.queue.clear();
+            for v in 0..n {
+                if self.mate[v] == NONE && self.label[self.inblossom[v]] == 0 {
+                    self.assign_label(v, 1, NONE);
+                }
+            }
+            let mut augmented = false;
+            loop {
+                while !augmented {
+                    let v = match self.queue.pop() {
+                        Some(v) => v,
+                        None => break,
+                    };
+                    for idx in 0..self.neighbend[v].len() {
+                        let p = self.neighbend[v][idx];
+                        let k = p / 2;
+                        let w = self.endpoint[p];
+                        if self.inblossom[v] == self.inblossom[w] {
+                            continue; // internal edge of a blossom
+                        }
+                        let mut kslack = 0.0;
+                        if !self.allowedge[k] {
+                            kslack = self.slack(k);
+                            if kslack <= 0.0 {
+                                self.allowedge[k] = true;
+                            }
+                        }
+                        if self.allowedge[k] {
+                            if self.label[self.inblossom[w]] == 0 {
+                                // w is free, label it T and its mate S
+                                self.assign_label(w, 2, p ^ 1);
+                            } else if self.label[self.inblossom[w]] == 1 {
+                                // Either a new blossom or an augmenting path
+                                let base = self.scan_blossom(v, w);
+                                if base != NONE {
+                                    self.add_blossom(base, k);
+                                } else {
+                                    self.augment_matching(k);
+                                    augmented = true;
+                                    break;
+                                }
+                            } else if self.label[w] == 0 {
+                                // w is inside a T-blossom but not yet reached
+                                self.label[w] = 2;
+                                self.labelend[w] = p ^ 1;
+                            }
+                        } else if self.label[self.inblossom[w]] == 1 {
+                            let b = self.inblossom[v];
+                            if self.bestedge[b] == NONE || kslack < self.slack(self.bestedge[b]) {
+                                self.bestedge[b] = k;
+                            }
+                        } else if self.label[w] == 0
+                            && (self.bestedge[w] == NONE || kslack < self.slack(self.bestedge[w]))
+                        {
+                            self.bestedge[w] = k;
+                        }
+                    }
+                }
+                if augmented {
+                    break;
+                }
+
+                // No augmenting path with the allowed edges, update the dual variables
+                let mut deltatype = 1;
+                let mut delta = self.dualvar[..n]
+                    .iter()
+                    .cloned()
+                    .fold(f64::INFINITY, f64::min);
+                let mut deltaedge = NONE;
+                let mut deltablossom = NONE;
+                for v in 0..n {
+                    if self.label[self.inblossom[v]] == 0 && self.bestedge[v] != NONE {
+                        let d = self.slack(self.bestedge[v]);
+                        if d < delta {
+                            delta = d;
+                            deltatype = 2;
+                            deltaedge = self.bestedge[v];
+                        }
+                    }
+                }
+                for b in 0..2 * n {
+                    if self.blossomparent[b] == NONE
+                        && self.label[b] == 1
+                        && self.bestedge[b] != NONE
+                    {
+                        let d = self.slack(self.bestedge[b]) / 2.0;
+                        if d < delta {
+                            delta = d;
+                            deltatype = 3;
+                            deltaedge = self.bestedge[b];
+                        }
+                    }
+                }
+                for b in n..2 * n {
+                    if self.blossombase[b] != NONE
+                        && self.blossomparent[b] == NONE
+                        && self.label[b] == 2
+                        && self.dualvar[b] < delta
+                    {
+                        delta = self.dualvar[b];
+                        deltatype = 4;
+                        deltablossom = b;
+                    }
+                }
+                for v in 0..n {
+                    match self.label[self.inblossom[v]] {
+                        1 => self.dualvar[v] -= delta,
+                        2 => self.dualvar[v] += delta,
+                        _ => {}
+                    }
+                }
+                for b in n..2 * n {
+                    if self.blossombase[b] != NONE && self.blossomparent[b] == NONE {
+                        match self.label[b] {
+                            1 => self.dualvar[b] += delta,
+                            2 => self.dualvar[b] -= delta,
+                            _ => {}
+                        }
+                    }
+                }
+                match deltatype {
+                    1 => break, // the optimum is reached
+                    2 => {
+                        self.allowedge[deltaedge] = true;
+                        let (mut i, j, _) = self.edges[deltaedge];
+                        if self.label[self.inblossom[i]] == 0 {
+                            i = j;
+                        }
+                        self.queue.push(i);
+                    }
+                    3 => {
+                        self.allowedge[deltaedge] = true;
+                        let (i, _, _) = self.edges[deltaedge];
+                        self.queue.push(i);
+                    }
+                    _ => self.expand_blossom(deltablossom, false),
+                }
+            }
+            if !augmented {
+                break;
+            }
+            // Expand the S-blossoms with zero dual at the end of the stage
+            for b in n..2 * n {
+                if self.blossomparent[b] == NONE
+                    && self.blossombase[b] != NONE
+                    && self.label[b] == 1
+                    && self.dualvar[b] == 0.0
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+        self.mate
+            .iter()
+            .map(|p| {
+                if *p == NONE {
+                    None
+                } else {
+                    Some(self.endpoint[*p])
+                }
+            })
+            .collect()
+    }
+
+    fn slack(&self, k: usize) -> f64 {
+        let (i, j, w) = self.edges[k];
+        self.dualvar[i] + self.dualvar[j] - 2.0 * w
+    }
+
+    fn blossom_leaves(&self, b: usize) -> Vec<usize> {
+        if b < self.n {
+            return vec![b];
+        }
+        let mut leaves = vec![];
+        let mut stack = vec![b];
+        while let Some(t) = stack.pop() {
+            if t < self.n {
+                leaves.push(t);
+            } else {
+                stack.extend(self.blossomchilds[t].iter().rev());
+            }
+        }
+        leaves
+    }
+
+    // Labels the top-level blossom of `w` with `t` (S or T), reached through the endpoint
+    // `p`. The mate of a T-blossom is labeled S.
+    fn assign_label(&mut self, w: usize, t: u8, p: usize) {
+        let b = self.inblossom[w];
+        self.label[w] = t;
+        self.label[b] = t;
+        self.labelend[w] = p;
+        self.labelend[b] = p;
+        self.bestedge[w] = NONE;
+        self.bestedge[b] = NONE;
+        if t == 1 {
+            let leaves = self.blossom_leaves(b);
+            self.queue.extend(leaves);
+        } else {
+            let base = self.blossombase[b];
+            let mate = self.mate[base];
+            self.assign_label(self.endpoint[mate], 1, mate ^ 1);
+        }
+    }
+
+    // Traces back from `v` and `w` to find the base of a new blossom, or `NONE` if they
+    // are in different trees (an augmenting path)
+    fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+        let mut path = vec![];
+        let mut base = NONE;
+        while v != NONE || w != NONE {
+            let mut b = self.inblossom[v];
+            if self.label[b] & 4 != 0 {
+                base = self.blossombase[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = 5;
+            if self.labelend[b] == NONE {
+                v = NONE; // the root of the tree
+            } else {
+                v = self.endpoint[self.labelend[b]];
+                b = self.inblossom[v];
+                v = self.endpoint[self.labelend[b]];
+            }
+            if w != NONE {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in path {
+            self.label[b] = 1;
+        }
+        base
+    }
+
+    // Builds a new blossom with the base `base`, closed by the edge `k`
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (mut v, mut w, _) = self.edges[k];
+        let bb = self.inblossom[base];
+        let mut bv = self.inblossom[v];
+        let mut bw = self.inblossom[w];
+        let b = self.unusedblossoms.pop().unwrap();
+        self.blossombase[b] = base;
+        self.blossomparent[b] = NONE;
+        self.blossomparent[bb] = b;
+        let mut path = vec![];
+        let mut endps = vec![];
+        while bv != bb {
+            self.blossomparent[bv] = b;
+            path.push(bv);
+            endps.push(self.labelend[bv]);
+            v = self.endpoint[self.labelend[bv]];
+            bv = self.inblossom[v];
+        }
+        path.push(bb);
+        path.reverse();
+        endps.reverse();
+        endps.push(2 * k);
+        while bw != bb {
+            self.blossomparent[bw] = b;
+            path.push(bw);
+            endps.push(self.labelend[bw] ^ 1);
+            w = self.endpoint[self.labelend[bw]];
+            bw = self.inblossom[w];
+        }
+        self.blossomchilds[b] = path.clone();
+        self.blossomendps[b] = endps;
+        self.label[b] = 1;
+        self.labelend[b] = self.labelend[bb];
+        self.dualvar[b] = 0.0;
+        for v in self.blossom_leaves(b) {
+            if self.label[self.inblossom[v]] == 2 {
+                // The T-vertices of the blossom become S-vertices
+                self.queue.push(v);
+            }
+            self.inblossom[v] = b;
+        }
+        // Compute the least-slack edges to the neighboring S-blossoms
+        let mut bestedgeto = vec![NONE; 2 * self.n];
+        for bv in path {
+            let nblist: Vec<usize> = match self.blossombestedges[bv].take() {
+                Some(list) => list,
+                None => self
+                    .blossom_leaves(bv)
+                    .into_iter()
+                    .flat_map(|v| self.neighbend[v].iter().map(|p| p / 2))
+                    .collect(),
+            };
+            for k in nblist {
+                let (i, mut j, _) = self.edges[k];
+                if self.inblossom[j] == b {
+                    j = i;
+                }
+                let bj = self.inblossom[j];
+                if bj != b
+                    && self.label[bj] == 1
+                    && (bestedgeto[bj] == NONE || self.slack(k) < self.slack(bestedgeto[bj]))
+                {
+                    bestedgeto[bj] = k;
+                }
+            }
+            self.bestedge[bv] = NONE;
+        }
+        let best: Vec<usize> = bestedgeto.into_iter().filter(|k| *k != NONE).collect();
+        self.bestedge[b] = NONE;
+        for k in &best {
+            if self.bestedge[b] == NONE || self.slack(*k) < self.slack(self.bestedge[b]) {
+                self.bestedge[b] = *k;
+            }
+        }
+        self.blossombestedges[b] = Some(best);
+    }
+
+    // Expands the blossom `b` into its sub-blossoms
+    fn expand_blossom(&mut self, b: usize, endstage: bool) {
+        for s in self.blossomchilds[b].clone() {
+            self.blossomparent[s] = NONE;
+            if s < self.n {
+                self.inblossom[s] = s;
+            } else if endstage && self.dualvar[s] == 0.0 {
+                self.expand_blossom(s, endstage);
+            } else {
+                for v in self.blossom_leaves(s) {
+                    self.inblossom[v] = s;
+                }
+            }
+        }
+        // A T-blossom expanded in the middle of a stage relabels its sub-blossoms
+        if !endstage && self.label[b] == 2 {
+            let len = self.blossomchilds[b].len() as isize;
+            let child = |m: &Matcher, j: isize| m.blossomchilds[b][j.rem_euclid(len) as usize];
+            let endp = |m: &Matcher, j: isize| m.blossomendps[b][j.rem_euclid(len) as usize];
+            let entrychild = self.inblossom[self.endpoint[self.labelend[b] ^ 1]];
+            let mut j = self.blossomchilds[b]
+                .iter()
+                .position(|c| *c == entrychild)
+                .unwrap() as isize;
+            let (jstep, endptrick) = if j & 1 == 1 {
+                j -= len;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+            let mut p = self.labelend[b];
+            while j != 0 {
+                // Relabel the T-sub-blossom
+                self.label[self.endpoint[p ^ 1]] = 0;
+                let q = endp(self, j - endptrick as isize) ^ endptrick ^ 1;
+                self.label[self.endpoint[q]] = 0;
+                self.assign_label(self.endpoint[p ^ 1], 2, p);
+                let q = endp(self, j - endptrick as isize) / 2;
+                self.allowedge[q] = true;
+                j += jstep;
+                p = endp(self, j - endptrick as isize) ^ endptrick;
+                self.allowedge[p / 2] = true;
+                j += jstep;
+            }
+            // Relabel the base T-sub-blossom without assigning a label to its mate
+            let bv = child(self, j);
+            self.label[self.endpoint[p ^ 1]] = 2;
+            self.label[bv] = 2;
+            self.labelend[self.endpoint[p ^ 1]] = p;
+            self.labelend[bv] = p;
+            self.bestedge[bv] = NONE;
+            j += jstep;
+            while child(self, j) != entrychild {
+                let bv = child(self, j);
+                if self.label[bv] == 1 {
+                    j += jstep;
+                    continue;
+                }
+                // Label the sub-blossom if one of its vertices is reachable
+                if let Some(v) = self
+                    .blossom_leaves(bv)
+                    .into_iter()
+                    .find(|v| self.label[*v] != 0)
+                {
+                    self.label[v] = 0;
+                    let base_mate = self.mate[self.blossombase[bv]];
+                    self.label[self.endpoint[base_mate]] = 0;
+                    self.assign_label(v, 2, self.labelend[v]);
+                }
+                j += jstep;
+            }
+        }
+        self.label[b] = 0;
+        self.labelend[b] = NONE;
+        self.blossomchilds[b].clear();
+        self.blossomendps[b].clear();
+        self.blossombase[b] = NONE;
+        self.blossombestedges[b] = None;
+        self.bestedge[b] = NONE;
+        self.unusedblossoms.push(b);
+    }
+
+    // Swaps the matched and unmatched edges of the blossom `b` along the path from the
+    // vertex `v` to the base, so that `v` becomes the new base
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.blossomparent[t] != b {
+            t = self.blossomparent[t];
+        }
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+        let len = self.blossomchilds[b].len() as isize;
+        let i = self.blossomchilds[b].iter().position(|c| *c == t).unwrap();
+        let mut j = i as isize;
+        let (jstep, endptrick) = if j & 1 == 1 {
+            j -= len;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+        while j != 0 {
+            j += jstep;
+            let t = self.blossomchilds[b][j.rem_euclid(len) as usize];
+            let p =
+                self.blossomendps[b][(j - endptrick as isize).rem_euclid(len) as usize] ^ endptrick;
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+            j += jstep;
+            let t = self.blossomchilds[b][j.rem_euclid(len) as usize];
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+            self.mate[self.endpoint[p]] = p ^ 1;
+            self.mate[self.endpoint[p ^ 1]] = p;
+        }
+        self.blossomchilds[b].rotate_left(i);
+        self.blossomendps[b].rotate_left(i);
+        self.blossombase[b] = self.blossombase[self.blossomchilds[b][0]];
+    }
+
+    // Augments the matching along the path through the edge `k`
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)] {
+            loop {
+                let bs = self.inblossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = p;
+                if self.labelend[bs] == NONE {
+                    break; // the root of the tree
+                }
+                let t = self.endpoint[self.labelend[bs]];
+                let bt = self.inblossom[t];
+                s = self.endpoint[self.labelend[bt]];
+                let j = self.endpoint[self.labelend[bt] ^ 1];
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = self.labelend[bt];
+                p = self.labelend[bt] ^ 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    fn total_weight(g: &UGraph, matching: &[(usize, usize)]) -> f32 {
+        matching
+            .iter()
+            .map(|(a, b)| g.get_edge(*a, *b).unwrap())
+            .sum()
+    }
+
+    // Best total weight by trying every matching
+    fn brute_force(g: &UGraph, used: &mut Vec<bool>, from: usize) -> f32 {
+        let node = match (from..g.get_n_nodes()).find(|n| !used[*n]) {
+            Some(node) => node,
+            None => return 0.0,
+        };
+        used[node] = true;
+        let mut best = brute_force(g, used, node + 1); // node unmatched
+        for n in g.get_neighbors_of(node) {
+            if !used[n] && n != node {
+                used[n] = true;
+                let w = g.get_edge(node, n).unwrap() + brute_force(g, used, node + 1);
+                best = best.max(w);
+                used[n] = false;
+            }
+        }
+        used[node] = false;
+        best
+    }
+
+    #[test]
+    fn max_weight_matching_check_blossom() {
+        // A 5-cycle where the best matching needs an edge from the pendant node
+        let edges = vec![
+            (0, 1, 8.0),
+            (1, 2, 9.0),
+            (2, 3, 10.0),
+            (3, 4, 7.0),
+            (4, 0, 9.0),
+            (2, 5, 6.0),
+        ];
+        let g = UGraph::from_weighted_edges(6, edges);
+        let matching = max_weight_matching(&g);
+        assert_eq!(total_weight(&g, &matching), 21.0);
+        assert_eq!(matching, vec![(0, 1), (2, 5), (3, 4)]);
+    }
+
+    #[test]
+    fn max_weight_matching_ignores_non_positive_edges() {
+        let g = UGraph::from_weighted_edges(3, vec![(0, 1, -1.0), (1, 1, 5.0)]);
+        assert_eq!(max_weight_matching(&g), vec![]);
+    }
+
+    #[test]
+    fn max_weight_matching_matches_brute_force() {
+        let mut rng = Rng::new(42);
+        for _ in 0..300 {
+            let n_nodes = 2 + rng.gen_range(8);
+            let mut g = UGraph::from_adjacency_matrix(vec![vec![0.0; n_nodes]; n_nodes]);
+            for node1 in 0..n_nodes {
+                for node2 in node1 + 1..n_nodes {
+                    if rng.gen_range(2) == 0 {
+                        g.add_weighted_edge(node1, node2, 1.0 + rng.gen_range(10) as f32);
+                    }
+                }
+            }
+            let matching = max_weight_matching(&g);
+            let mut covered: Vec<usize> = matching.iter().flat_map(|(a, b)| [*a, *b]).collect();
+            covered.sort_unstable();
+            covered.dedup();
+            assert_eq!(covered.len(), 2 * matching.len());
+            let best = brute_force(&g, &mut vec![false; n_nodes], 0);
+            assert_eq!(total_weight(&g, &matching), best);
+        }
+    }
+
+    // Checks that no pair prefers each other to their partners
+    fn is_stable(preferences_a: &[Vec<usize>], preferences_b: &[Vec<usize>], g: &DGraph) -> bool {
+        let n_a = preferences_a.len();
+        let partner_a: Vec<Option<usize>> = (0..n_a)
+            .map(|a| g.get_successors_of(a).first().map(|b| b - n_a))
+            .collect();
+        let partner_b: Vec<Option<usize>> = (0..preferences_b.len())
+            .map(|b| g.get_predecessors_of(n_a + b).first().copied())
+            .collect();
+        let prefers = |list: &Vec<usize>, new: usize, current: Option<usize>| {
+            let pos = |m: usize| list.iter().position(|x| *x == m);
+            match (pos(new), current) {
+                (None, _) => false,
+                (Some(_), None) => true,
+                (Some(p), Some(c)) => p < pos(c).unwrap(),
+            }
+        };
+        (0..n_a).all(|a| {
+            preferences_a[a].iter().all(|b| {
+                !(prefers(&preferences_a[a], *b, partner_a[a])
+                    && prefers(&preferences_b[*b], a, partner_b[*b]))
+            })
+        })
+    }
+
+    #[test]
+    fn stable_matching_check_random_preferences() {
+        let mut rng = Rng::new(7);
+        for _ in 0..50 {
+            let (n_a, n_b) = (1 + rng.gen_range(6), 1 + rng.gen_range(6));
+            let mut random_lists = |n: usize, n_other: usize| -> Vec<Vec<usize>> {
+                (0..n)
+                    .map(|_| {
+                        let mut list: Vec<usize> = (0..n_other).collect();
+                        rng.shuffle(&mut list);
+                        list.truncate(1 + rng.gen_range(n_other));
+                        list
+                    })
+                    .collect()
+            };
+            let preferences_a = random_lists(n_a, n_b);
+            let preferences_b = random_lists(n_b, n_a);
+            let g = stable_matching(&preferences_a, &preferences_b);
+            assert!((0..n_a + n_b).all(|n| g.get_predecessors_of(n).len() <= 1));
+            assert!((0..n_a).all(|a| g.get_successors_of(a).len() <= 1));
+            assert!(is_stable(&preferences_a, &preferences_b, &g));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "are not valid")]
+    fn stable_matching_panic_repeated_member() {
+        stable_matching(&[vec![0, 0]], &[vec![0]]);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{float, FlowNetwork, ResidualArc};
+use std::collections::VecDeque;
+
+/// Computes a maximum flow from `source` to `sink` with the Edmonds-Karp algorithm
+/// (shortest augmenting paths) and returns its value. The flow is stored in the arcs of
+/// the network, starting from the flow that they already have, so after the call
+/// `net.get_residual_reachable_from(source)` gives the source side of a minimum cut.
+///
+/// # Panics
+///
+/// * If some node is not valid or the source and the sink are the same node.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::max_flow;
+/// use graphst::FlowNetwork;
+/// let arcs = vec![(0, 1, 3.0), (0, 2, 2.0), (1, 2, 1.0), (1, 3, 2.0), (2, 3, 3.0)];
+/// let mut net = FlowNetwork::from_arcs(4, arcs);
+/// assert_eq!(max_flow(&mut net, 0, 3), 5.0);
+/// assert_eq!(net.get_excess_of(3), 5.0);
+/// ```
+pub fn max_flow(net: &mut FlowNetwork, source: usize, sink: usize) -> f32 {
+    let n_nodes = net.get_n_nodes();
+    if let Some(node) = [source, sink].iter().find(|n| **n >= n_nodes) {
+        panic!(
+            "[algorithm::max_flow] Error: The node {} is not valid!",
+            node
+        );
+    }
+    if source == sink {
+        panic!("[algorithm::max_flow] Error: The source and the sink are the same node!");
+    }
+    let mut total = 0.0;
+    while let Some(path) = shortest_augmenting_path(net, source, sink) {
+        let amount = path
+            .iter()
+            .map(|r| r.capacity)
+            .fold(f32::INFINITY, f32::min);
+        for residual in &path {
+            net.push_flow(residual, amount);
+        }
+        total += amount;
+    }
+    total
+}
+
+//--------- Private functions ---------
+
+// Returns the residual arcs of a path from `source` to `sink` with the minimum number of
+// arcs (BFS), or `None` if the sink is not reachable in the residual graph
+fn shortest_augmenting_path(
+    net: &FlowNetwork,
+    source: usize,
+    sink: usize,
+) -> Option<Vec<ResidualArc>> {
+    let mut parent: Vec<Option<ResidualArc>> = vec![None; net.get_n_nodes()];
+    let mut visited = vec![false; net.get_n_nodes()];
+    visited[source] = true;
+    let mut queue = VecDeque::from(vec![source]);
+    while let Some(node) = queue.pop_front() {
+        for residual in net.get_residual_arcs_of(node) {
+            if !visited[residual.dest] && float::greater_than(residual.capacity, 0.0) {
+                visited[residual.dest] = true;
+                parent[residual.dest] = Some(residual);
+                if residual.dest == sink {
+                    let mut path = vec![];
+                    let mut current = sink;
+                    while let Some(r) = parent[current] {
+                        path.push(r);
+                        current = r.src;
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                queue.push_back(residual.dest);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_flow_uses_backward_arcs() {
+        // The first augmenting path 0-1-2-3 has to be partially canceled
+        let arcs = vec![
+            (0, 1, 1.0),
+            (0, 2, 1.0),
+            (1, 2, 1.0),
+            (1, 3, 1.0),
+            (2, 3, 1.0),
+        ];
+        let mut net = FlowNetwork::from_arcs(4, arcs);
+        assert_eq!(max_flow(&mut net, 0, 3), 2.0);
+        assert_eq!(net.get_residual_reachable_from(0), vec![0]);
+    }
+
+    #[test]
+    fn max_flow_check_parallel_arcs_and_unreachable_sink() {
+        let mut net = FlowNetwork::from_arcs(3, vec![(0, 1, 1.5), (0, 1, 2.0)]);
+        assert_eq!(max_flow(&mut net, 0, 1), 3.5);
+        assert_eq!(max_flow(&mut net, 0, 2), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "are the same node")]
+    fn max_flow_panic_same_node() {
+        max_flow(&mut FlowNetwork::with_nodes(2), 1, 1);
+    }
+}
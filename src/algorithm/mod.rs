@@ -3,5 +3,141 @@
 //! `algorithm` is a `graphst` module that gives access to some some popular
 //! graph algorithms implementations.
 
+mod buffers;
+pub use buffers::SearchBuffers;
+
+mod bfs;
+pub use bfs::{bfs, bfs_to, BfsTraversal};
+
+mod zero_one_bfs;
+pub use zero_one_bfs::zero_one_bfs;
+
+mod dfs;
+pub use dfs::{dfs, dfs_all, DfsEvent};
+
 mod dijkstra;
-pub use dijkstra::dijkstra;
+pub use dijkstra::{
+    dijkstra, dijkstra_dense, dijkstra_dial, dijkstra_to, dijkstra_with_buffers,
+    dijkstra_with_paths, try_dijkstra, ShortestPathTree,
+};
+
+mod dijkstra_iter;
+pub use dijkstra_iter::{DijkstraIter, DijkstraStep};
+
+mod floyd_warshall;
+pub use floyd_warshall::{floyd_warshall, floyd_warshall_with};
+
+mod bipartite;
+pub use bipartite::{bipartite_projection, ProjectionMode};
+
+mod scc;
+pub use scc::{condensation, strongly_connected_components};
+
+mod dependencies;
+pub use dependencies::resolve_dependencies;
+
+mod max_flow;
+pub use max_flow::max_flow;
+
+mod multi_commodity;
+pub use multi_commodity::{route_commodities, CommodityRouting};
+
+mod turn_penalties;
+pub use turn_penalties::{dijkstra_to_with_turn_penalties, dijkstra_with_turn_penalties};
+
+mod node_weighted;
+pub use node_weighted::{node_weighted_shortest_paths, NodeCost};
+
+mod multi_criteria;
+pub use multi_criteria::{multi_criteria_shortest_paths, ParetoPath};
+
+mod disjoint_paths;
+pub use disjoint_paths::{edge_disjoint_paths, node_disjoint_paths};
+
+mod connectivity;
+pub use connectivity::{
+    edge_connectivity, is_k_connected, is_k_edge_connected, vertex_connectivity,
+};
+
+mod biconnected;
+pub use biconnected::biconnected_components;
+
+mod threshold_sweep;
+pub use threshold_sweep::{weight_threshold_sweep, ThresholdLevel};
+
+mod mst;
+pub use mst::{boruvka_mst, kruskal_mst, prim_mst, prim_mst_from};
+
+mod gomory_hu;
+pub use gomory_hu::{gomory_hu_tree, GomoryHuTree};
+
+mod k_medoids;
+pub use k_medoids::{k_medoids_on_graph, KMedoids};
+
+mod voronoi;
+pub use voronoi::{graph_voronoi, GraphVoronoi};
+
+mod multi_source;
+pub use multi_source::{multi_source_dijkstra, NearestSources};
+
+mod distance_matrix;
+pub use distance_matrix::{distance_matrix, distance_matrix_blocks, par_distance_matrix};
+
+mod distance_oracle;
+pub use distance_oracle::DistanceOracle;
+
+mod bellman_ford;
+pub use bellman_ford::bellman_ford;
+
+mod spfa;
+pub use spfa::{spfa, spfa_with_limit};
+
+mod arbitrage;
+pub use arbitrage::{find_arbitrage, Arbitrage};
+
+mod dag_paths;
+pub use dag_paths::{
+    critical_path, critical_path_schedule, dag_longest_paths, dag_shortest_paths,
+    CriticalPathSchedule,
+};
+
+mod topological_orders;
+pub use topological_orders::TopologicalOrders;
+
+mod transitive;
+pub use transitive::{transitive_closure, transitive_reduction};
+
+mod auto;
+pub use auto::{shortest_paths_auto, ShortestPathMethod, ShortestPaths};
+
+mod trails;
+pub use trails::trail_decomposition;
+
+mod matching;
+pub use matching::{max_weight_matching, stable_matching};
+
+mod implicit_search;
+pub use implicit_search::{implicit_astar, implicit_dijkstra};
+
+mod astar;
+pub use astar::astar;
+
+mod k_shortest;
+pub use k_shortest::k_shortest_paths;
+
+mod widest_path;
+pub use widest_path::widest_path;
+
+mod coloring;
+pub use coloring::edge_coloring;
+
+mod orientation;
+pub use orientation::{acyclic_orientation, balanced_orientation};
+
+mod chordal;
+pub use chordal::{chordal_coloring, chordal_max_clique, is_chordal};
+
+mod tree_decomposition;
+pub use tree_decomposition::{
+    tree_decomposition, tree_decomposition_with, EliminationHeuristic, TreeDecomposition,
+};
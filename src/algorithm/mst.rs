@@ -0,0 +1,304 @@
+use crate::dense;
+use crate::graph::Graph;
+use crate::parallel;
+use crate::{DisjointSet, UGraph};
+use std::cmp::Ordering;
+
+/// Returns the minimum spanning tree of an undirected graph with Kruskal's algorithm: the
+/// subgraph with all the nodes that connects them with the lowest total weight. The edges
+/// are taken by increasing weight, skipping the ones that join nodes already connected
+/// (tracked with a `DisjointSet`), so it runs in `O(m log m)`. If the graph is not
+/// connected, it returns a minimum spanning forest with a tree per component. The
+/// self-loops are never part of the tree, and negative weights are supported.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::kruskal_mst;
+/// use graphst::{Graph, UGraph};
+/// let edges = vec![(0, 1, 4.0), (1, 2, 1.0), (0, 2, 2.0), (2, 3, 5.0), (1, 3, 3.0)];
+/// let g = UGraph::from_weighted_edges(4, edges);
+/// let mst = kruskal_mst(&g);
+/// assert_eq!(mst.get_edges(), vec![(0, 2, 2.0), (1, 2, 1.0), (1, 3, 3.0)]);
+/// let total: f32 = mst.get_edges().iter().map(|(_, _, w)| w).sum();
+/// assert_eq!(total, 6.0);
+/// ```
+pub fn kruskal_mst(g: &UGraph) -> UGraph {
+    let n_nodes = g.get_n_nodes();
+    let mut edges = g.get_edges();
+    edges.sort_by(|a, b| a.2.total_cmp(&b.2));
+    let mut mst = UGraph::from_edges(n_nodes, vec![]);
+    let mut components = DisjointSet::new(n_nodes);
+    for (node1, node2, weight) in edges {
+        if components.get_n_sets() <= 1 {
+            break; // The tree already spans all the nodes
+        }
+        if components.union(node1, node2) {
+            mst.add_weighted_edge(node1, node2, weight);
+        }
+    }
+    mst
+}
+
+/// Returns the minimum spanning tree of an undirected graph with Prim's algorithm, growing
+/// the tree from each node not reached yet. The closest node to the tree is selected with
+/// a linear scan (`dense::row_min_scan`) instead of a heap, so it runs in `O(n^2)`, which
+/// is optimal for dense graphs. It gives the same total weight as `kruskal_mst` (a
+/// minimum spanning forest if the graph is not connected).
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::{kruskal_mst, prim_mst};
+/// use graphst::{Graph, UGraph};
+/// let edges = vec![(0, 1, 4.0), (1, 2, 1.0), (0, 2, 2.0), (2, 3, 5.0), (1, 3, 3.0)];
+/// let g = UGraph::from_weighted_edges(4, edges);
+/// assert_eq!(prim_mst(&g).get_edges(), kruskal_mst(&g).get_edges());
+/// ```
+pub fn prim_mst(g: &UGraph) -> UGraph {
+    let n_nodes = g.get_n_nodes();
+    let mut parents = vec![None; n_nodes];
+    let mut in_tree = vec![false; n_nodes];
+    for root in g.get_nodes() {
+        if !in_tree[root] {
+            grow_tree(g, root, &mut in_tree, &mut parents);
+        }
+    }
+    let mut mst = UGraph::from_edges(n_nodes, vec![]);
+    for (node, parent) in parents.into_iter().enumerate() {
+        if let Some(parent) = parent {
+            mst.add_weighted_edge(node, parent, g.get_edge(node, parent).unwrap_or(0.0));
+        }
+    }
+    mst
+}
+
+/// Returns the minimum spanning tree of the component of `root` with Prim's algorithm, as
+/// the parent of each node in the tree rooted at `root`. The root and the nodes of other
+/// components get `None`.
+///
+/// # Panics
+///
+/// * If the root node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::prim_mst_from;
+/// use graphst::UGraph;
+/// let edges = vec![(0, 1, 4.0), (1, 2, 1.0), (0, 2, 2.0), (3, 4, 1.0)];
+/// let g = UGraph::from_weighted_edges(5, edges);
+/// assert_eq!(prim_mst_from(&g, 0), vec![None, Some(2), Some(0), None, None]);
+/// ```
+pub fn prim_mst_from(g: &UGraph, root: usize) -> Vec<Option<usize>> {
+    let n_nodes = g.get_n_nodes();
+    if root >= n_nodes {
+        panic!(
+            "[algorithm::prim_mst_from] Error: The node {} is not valid!",
+            root
+        );
+    }
+    let mut parents = vec![None; n_nodes];
+    grow_tree(g, root, &mut vec![false; n_nodes], &mut parents);
+    parents
+}
+
+/// Returns the minimum spanning tree of an undirected graph with Borůvka's algorithm. In
+/// each round every component picks its cheapest edge to another component, and all the
+/// picked edges are added at once, so the number of components at least halves and there
+/// are `O(log n)` rounds. The cheapest edge leaving each node is searched in parallel with
+/// `parallel::par_map_nodes`, and then reduced to the cheapest edge of each component.
+/// The ties between equal weights are
+/// broken by the order of `get_edges`, so the result is the same tree as `kruskal_mst`
+/// (a minimum spanning forest if the graph is not connected).
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::{boruvka_mst, kruskal_mst};
+/// use graphst::{Graph, UGraph};
+/// let edges = vec![(0, 1, 1.0), (1, 2, 1.0), (0, 2, 1.0), (2, 3, 2.0), (3, 4, 0.5)];
+/// let g = UGraph::from_weighted_edges(5, edges);
+/// let mst = boruvka_mst(&g);
+/// assert_eq!(mst.get_edges(), vec![(0, 1, 1.0), (0, 2, 1.0), (2, 3, 2.0), (3, 4, 0.5)]);
+/// assert_eq!(mst, kruskal_mst(&g));
+/// ```
+pub fn boruvka_mst(g: &UGraph) -> UGraph {
+    let n_nodes = g.get_n_nodes();
+    let edges = g.get_edges();
+    // Indices in `edges` of the edges of each node
+    let mut incident = vec![vec![]; n_nodes];
+    for (idx, (node1, node2, _)) in edges.iter().enumerate() {
+        incident[*node1].push(idx);
+        if node1 != node2 {
+            incident[*node2].push(idx);
+        }
+    }
+    let mut mst = UGraph::from_edges(n_nodes, vec![]);
+    let mut components = DisjointSet::new(n_nodes);
+    loop {
+        let roots: Vec<usize> = (0..n_nodes).map(|node| components.find(node)).collect();
+        // Cheapest edge (index in `edges`) leaving the component of each node
+        let node_cheapest = parallel::par_map_nodes(g, |node| {
+            incident[node]
+                .iter()
+                .copied()
+                .filter(|idx| roots[edges[*idx].0] != roots[edges[*idx].1])
+                .min_by(|a, b| cheaper(&edges, *a, *b))
+        });
+        // Cheapest edge leaving each component, by its root
+        let mut cheapest: Vec<Option<usize>> = vec![None; n_nodes];
+        for (node, idx) in node_cheapest.into_iter().enumerate() {
+            if let Some(idx) = idx {
+                let root = roots[node];
+                if cheapest[root].map_or(true, |best| cheaper(&edges, idx, best).is_lt()) {
+                    cheapest[root] = Some(idx);
+                }
+            }
+        }
+        let mut merged = false;
+        for idx in cheapest.into_iter().flatten() {
+            let (node1, node2, weight) = edges[idx];
+            // Two components can pick the same edge
+            if components.union(node1, node2) {
+                mst.add_weighted_edge(node1, node2, weight);
+                merged = true;
+            }
+        }
+        if !merged {
+            return mst;
+        }
+    }
+}
+
+//--------- Private functions ---------
+
+// Compares two edges (indices in `edges`) by weight, breaking the ties by index
+fn cheaper(edges: &[(usize, usize, f32)], idx1: usize, idx2: usize) -> Ordering {
+    edges[idx1]
+        .2
+        .total_cmp(&edges[idx2].2)
+        .then(idx1.cmp(&idx2))
+}
+
+// Adds the nodes of the component of `root` to the tree, setting their parents. The
+// nodes of other components keep an infinite distance, so the scan never selects them
+fn grow_tree(g: &UGraph, root: usize, in_tree: &mut [bool], parents: &mut [Option<usize>]) {
+    // best: The weight of the cheapest edge from the tree to each node
+    let mut best = vec![f32::INFINITY; g.get_n_nodes()];
+    best[root] = f32::NEG_INFINITY;
+    while let Some(current) = dense::row_min_scan(&best, in_tree) {
+        in_tree[current] = true;
+        for n in g.get_neighbors_of(current) {
+            if let Some(weight) = g.get_edge(current, n) {
+                if !in_tree[n] && weight < best[n] {
+                    best[n] = weight;
+                    parents[n] = Some(current);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Total weight of the minimum spanning forest with Prim's algorithm from every node
+    fn prim_weight(g: &UGraph) -> f32 {
+        let n_nodes = g.get_n_nodes();
+        let mut in_tree = vec![false; n_nodes];
+        let mut total = 0.0;
+        for root in g.get_nodes() {
+            if in_tree[root] {
+                continue;
+            }
+            in_tree[root] = true;
+            loop {
+                let best = g
+                    .get_edges()
+                    .into_iter()
+                    .filter(|(node1, node2, _)| in_tree[*node1] != in_tree[*node2])
+                    .min_by(|a, b| a.2.total_cmp(&b.2));
+                match best {
+                    Some((node1, node2, weight)) => {
+                        in_tree[node1] = true;
+                        in_tree[node2] = true;
+                        total += weight;
+                    }
+                    None => break,
+                }
+            }
+        }
+        total
+    }
+
+    #[test]
+    fn kruskal_mst_matches_prim() {
+        let mut rng = crate::rng::Rng::new(37);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(12);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(30) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                let weight = rng.gen_range(10) as f32 - 3.0;
+                adj_mat[node1][node2] = weight;
+                adj_mat[node2][node1] = weight;
+            }
+            let g = UGraph::from_adjacency_matrix(adj_mat);
+            let mst = kruskal_mst(&g);
+            let edges = mst.get_edges();
+            let total: f32 = edges.iter().map(|(_, _, w)| w).sum();
+            assert_eq!(total, prim_weight(&g));
+            assert_eq!(boruvka_mst(&g), mst);
+            let prim_edges = prim_mst(&g).get_edges();
+            assert_eq!(prim_edges.iter().map(|(_, _, w)| w).sum::<f32>(), total);
+            // A forest has one edge less than nodes per component
+            let mut components = DisjointSet::new(n_nodes);
+            for (node1, node2, _) in g.get_edges() {
+                components.union(node1, node2);
+            }
+            assert_eq!(edges.len(), n_nodes - components.get_n_sets());
+            assert!(edges
+                .iter()
+                .all(|(node1, node2, w)| g.get_edge(*node1, *node2) == Some(*w)));
+        }
+    }
+
+    #[test]
+    fn boruvka_mst_matches_kruskal_on_big_graphs() {
+        // Big enough to split the nodes between several threads
+        let mut rng = crate::rng::Rng::new(41);
+        let n_nodes = 300;
+        let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+        for _ in 0..1500 {
+            let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+            let weight = rng.gen_range(20) as f32 + 1.0; // many ties
+            adj_mat[node1][node2] = weight;
+            adj_mat[node2][node1] = weight;
+        }
+        let g = UGraph::from_adjacency_matrix(adj_mat);
+        assert_eq!(boruvka_mst(&g), kruskal_mst(&g));
+    }
+
+    #[test]
+    fn kruskal_mst_check_forest_and_self_loops() {
+        let edges = vec![
+            (0, 0, -5.0),
+            (0, 1, 2.0),
+            (2, 3, 1.0),
+            (3, 4, 1.0),
+            (2, 4, 1.0),
+        ];
+        let mst = kruskal_mst(&UGraph::from_weighted_edges(6, edges));
+        assert_eq!(mst.get_n_nodes(), 6);
+        assert_eq!(mst.get_edges(), vec![(0, 1, 2.0), (2, 3, 1.0), (2, 4, 1.0)]);
+        assert_eq!(kruskal_mst(&UGraph::new()).get_n_nodes(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn prim_mst_from_panic_not_valid_root() {
+        prim_mst_from(&UGraph::from_edges(2, vec![(0, 1)]), 2);
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{dense, float, FlowNetwork};
+
+// Number of parts in which each demand is split, routed in round-robin between commodities
+const N_INCREMENTS: usize = 10;
+
+/// The `CommodityRouting` struct holds the result of `route_commodities`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommodityRouting {
+    /// The paths used by each commodity, as the nodes of the path and the flow sent
+    /// through it.
+    pub paths: Vec<Vec<(Vec<usize>, f32)>>,
+    /// The fraction of the demand of each commodity that was routed, from `0.0` to `1.0`.
+    pub routed_fraction: Vec<f32>,
+    /// The total flow of all the commodities through each arc of the network.
+    pub arc_loads: Vec<f32>,
+    /// The sorted indices of the arcs whose capacity is used completely.
+    pub congested_arcs: Vec<usize>,
+}
+
+/// Routes several commodities, each one with its own source, sink and demand, sharing
+/// the capacities of the network. This is a path-based heuristic: each demand is split in
+/// 10 increments that are routed in round-robin between the commodities, each one through
+/// the shortest path with free capacity. The length of an arc is `1.0 + cost` (negative
+/// costs count as `0.0`), scaled up as its capacity gets used, so the later increments
+/// avoid the congested arcs. The current flows of the network are ignored.
+///
+/// # Arguments
+///
+/// * `net` - The `FlowNetwork` with the capacities and costs of the arcs.
+/// * `demands` - A slice of `(source, sink, demand)` triplets, one for each commodity.
+///
+/// # Panics
+///
+/// * If some commodity has an invalid node value or a negative demand.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::route_commodities;
+/// use graphst::FlowNetwork;
+/// // Two commodities competing for the arc 1->2
+/// let net = FlowNetwork::from_arcs(4, vec![(0, 1, 2.0), (3, 1, 2.0), (1, 2, 2.0)]);
+/// let routing = route_commodities(&net, &[(0, 2, 2.0), (3, 2, 2.0)]);
+/// assert!(routing.routed_fraction.iter().all(|f| graphst::float::approx_eq(*f, 0.5)));
+/// assert_eq!(routing.congested_arcs, vec![2]);
+/// assert_eq!(routing.paths[0][0].0, vec![0, 1, 2]);
+/// ```
+pub fn route_commodities(net: &FlowNetwork, demands: &[(usize, usize, f32)]) -> CommodityRouting {
+    for (source, sink, demand) in demands {
+        if let Some(node) = [source, sink].iter().find(|n| ***n >= net.get_n_nodes()) {
+            panic!(
+                "[algorithm::route_commodities] Error: The node {} is not valid!",
+                node
+            );
+        }
+        if *demand < 0.0 || demand.is_nan() {
+            panic!(
+                "[algorithm::route_commodities] Error: The demand {} is not valid!",
+                demand
+            );
+        }
+    }
+    let mut loads = vec![0.0; net.get_n_arcs()];
+    let mut routed = vec![0.0; demands.len()];
+    let mut paths: Vec<Vec<(Vec<usize>, f32)>> = vec![vec![]; demands.len()];
+    // Commodities that can still route more flow
+    let mut active: Vec<bool> = demands
+        .iter()
+        .map(|(source, sink, demand)| source != sink && float::greater_than(*demand, 0.0))
+        .collect();
+    for _ in 0..N_INCREMENTS {
+        for (idx, (source, sink, demand)) in demands.iter().enumerate() {
+            if !active[idx] {
+                continue;
+            }
+            // The remaining demand of the last increment includes the rounding errors
+            let mut pending = (demand / N_INCREMENTS as f32).min(demand - routed[idx]);
+            while float::greater_than(pending, 0.0) {
+                let arcs = match cheapest_path(net, &loads, *source, *sink) {
+                    Some(arcs) => arcs,
+                    None => {
+                        active[idx] = false; // the sink is not reachable anymore
+                        break;
+                    }
+                };
+                let amount = arcs
+                    .iter()
+                    .map(|a| net.get_arc(*a).capacity - loads[*a])
+                    .fold(pending, f32::min);
+                for arc in &arcs {
+                    loads[*arc] += amount;
+                }
+                pending -= amount;
+                routed[idx] += amount;
+                let mut nodes = vec![*source];
+                nodes.extend(arcs.iter().map(|a| net.get_arc(*a).dest));
+                match paths[idx].iter_mut().find(|(p, _)| *p == nodes) {
+                    Some((_, flow)) => *flow += amount,
+                    None => paths[idx].push((nodes, amount)),
+                }
+            }
+        }
+    }
+    let routed_fraction = demands
+        .iter()
+        .zip(&routed)
+        .map(|((source, sink, demand), r)| {
+            if source == sink || float::is_zero(*demand) {
+                1.0
+            } else {
+                (r / demand).min(1.0)
+            }
+        })
+        .collect();
+    let congested_arcs = (0..net.get_n_arcs())
+        .filter(|a| {
+            let capacity = net.get_arc(*a).capacity;
+            float::greater_than(loads[*a], 0.0) && !float::less_than(loads[*a], capacity)
+        })
+        .collect();
+    CommodityRouting {
+        paths,
+        routed_fraction,
+        arc_loads: loads,
+        congested_arcs,
+    }
+}
+
+//--------- Private functions ---------
+
+// Returns the arcs of the shortest path from `source` to `sink` using only the arcs with
+// free capacity, or `None` if there is no such path
+fn cheapest_path(
+    net: &FlowNetwork,
+    loads: &[f32],
+    source: usize,
+    sink: usize,
+) -> Option<Vec<usize>> {
+    let n_nodes = net.get_n_nodes();
+    let mut dist = vec![f32::INFINITY; n_nodes];
+    let mut visited = vec![false; n_nodes];
+    let mut parent_arc = vec![None; n_nodes];
+    dist[source] = 0.0;
+    while let Some(node) = dense::row_min_scan(&dist, &visited) {
+        if node == sink {
+            break;
+        }
+        visited[node] = true;
+        for arc in net.get_out_arcs_of(node) {
+            let data = net.get_arc(*arc);
+            let free = data.capacity - loads[*arc];
+            if !float::greater_than(free, 0.0) {
+                continue;
+            }
+            let length = (1.0 + data.cost.max(0.0)) * data.capacity / free;
+            if dist[node] + length < dist[data.dest] {
+                dist[data.dest] = dist[node] + length;
+                parent_arc[data.dest] = Some(*arc);
+            }
+        }
+    }
+    if dist[sink] == f32::INFINITY {
+        return None;
+    }
+    let mut arcs = vec![];
+    let mut current = sink;
+    while let Some(arc) = parent_arc[current] {
+        arcs.push(arc);
+        current = net.get_arc(arc).src;
+    }
+    arcs.reverse();
+    Some(arcs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn route_commodities_splits_over_parallel_paths() {
+        // Two paths from 0 to 3 with capacity 1 each
+        let net =
+            FlowNetwork::from_arcs(4, vec![(0, 1, 1.0), (1, 3, 1.0), (0, 2, 1.0), (2, 3, 1.0)]);
+        let routing = route_commodities(&net, &[(0, 3, 2.0)]);
+        assert_eq!(routing.routed_fraction, vec![1.0]);
+        assert_eq!(routing.paths[0].len(), 2);
+        assert_eq!(routing.congested_arcs, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn route_commodities_check_unreachable_and_trivial() {
+        let net = FlowNetwork::from_arcs(3, vec![(0, 1, 1.0)]);
+        let routing = route_commodities(&net, &[(0, 2, 1.0), (1, 1, 5.0), (0, 1, 0.5)]);
+        assert_eq!(routing.routed_fraction, vec![0.0, 1.0, 1.0]);
+        assert!(routing.paths[0].is_empty());
+        assert_eq!(routing.arc_loads, vec![0.5]);
+        assert!(routing.congested_arcs.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "demand -1 is not valid")]
+    fn route_commodities_panic_negative_demand() {
+        route_commodities(&FlowNetwork::with_nodes(2), &[(0, 1, -1.0)]);
+    }
+}
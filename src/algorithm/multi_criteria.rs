@@ -0,0 +1,197 @@
+use crate::graph::Graph;
+use std::cmp::Ordering;
+
+/// The `ParetoPath` struct is a non-dominated path found by
+/// `multi_criteria_shortest_paths`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParetoPath {
+    /// The total cost of the path for each criterion.
+    pub costs: Vec<f32>,
+    /// The nodes of the path, from the source to the destination.
+    pub path: Vec<usize>,
+}
+
+/// Given a graph and the weights of its edges for several criteria (e.g. time and cost),
+/// returns for each node the Pareto-optimal paths from the source: the paths that are
+/// not dominated by another path (no worse in every criterion and better in some). Only
+/// the edges of `g` are used, with the weights of the matrices instead of the weights of
+/// the graph. The paths of each node are sorted lexicographically by their costs, so the
+/// first one is the lexicographic optimum, and paths with the same costs are reported
+/// once. The weights must be non-negative. Label setting (Martins' algorithm) is used.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+/// * `src` - The source node of the paths.
+/// * `weights` - A slice with a squared weights matrix for each criterion, where
+///   `weights[c][node1][node2]` is the weight of the edge for the criterion `c`.
+///
+/// # Panics
+///
+/// * If the source node is not valid.
+/// * If there are no criteria or some matrix doesn't have the size of the graph.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::multi_criteria_shortest_paths;
+/// use graphst::DGraph;
+/// // From 0 to 2: direct (time 10, cost 1) or through 1 (time 2, cost 5)
+/// let g = DGraph::from_edges(3, vec![(0, 1), (1, 2), (0, 2)]);
+/// let time = vec![vec![0.0, 1.0, 10.0], vec![0.0, 0.0, 1.0], vec![0.0; 3]];
+/// let cost = vec![vec![0.0, 2.0, 1.0], vec![0.0, 0.0, 3.0], vec![0.0; 3]];
+/// let paths = multi_criteria_shortest_paths(&g, 0, &[time, cost]);
+/// assert_eq!(paths[2].len(), 2);
+/// assert_eq!(paths[2][0].costs, vec![2.0, 5.0]);
+/// assert_eq!(paths[2][0].path, vec![0, 1, 2]);
+/// assert_eq!(paths[2][1].path, vec![0, 2]);
+/// ```
+pub fn multi_criteria_shortest_paths<G>(
+    g: &G,
+    src: usize,
+    weights: &[Vec<Vec<f32>>],
+) -> Vec<Vec<ParetoPath>>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if src >= n_nodes {
+        panic!(
+            "[algorithm::multi_criteria_shortest_paths] Error: The node {} is not valid!",
+            src
+        );
+    }
+    if weights.is_empty()
+        || weights
+            .iter()
+            .any(|mat| mat.len() != n_nodes || mat.iter().any(|row| row.len() != n_nodes))
+    {
+        panic!(
+            "[algorithm::multi_criteria_shortest_paths] Error: The weights matrices are not valid!"
+        );
+    }
+    // labels: (node, costs, index of the predecessor label)
+    let mut labels: Vec<(usize, Vec<f32>, Option<usize>)> =
+        vec![(src, vec![0.0; weights.len()], None)];
+    let mut permanent = vec![vec![]; n_nodes];
+    let mut tentative: Vec<Vec<usize>> = vec![vec![]; n_nodes];
+    tentative[src].push(0);
+    loop {
+        // Select the lexicographically smallest tentative label
+        let best = tentative
+            .iter()
+            .enumerate()
+            .flat_map(|(node, ls)| ls.iter().enumerate().map(move |(pos, l)| (node, pos, *l)))
+            .min_by(|a, b| lexicographic(&labels[a.2].1, &labels[b.2].1));
+        let (node, pos, label) = match best {
+            Some(best) => best,
+            None => break,
+        };
+        tentative[node].swap_remove(pos);
+        permanent[node].push(label);
+        for n in g.get_out_neighbors_of(node) {
+            let costs: Vec<f32> = labels[label]
+                .1
+                .iter()
+                .zip(weights)
+                .map(|(c, mat)| c + mat[node][n])
+                .collect();
+            let dominated = permanent[n]
+                .iter()
+                .chain(&tentative[n])
+                .any(|l| dominates_or_equals(&labels[*l].1, &costs));
+            if dominated {
+                continue;
+            }
+            tentative[n].retain(|l| !dominates_or_equals(&costs, &labels[*l].1));
+            labels.push((n, costs, Some(label)));
+            tentative[n].push(labels.len() - 1);
+        }
+    }
+    permanent
+        .into_iter()
+        .map(|node_labels| {
+            let mut paths: Vec<ParetoPath> = node_labels
+                .into_iter()
+                .map(|l| {
+                    let mut path = vec![];
+                    let mut current = Some(l);
+                    while let Some(idx) = current {
+                        path.push(labels[idx].0);
+                        current = labels[idx].2;
+                    }
+                    path.reverse();
+                    ParetoPath {
+                        costs: labels[l].1.clone(),
+                        path,
+                    }
+                })
+                .collect();
+            paths.sort_by(|a, b| lexicographic(&a.costs, &b.costs));
+            paths
+        })
+        .collect()
+}
+
+//--------- Private functions ---------
+
+fn lexicographic(a: &[f32], b: &[f32]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| x.partial_cmp(y).unwrap_or(Ordering::Equal))
+        .find(|ord| *ord != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+// Returns true if `a` is no worse than `b` in every criterion
+fn dominates_or_equals(a: &[f32], b: &[f32]) -> bool {
+    a.iter().zip(b).all(|(x, y)| x <= y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UGraph;
+
+    #[test]
+    fn multi_criteria_shortest_paths_single_criterion_is_dijkstra() {
+        let g = UGraph::from_weighted_edges(4, vec![(0, 1, 1.0), (1, 2, 2.0), (0, 2, 4.0)]);
+        let paths = multi_criteria_shortest_paths(&g, 0, &[g.get_adjacency_matrix().into_owned()]);
+        assert_eq!(
+            paths[2],
+            vec![ParetoPath {
+                costs: vec![3.0],
+                path: vec![0, 1, 2]
+            }]
+        );
+        assert_eq!(paths[0][0].path, vec![0]);
+        assert!(paths[3].is_empty()); // not reachable
+    }
+
+    #[test]
+    fn multi_criteria_shortest_paths_drops_dominated_paths() {
+        // 0->1->3 (1, 1) dominates 0->2->3 (2, 2), and 0->3 (0, 5) is not dominated
+        let g = crate::DGraph::from_edges(4, vec![(0, 1), (1, 3), (0, 2), (2, 3), (0, 3)]);
+        let mut a = vec![vec![0.0; 4]; 4];
+        let mut b = vec![vec![0.0; 4]; 4];
+        for (n1, n2, wa, wb) in [
+            (0, 1, 0.5, 0.5),
+            (1, 3, 0.5, 0.5),
+            (0, 2, 1.0, 1.0),
+            (2, 3, 1.0, 1.0),
+            (0, 3, 0.0, 5.0),
+        ] {
+            a[n1][n2] = wa;
+            b[n1][n2] = wb;
+        }
+        let paths = multi_criteria_shortest_paths(&g, 0, &[a, b]);
+        let found: Vec<_> = paths[3].iter().map(|p| p.path.clone()).collect();
+        assert_eq!(found, vec![vec![0, 3], vec![0, 1, 3]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "weights matrices are not valid")]
+    fn multi_criteria_shortest_paths_panic_no_criteria() {
+        multi_criteria_shortest_paths(&UGraph::from_edges(2, vec![(0, 1)]), 0, &[]);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::float::FloatOrd;
+use crate::graph::Graph;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The `NearestSources` struct is the result of `multi_source_dijkstra`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NearestSources {
+    /// The distance from each node to its nearest source, `f32::INFINITY` if no source
+    /// reaches it.
+    pub distances: Vec<f32>,
+    /// The nearest source of each node, `None` if no source reaches it.
+    pub nearest: Vec<Option<usize>>,
+}
+
+/// Given a graph (that implements `Graph`) and a set of source nodes, returns for every
+/// node the distance to its nearest source and which source it is, with a single Dijkstra
+/// search started from all the sources at once. It answers the "nearest facility" query
+/// in `O((n + m) log n)`, instead of running a search per source. In case of a tie the
+/// source that comes first in `sources` wins. `graph_voronoi` builds the regions of the
+/// sources on top of it. For directed graphs
+/// the paths go from the sources to the nodes. The weights must be non-negative.
+///
+/// # Panics
+///
+/// * If some source is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::multi_source_dijkstra;
+/// use graphst::UGraph;
+/// // Two warehouses at the nodes 0 and 4 of a road
+/// let g = UGraph::from_weighted_edges(6, vec![(0, 1, 2.0), (1, 2, 2.0), (2, 3, 1.0), (3, 4, 2.0)]);
+/// let result = multi_source_dijkstra(&g, &[0, 4]);
+/// assert_eq!(result.nearest, vec![Some(0), Some(0), Some(4), Some(4), Some(4), None]);
+/// assert_eq!(result.distances[2], 3.0);
+/// ```
+pub fn multi_source_dijkstra<G>(g: &G, sources: &[usize]) -> NearestSources
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if let Some(source) = sources.iter().find(|s| **s >= n_nodes) {
+        panic!(
+            "[algorithm::multi_source_dijkstra] Error: The node {} is not valid!",
+            source
+        );
+    }
+    let mut distances = vec![f32::INFINITY; n_nodes];
+    // Position in `sources` of the nearest source of each node, to break the ties
+    let mut source_pos: Vec<Option<usize>> = vec![None; n_nodes];
+    let mut visited = vec![false; n_nodes];
+    let mut heap = BinaryHeap::new();
+    for (pos, source) in sources.iter().enumerate() {
+        if source_pos[*source].is_none() {
+            distances[*source] = 0.0;
+            source_pos[*source] = Some(pos);
+            heap.push(Reverse((FloatOrd(0.0), pos, *source)));
+        }
+    }
+    while let Some(Reverse((_, pos, current))) = heap.pop() {
+        if visited[current] {
+            continue; // Outdated entry
+        }
+        visited[current] = true;
+        for n in g.get_out_neighbors_of(current) {
+            if let Some(weight) = g.get_edge(current, n) {
+                let new_dist = distances[current] + weight;
+                let better = new_dist < distances[n]
+                    || (new_dist == distances[n] && Some(pos) < source_pos[n]);
+                if !visited[n] && better {
+                    distances[n] = new_dist;
+                    source_pos[n] = Some(pos);
+                    heap.push(Reverse((FloatOrd(new_dist), pos, n)));
+                }
+            }
+        }
+    }
+    NearestSources {
+        distances,
+        nearest: source_pos
+            .into_iter()
+            .map(|pos| pos.map(|p| sources[p]))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::dijkstra;
+    use crate::DGraph;
+
+    #[test]
+    fn multi_source_dijkstra_matches_dijkstra_per_source() {
+        let mut rng = crate::rng::Rng::new(41);
+        for _ in 0..50 {
+            let n_nodes = 1 + rng.gen_range(15);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(40) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                adj_mat[node1][node2] = 1.0 + rng.gen_range(3) as f32;
+            }
+            let g = DGraph::from_adjacency_matrix(adj_mat);
+            let sources: Vec<usize> = (0..1 + rng.gen_range(3))
+                .map(|_| rng.gen_range(n_nodes))
+                .collect();
+            let result = multi_source_dijkstra(&g, &sources);
+            let per_source: Vec<Vec<f32>> = sources.iter().map(|s| dijkstra(&g, *s)).collect();
+            for node in 0..n_nodes {
+                let distance = per_source
+                    .iter()
+                    .map(|dist| dist[node])
+                    .fold(f32::INFINITY, f32::min);
+                // The first source at the minimum distance wins the ties
+                let nearest = (distance < f32::INFINITY).then(|| {
+                    let pos = per_source.iter().position(|dist| dist[node] == distance);
+                    sources[pos.unwrap()]
+                });
+                assert_eq!(result.distances[node], distance);
+                assert_eq!(result.nearest[node], nearest);
+            }
+        }
+    }
+
+    #[test]
+    fn multi_source_dijkstra_check_single_and_no_sources() {
+        let g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 1.5)]);
+        assert_eq!(multi_source_dijkstra(&g, &[0]).distances, dijkstra(&g, 0));
+        let result = multi_source_dijkstra(&g, &[]);
+        assert_eq!(result.nearest, vec![None; 3]);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::float::{self, FloatOrd};
+use crate::graph::Graph;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The `NodeCost` enum selects what a step of a path costs in
+/// `node_weighted_shortest_paths`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NodeCost {
+    /// Only the weight of the node the step enters, ignoring the edge weights.
+    Nodes,
+    /// The weight of the node the step enters plus the weight of the edge.
+    NodesAndEdges,
+}
+
+/// Same as `dijkstra` but accumulating the weights of the nodes (see
+/// `Graph::get_node_weight`): every step of a path pays the weight of the node it enters,
+/// and with `NodeCost::NodesAndEdges` also the weight of the edge. The source is not paid,
+/// so its distance is `0.0`. It fits the grids and terrains where the cost of crossing
+/// lives on the cells rather than on the moves between them. The node and edge weights
+/// must be non-negative. The unreachable nodes get `f32::INFINITY`.
+///
+/// # Panics
+///
+/// * If the source node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::{node_weighted_shortest_paths, NodeCost};
+/// use graphst::{Graph, UGraph};
+/// // A row of 4 cells where the cell 1 is a swamp and 2 a road
+/// let mut g = UGraph::from_weighted_edges(4, vec![(0, 1, 2.0), (1, 2, 2.0), (2, 3, 2.0)]);
+/// g.set_node_weight(1, 5.0);
+/// g.set_node_weight(2, 0.5);
+/// let dist = node_weighted_shortest_paths(&g, 0, NodeCost::Nodes);
+/// assert_eq!(dist, vec![0.0, 5.0, 5.5, 6.5]);
+/// let dist = node_weighted_shortest_paths(&g, 3, NodeCost::NodesAndEdges);
+/// assert_eq!(dist, vec![12.5, 9.5, 2.5, 0.0]);
+/// ```
+pub fn node_weighted_shortest_paths<G>(g: &G, src: usize, cost: NodeCost) -> Vec<f32>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if src >= n_nodes {
+        panic!(
+            "[algorithm::node_weighted_shortest_paths] Error: The node {} is not valid!",
+            src
+        );
+    }
+    let mut dist = vec![f32::INFINITY; n_nodes];
+    let mut visited = vec![false; n_nodes];
+    let mut heap = BinaryHeap::new();
+    dist[src] = 0.0;
+    heap.push(Reverse((FloatOrd(0.0), src)));
+    while let Some(Reverse((_, current))) = heap.pop() {
+        if visited[current] {
+            continue;
+        }
+        visited[current] = true;
+        for n in g.get_out_neighbors_of(current) {
+            let edge_weight = match g.get_edge(current, n) {
+                Some(weight) => weight,
+                None => continue,
+            };
+            let step = match cost {
+                NodeCost::Nodes => g.get_node_weight(n),
+                NodeCost::NodesAndEdges => g.get_node_weight(n) + edge_weight,
+            };
+            if !visited[n] && float::greater_than(dist[n], dist[current] + step) {
+                dist[n] = dist[current] + step;
+                heap.push(Reverse((FloatOrd(dist[n]), n)));
+            }
+        }
+    }
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::dijkstra;
+    use crate::{DGraph, UGraph};
+
+    #[test]
+    fn node_weighted_shortest_paths_matches_dijkstra() {
+        let mut rng = crate::rng::Rng::new(38);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(12);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(30) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                let weight = 1.0 + rng.gen_range(5) as f32;
+                adj_mat[node1][node2] = weight;
+                adj_mat[node2][node1] = weight;
+            }
+            let mut g = UGraph::from_adjacency_matrix(adj_mat);
+            for node in g.get_nodes() {
+                g.set_node_weight(node, 1.0 + rng.gen_range(5) as f32);
+            }
+            // The node weights moved onto the edges that enter each node
+            let mut nodes_only = DGraph::from_edges(n_nodes, vec![]);
+            let mut both = DGraph::from_edges(n_nodes, vec![]);
+            for (node1, node2, weight) in g.get_edges() {
+                for (src, dest) in [(node1, node2), (node2, node1)].iter() {
+                    nodes_only.add_weighted_edge(*src, *dest, g.get_node_weight(*dest));
+                    both.add_weighted_edge(*src, *dest, g.get_node_weight(*dest) + weight);
+                }
+            }
+            let src = rng.gen_range(n_nodes);
+            assert_eq!(
+                node_weighted_shortest_paths(&g, src, NodeCost::Nodes),
+                dijkstra(&nodes_only, src)
+            );
+            assert_eq!(
+                node_weighted_shortest_paths(&g, src, NodeCost::NodesAndEdges),
+                dijkstra(&both, src)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn node_weighted_shortest_paths_panic_not_valid_src() {
+        let g = DGraph::from_edges(2, vec![(0, 1)]);
+        node_weighted_shortest_paths(&g, 2, NodeCost::Nodes);
+    }
+}
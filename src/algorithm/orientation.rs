@@ -0,0 +1,181 @@
+use crate::{DGraph, Graph, UGraph};
+use std::collections::VecDeque;
+
+/// Returns an acyclic orientation of an undirected graph: a directed graph with each edge
+/// oriented from its lower node to its higher node, keeping its weight. Any path goes
+/// through increasing nodes, so the result is a directed acyclic graph (and the nodes
+/// sorted are a topological order). The self-loops can't be oriented without creating a
+/// cycle, so they are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::{acyclic_orientation, resolve_dependencies};
+/// use graphst::{Graph, UGraph};
+/// let g = UGraph::from_edges(3, vec![(0, 1), (1, 2), (2, 0), (2, 2)]);
+/// let dag = acyclic_orientation(&g);
+/// assert_eq!(dag.get_edges(), vec![(0, 1, 1.0), (0, 2, 1.0), (1, 2, 1.0)]);
+/// assert!(resolve_dependencies(&dag).is_ok());
+/// ```
+pub fn acyclic_orientation(g: &UGraph) -> DGraph {
+    let edges = g
+        .get_edges()
+        .into_iter()
+        .filter(|(node1, node2, _)| node1 != node2)
+        .collect();
+    DGraph::from_weighted_edges(g.get_n_nodes(), edges)
+}
+
+/// Returns an orientation of an undirected graph that minimizes the maximum out-degree
+/// of its nodes, as a directed graph where each edge keeps its weight. When the edges are
+/// tasks and the nodes the resources that can take them, it assigns each task to one of
+/// its two resources with the lowest maximum load. The self-loops are kept and count as
+/// an out edge of their node.
+///
+/// It starts from the orientation of `acyclic_orientation` and reverses the directed paths
+/// that go from a node to another with at least two out edges less, which lowers the
+/// out-degree of the first node. When no such path is left, the nodes reachable from a
+/// node of maximum out-degree `d` have at least `d - 1` out edges, so no orientation has a
+/// lower maximum.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::balanced_orientation;
+/// use graphst::{Graph, UGraph};
+/// // A star: the center would have every edge in a lower to higher orientation
+/// let g = UGraph::from_edges(4, vec![(0, 1), (0, 2), (0, 3), (1, 2)]);
+/// let oriented = balanced_orientation(&g);
+/// let max_out_degree = (0..4).map(|n| oriented.get_successors_of(n).len()).max();
+/// assert_eq!(max_out_degree, Some(1));
+/// ```
+pub fn balanced_orientation(g: &UGraph) -> DGraph {
+    let n_nodes = g.get_n_nodes();
+    let mut out: Vec<Vec<usize>> = vec![vec![]; n_nodes];
+    for (node1, node2, _) in g.get_edges() {
+        if node1 != node2 {
+            out[node1].push(node2);
+        }
+    }
+    // Improve the nodes by decreasing out-degree until no path can be reversed
+    let mut improved = true;
+    while improved {
+        improved = false;
+        let mut nodes: Vec<usize> = (0..n_nodes).collect();
+        nodes.sort_by_key(|n| std::cmp::Reverse(out[*n].len()));
+        for node in nodes {
+            while let Some(path) = find_unloading_path(&out, node) {
+                for w in path.windows(2) {
+                    out[w[0]].retain(|n| *n != w[1]);
+                    out[w[1]].push(w[0]);
+                }
+                improved = true;
+            }
+        }
+    }
+    let mut edges = vec![];
+    for (node1, successors) in out.iter().enumerate() {
+        for node2 in successors {
+            edges.push((node1, *node2, g.get_edge(node1, *node2).unwrap_or(1.0)));
+        }
+    }
+    for node in g.get_nodes() {
+        if let Some(weight) = g.get_edge(node, node) {
+            edges.push((node, node, weight));
+        }
+    }
+    DGraph::from_weighted_edges(n_nodes, edges)
+}
+
+//--------- Private functions ---------
+
+// Breadth first search from `src` along the oriented edges to a node with at least two
+// out edges less than `src`, returning the path to it
+fn find_unloading_path(out: &[Vec<usize>], src: usize) -> Option<Vec<usize>> {
+    let target_degree = out[src].len().checked_sub(2)?;
+    let mut parents = vec![None; out.len()];
+    let mut visited = vec![false; out.len()];
+    let mut queue = VecDeque::from(vec![src]);
+    visited[src] = true;
+    while let Some(node) = queue.pop_front() {
+        if out[node].len() <= target_degree {
+            let mut path = vec![node];
+            while let Some(parent) = parents[path[path.len() - 1]] {
+                path.push(parent);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for n in &out[node] {
+            if !visited[*n] {
+                visited[*n] = true;
+                parents[*n] = Some(node);
+                queue.push_back(*n);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn max_out_degree(g: &DGraph) -> usize {
+        g.get_nodes()
+            .iter()
+            .map(|n| g.get_successors_of(*n).len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn balanced_orientation_matches_brute_force() {
+        let mut rng = crate::rng::Rng::new(27);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(7);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(12) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                if node1 != node2 {
+                    let weight = 1.0 + rng.gen_range(3) as f32;
+                    adj_mat[node1][node2] = weight;
+                    adj_mat[node2][node1] = weight;
+                }
+            }
+            let g = UGraph::from_adjacency_matrix(adj_mat);
+            let edges = g.get_edges();
+            // Every orientation, a bit per edge
+            let best = (0..1usize << edges.len())
+                .map(|mask| {
+                    let oriented = edges
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, (n1, n2, w))| {
+                            if mask >> idx & 1 == 1 {
+                                (*n2, *n1, *w)
+                            } else {
+                                (*n1, *n2, *w)
+                            }
+                        })
+                        .collect();
+                    max_out_degree(&DGraph::from_weighted_edges(n_nodes, oriented))
+                })
+                .min();
+            let oriented = balanced_orientation(&g);
+            assert_eq!(Some(max_out_degree(&oriented)), best);
+            for (node1, node2, weight) in oriented.get_edges() {
+                assert_eq!(g.get_edge(node1, node2), Some(weight));
+            }
+            assert_eq!(oriented.get_edges().len(), edges.len());
+        }
+    }
+
+    #[test]
+    fn acyclic_orientation_check_edges() {
+        let g = UGraph::from_weighted_edges(4, vec![(3, 0, 2.0), (2, 1, 1.0), (1, 1, 4.0)]);
+        let dag = acyclic_orientation(&g);
+        assert_eq!(dag.get_edges(), vec![(0, 3, 2.0), (1, 2, 1.0)]);
+        assert!(balanced_orientation(&g).get_edge(1, 1).is_some());
+    }
+}
@@ -0,0 +1,183 @@
+use crate::graph::Graph;
+use crate::DGraph;
+
+/// Returns the strongly connected components of a directed graph, the maximal sets of
+/// nodes that can reach each other, with Tarjan's single-pass algorithm in `O(n + m)`.
+/// Each component is sorted, and the components are returned in reverse topological
+/// order of the condensed graph: no edge goes from a component to a previous one. A node
+/// in no cycle is a component of its own.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::strongly_connected_components;
+/// use graphst::DGraph;
+/// let g = DGraph::from_edges(5, vec![(0, 1), (1, 0), (1, 2), (2, 3), (3, 2), (4, 0)]);
+/// assert_eq!(
+///     strongly_connected_components(&g),
+///     vec![vec![2, 3], vec![0, 1], vec![4]]
+/// );
+/// ```
+pub fn strongly_connected_components(g: &DGraph) -> Vec<Vec<usize>> {
+    tarjan_scc(g)
+}
+
+/// Returns the condensation of a directed graph, where each strongly connected component
+/// is collapsed into a single node, and the component of each node. The components are
+/// numbered in topological order, so every edge of the condensation goes from a lower to
+/// a higher component and the result is a directed acyclic graph. The edge between two
+/// components has the minimum weight of the edges between their nodes, and the edges
+/// inside a component are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::condensation;
+/// use graphst::{DGraph, Graph};
+/// let edges = vec![(0, 1, 1.0), (1, 0, 1.0), (1, 2, 3.0), (0, 2, 2.0), (2, 3, 1.0), (3, 2, 1.0)];
+/// let (dag, component_of) = condensation(&DGraph::from_weighted_edges(4, edges));
+/// assert_eq!(component_of, vec![0, 0, 1, 1]);
+/// assert_eq!(dag.get_edges(), vec![(0, 1, 2.0)]);
+/// ```
+pub fn condensation(g: &DGraph) -> (DGraph, Vec<usize>) {
+    let components = tarjan_scc(g);
+    let n_components = components.len();
+    let mut component_of = vec![0; g.get_n_nodes()];
+    // Tarjan's order is the reverse topological order
+    for (idx, component) in components.iter().enumerate() {
+        for node in component {
+            component_of[*node] = n_components - 1 - idx;
+        }
+    }
+    let mut weights = vec![vec![0.0f32; n_components]; n_components];
+    for (node1, node2, weight) in g.get_edges() {
+        let (c1, c2) = (component_of[node1], component_of[node2]);
+        if c1 != c2 && (weights[c1][c2] == 0.0 || weight < weights[c1][c2]) {
+            weights[c1][c2] = weight;
+        }
+    }
+    (DGraph::from_adjacency_matrix(weights), component_of)
+}
+
+// Returns the strongly connected components of the graph (Tarjan's algorithm, iterative
+// to avoid overflowing the stack). Each component is sorted, and the components are
+// returned in reverse topological order of the condensed graph.
+pub(crate) fn tarjan_scc<G>(g: &G) -> Vec<Vec<usize>>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    let mut index = vec![usize::MAX; n_nodes]; // discovery index (MAX = not visited)
+    let mut lowlink = vec![0; n_nodes];
+    let mut on_stack = vec![false; n_nodes];
+    let mut stack = vec![];
+    let mut components = vec![];
+    let mut next_index = 0;
+
+    for root in g.get_nodes() {
+        if index[root] != usize::MAX {
+            continue;
+        }
+        // call_stack: (node, successors, position of the next successor to explore)
+        let mut call_stack = vec![(root, g.get_out_neighbors_of(root), 0)];
+        index[root] = next_index;
+        lowlink[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        while let Some((node, successors, pos)) = call_stack.last_mut() {
+            let node = *node;
+            if *pos < successors.len() {
+                let next = successors[*pos];
+                *pos += 1;
+                if index[next] == usize::MAX {
+                    index[next] = next_index;
+                    lowlink[next] = next_index;
+                    next_index += 1;
+                    stack.push(next);
+                    on_stack[next] = true;
+                    call_stack.push((next, g.get_out_neighbors_of(next), 0));
+                } else if on_stack[next] {
+                    lowlink[node] = lowlink[node].min(index[next]);
+                }
+            } else {
+                call_stack.pop();
+                if let Some((parent, _, _)) = call_stack.last() {
+                    lowlink[*parent] = lowlink[*parent].min(lowlink[node]);
+                }
+                if lowlink[node] == index[node] {
+                    // The node is the root of a component
+                    let mut component = vec![];
+                    loop {
+                        let member = stack.pop().expect("The stack can't be empty");
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+    }
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::transitive_closure;
+
+    #[test]
+    fn tarjan_scc_check_components() {
+        let edges = vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3), (5, 5)];
+        let g = DGraph::from_edges(6, edges);
+        let mut components = tarjan_scc(&g);
+        components.sort();
+        assert_eq!(components, vec![vec![0, 1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn tarjan_scc_check_reverse_topological_order() {
+        let g = DGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+        assert_eq!(tarjan_scc(&g), vec![vec![2], vec![1], vec![0]]);
+    }
+
+    #[test]
+    fn strongly_connected_components_matches_mutual_reachability() {
+        let mut rng = crate::rng::Rng::new(28);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(12);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(25) {
+                adj_mat[rng.gen_range(n_nodes)][rng.gen_range(n_nodes)] = 1.0;
+            }
+            let g = DGraph::from_adjacency_matrix(adj_mat);
+            let closure = transitive_closure(&g);
+            let mut component_of = vec![0; n_nodes];
+            for (idx, component) in strongly_connected_components(&g).iter().enumerate() {
+                component.iter().for_each(|n| component_of[*n] = idx);
+            }
+            let (dag, condensed_of) = condensation(&g);
+            assert!(crate::algorithm::resolve_dependencies(&dag).is_ok());
+            for (node1, node2, _) in g.get_edges() {
+                let (c1, c2) = (condensed_of[node1], condensed_of[node2]);
+                assert!(c1 == c2 || (c1 < c2 && dag.get_edge(c1, c2).is_some()));
+            }
+            for node1 in 0..n_nodes {
+                for node2 in 0..n_nodes {
+                    assert_eq!(
+                        component_of[node1] == component_of[node2],
+                        condensed_of[node1] == condensed_of[node2]
+                    );
+                    let mutual = node1 == node2
+                        || (closure.get_edge(node1, node2).is_some()
+                            && closure.get_edge(node2, node1).is_some());
+                    assert_eq!(component_of[node1] == component_of[node2], mutual);
+                }
+            }
+        }
+    }
+}
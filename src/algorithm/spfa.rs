@@ -0,0 +1,169 @@
+use crate::graph::Graph;
+use crate::{float, GraphError};
+use std::collections::VecDeque;
+
+/// Given a graph (that implements `Graph`) and a source node, returns the shortest path
+/// to each node from the source with the SPFA algorithm (Shortest Path Faster Algorithm),
+/// the queue based variant of Bellman-Ford. Only the nodes whose distance changed are
+/// relaxed again, so it is usually much faster than `bellman_ford` on graphs with a few
+/// negative weights, with the same `O(V * E)` worst case. The unreachable nodes get
+/// `f32::INFINITY`.
+///
+/// A node is dequeued at most `n_nodes` times unless a negative cycle is reachable, which
+/// is how the negative cycles are detected.
+///
+/// # Errors
+///
+/// * `GraphError::InvalidNode` if the source node is not valid.
+/// * `GraphError::NegativeCycle` if a negative cycle is reachable from the source.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::spfa;
+/// use graphst::{DGraph, GraphError};
+/// let g = DGraph::from_weighted_edges(3, vec![(0, 1, 4.0), (0, 2, 2.0), (2, 1, -3.0)]);
+/// assert_eq!(spfa(&g, 0), Ok(vec![0.0, -1.0, 2.0]));
+/// let g = DGraph::from_weighted_edges(2, vec![(0, 1, 1.0), (1, 0, -2.0)]);
+/// assert!(matches!(spfa(&g, 0), Err(GraphError::NegativeCycle { .. })));
+/// ```
+pub fn spfa<G>(g: &G, src: usize) -> Result<Vec<f32>, GraphError>
+where
+    G: Graph,
+{
+    search(g, src, None, "algorithm::spfa")
+}
+
+/// Same as `spfa` but stopping after `max_relaxations` edge relaxations, to bound the
+/// running time on big graphs where a negative cycle would only be detected after many
+/// rounds.
+///
+/// # Errors
+///
+/// * `GraphError::InvalidNode` if the source node is not valid.
+/// * `GraphError::NegativeCycle` if a negative cycle is detected before the limit.
+/// * `GraphError::IterationLimit` if the limit of relaxations is reached before the
+///   distances converge.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::spfa_with_limit;
+/// use graphst::{DGraph, GraphError};
+/// let g = DGraph::from_weighted_edges(3, vec![(0, 1, 1.0), (1, 2, -1.0), (2, 1, 0.5)]);
+/// assert_eq!(
+///     spfa_with_limit(&g, 0, 5),
+///     Err(GraphError::IterationLimit {
+///         operation: "algorithm::spfa_with_limit",
+///         limit: 5,
+///     })
+/// );
+/// let g = DGraph::from_weighted_edges(3, vec![(0, 1, 1.0), (1, 2, -1.0)]);
+/// assert_eq!(spfa_with_limit(&g, 0, 5), Ok(vec![0.0, 1.0, 0.0]));
+/// ```
+pub fn spfa_with_limit<G>(g: &G, src: usize, max_relaxations: usize) -> Result<Vec<f32>, GraphError>
+where
+    G: Graph,
+{
+    search(g, src, Some(max_relaxations), "algorithm::spfa_with_limit")
+}
+
+//--------- Private functions ---------
+
+fn search<G>(
+    g: &G,
+    src: usize,
+    max_relaxations: Option<usize>,
+    caller: &'static str,
+) -> Result<Vec<f32>, GraphError>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if src >= n_nodes {
+        return Err(GraphError::InvalidNode {
+            operation: caller,
+            node: src,
+            n_nodes,
+        });
+    }
+    let mut dist = vec![f32::INFINITY; n_nodes];
+    let mut in_queue = vec![false; n_nodes];
+    // Number of times each node was dequeued, more than `n_nodes` means a negative cycle
+    let mut n_dequeued = vec![0; n_nodes];
+    let mut n_relaxations = 0;
+    let mut queue = VecDeque::new();
+    dist[src] = 0.0;
+    queue.push_back(src);
+    in_queue[src] = true;
+    while let Some(current) = queue.pop_front() {
+        in_queue[current] = false;
+        n_dequeued[current] += 1;
+        if n_dequeued[current] > n_nodes {
+            return Err(GraphError::NegativeCycle {
+                operation: caller,
+                node: current,
+                n_nodes,
+            });
+        }
+        for n in g.get_out_neighbors_of(current) {
+            if let Some(weight) = g.get_edge(current, n) {
+                if float::greater_than(dist[n], dist[current] + weight) {
+                    if let Some(limit) = max_relaxations {
+                        if n_relaxations == limit {
+                            return Err(GraphError::IterationLimit {
+                                operation: caller,
+                                limit,
+                            });
+                        }
+                    }
+                    n_relaxations += 1;
+                    dist[n] = dist[current] + weight;
+                    if !in_queue[n] {
+                        in_queue[n] = true;
+                        queue.push_back(n);
+                    }
+                }
+            }
+        }
+    }
+    Ok(dist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::bellman_ford;
+    use crate::DGraph;
+
+    #[test]
+    fn spfa_matches_bellman_ford() {
+        let mut rng = crate::rng::Rng::new(21);
+        for _ in 0..50 {
+            let n_nodes = 1 + rng.gen_range(10);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(25) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                adj_mat[node1][node2] = rng.gen_range(12) as f32 - 2.0;
+            }
+            let g = DGraph::from_adjacency_matrix(adj_mat);
+            let src = rng.gen_range(n_nodes);
+            match bellman_ford(&g, src) {
+                Ok(dist) => assert_eq!(spfa(&g, src), Ok(dist)),
+                Err(_) => assert!(matches!(
+                    spfa(&g, src),
+                    Err(GraphError::NegativeCycle { .. })
+                )),
+            }
+        }
+    }
+
+    #[test]
+    fn spfa_check_invalid_node() {
+        let g = DGraph::from_edges(2, vec![(0, 1)]);
+        assert!(matches!(
+            spfa(&g, 2),
+            Err(GraphError::InvalidNode { node: 2, .. })
+        ));
+    }
+}
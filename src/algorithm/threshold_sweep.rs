@@ -0,0 +1,128 @@
+use crate::graph::Graph;
+use crate::DisjointSet;
+
+/// The `ThresholdLevel` struct describes the components of a graph that only keeps the
+/// edges with a weight of at least `threshold`, one of the levels of
+/// `weight_threshold_sweep`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThresholdLevel {
+    /// The minimum weight of the kept edges.
+    pub threshold: f32,
+    /// The number of kept edges.
+    pub n_edges: usize,
+    /// The number of (weakly) connected components, where the isolated nodes are
+    /// components of their own.
+    pub n_components: usize,
+    /// The number of nodes of the largest component.
+    pub largest_component: usize,
+}
+
+/// Returns the component structure of the graph at each threshold, removing the edges
+/// with a weight below it. Sweeping the thresholds shows the backbone of a weighted
+/// network: the strong edges that keep most of the nodes together, and the threshold
+/// where the giant component breaks apart (its percolation point). The levels are
+/// returned in the order of `thresholds`. The edges are added once by decreasing weight
+/// to a `DisjointSet`, so the sweep costs about the same as a single level.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::weight_threshold_sweep;
+/// use graphst::UGraph;
+/// // Two strongly tied pairs joined by a weak edge
+/// let g = UGraph::from_weighted_edges(4, vec![(0, 1, 5.0), (2, 3, 4.0), (1, 2, 1.0)]);
+/// let levels = weight_threshold_sweep(&g, &[1.0, 2.0, 4.5]);
+/// let components: Vec<usize> = levels.iter().map(|l| l.n_components).collect();
+/// assert_eq!(components, vec![1, 2, 3]);
+/// assert_eq!(levels[1].largest_component, 2);
+/// ```
+pub fn weight_threshold_sweep<G>(g: &G, thresholds: &[f32]) -> Vec<ThresholdLevel>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    let mut edges = g.get_edges();
+    edges.sort_by(|a, b| b.2.total_cmp(&a.2));
+    let mut order: Vec<usize> = (0..thresholds.len()).collect();
+    order.sort_by(|a, b| thresholds[*b].total_cmp(&thresholds[*a]));
+    let mut components = DisjointSet::new(n_nodes);
+    let mut largest_component = if n_nodes > 0 { 1 } else { 0 };
+    let mut n_added = 0;
+    let mut levels = vec![None; thresholds.len()];
+    for idx in order {
+        let threshold = thresholds[idx];
+        while n_added < edges.len() && edges[n_added].2 >= threshold {
+            let (node1, node2, _) = edges[n_added];
+            if components.union(node1, node2) {
+                largest_component = largest_component.max(components.get_set_size(node1));
+            }
+            n_added += 1;
+        }
+        levels[idx] = Some(ThresholdLevel {
+            threshold,
+            n_edges: n_added,
+            n_components: components.get_n_sets(),
+            largest_component,
+        });
+    }
+    levels.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::bfs;
+    use crate::UGraph;
+
+    #[test]
+    fn weight_threshold_sweep_matches_bfs_components() {
+        let mut rng = crate::rng::Rng::new(34);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(15);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(30) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                let weight = 1.0 + rng.gen_range(6) as f32;
+                adj_mat[node1][node2] = weight;
+                adj_mat[node2][node1] = weight;
+            }
+            let g = UGraph::from_adjacency_matrix(adj_mat.clone());
+            let thresholds = vec![3.0, 0.5, 6.0, 2.0, 10.0];
+            for level in weight_threshold_sweep(&g, &thresholds) {
+                let kept: Vec<Vec<f32>> = adj_mat
+                    .iter()
+                    .map(|row| {
+                        let keep = |w: &f32| if *w >= level.threshold { *w } else { 0.0 };
+                        row.iter().map(keep).collect()
+                    })
+                    .collect();
+                let kept = UGraph::from_adjacency_matrix(kept);
+                // Sizes of the components, from a BFS of each unreached node
+                let mut reached = vec![false; n_nodes];
+                let mut sizes = vec![];
+                for node in 0..n_nodes {
+                    if !reached[node] {
+                        let hops = bfs(&kept, node).hops;
+                        let component: Vec<usize> =
+                            (0..n_nodes).filter(|n| hops[*n].is_some()).collect();
+                        component.iter().for_each(|n| reached[*n] = true);
+                        sizes.push(component.len());
+                    }
+                }
+                assert_eq!(level.n_components, sizes.len());
+                assert_eq!(level.largest_component, *sizes.iter().max().unwrap());
+                assert_eq!(level.n_edges, kept.get_edges().len());
+            }
+        }
+    }
+
+    #[test]
+    fn weight_threshold_sweep_check_empty() {
+        let levels = weight_threshold_sweep(&UGraph::new(), &[1.0]);
+        assert_eq!(
+            (levels[0].n_components, levels[0].largest_component),
+            (0, 0)
+        );
+        assert!(weight_threshold_sweep(&UGraph::new(), &[]).is_empty());
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{DGraph, DagGraph, Graph};
+
+/// The `TopologicalOrders` struct is an iterator over the topological orderings of a
+/// directed acyclic graph, where every node comes after its predecessors. Each call to
+/// `next` backtracks from the previous ordering to the next one in lexicographic order,
+/// so the orderings are generated lazily in `O(n^2)` each. The number of orderings can
+/// grow factorially with the number of nodes, so the iterator stops after `limit`
+/// orderings.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::TopologicalOrders;
+/// use graphst::DagGraph;
+/// // 0: fetch, 1: build (needs fetch), 2: lint (needs fetch), 3: release (needs both)
+/// let dag = DagGraph::from_weighted_edges(
+///     4,
+///     vec![(0, 1, 1.0), (0, 2, 1.0), (1, 3, 1.0), (2, 3, 1.0)],
+/// )
+/// .unwrap();
+/// let orders: Vec<Vec<usize>> = TopologicalOrders::new(&dag, 10).collect();
+/// assert_eq!(orders, vec![vec![0, 1, 2, 3], vec![0, 2, 1, 3]]);
+/// assert_eq!(TopologicalOrders::new(&dag, 1).count(), 1);
+/// ```
+#[derive(Debug)]
+pub struct TopologicalOrders<'a> {
+    g: &'a DGraph,
+    // Number of predecessors of each node not placed in `order` yet
+    in_degree: Vec<usize>,
+    placed: Vec<bool>,
+    order: Vec<usize>,
+    started: bool,
+    remaining: usize,
+}
+
+impl<'a> TopologicalOrders<'a> {
+    /// Creates the iterator from a directed acyclic graph, yielding up to `limit`
+    /// orderings.
+    pub fn new(g: &'a DagGraph, limit: usize) -> TopologicalOrders<'a> {
+        let g = g.get_graph();
+        TopologicalOrders {
+            g,
+            in_degree: g
+                .get_nodes()
+                .iter()
+                .map(|n| g.get_predecessors_of(*n).len())
+                .collect(),
+            placed: vec![false; g.get_n_nodes()],
+            order: vec![],
+            started: false,
+            remaining: limit,
+        }
+    }
+
+    //--------- Private functions ---------
+
+    fn place(&mut self, node: usize) {
+        self.placed[node] = true;
+        self.order.push(node);
+        for n in self.g.get_successors_of(node) {
+            self.in_degree[n] -= 1;
+        }
+    }
+
+    fn unplace(&mut self) -> Option<usize> {
+        let node = self.order.pop()?;
+        self.placed[node] = false;
+        for n in self.g.get_successors_of(node) {
+            self.in_degree[n] += 1;
+        }
+        Some(node)
+    }
+
+    // Returns the lowest node after `start` whose predecessors are all placed
+    fn next_available(&self, start: usize) -> Option<usize> {
+        (start..self.placed.len()).find(|n| !self.placed[*n] && self.in_degree[*n] == 0)
+    }
+
+    // Completes the ordering with the lowest available node at each position
+    fn complete(&mut self) {
+        while let Some(node) = self.next_available(0) {
+            self.place(node);
+        }
+    }
+}
+
+impl<'a> Iterator for TopologicalOrders<'a> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+        } else {
+            // Replace the last node that has a higher alternative, if any
+            loop {
+                let node = self.unplace()?;
+                if let Some(next) = self.next_available(node + 1) {
+                    self.place(next);
+                    break;
+                }
+            }
+        }
+        self.complete();
+        self.remaining -= 1;
+        Some(self.order.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every permutation of the nodes that respects the edges, in lexicographic order
+    fn brute_force(dag: &DagGraph, order: &mut Vec<usize>, orders: &mut Vec<Vec<usize>>) {
+        let g = dag.get_graph();
+        if order.len() == g.get_n_nodes() {
+            orders.push(order.clone());
+            return;
+        }
+        for node in g.get_nodes() {
+            let ready = g
+                .get_predecessors_of(node)
+                .iter()
+                .all(|p| order.contains(p));
+            if !order.contains(&node) && ready {
+                order.push(node);
+                brute_force(dag, order, orders);
+                order.pop();
+            }
+        }
+    }
+
+    #[test]
+    fn topological_orders_matches_brute_force() {
+        let mut rng = crate::rng::Rng::new(19);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(6);
+            let mut dag = DagGraph::with_nodes(n_nodes);
+            for _ in 0..rng.gen_range(10) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                if node1 < node2 {
+                    dag.add_edge(node1, node2).unwrap();
+                }
+            }
+            let mut expected = vec![];
+            brute_force(&dag, &mut vec![], &mut expected);
+            let orders: Vec<Vec<usize>> = TopologicalOrders::new(&dag, usize::MAX).collect();
+            assert_eq!(orders, expected);
+        }
+    }
+
+    #[test]
+    fn topological_orders_check_limit() {
+        // 5 independent nodes have 120 orderings
+        let dag = DagGraph::with_nodes(5);
+        assert_eq!(TopologicalOrders::new(&dag, usize::MAX).count(), 120);
+        let mut orders = TopologicalOrders::new(&dag, 2);
+        assert_eq!(orders.next(), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(orders.next(), Some(vec![0, 1, 2, 4, 3]));
+        assert_eq!(orders.next(), None);
+        assert_eq!(TopologicalOrders::new(&dag, 0).next(), None);
+    }
+}
@@ -0,0 +1,255 @@
+use crate::graph::Graph;
+
+/// Splits the edges of the graph into the minimum number of edge-disjoint trails (walks
+/// that don't repeat edges), returned as the sequences of their nodes. Each connected
+/// component with edges needs `max(1, odd / 2)` trails in undirected graphs, where `odd`
+/// is its number of nodes with odd degree, and `max(1, surplus)` trails in directed
+/// graphs, where `surplus` is the sum of `out_degree - in_degree` over the nodes with
+/// more outgoing than incoming arcs. When the component has an Eulerian circuit it is
+/// returned as a single closed trail. The weights of the edges are ignored.
+///
+/// The components are balanced with dummy edges between their unbalanced nodes, an
+/// Eulerian circuit is found with Hierholzer's algorithm and the circuit is cut at the
+/// dummy edges.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::trail_decomposition;
+/// use graphst::UGraph;
+/// // A star with 4 leaves has 4 odd nodes, so it can't be drawn in one stroke
+/// let g = UGraph::from_edges(5, vec![(0, 1), (0, 2), (0, 3), (0, 4)]);
+/// let trails = trail_decomposition(&g);
+/// assert_eq!(trails.len(), 2);
+/// assert_eq!(trails.iter().map(|t| t.len() - 1).sum::<usize>(), 4);
+/// ```
+pub fn trail_decomposition<G>(g: &G) -> Vec<Vec<usize>>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    let directed = g.is_directed();
+    // Each edge is stored as (node1, node2, is_dummy)
+    let mut edges: Vec<(usize, usize, bool)> = g
+        .get_edges()
+        .into_iter()
+        .map(|(node1, node2, _)| (node1, node2, false))
+        .collect();
+    let component = components_of(n_nodes, &edges);
+    let n_components = component
+        .iter()
+        .filter_map(|c| *c)
+        .max()
+        .map_or(0, |c| c + 1);
+
+    // Balance each component with dummy edges from the ends to the starts of the trails
+    let mut balance = vec![0i64; n_nodes];
+    for (node1, node2, _) in &edges {
+        if directed {
+            balance[*node1] += 1;
+            balance[*node2] -= 1;
+        } else if node1 != node2 {
+            balance[*node1] += 1;
+            balance[*node2] += 1;
+        }
+    }
+    let mut starts = vec![vec![]; n_components];
+    let mut ends = vec![vec![]; n_components];
+    for node in 0..n_nodes {
+        if let Some(c) = component[node] {
+            if directed {
+                for _ in 0..balance[node].max(0) {
+                    starts[c].push(node);
+                }
+                for _ in 0..(-balance[node]).max(0) {
+                    ends[c].push(node);
+                }
+            } else if balance[node] % 2 == 1 {
+                // The odd nodes alternate between starts and ends of the trails
+                if starts[c].len() == ends[c].len() {
+                    starts[c].push(node);
+                } else {
+                    ends[c].push(node);
+                }
+            }
+        }
+    }
+    for c in 0..n_components {
+        for (end, start) in ends[c].iter().zip(&starts[c]) {
+            edges.push((*end, *start, true));
+        }
+    }
+
+    // Adjacency lists with the ids of the edges
+    let mut adjacency = vec![vec![]; n_nodes];
+    for (id, (node1, node2, _)) in edges.iter().enumerate() {
+        adjacency[*node1].push((*node2, id));
+        if !directed && node1 != node2 {
+            adjacency[*node2].push((*node1, id));
+        }
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut next = vec![0; n_nodes];
+    let mut visited_component = vec![false; n_components];
+    let mut trails = vec![];
+    for (node, c) in component.iter().enumerate() {
+        let c = match c {
+            Some(c) if !visited_component[*c] => *c,
+            _ => continue,
+        };
+        visited_component[c] = true;
+        // Start at a trail start if possible, so that closed circuits stay intact
+        let start = starts[c].first().copied().unwrap_or(node);
+        let mut steps = eulerian_circuit(start, &adjacency, &mut used, &mut next);
+        if let Some(pos) = steps.iter().position(|(_, _, id)| edges[*id].2) {
+            steps.rotate_left(pos + 1);
+        }
+        let mut trail: Vec<usize> = vec![];
+        for (from, to, id) in steps {
+            if edges[id].2 {
+                if !trail.is_empty() {
+                    trails.push(std::mem::take(&mut trail));
+                }
+                continue;
+            }
+            if trail.is_empty() {
+                trail.push(from);
+            }
+            trail.push(to);
+        }
+        if !trail.is_empty() {
+            trails.push(trail);
+        }
+    }
+    trails
+}
+
+//--------- Private functions ---------
+
+// Returns the connected components (weakly for directed graphs) of the nodes with edges,
+// numbered by their smallest node
+fn components_of(n_nodes: usize, edges: &[(usize, usize, bool)]) -> Vec<Option<usize>> {
+    let mut neighbors = vec![vec![]; n_nodes];
+    for (node1, node2, _) in edges {
+        neighbors[*node1].push(*node2);
+        neighbors[*node2].push(*node1);
+    }
+    let mut component = vec![None; n_nodes];
+    let mut n_components = 0;
+    for node in 0..n_nodes {
+        if component[node].is_some() || neighbors[node].is_empty() {
+            continue;
+        }
+        component[node] = Some(n_components);
+        let mut stack = vec![node];
+        while let Some(current) = stack.pop() {
+            for n in &neighbors[current] {
+                if component[*n].is_none() {
+                    component[*n] = Some(n_components);
+                    stack.push(*n);
+                }
+            }
+        }
+        n_components += 1;
+    }
+    component
+}
+
+// Returns the steps `(from, to, edge_id)` of the Eulerian circuit from `start` with
+// Hierholzer's algorithm
+fn eulerian_circuit(
+    start: usize,
+    adjacency: &[Vec<(usize, usize)>],
+    used: &mut [bool],
+    next: &mut [usize],
+) -> Vec<(usize, usize, usize)> {
+    let mut stack: Vec<(usize, Option<usize>)> = vec![(start, None)];
+    let mut circuit = vec![];
+    while let Some((node, edge)) = stack.last().copied() {
+        while next[node] < adjacency[node].len() && used[adjacency[node][next[node]].1] {
+            next[node] += 1;
+        }
+        if next[node] < adjacency[node].len() {
+            let (neighbor, id) = adjacency[node][next[node]];
+            used[id] = true;
+            stack.push((neighbor, Some(id)));
+        } else {
+            stack.pop();
+            circuit.push((node, edge));
+        }
+    }
+    circuit.reverse();
+    circuit
+        .windows(2)
+        .map(|w| (w[0].0, w[1].0, w[1].1.unwrap()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DGraph, UGraph};
+
+    // Checks that the trails are valid and cover every edge exactly once
+    fn check_cover<G: Graph>(g: &G, trails: &[Vec<usize>]) {
+        let mut covered = vec![];
+        for trail in trails {
+            for w in trail.windows(2) {
+                assert!(g.get_edge(w[0], w[1]).is_some());
+                let pair = if g.is_directed() {
+                    (w[0], w[1])
+                } else {
+                    (w[0].min(w[1]), w[0].max(w[1]))
+                };
+                covered.push(pair);
+            }
+        }
+        covered.sort_unstable();
+        let mut edges: Vec<_> = g.get_edges().into_iter().map(|(a, b, _)| (a, b)).collect();
+        edges.sort_unstable();
+        assert_eq!(covered, edges);
+    }
+
+    #[test]
+    fn trail_decomposition_check_eulerian_circuit() {
+        let g = UGraph::from_edges(5, vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+        let trails = trail_decomposition(&g);
+        assert_eq!(trails.len(), 1);
+        assert_eq!(trails[0].first(), trails[0].last());
+        check_cover(&g, &trails);
+    }
+
+    #[test]
+    fn trail_decomposition_check_components_and_odd_nodes() {
+        // K4 has 4 odd nodes and the path 4-5-6 has 2
+        let edges = vec![
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (4, 5),
+            (5, 6),
+        ];
+        let g = UGraph::from_edges(8, edges);
+        let trails = trail_decomposition(&g);
+        assert_eq!(trails.len(), 3);
+        check_cover(&g, &trails);
+    }
+
+    #[test]
+    fn trail_decomposition_check_directed() {
+        // The node 0 has 2 more outgoing arcs than incoming ones
+        let g = DGraph::from_edges(4, vec![(0, 1), (0, 2), (0, 3), (1, 0), (2, 3)]);
+        let trails = trail_decomposition(&g);
+        assert_eq!(trails.len(), 2);
+        check_cover(&g, &trails);
+        assert!(trail_decomposition(&DGraph::from_edges(3, vec![])).is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+use crate::algorithm::resolve_dependencies;
+use crate::{DGraph, Graph};
+
+/// Returns the transitive closure of a directed graph: a graph with the same nodes and an
+/// edge `u -> v` (of weight `1.0`) whenever `v` is reachable from `u` through a path of
+/// one or more edges. A node only has a self-loop if it belongs to a cycle. It runs a
+/// depth first search from each node, in `O(n (n + m))`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::transitive_closure;
+/// use graphst::{DGraph, Graph};
+/// let g = DGraph::from_edges(4, vec![(0, 1), (1, 2), (3, 3)]);
+/// let closure = transitive_closure(&g);
+/// assert_eq!(
+///     closure.get_edges(),
+///     vec![(0, 1, 1.0), (0, 2, 1.0), (1, 2, 1.0), (3, 3, 1.0)]
+/// );
+/// ```
+pub fn transitive_closure(g: &DGraph) -> DGraph {
+    let n_nodes = g.get_n_nodes();
+    let mut edges = vec![];
+    for src in g.get_nodes() {
+        let reached = reachable_from(g, src);
+        edges.extend((0..n_nodes).filter(|n| reached[*n]).map(|n| (src, n)));
+    }
+    DGraph::from_edges(n_nodes, edges)
+}
+
+/// Returns the transitive reduction of a directed acyclic graph: the graph with the fewest
+/// edges that has the same reachability. An edge `u -> v` is removed if `v` is reachable
+/// from another successor of `u`, and the kept edges keep their weights. Rendering the
+/// reduction of a dependency graph (e.g. with `dot::to_dot`) shows only the direct
+/// dependencies.
+///
+/// # Panics
+///
+/// * If the graph has cycles, where the reduction is not unique.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::transitive_reduction;
+/// use graphst::{DGraph, Graph};
+/// // 0: app, 1: http (needs 2), 2: json; the app also depends on json directly
+/// let g = DGraph::from_edges(3, vec![(0, 1), (0, 2), (1, 2)]);
+/// assert_eq!(transitive_reduction(&g).get_edges(), vec![(0, 1, 1.0), (1, 2, 1.0)]);
+/// ```
+pub fn transitive_reduction(g: &DGraph) -> DGraph {
+    if resolve_dependencies(g).is_err() {
+        panic!("[algorithm::transitive_reduction] Error: The graph has cycles!");
+    }
+    let reachable: Vec<Vec<bool>> = g
+        .get_nodes()
+        .iter()
+        .map(|n| reachable_from(g, *n))
+        .collect();
+    let edges = g
+        .get_edges()
+        .into_iter()
+        .filter(|(src, dest, _)| {
+            !g.get_successors_of(*src)
+                .iter()
+                .any(|n| n != dest && reachable[*n][*dest])
+        })
+        .collect();
+    DGraph::from_weighted_edges(g.get_n_nodes(), edges)
+}
+
+//--------- Private functions ---------
+
+// Marks the nodes reachable from `src` through one or more edges
+fn reachable_from(g: &DGraph, src: usize) -> Vec<bool> {
+    let mut reached = vec![false; g.get_n_nodes()];
+    let mut stack = g.get_successors_of(src);
+    while let Some(node) = stack.pop() {
+        if reached[node] {
+            continue;
+        }
+        reached[node] = true;
+        stack.extend(
+            g.get_successors_of(node)
+                .into_iter()
+                .filter(|n| !reached[*n]),
+        );
+    }
+    reached
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::floyd_warshall;
+
+    #[test]
+    fn transitive_closure_matches_floyd_warshall() {
+        let mut rng = crate::rng::Rng::new(22);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(12);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(20) {
+                adj_mat[rng.gen_range(n_nodes)][rng.gen_range(n_nodes)] = 1.0;
+            }
+            let g = DGraph::from_adjacency_matrix(adj_mat);
+            let dist = floyd_warshall(&g);
+            let closure = transitive_closure(&g);
+            for (node1, row) in dist.iter().enumerate() {
+                for (node2, d) in row.iter().enumerate() {
+                    // A node reaches itself if some successor reaches it back
+                    let expected = if node1 == node2 {
+                        g.get_successors_of(node1)
+                            .iter()
+                            .any(|s| dist[*s][node1].is_finite())
+                    } else {
+                        d.is_finite()
+                    };
+                    assert_eq!(closure.get_edge(node1, node2).is_some(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transitive_closure_check_cycle() {
+        let g = DGraph::from_edges(3, vec![(0, 1), (1, 0), (1, 2)]);
+        let closure = transitive_closure(&g);
+        let expected = vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)];
+        assert_eq!(closure, DGraph::from_edges(3, expected));
+    }
+
+    #[test]
+    fn transitive_reduction_check_minimal() {
+        let mut rng = crate::rng::Rng::new(24);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(10);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(30) {
+                // Edges from lower to higher nodes can't create cycles
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                if node1 < node2 {
+                    adj_mat[node1][node2] = 1.0 + rng.gen_range(3) as f32;
+                }
+            }
+            let g = DGraph::from_adjacency_matrix(adj_mat);
+            let reduction = transitive_reduction(&g);
+            let closure = transitive_closure(&g);
+            assert_eq!(transitive_closure(&reduction), closure);
+            // Removing any edge of the reduction loses reachability
+            for (src, dest, weight) in reduction.get_edges() {
+                assert_eq!(g.get_edge(src, dest), Some(weight));
+                let mut smaller = reduction.clone();
+                smaller.add_weighted_edge(src, dest, 0.0);
+                assert_ne!(transitive_closure(&smaller), closure);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "has cycles")]
+    fn transitive_reduction_panic_cycle() {
+        transitive_reduction(&DGraph::from_edges(2, vec![(0, 1), (1, 0)]));
+    }
+}
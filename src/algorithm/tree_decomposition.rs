@@ -0,0 +1,224 @@
+use crate::graph::Graph;
+use crate::UGraph;
+
+/// The `EliminationHeuristic` enum selects the node eliminated at each step of
+/// `tree_decomposition_with`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EliminationHeuristic {
+    /// The node with the fewest neighbors.
+    MinDegree,
+    /// The node whose elimination adds the fewest fill edges between its neighbors.
+    MinFill,
+}
+
+/// The `TreeDecomposition` struct is the result of `tree_decomposition`: a tree whose
+/// nodes are bags of nodes of the graph, such that every edge of the graph is inside
+/// some bag and the bags that contain a node form a connected subtree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeDecomposition {
+    /// The nodes of the graph in each bag, sorted.
+    pub bags: Vec<Vec<usize>>,
+    /// The edges of the tree, as pairs of bag indices.
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl TreeDecomposition {
+    /// Returns the width of the decomposition: the size of its largest bag minus one. It
+    /// is an upper bound of the treewidth of the graph.
+    pub fn width(&self) -> usize {
+        self.bags.iter().map(|bag| bag.len()).max().unwrap_or(1) - 1
+    }
+}
+
+/// Returns a tree decomposition of the graph built with the min-fill heuristic (see
+/// `tree_decomposition_with`). Dynamic programming over the bags solves many NP-hard
+/// problems in time exponential only in the width of the decomposition.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::tree_decomposition;
+/// use graphst::UGraph;
+/// // A cycle has treewidth 2
+/// let g = UGraph::from_edges(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+/// let td = tree_decomposition(&g);
+/// assert_eq!(td.width(), 2);
+/// assert_eq!(td.edges.len(), td.bags.len() - 1);
+/// ```
+pub fn tree_decomposition(g: &UGraph) -> TreeDecomposition {
+    tree_decomposition_with(g, EliminationHeuristic::MinFill)
+}
+
+/// Returns a tree decomposition of the graph from a greedy elimination ordering: at each
+/// step the node selected by `heuristic` is eliminated, its bag is the node with its
+/// current neighbors, and its neighbors are connected to each other. Each bag is linked
+/// to the bag of the first of its neighbors eliminated later, and the bags of the last
+/// nodes of each component are chained, so the result is a single tree. There is one bag
+/// per node of the graph, and self-loops are ignored.
+///
+/// # Arguments
+///
+/// * `g` - An undirected graph.
+/// * `heuristic` - The rule to choose the next node to eliminate. Min-fill usually gives
+///   smaller widths, min-degree is faster.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::{tree_decomposition_with, EliminationHeuristic};
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(4, vec![(0, 1), (1, 2), (1, 3)]);
+/// let td = tree_decomposition_with(&g, EliminationHeuristic::MinDegree);
+/// assert_eq!(td.width(), 1); // a tree
+/// ```
+pub fn tree_decomposition_with(g: &UGraph, heuristic: EliminationHeuristic) -> TreeDecomposition {
+    let n_nodes = g.get_n_nodes();
+    let mut adjacent = vec![vec![false; n_nodes]; n_nodes];
+    for (node1, node2, _) in g.get_edges() {
+        if node1 != node2 {
+            adjacent[node1][node2] = true;
+            adjacent[node2][node1] = true;
+        }
+    }
+    let mut eliminated = vec![false; n_nodes];
+    // step[node]: position of the node in the elimination ordering
+    let mut step = vec![0; n_nodes];
+    let mut neighbors_at_elimination = vec![vec![]; n_nodes];
+    for current_step in 0..n_nodes {
+        let neighbors_of = |node: usize, adjacent: &Vec<Vec<bool>>| -> Vec<usize> {
+            (0..n_nodes)
+                .filter(|n| !eliminated[*n] && adjacent[node][*n])
+                .collect()
+        };
+        let node = (0..n_nodes)
+            .filter(|n| !eliminated[*n])
+            .min_by_key(|n| {
+                let neighbors = neighbors_of(*n, &adjacent);
+                match heuristic {
+                    EliminationHeuristic::MinDegree => neighbors.len(),
+                    EliminationHeuristic::MinFill => count_fill(&neighbors, &adjacent),
+                }
+            })
+            .unwrap();
+        let neighbors = neighbors_of(node, &adjacent);
+        for (i, n1) in neighbors.iter().enumerate() {
+            for n2 in &neighbors[i + 1..] {
+                adjacent[*n1][*n2] = true;
+                adjacent[*n2][*n1] = true;
+            }
+        }
+        eliminated[node] = true;
+        step[node] = current_step;
+        neighbors_at_elimination[node] = neighbors;
+    }
+
+    // The bag `i` belongs to the node `i`
+    let mut bags = vec![];
+    let mut edges = vec![];
+    let mut last_root: Option<usize> = None;
+    let mut roots = vec![];
+    for (node, neighbors) in neighbors_at_elimination.into_iter().enumerate() {
+        match neighbors.iter().min_by_key(|n| step[**n]) {
+            Some(parent) => edges.push((node, *parent)),
+            None => roots.push(node),
+        }
+        let mut bag = neighbors;
+        bag.push(node);
+        bag.sort_unstable();
+        bags.push(bag);
+    }
+    roots.sort_by_key(|n| step[*n]);
+    for root in roots {
+        if let Some(previous) = last_root {
+            edges.push((previous, root));
+        }
+        last_root = Some(root);
+    }
+    TreeDecomposition { bags, edges }
+}
+
+//--------- Private functions ---------
+
+// Number of missing edges between the nodes passed as parameters
+fn count_fill(nodes: &[usize], adjacent: &[Vec<bool>]) -> usize {
+    let mut fill = 0;
+    for (i, n1) in nodes.iter().enumerate() {
+        fill += nodes[i + 1..]
+            .iter()
+            .filter(|n2| !adjacent[*n1][**n2])
+            .count();
+    }
+    fill
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Checks the three properties of a tree decomposition
+    fn check_valid(g: &UGraph, td: &TreeDecomposition) {
+        let n_bags = td.bags.len();
+        assert_eq!(td.edges.len(), n_bags.saturating_sub(1));
+        for (node1, node2, _) in g.get_edges() {
+            assert!(td
+                .bags
+                .iter()
+                .any(|bag| bag.contains(&node1) && bag.contains(&node2)));
+        }
+        for node in g.get_nodes() {
+            // The bags with the node must be connected through tree edges between them
+            let with: Vec<bool> = td.bags.iter().map(|bag| bag.contains(&node)).collect();
+            let first = with.iter().position(|w| *w).unwrap();
+            let mut reached = vec![false; n_bags];
+            reached[first] = true;
+            let mut stack = vec![first];
+            while let Some(bag) = stack.pop() {
+                for (a, b) in &td.edges {
+                    for (from, to) in [(*a, *b), (*b, *a)] {
+                        if from == bag && with[to] && !reached[to] {
+                            reached[to] = true;
+                            stack.push(to);
+                        }
+                    }
+                }
+            }
+            assert_eq!(reached, with);
+        }
+    }
+
+    #[test]
+    fn tree_decomposition_check_grid() {
+        let mut edges = vec![];
+        for row in 0..3 {
+            for col in 0..3 {
+                let node = row * 3 + col;
+                if col < 2 {
+                    edges.push((node, node + 1));
+                }
+                if row < 2 {
+                    edges.push((node, node + 3));
+                }
+            }
+        }
+        let g = UGraph::from_edges(9, edges);
+        for heuristic in [
+            EliminationHeuristic::MinFill,
+            EliminationHeuristic::MinDegree,
+        ] {
+            let td = tree_decomposition_with(&g, heuristic);
+            check_valid(&g, &td);
+            assert!(td.width() >= 3 && td.width() <= 4);
+        }
+    }
+
+    #[test]
+    fn tree_decomposition_check_components_and_cliques() {
+        // K4 plus a separate edge and an isolated node
+        let edges = vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (4, 5)];
+        let g = UGraph::from_edges(7, edges);
+        let td = tree_decomposition(&g);
+        check_valid(&g, &td);
+        assert_eq!(td.width(), 3);
+        assert_eq!(tree_decomposition(&UGraph::new()).width(), 0);
+    }
+}
@@ -0,0 +1,283 @@
+use crate::float::{self, FloatOrd};
+use crate::graph::Graph;
+use crate::path;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Same as `dijkstra` but adding a turn penalty each time a path goes through a node.
+/// The function `penalty(prev, via, next)` returns the extra cost of arriving to `via`
+/// from `prev` and leaving it towards `next`, and it can return `f32::INFINITY` to forbid
+/// a turn (like the U-turns in a road network). Internally the search runs over the
+/// edge-expanded graph, where each state is the edge used to arrive to a node, with a
+/// binary heap as in `dijkstra`. It runs in `O(T log T)` time, with `T` the number of
+/// turns (pairs of consecutive edges), and `O(E)` memory. The penalties must be
+/// non-negative. Use `dijkstra_to_with_turn_penalties` to also get the path.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+/// * `src` - The source node of the paths.
+/// * `penalty` - The turn penalty function `Fn(prev, via, next) -> f32`.
+///
+/// # Panics
+///
+/// * If the source node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::dijkstra_with_turn_penalties;
+/// use graphst::UGraph;
+/// // A square 0-1-2-3-0 with the diagonal 1-3
+/// let g = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3), (3, 0), (1, 3)]);
+/// // Every path going through the node 1 pays a penalty of 5
+/// let dist = dijkstra_with_turn_penalties(&g, 0, |_, via, _| if via == 1 { 5.0 } else { 0.0 });
+/// assert_eq!(dist, vec![0.0, 1.0, 2.0, 1.0]); // 2 is reached through 3
+/// ```
+pub fn dijkstra_with_turn_penalties<G, F>(g: &G, src: usize, penalty: F) -> Vec<f32>
+where
+    G: Graph,
+    F: Fn(usize, usize, usize) -> f32,
+{
+    if src >= g.get_n_nodes() {
+        panic!(
+            "[algorithm::dijkstra_with_turn_penalties] Error: The node {} is not valid!",
+            src
+        );
+    }
+    turn_search(g, src, None, penalty).dist
+}
+
+/// Returns the cost and the nodes of the shortest path from `src` to `dest` with the turn
+/// penalties of `dijkstra_with_turn_penalties`, or `None` if `dest` is not reachable. The
+/// path can go through a node more than once when the turns force a detour. The search
+/// stops as soon as `dest` is settled.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+/// * `src` - The source node of the path.
+/// * `dest` - The destination node of the path.
+/// * `penalty` - The turn penalty function `Fn(prev, via, next) -> f32`.
+///
+/// # Panics
+///
+/// * If some node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::dijkstra_to_with_turn_penalties;
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3), (3, 0), (1, 3)]);
+/// let path = dijkstra_to_with_turn_penalties(&g, 0, 2, |_, via, _| if via == 1 { 5.0 } else { 0.0 });
+/// assert_eq!(path, Some((2.0, vec![0, 3, 2])));
+/// ```
+pub fn dijkstra_to_with_turn_penalties<G, F>(
+    g: &G,
+    src: usize,
+    dest: usize,
+    penalty: F,
+) -> Option<(f32, Vec<usize>)>
+where
+    G: Graph,
+    F: Fn(usize, usize, usize) -> f32,
+{
+    let n_nodes = g.get_n_nodes();
+    if let Some(node) = [src, dest].iter().find(|n| **n >= n_nodes) {
+        panic!(
+            "[algorithm::dijkstra_to_with_turn_penalties] Error: The node {} is not valid!",
+            node
+        );
+    }
+    if src == dest {
+        return Some((0.0, vec![src]));
+    }
+    let search = turn_search(g, src, Some(dest), penalty);
+    let last_arc = search.last_arc[dest]?;
+    let mut path = vec![src];
+    for arc in path::from_predecessors(&search.arc_pred, last_arc) {
+        path.push(search.arcs[arc].1);
+    }
+    Some((search.dist[dest], path))
+}
+
+//--------- Private functions ---------
+
+// The result of the search over the edge-expanded graph
+struct TurnSearch {
+    dist: Vec<f32>,                 // best distance to each node
+    arcs: Vec<(usize, usize, f32)>, // the directed edges (source, target, weight)
+    arc_pred: Vec<Option<usize>>,   // the previous arc in the best path to each arc
+    last_arc: Vec<Option<usize>>,   // the arc used to reach each node with its best distance
+}
+
+// Dijkstra over the edge-expanded graph, where each state is the edge used to arrive to
+// a node. The states are selected with a binary heap, so it runs in O(A log A) with A the
+// number of turns (pairs of consecutive arcs). Stops when `dest` is settled, if given
+fn turn_search<G, F>(g: &G, src: usize, dest: Option<usize>, penalty: F) -> TurnSearch
+where
+    G: Graph,
+    F: Fn(usize, usize, usize) -> f32,
+{
+    let n_nodes = g.get_n_nodes();
+    // The states of the edge-expanded graph are the directed edges (both directions for
+    // undirected graphs), indexed by their source node
+    let mut arcs = vec![];
+    let mut out_arcs = vec![vec![]; n_nodes];
+    for node in g.get_nodes() {
+        for n in g.get_out_neighbors_of(node) {
+            if let Some(weight) = g.get_edge(node, n) {
+                out_arcs[node].push(arcs.len());
+                arcs.push((node, n, weight));
+            }
+        }
+    }
+    let mut arc_dist = vec![f32::INFINITY; arcs.len()];
+    let mut arc_pred = vec![None; arcs.len()];
+    let mut visited = vec![false; arcs.len()];
+    let mut heap = BinaryHeap::new();
+    for arc in &out_arcs[src] {
+        arc_dist[*arc] = arcs[*arc].2; // no penalty at the source
+        heap.push(Reverse((FloatOrd(arc_dist[*arc]), *arc)));
+    }
+    let mut dist = vec![f32::INFINITY; n_nodes];
+    let mut last_arc = vec![None; n_nodes];
+    dist[src] = 0.0;
+    while let Some(Reverse((_, current))) = heap.pop() {
+        if visited[current] {
+            continue; // Outdated entry, the arc was reached with a shorter distance
+        }
+        visited[current] = true;
+        let (prev, via, _) = arcs[current];
+        if arc_dist[current] < dist[via] {
+            dist[via] = arc_dist[current];
+            last_arc[via] = Some(current);
+        }
+        if dest == Some(via) {
+            break;
+        }
+        for next_arc in &out_arcs[via] {
+            let (_, next, weight) = arcs[*next_arc];
+            let new_dist = arc_dist[current] + penalty(prev, via, next) + weight;
+            if !visited[*next_arc] && float::greater_than(arc_dist[*next_arc], new_dist) {
+                arc_dist[*next_arc] = new_dist;
+                arc_pred[*next_arc] = Some(current);
+                heap.push(Reverse((FloatOrd(new_dist), *next_arc)));
+            }
+        }
+    }
+    TurnSearch {
+        dist,
+        arcs,
+        arc_pred,
+        last_arc,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::dijkstra;
+    use crate::DGraph;
+
+    #[test]
+    fn dijkstra_with_turn_penalties_matches_dijkstra_without_penalties() {
+        let edges = vec![
+            (0, 1, 2.0),
+            (1, 2, 1.0),
+            (0, 2, 4.0),
+            (2, 3, 1.5),
+            (3, 1, 0.5),
+        ];
+        let g = DGraph::from_weighted_edges(5, edges);
+        assert_eq!(
+            dijkstra_with_turn_penalties(&g, 0, |_, _, _| 0.0),
+            dijkstra(&g, 0)
+        );
+    }
+
+    #[test]
+    fn dijkstra_with_turn_penalties_forbids_turns() {
+        // Forbidding 0->1->2 forces the long way 0->3->2
+        let edges = vec![(0, 1, 1.0), (1, 2, 1.0), (0, 3, 5.0), (3, 2, 5.0)];
+        let g = DGraph::from_weighted_edges(4, edges);
+        let no_left_turn = |prev, via, next| {
+            if (prev, via, next) == (0, 1, 2) {
+                f32::INFINITY
+            } else {
+                0.0
+            }
+        };
+        assert_eq!(
+            dijkstra_with_turn_penalties(&g, 0, no_left_turn),
+            vec![0.0, 1.0, 10.0, 5.0]
+        );
+    }
+
+    #[test]
+    fn dijkstra_to_with_turn_penalties_check_paths() {
+        // The U-turns are forbidden, but leaving the source is not a turn, so 2->1 is
+        // allowed when the path starts at 2
+        let edges = vec![
+            (0, 1, 1.0),
+            (1, 2, 1.0),
+            (2, 3, 1.0),
+            (3, 1, 1.0),
+            (2, 1, 1.0),
+        ];
+        let g = DGraph::from_weighted_edges(5, edges);
+        let no_u_turn = |prev, _, next| if prev == next { f32::INFINITY } else { 0.0 };
+        assert_eq!(
+            dijkstra_to_with_turn_penalties(&g, 0, 2, no_u_turn),
+            Some((2.0, vec![0, 1, 2]))
+        );
+        assert_eq!(
+            dijkstra_to_with_turn_penalties(&g, 2, 1, no_u_turn),
+            Some((1.0, vec![2, 1]))
+        );
+        assert_eq!(
+            dijkstra_to_with_turn_penalties(&g, 0, 0, no_u_turn),
+            Some((0.0, vec![0]))
+        );
+        assert_eq!(dijkstra_to_with_turn_penalties(&g, 0, 4, no_u_turn), None);
+        // The path to each node costs its distance
+        let dist = dijkstra_with_turn_penalties(&g, 0, no_u_turn);
+        for (node, d) in dist.iter().enumerate().take(4) {
+            let (cost, path) = dijkstra_to_with_turn_penalties(&g, 0, node, no_u_turn).unwrap();
+            assert_eq!(cost, *d);
+            assert_eq!(path[0], 0);
+            assert_eq!(path[path.len() - 1], node);
+        }
+    }
+
+    #[test]
+    fn dijkstra_to_with_turn_penalties_check_revisit() {
+        // Forbidding the turn 0->1->2 forces the loop 1->3->1 before going to 2
+        let edges = vec![(0, 1, 1.0), (1, 2, 1.0), (1, 3, 1.0), (3, 1, 1.0)];
+        let g = DGraph::from_weighted_edges(4, edges);
+        let no_turn = |prev, via, next| {
+            if (prev, via, next) == (0, 1, 2) {
+                f32::INFINITY
+            } else {
+                0.0
+            }
+        };
+        assert_eq!(
+            dijkstra_to_with_turn_penalties(&g, 0, 2, no_turn),
+            Some((4.0, vec![0, 1, 3, 1, 2]))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn dijkstra_to_with_turn_penalties_panic_not_valid_node() {
+        dijkstra_to_with_turn_penalties(&DGraph::from_edges(2, vec![(0, 1)]), 0, 2, |_, _, _| 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn dijkstra_with_turn_penalties_panic_not_valid_node() {
+        dijkstra_with_turn_penalties(&DGraph::new(), 0, |_, _, _| 0.0);
+    }
+}
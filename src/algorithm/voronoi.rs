@@ -0,0 +1,96 @@
+use crate::algorithm::multi_source_dijkstra;
+use crate::graph::Graph;
+
+/// The `GraphVoronoi` struct holds the result of `graph_voronoi`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphVoronoi {
+    /// The seed of the region of each node, `None` for the nodes not reachable from any
+    /// seed.
+    pub regions: Vec<Option<usize>>,
+    /// The distance from each node to the seed of its region (`f32::INFINITY` if it has
+    /// no region).
+    pub distances: Vec<f32>,
+    /// The edges between nodes of different regions (the cut edges), as
+    /// `(node1, node2, weight)` triplets in the order of `get_edges`.
+    pub boundary: Vec<(usize, usize, f32)>,
+}
+
+/// Partitions the nodes of the graph in the Voronoi regions of the seeds: each node is
+/// assigned to the seed with the shortest path to it, computed with
+/// `multi_source_dijkstra`. In case of a tie the seed that comes first in `seeds` wins. For directed
+/// graphs the paths go from the seeds to the nodes. The weights must be non-negative.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+/// * `seeds` - A slice with the seed nodes.
+///
+/// # Panics
+///
+/// * If some seed is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::graph_voronoi;
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(5, vec![(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let voronoi = graph_voronoi(&g, &[0, 4]);
+/// assert_eq!(voronoi.regions, vec![Some(0), Some(0), Some(0), Some(4), Some(4)]);
+/// assert_eq!(voronoi.distances, vec![0.0, 1.0, 2.0, 1.0, 0.0]);
+/// assert_eq!(voronoi.boundary, vec![(2, 3, 1.0)]);
+/// ```
+pub fn graph_voronoi<G>(g: &G, seeds: &[usize]) -> GraphVoronoi
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if let Some(seed) = seeds.iter().find(|s| **s >= n_nodes) {
+        panic!(
+            "[algorithm::graph_voronoi] Error: The node {} is not valid!",
+            seed
+        );
+    }
+    let nearest = multi_source_dijkstra(g, seeds);
+    let regions = nearest.nearest;
+    let boundary = g
+        .get_edges()
+        .into_iter()
+        .filter(|(node1, node2, _)| regions[*node1] != regions[*node2])
+        .collect();
+    GraphVoronoi {
+        regions,
+        distances: nearest.distances,
+        boundary,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DGraph, UGraph};
+
+    #[test]
+    fn graph_voronoi_breaks_ties_by_seed_order() {
+        let g = UGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+        assert_eq!(graph_voronoi(&g, &[2, 0]).regions[1], Some(2));
+        assert_eq!(graph_voronoi(&g, &[0, 2]).regions[1], Some(0));
+    }
+
+    #[test]
+    fn graph_voronoi_check_directed_unreachable() {
+        let g = DGraph::from_weighted_edges(4, vec![(0, 1, 5.0), (2, 1, 1.0), (3, 2, 1.0)]);
+        let voronoi = graph_voronoi(&g, &[0, 2]);
+        assert_eq!(voronoi.regions, vec![Some(0), Some(2), Some(2), None]);
+        assert_eq!(voronoi.distances[3], f32::INFINITY);
+        assert_eq!(voronoi.boundary, vec![(0, 1, 5.0), (3, 2, 1.0)]);
+    }
+
+    #[test]
+    fn graph_voronoi_check_no_seeds() {
+        let g = UGraph::from_edges(2, vec![(0, 1)]);
+        let voronoi = graph_voronoi(&g, &[]);
+        assert_eq!(voronoi.regions, vec![None, None]);
+        assert!(voronoi.boundary.is_empty());
+    }
+}
@@ -0,0 +1,113 @@
+use crate::float::FloatOrd;
+use crate::graph::Graph;
+use crate::path;
+use std::collections::BinaryHeap;
+
+/// Returns the width and the nodes of the widest path from `src` to `dest`, the path that
+/// maximizes the minimum weight of its edges (its bottleneck), or `None` if `dest` is not
+/// reachable. With the weights as capacities it is the route with the largest bandwidth.
+/// It is a Dijkstra search that settles the nodes by decreasing width, relaxing each edge
+/// with `min(width, weight)` instead of a sum. The width of the path from a node to
+/// itself is `f32::INFINITY`.
+///
+/// # Panics
+///
+/// * If some node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::widest_path;
+/// use graphst::UGraph;
+/// // A direct link of 10 and a detour with links of 50 and 40
+/// let g = UGraph::from_weighted_edges(3, vec![(0, 2, 10.0), (0, 1, 50.0), (1, 2, 40.0)]);
+/// assert_eq!(widest_path(&g, 0, 2), Some((40.0, vec![0, 1, 2])));
+/// ```
+pub fn widest_path<G>(g: &G, src: usize, dest: usize) -> Option<(f32, Vec<usize>)>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if let Some(node) = [src, dest].iter().find(|n| **n >= n_nodes) {
+        panic!(
+            "[algorithm::widest_path] Error: The node {} is not valid!",
+            node
+        );
+    }
+    // width: The largest bottleneck found so far to each node
+    let mut width = vec![f32::NEG_INFINITY; n_nodes];
+    let mut predecessors = vec![None; n_nodes];
+    let mut visited = vec![false; n_nodes];
+    let mut heap = BinaryHeap::new();
+    width[src] = f32::INFINITY;
+    heap.push((FloatOrd(f32::INFINITY), src));
+    // Select the widest not visited node
+    while let Some((_, current)) = heap.pop() {
+        if visited[current] {
+            continue;
+        }
+        if current == dest {
+            return Some((width[dest], path::from_predecessors(&predecessors, dest)));
+        }
+        visited[current] = true;
+        for n in g.get_out_neighbors_of(current) {
+            if let Some(weight) = g.get_edge(current, n) {
+                let new_width = width[current].min(weight);
+                if !visited[n] && new_width > width[n] {
+                    width[n] = new_width;
+                    predecessors[n] = Some(current);
+                    heap.push((FloatOrd(new_width), n));
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::bfs;
+    use crate::DGraph;
+
+    #[test]
+    fn widest_path_matches_threshold_search() {
+        let mut rng = crate::rng::Rng::new(29);
+        for _ in 0..50 {
+            let n_nodes = 2 + rng.gen_range(12);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(40) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                adj_mat[node1][node2] = 1.0 + rng.gen_range(9) as f32;
+            }
+            // The width is the largest weight that keeps `dest` reachable using only the
+            // edges at least that wide
+            let expected = (1..10).rev().map(|w| w as f32).find(|w| {
+                let wide: Vec<Vec<f32>> = adj_mat
+                    .iter()
+                    .map(|row| row.iter().map(|x| if x >= w { *x } else { 0.0 }).collect())
+                    .collect();
+                bfs(&DGraph::from_adjacency_matrix(wide), 0).hops[n_nodes - 1].is_some()
+            });
+            let g = DGraph::from_adjacency_matrix(adj_mat);
+            match widest_path(&g, 0, n_nodes - 1) {
+                Some((width, path)) => {
+                    assert_eq!(Some(width), expected);
+                    let bottleneck = path
+                        .windows(2)
+                        .map(|w| g.get_edge(w[0], w[1]).unwrap())
+                        .fold(f32::INFINITY, f32::min);
+                    assert_eq!(bottleneck, width);
+                }
+                None => assert_eq!(expected, None),
+            }
+        }
+    }
+
+    #[test]
+    fn widest_path_check_same_node_and_unreachable() {
+        let g = DGraph::from_edges(3, vec![(0, 1)]);
+        assert_eq!(widest_path(&g, 1, 1), Some((f32::INFINITY, vec![1])));
+        assert_eq!(widest_path(&g, 1, 0), None);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::graph::Graph;
+use std::collections::VecDeque;
+
+/// Given a graph (that implements `Graph`), a source node and a predicate that selects the
+/// free edges, returns the shortest path to each node from the source where the free edges
+/// cost `0` and the rest of the edges cost `1`. The nodes are kept in a deque instead of a
+/// priority queue (free edges push to the front, the rest to the back), so it runs in
+/// `O(V + E)`. The unreachable nodes get `f32::INFINITY`.
+///
+/// A weight of `0.0` means that there is no edge in the graphs of the crate, so the free
+/// edges are chosen by `is_free` from their nodes and weight (e.g. the open doors of a
+/// maze, or the roads that don't need a toll).
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+/// * `src` - The source node.
+/// * `is_free` - A closure that returns `true` for the edges `(node1, node2, weight)`
+///   with cost `0`.
+///
+/// # Panics
+///
+/// * If the source node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::zero_one_bfs;
+/// use graphst::DGraph;
+/// // The edges with weight 2.0 are free
+/// let g = DGraph::from_weighted_edges(4, vec![(0, 1, 1.0), (1, 2, 2.0), (0, 2, 1.0), (2, 3, 2.0)]);
+/// let costs = zero_one_bfs(&g, 0, |_, _, weight| weight == 2.0);
+/// assert_eq!(costs, vec![0.0, 1.0, 1.0, 1.0]);
+/// ```
+pub fn zero_one_bfs<G, F>(g: &G, src: usize, is_free: F) -> Vec<f32>
+where
+    G: Graph,
+    F: Fn(usize, usize, f32) -> bool,
+{
+    let n_nodes = g.get_n_nodes();
+    if src >= n_nodes {
+        panic!(
+            "[algorithm::zero_one_bfs] Error: The node {} is not valid!",
+            src
+        );
+    }
+    let mut dist = vec![f32::INFINITY; n_nodes];
+    let mut visited = vec![false; n_nodes];
+    let mut deque = VecDeque::new();
+    dist[src] = 0.0;
+    deque.push_back(src);
+    while let Some(current) = deque.pop_front() {
+        if visited[current] {
+            continue; // Already reached with a lower cost
+        }
+        visited[current] = true;
+        for n in g.get_out_neighbors_of(current) {
+            let weight = match g.get_edge(current, n) {
+                Some(weight) => weight,
+                None => continue,
+            };
+            let free = is_free(current, n, weight);
+            let new_dist = if free {
+                dist[current]
+            } else {
+                dist[current] + 1.0
+            };
+            if !visited[n] && new_dist < dist[n] {
+                dist[n] = new_dist;
+                if free {
+                    deque.push_front(n);
+                } else {
+                    deque.push_back(n);
+                }
+            }
+        }
+    }
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UGraph;
+
+    #[test]
+    fn zero_one_bfs_matches_relaxation() {
+        let mut rng = crate::rng::Rng::new(13);
+        for _ in 0..50 {
+            let n_nodes = 1 + rng.gen_range(12);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(30) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                let weight = 1.0 + rng.gen_range(2) as f32;
+                adj_mat[node1][node2] = weight;
+                adj_mat[node2][node1] = weight;
+            }
+            let g = UGraph::from_adjacency_matrix(adj_mat);
+            let cost = |weight: f32| if weight == 2.0 { 0.0 } else { 1.0 };
+            // Relax every edge until nothing changes
+            let src = rng.gen_range(n_nodes);
+            let mut expected = vec![f32::INFINITY; n_nodes];
+            expected[src] = 0.0;
+            let mut changed = true;
+            while changed {
+                changed = false;
+                for (node1, node2, weight) in g.get_edges() {
+                    for (a, b) in [(node1, node2), (node2, node1)] {
+                        if expected[a] + cost(weight) < expected[b] {
+                            expected[b] = expected[a] + cost(weight);
+                            changed = true;
+                        }
+                    }
+                }
+            }
+            assert_eq!(zero_one_bfs(&g, src, |_, _, w| w == 2.0), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn zero_one_bfs_panic_not_valid_src() {
+        let g = UGraph::from_edges(2, vec![(0, 1)]);
+        zero_one_bfs(&g, 2, |_, _, _| false);
+    }
+}
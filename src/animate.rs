@@ -0,0 +1,321 @@
+//! # Animate
+//!
+//! `animate` is a `graphst` module that records the steps of some algorithms as a
+//! sequence of DOT frames (see the `dot` module), to build animations for teaching. Each
+//! frame can be rendered with Graphviz (e.g. `dot -Tsvg frame_000.dot`) and the images
+//! joined into a GIF or a slideshow with external tools.
+
+use crate::algorithm::DijkstraIter;
+use crate::dot;
+use crate::graph::Graph;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const VISITED_COLOR: &str = "#a6d96a";
+const FRONTIER_COLOR: &str = "#fdae61";
+const CURRENT_COLOR: &str = "#d7191c";
+
+/// The `Animation` struct holds the DOT frames of an animation, in order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Animation {
+    frames: Vec<String>,
+}
+
+impl Animation {
+    /// Returns the DOT source of the frames.
+    pub fn get_frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// Returns the number of frames.
+    pub fn get_n_frames(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Writes each frame to the directory `dir` as `{prefix}_{index}.dot`, with the index
+    /// padded to three digits so the files sort in order. Returns the paths of the files.
+    ///
+    /// # Errors
+    ///
+    /// * If some file can't be written.
+    pub fn write_frames<P>(&self, dir: P, prefix: &str) -> io::Result<Vec<PathBuf>>
+    where
+        P: AsRef<Path>,
+    {
+        let mut paths = vec![];
+        for (idx, frame) in self.frames.iter().enumerate() {
+            let path = dir.as_ref().join(format!("{}_{:03}.dot", prefix, idx));
+            fs::write(&path, frame)?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+}
+
+/// Returns the animation of a breadth-first search from `src`: one frame per level,
+/// where the nodes of the current level (the frontier) are highlighted, the nodes of the
+/// previous levels are marked as visited, and the edges of the BFS tree are drawn in bold.
+///
+/// # Panics
+///
+/// * If the source node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::animate::bfs_frames;
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(4, vec![(0, 1), (0, 2), (2, 3)]);
+/// let animation = bfs_frames(&g, 0);
+/// assert_eq!(animation.get_n_frames(), 3); // the levels {0}, {1, 2} and {3}
+/// ```
+pub fn bfs_frames<G>(g: &G, src: usize) -> Animation
+where
+    G: Graph,
+{
+    check_node(g, src, "bfs_frames");
+    let mut states = vec![NodeState::Unvisited; g.get_n_nodes()];
+    let mut tree = vec![];
+    let mut frames = vec![];
+    let mut frontier = vec![src];
+    states[src] = NodeState::Frontier;
+    while !frontier.is_empty() {
+        frames.push(render(g, &states, &tree));
+        for node in &frontier {
+            states[*node] = NodeState::Visited;
+        }
+        let mut next = vec![];
+        for node in frontier {
+            for n in g.get_out_neighbors_of(node) {
+                if states[n] == NodeState::Unvisited {
+                    states[n] = NodeState::Frontier;
+                    tree.push((node, n));
+                    next.push(n);
+                }
+            }
+        }
+        frontier = next;
+    }
+    Animation { frames }
+}
+
+/// Returns the animation of Dijkstra's algorithm from `src`, driven by `DijkstraIter`: one
+/// frame per settled node, in the order the search settles them, where the node settled
+/// in that step is highlighted, the previously settled nodes are marked as visited, the
+/// frontier is marked with its tentative distances, the nodes are labeled with their
+/// distance, and the edges of the shortest path tree found so far are drawn in bold.
+///
+/// # Panics
+///
+/// * If the source node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::animate::dijkstra_frames;
+/// use graphst::DGraph;
+/// let g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 1.0)]);
+/// let animation = dijkstra_frames(&g, 0);
+/// assert_eq!(animation.get_n_frames(), 3);
+/// assert!(animation.get_frames()[0].contains("1 [label=\"1\\nd=2?\""));
+/// assert!(animation.get_frames()[2].contains("2 [label=\"2\\nd=3\""));
+/// ```
+pub fn dijkstra_frames<G>(g: &G, src: usize) -> Animation
+where
+    G: Graph,
+{
+    check_node(g, src, "dijkstra_frames");
+    let mut steps = DijkstraIter::new(g, src);
+    let mut states = vec![NodeState::Unvisited; g.get_n_nodes()];
+    let mut tree = vec![];
+    let mut frames = vec![];
+    while let Some(step) = steps.next() {
+        if let Some(prev) = step.predecessor {
+            tree.push((prev, step.node));
+        }
+        states[step.node] = NodeState::Current(step.distance);
+        for node in steps.get_frontier() {
+            states[node] = NodeState::Tentative(steps.get_distances()[node]);
+        }
+        frames.push(render(g, &states, &tree));
+        states[step.node] = NodeState::Settled(step.distance);
+    }
+    Animation { frames }
+}
+
+/// Returns the animation of a walk along `path`: one frame per node of the path, where
+/// the current node is highlighted and the nodes and edges already walked are marked.
+///
+/// # Panics
+///
+/// * If some node of the path is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::animate::path_frames;
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+/// let animation = path_frames(&g, &[0, 1, 2]);
+/// assert_eq!(animation.get_n_frames(), 3);
+/// assert!(animation.get_frames()[2].contains("1 -- 2 [label=\"1\", color="));
+/// ```
+pub fn path_frames<G>(g: &G, path: &[usize]) -> Animation
+where
+    G: Graph,
+{
+    for node in path {
+        check_node(g, *node, "path_frames");
+    }
+    let mut states = vec![NodeState::Unvisited; g.get_n_nodes()];
+    let mut tree = vec![];
+    let mut frames = vec![];
+    for (idx, node) in path.iter().enumerate() {
+        if idx > 0 {
+            states[path[idx - 1]] = NodeState::Visited;
+            tree.push((path[idx - 1], *node));
+        }
+        states[*node] = NodeState::Frontier;
+        frames.push(render(g, &states, &tree));
+    }
+    Animation { frames }
+}
+
+//--------- Private functions ---------
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum NodeState {
+    Unvisited,
+    Frontier,
+    Visited,
+    // Nodes of Dijkstra's algorithm, with their (tentative) distance
+    Tentative(f32),
+    Current(f32),
+    Settled(f32),
+}
+
+fn check_node<G>(g: &G, node: usize, function: &str)
+where
+    G: Graph,
+{
+    if node >= g.get_n_nodes() {
+        panic!(
+            "[animate::{}] Error: The node {} is not valid!",
+            function, node
+        );
+    }
+}
+
+// Returns the DOT frame with the nodes styled by state and the tree edges in bold
+fn render<G>(g: &G, states: &[NodeState], tree: &[(usize, usize)]) -> String
+where
+    G: Graph,
+{
+    let directed = g.is_directed();
+    let mut dot = dot::header(g);
+    for node in g.get_nodes() {
+        let style = match states[node] {
+            NodeState::Unvisited => String::new(),
+            NodeState::Frontier => format!(" [style=filled, fillcolor=\"{}\"]", FRONTIER_COLOR),
+            NodeState::Visited => format!(" [style=filled, fillcolor=\"{}\"]", VISITED_COLOR),
+            NodeState::Tentative(d) => format!(
+                " [label=\"{}\\nd={}?\", style=filled, fillcolor=\"{}\"]",
+                node, d, FRONTIER_COLOR
+            ),
+            NodeState::Current(d) => format!(
+                " [label=\"{}\\nd={}\", style=filled, fillcolor=\"{}\"]",
+                node, d, CURRENT_COLOR
+            ),
+            NodeState::Settled(d) => format!(
+                " [label=\"{}\\nd={}\", style=filled, fillcolor=\"{}\"]",
+                node, d, VISITED_COLOR
+            ),
+        };
+        writeln!(dot, "    {}{};", node, style).expect("Writing to a String can't fail");
+    }
+    dot::write_edges_with(g, &mut dot, |node1, node2| {
+        let in_tree = tree
+            .iter()
+            .any(|(a, b)| (*a, *b) == (node1, node2) || (!directed && (*b, *a) == (node1, node2)));
+        if in_tree {
+            format!(", color=\"{}\", penwidth=3", CURRENT_COLOR)
+        } else {
+            String::new()
+        }
+    });
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::DijkstraStep;
+    use crate::{DGraph, UGraph};
+
+    #[test]
+    fn bfs_frames_check_frontier_and_tree() {
+        let g = DGraph::from_edges(3, vec![(0, 1), (1, 2), (2, 0)]);
+        let animation = bfs_frames(&g, 0);
+        assert_eq!(animation.get_n_frames(), 3);
+        let frame = &animation.get_frames()[1];
+        assert!(frame.contains(&format!(
+            "    0 [style=filled, fillcolor=\"{}\"];",
+            VISITED_COLOR
+        )));
+        assert!(frame.contains(&format!(
+            "    1 [style=filled, fillcolor=\"{}\"];",
+            FRONTIER_COLOR
+        )));
+        assert!(frame.contains("    2;"));
+        assert!(frame.contains("0 -> 1 [label=\"1\", color="));
+        assert!(frame.contains("    2 -> 0 [label=\"1\"];"));
+    }
+
+    #[test]
+    fn dijkstra_frames_skip_unreachable_nodes() {
+        let g = UGraph::from_weighted_edges(4, vec![(0, 1, 1.0), (2, 3, 1.0)]);
+        let animation = dijkstra_frames(&g, 1);
+        assert_eq!(animation.get_n_frames(), 2);
+        assert!(animation.get_frames()[1].contains("0 [label=\"0\\nd=1\""));
+    }
+
+    #[test]
+    fn dijkstra_frames_follow_settle_order_and_frontier() {
+        // The nodes 1 and 2 tie at distance 1: the frames follow the order of the search
+        let g = DGraph::from_edges(4, vec![(0, 2), (0, 1), (2, 3)]);
+        let steps: Vec<DijkstraStep> = DijkstraIter::new(&g, 0).collect();
+        let animation = dijkstra_frames(&g, 0);
+        assert_eq!(animation.get_n_frames(), steps.len());
+        for (frame, step) in animation.get_frames().iter().zip(steps) {
+            assert!(frame.contains(&format!(
+                "    {} [label=\"{}\\nd={}\", style=filled, fillcolor=\"{}\"];",
+                step.node, step.node, step.distance, CURRENT_COLOR
+            )));
+        }
+        let first = &animation.get_frames()[0];
+        assert!(first.contains(&format!(
+            "    1 [label=\"1\\nd=1?\", style=filled, fillcolor=\"{}\"];",
+            FRONTIER_COLOR
+        )));
+        assert!(first.contains("    3;"));
+    }
+
+    #[test]
+    fn write_frames_check_file_names() {
+        let dir = std::env::temp_dir().join("graphst_animate_test");
+        fs::create_dir_all(&dir).unwrap();
+        let g = UGraph::from_edges(2, vec![(0, 1)]);
+        let paths = path_frames(&g, &[1, 0]).write_frames(&dir, "walk").unwrap();
+        assert_eq!(
+            paths,
+            vec![dir.join("walk_000.dot"), dir.join("walk_001.dot")]
+        );
+        assert!(fs::read_to_string(&paths[1])
+            .unwrap()
+            .starts_with("graph {"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,437 @@
+//! # Backend
+//!
+//! `backend` is a `graphst` module with the `DenseBackend` trait, the extension point for
+//! the heavy computations over dense matrices (all-pairs shortest paths, matrix powers).
+//! The crate provides the `CpuBackend` implementation, and the functions that accept a
+//! backend, like `algorithm::floyd_warshall_with`, run on any implementation of the trait.
+//!
+//! With the `gpu` feature enabled the module also provides the `GpuBackend`, which runs
+//! the computations as `wgpu` compute shaders (Vulkan, Metal, DX12 or WebGPU). It is the
+//! only part of the crate with dependencies, so it is disabled by default.
+
+/// The `DenseBackend` trait defines the dense matrix computations that can be offloaded.
+/// The matrices are squared and stored by rows.
+pub trait DenseBackend {
+    /// Runs the Floyd-Warshall relaxation in place over the distance matrix `dist`, where
+    /// `f32::INFINITY` means that there is no path. After the call `dist[i][j]` holds the
+    /// length of the shortest path from `i` to `j`.
+    fn floyd_warshall(&self, dist: &mut [Vec<f32>]);
+
+    /// Returns the product of the matrices `a` and `b`.
+    fn mat_mul(&self, a: &[Vec<f32>], b: &[Vec<f32>]) -> Vec<Vec<f32>>;
+
+    /// Returns the matrix `a` raised to the power `k`, computed by repeated squaring
+    /// with `mat_mul`. The power `0` is the identity matrix.
+    fn mat_pow(&self, a: &[Vec<f32>], k: u32) -> Vec<Vec<f32>> {
+        let n = a.len();
+        let mut result: Vec<Vec<f32>> = (0..n)
+            .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+            .collect();
+        let mut base = a.to_vec();
+        let mut k = k;
+        while k > 0 {
+            if k & 1 == 1 {
+                result = self.mat_mul(&result, &base);
+            }
+            k >>= 1;
+            if k > 0 {
+                base = self.mat_mul(&base, &base);
+            }
+        }
+        result
+    }
+}
+
+/// The `CpuBackend` struct implements `DenseBackend` with plain loops on the CPU.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::backend::{CpuBackend, DenseBackend};
+/// let a = vec![vec![1.0, 1.0], vec![1.0, 0.0]];
+/// let fib = CpuBackend.mat_pow(&a, 10);
+/// assert_eq!(fib[0][1], 55.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CpuBackend;
+
+impl DenseBackend for CpuBackend {
+    fn floyd_warshall(&self, dist: &mut [Vec<f32>]) {
+        for k in 0..dist.len() {
+            let row_k = dist[k].clone();
+            for row_i in dist.iter_mut() {
+                let dist_ik = row_i[k];
+                if dist_ik == f32::INFINITY {
+                    continue; // no path through k from i
+                }
+                for (dist_ij, dist_kj) in row_i.iter_mut().zip(&row_k) {
+                    if dist_ik + dist_kj < *dist_ij {
+                        *dist_ij = dist_ik + dist_kj;
+                    }
+                }
+            }
+        }
+    }
+
+    fn mat_mul(&self, a: &[Vec<f32>], b: &[Vec<f32>]) -> Vec<Vec<f32>> {
+        let n = a.len();
+        if b.len() != n || a.iter().chain(b).any(|row| row.len() != n) {
+            panic!("[CpuBackend::mat_mul] Error: The matrices are not squared of the same size!");
+        }
+        let mut result = vec![vec![0.0; n]; n];
+        for (row_a, row_result) in a.iter().zip(result.iter_mut()) {
+            for (a_ik, row_b) in row_a.iter().zip(b) {
+                if *a_ik == 0.0 {
+                    continue;
+                }
+                for (r_ij, b_kj) in row_result.iter_mut().zip(row_b) {
+                    *r_ij += a_ik * b_kj;
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(feature = "gpu")]
+pub use gpu::GpuBackend;
+
+#[cfg(feature = "gpu")]
+mod gpu {
+    use super::DenseBackend;
+    use wgpu::util::DeviceExt;
+
+    const WORKGROUP_SIZE: u32 = 8;
+
+    // Relaxation of every pair (i, j) through the node k. The infinities are uploaded as
+    // the INF sentinel because the shaders may assume finite math.
+    const FLOYD_WARSHALL_SHADER: &str = "
+        const INF: f32 = 3.0e38;
+        struct Params { n: u32, k: u32 }
+        @group(0) @binding(0) var<storage, read_write> dist: array<f32>;
+        @group(0) @binding(1) var<uniform> params: Params;
+
+        @compute @workgroup_size(8, 8)
+        fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+            let n = params.n;
+            let k = params.k;
+            if (id.x >= n || id.y >= n) {
+                return;
+            }
+            let dist_ik = dist[id.y * n + k];
+            let dist_kj = dist[k * n + id.x];
+            if (dist_ik >= INF || dist_kj >= INF) {
+                return;
+            }
+            let idx = id.y * n + id.x;
+            dist[idx] = min(dist[idx], dist_ik + dist_kj);
+        }
+    ";
+
+    const MAT_MUL_SHADER: &str = "
+        struct Params { n: u32, k: u32 }
+        @group(0) @binding(0) var<storage, read> a: array<f32>;
+        @group(0) @binding(1) var<storage, read> b: array<f32>;
+        @group(0) @binding(2) var<storage, read_write> result: array<f32>;
+        @group(0) @binding(3) var<uniform> params: Params;
+
+        @compute @workgroup_size(8, 8)
+        fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+            let n = params.n;
+            if (id.x >= n || id.y >= n) {
+                return;
+            }
+            var sum = 0.0;
+            for (var k = 0u; k < n; k++) {
+                sum += a[id.y * n + k] * b[k * n + id.x];
+            }
+            result[id.y * n + id.x] = sum;
+        }
+    ";
+
+    // Infinity sentinel of the Floyd-Warshall shader
+    const INF: f32 = 3.0e38;
+
+    /// The `GpuBackend` struct implements `DenseBackend` with `wgpu` compute shaders. The
+    /// matrices are copied to the GPU for each call, so it pays off for big matrices,
+    /// where the O(n^3) work dominates the transfers. The size of the matrices is limited
+    /// by the maximum storage buffer size of the device.
+    ///
+    /// Only available with the `gpu` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use graphst::backend::{DenseBackend, GpuBackend};
+    /// let backend = GpuBackend::new().expect("No GPU adapter available");
+    /// let a = vec![vec![1.0, 1.0], vec![1.0, 0.0]];
+    /// assert_eq!(backend.mat_pow(&a, 10)[0][1], 55.0);
+    /// ```
+    pub struct GpuBackend {
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        floyd_warshall: wgpu::ComputePipeline,
+        mat_mul: wgpu::ComputePipeline,
+    }
+
+    impl GpuBackend {
+        /// Returns a backend on the default GPU adapter of the system, or `None` if there
+        /// is no adapter or the device can't be opened.
+        pub fn new() -> Option<GpuBackend> {
+            let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+            let adapter =
+                pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+                    power_preference: wgpu::PowerPreference::HighPerformance,
+                    ..Default::default()
+                }))?;
+            let (device, queue) = pollster::block_on(adapter.request_device(
+                &wgpu::DeviceDescriptor {
+                    label: Some("graphst"),
+                    required_features: wgpu::Features::empty(),
+                    required_limits: adapter.limits(),
+                },
+                None,
+            ))
+            .ok()?;
+            let floyd_warshall = pipeline(&device, "floyd_warshall", FLOYD_WARSHALL_SHADER);
+            let mat_mul = pipeline(&device, "mat_mul", MAT_MUL_SHADER);
+            Some(GpuBackend {
+                device,
+                queue,
+                floyd_warshall,
+                mat_mul,
+            })
+        }
+
+        //--------- Private functions ---------
+
+        fn storage(&self, values: &[f32], usage: wgpu::BufferUsages) -> wgpu::Buffer {
+            let contents: Vec<u8> = values.iter().flat_map(|v| v.to_ne_bytes()).collect();
+            self.device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: None,
+                    contents: &contents,
+                    usage: wgpu::BufferUsages::STORAGE | usage,
+                })
+        }
+
+        fn params(&self, n: usize, k: usize) -> wgpu::Buffer {
+            let contents: Vec<u8> = [n as u32, k as u32]
+                .iter()
+                .flat_map(|v| v.to_ne_bytes())
+                .collect();
+            self.device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: None,
+                    contents: &contents,
+                    usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                })
+        }
+
+        fn bind_group(
+            &self,
+            pipeline: &wgpu::ComputePipeline,
+            buffers: &[&wgpu::Buffer],
+        ) -> wgpu::BindGroup {
+            let entries: Vec<wgpu::BindGroupEntry> = buffers
+                .iter()
+                .enumerate()
+                .map(|(binding, buffer)| wgpu::BindGroupEntry {
+                    binding: binding as u32,
+                    resource: buffer.as_entire_binding(),
+                })
+                .collect();
+            self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: None,
+                layout: &pipeline.get_bind_group_layout(0),
+                entries: &entries,
+            })
+        }
+
+        fn dispatch(
+            &self,
+            pipeline: &wgpu::ComputePipeline,
+            bind_group: &wgpu::BindGroup,
+            n: usize,
+            copy: Option<(&wgpu::Buffer, &wgpu::Buffer)>,
+        ) {
+            let groups = (n as u32).div_ceil(WORKGROUP_SIZE);
+            let mut encoder = self
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+            {
+                let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                    label: None,
+                    timestamp_writes: None,
+                });
+                pass.set_pipeline(pipeline);
+                pass.set_bind_group(0, bind_group, &[]);
+                pass.dispatch_workgroups(groups, groups, 1);
+            }
+            if let Some((src, dest)) = copy {
+                encoder.copy_buffer_to_buffer(src, 0, dest, 0, src.size());
+            }
+            self.queue.submit(Some(encoder.finish()));
+        }
+
+        // Reads back a buffer of n x n values copied to the mappable `staging` buffer
+        fn read(&self, staging: &wgpu::Buffer, n: usize) -> Vec<Vec<f32>> {
+            let slice = staging.slice(..);
+            slice.map_async(wgpu::MapMode::Read, |_| ());
+            self.device.poll(wgpu::Maintain::Wait);
+            let values: Vec<f32> = slice
+                .get_mapped_range()
+                .chunks_exact(4)
+                .map(|bytes| f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+                .collect();
+            staging.unmap();
+            values.chunks(n.max(1)).map(|row| row.to_vec()).collect()
+        }
+
+        fn staging(&self, n: usize) -> wgpu::Buffer {
+            self.device.create_buffer(&wgpu::BufferDescriptor {
+                label: None,
+                size: (n * n * 4) as u64,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            })
+        }
+    }
+
+    impl DenseBackend for GpuBackend {
+        fn floyd_warshall(&self, dist: &mut [Vec<f32>]) {
+            let n = dist.len();
+            if n == 0 {
+                return;
+            }
+            let values: Vec<f32> = dist
+                .iter()
+                .flatten()
+                .map(|d| if *d == f32::INFINITY { INF } else { *d })
+                .collect();
+            let buffer = self.storage(&values, wgpu::BufferUsages::COPY_SRC);
+            let params = self.params(n, 0);
+            let bind_group = self.bind_group(&self.floyd_warshall, &[&buffer, &params]);
+            let staging = self.staging(n);
+            for k in 0..n {
+                // The write is staged before the next submission, so each pass sees its k
+                let contents: Vec<u8> = [n as u32, k as u32]
+                    .iter()
+                    .flat_map(|v| v.to_ne_bytes())
+                    .collect();
+                self.queue.write_buffer(&params, 0, &contents);
+                let copy = if k == n - 1 {
+                    Some((&buffer, &staging))
+                } else {
+                    None
+                };
+                self.dispatch(&self.floyd_warshall, &bind_group, n, copy);
+            }
+            for (row, result) in dist.iter_mut().zip(self.read(&staging, n)) {
+                for (d, r) in row.iter_mut().zip(result) {
+                    *d = if r >= INF { f32::INFINITY } else { r };
+                }
+            }
+        }
+
+        fn mat_mul(&self, a: &[Vec<f32>], b: &[Vec<f32>]) -> Vec<Vec<f32>> {
+            let n = a.len();
+            if b.len() != n || a.iter().chain(b).any(|row| row.len() != n) {
+                panic!(
+                    "[GpuBackend::mat_mul] Error: The matrices are not squared of the same size!"
+                );
+            }
+            if n == 0 {
+                return Vec::new();
+            }
+            let a: Vec<f32> = a.iter().flatten().copied().collect();
+            let b: Vec<f32> = b.iter().flatten().copied().collect();
+            let a = self.storage(&a, wgpu::BufferUsages::empty());
+            let b = self.storage(&b, wgpu::BufferUsages::empty());
+            let result = self.storage(&vec![0.0; n * n], wgpu::BufferUsages::COPY_SRC);
+            let params = self.params(n, 0);
+            let bind_group = self.bind_group(&self.mat_mul, &[&a, &b, &result, &params]);
+            let staging = self.staging(n);
+            self.dispatch(&self.mat_mul, &bind_group, n, Some((&result, &staging)));
+            self.read(&staging, n)
+        }
+    }
+
+    fn pipeline(device: &wgpu::Device, label: &str, shader: &str) -> wgpu::ComputePipeline {
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some(label),
+            source: wgpu::ShaderSource::Wgsl(shader.into()),
+        });
+        device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some(label),
+            layout: None,
+            module: &module,
+            entry_point: "main",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mat_mul_check_values() {
+        let a = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+        let b = vec![vec![0.0, 1.0], vec![1.0, 0.0]];
+        assert_eq!(
+            CpuBackend.mat_mul(&a, &b),
+            vec![vec![2.0, 1.0], vec![4.0, 3.0]]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "not squared")]
+    fn mat_mul_panic_not_squared() {
+        CpuBackend.mat_mul(&[vec![1.0, 2.0]], &[vec![1.0]]);
+    }
+
+    #[test]
+    fn mat_pow_check_identity_and_walks() {
+        // Path 0-1-2: counts the walks of each length between the nodes
+        let a = vec![
+            vec![0.0, 1.0, 0.0],
+            vec![1.0, 0.0, 1.0],
+            vec![0.0, 1.0, 0.0],
+        ];
+        assert_eq!(CpuBackend.mat_pow(&a, 0)[1], vec![0.0, 1.0, 0.0]);
+        assert_eq!(CpuBackend.mat_pow(&a, 2)[0], vec![1.0, 0.0, 1.0]);
+        assert_eq!(CpuBackend.mat_pow(&a, 3)[1], vec![2.0, 0.0, 2.0]);
+    }
+
+    #[cfg(feature = "gpu")]
+    #[test]
+    fn gpu_backend_matches_cpu_backend() {
+        // Skipped in the machines without a GPU adapter
+        let gpu = match GpuBackend::new() {
+            Some(gpu) => gpu,
+            None => return,
+        };
+        let mut rng = crate::rng::Rng::new(963);
+        let n = 21;
+        let a: Vec<Vec<f32>> = (0..n)
+            .map(|_| (0..n).map(|_| rng.gen_range(4) as f32).collect())
+            .collect();
+        assert_eq!(gpu.mat_mul(&a, &a), CpuBackend.mat_mul(&a, &a));
+        let mut dist: Vec<Vec<f32>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| match (i == j, rng.gen_range(3)) {
+                        (true, _) => 0.0,
+                        (false, 0) => f32::INFINITY,
+                        (false, w) => w as f32,
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut expected = dist.clone();
+        gpu.floyd_warshall(&mut dist);
+        CpuBackend.floyd_warshall(&mut expected);
+        assert_eq!(dist, expected);
+    }
+}
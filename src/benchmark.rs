@@ -0,0 +1,134 @@
+//! # Benchmark
+//!
+//! `benchmark` is a `graphst` module with Graph500-style kernels to measure the
+//! performance of the crate with standard inputs (see `generate::kronecker`).
+
+use crate::graph::Graph;
+use crate::rng::Rng;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// The `BfsBenchmark` struct holds the results of `bfs_teps`. TEPS are the traversed
+/// edges per second, the metric reported by the Graph500 benchmark.
+#[derive(Clone, Debug)]
+pub struct BfsBenchmark {
+    /// The roots of the searches.
+    pub roots: Vec<usize>,
+    /// The time spent in each search.
+    pub times: Vec<Duration>,
+    /// The TEPS of each search.
+    pub teps: Vec<f64>,
+    /// The harmonic mean of the TEPS, the value that Graph500 reports.
+    pub harmonic_mean_teps: f64,
+}
+
+// Runs a BFS from `root` and returns the parent of each node (`None` if not reached)
+fn bfs_parents<G>(g: &G, root: usize) -> Vec<Option<usize>>
+where
+    G: Graph,
+{
+    let mut parents = vec![None; g.get_n_nodes()];
+    let mut queue = VecDeque::new();
+    parents[root] = Some(root);
+    queue.push_back(root);
+    while let Some(node) = queue.pop_front() {
+        for n in g.get_out_neighbors_of(node) {
+            if parents[n].is_none() {
+                parents[n] = Some(node);
+                queue.push_back(n);
+            }
+        }
+    }
+    parents
+}
+
+/// Runs the BFS kernel from `n_roots` random nodes with at least one edge and returns
+/// the time and TEPS of each search. The traversed edges of a search are the edges of
+/// the component of its root.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+/// * `n_roots` - The number of searches to run (Graph500 uses 64).
+/// * `seed` - The seed used to select the roots.
+///
+/// # Panics
+///
+/// * If the graph has no edges.
+///
+/// # Examples
+///
+/// ```
+/// let g = graphst::generate::kronecker(8, 16, 1);
+/// let result = graphst::benchmark::bfs_teps(&g, 4, 1);
+/// assert_eq!(result.teps.len(), 4);
+/// assert!(result.harmonic_mean_teps > 0.0);
+/// ```
+pub fn bfs_teps<G>(g: &G, n_roots: usize, seed: u64) -> BfsBenchmark
+where
+    G: Graph,
+{
+    let candidates: Vec<usize> = g
+        .get_nodes()
+        .into_iter()
+        .filter(|n| !g.get_out_neighbors_of(*n).is_empty())
+        .collect();
+    if candidates.is_empty() {
+        panic!("[benchmark::bfs_teps] Error: The graph has no edges!");
+    }
+    let mut rng = Rng::new(seed);
+    let roots: Vec<usize> = (0..n_roots)
+        .map(|_| candidates[rng.gen_range(candidates.len())])
+        .collect();
+
+    let mut times = vec![];
+    let mut teps = vec![];
+    for root in &roots {
+        let start = Instant::now();
+        let parents = bfs_parents(g, *root);
+        let elapsed = start.elapsed();
+        // Count the edges of the component (each undirected edge is seen twice)
+        let mut n_edges: usize = g
+            .get_nodes()
+            .into_iter()
+            .filter(|n| parents[*n].is_some())
+            .map(|n| g.get_out_neighbors_of(n).len())
+            .sum();
+        if !g.is_directed() {
+            n_edges /= 2;
+        }
+        times.push(elapsed);
+        teps.push(n_edges as f64 / elapsed.as_secs_f64().max(f64::MIN_POSITIVE));
+    }
+    let harmonic_mean_teps = if teps.is_empty() {
+        0.0
+    } else {
+        teps.len() as f64 / teps.iter().map(|t| 1.0 / t).sum::<f64>()
+    };
+    BfsBenchmark {
+        roots,
+        times,
+        teps,
+        harmonic_mean_teps,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UGraph;
+
+    #[test]
+    fn bfs_parents_check_tree() {
+        let g = UGraph::from_edges(5, vec![(0, 1), (1, 2), (0, 3)]);
+        let parents = bfs_parents(&g, 0);
+        assert_eq!(parents, vec![Some(0), Some(0), Some(1), Some(0), None]);
+    }
+
+    #[test]
+    #[should_panic(expected = "no edges")]
+    fn bfs_teps_panic_no_edges() {
+        let g = UGraph::from_adjacency_matrix(vec![vec![0.0; 3]; 3]);
+        bfs_teps(&g, 1, 0);
+    }
+}
@@ -0,0 +1,297 @@
+//! # Centrality
+//!
+//! `centrality` is a `graphst` module to compute several centrality measures of the nodes
+//! of a graph in a single pass. The measures based on shortest paths (closeness, harmonic
+//! and betweenness) share one Dijkstra search from each node, so requesting them together
+//! costs about the same as requesting one of them. The weights of the edges are used as
+//! distances, so they must be non-negative.
+
+use crate::float::{self, FloatOrd};
+use crate::graph::Graph;
+use crate::nx;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The `Centrality` enum lists the measures supported by `compute`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Centrality {
+    /// The degree of the node divided by `n - 1`, like `nx::degree_centrality`.
+    Degree,
+    /// The inverse of the average distance from the nodes that reach the node, scaled by
+    /// the fraction of nodes that reach it (Wasserman and Faust). Unreachable nodes have
+    /// `0.0`.
+    Closeness,
+    /// The sum of the inverse distances from the other nodes to the node, where the
+    /// unreachable nodes add `0.0`.
+    Harmonic,
+    /// The fraction of the shortest paths between pairs of other nodes that pass through
+    /// the node (Brandes' algorithm), normalized by the number of pairs.
+    Betweenness,
+}
+
+/// The `CentralityTable` struct holds the values computed by `compute`: a row per node
+/// with a value per requested measure, in the order of the request.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CentralityTable {
+    measures: Vec<Centrality>,
+    rows: Vec<Vec<f32>>,
+}
+
+impl CentralityTable {
+    /// Returns the measures of the table, in the order of its columns.
+    pub fn get_measures(&self) -> &[Centrality] {
+        &self.measures
+    }
+
+    /// Returns the value of each measure for the node, in the order of `get_measures`.
+    ///
+    /// # Panics
+    ///
+    /// * If the node is not valid.
+    pub fn get_row(&self, node: usize) -> &[f32] {
+        if node >= self.rows.len() {
+            panic!(
+                "[CentralityTable::get_row] Error: The node {} is not valid!",
+                node
+            );
+        }
+        &self.rows[node]
+    }
+
+    /// Returns the value of the measure for the node, or `None` if the measure was not
+    /// requested.
+    ///
+    /// # Panics
+    ///
+    /// * If the node is not valid.
+    pub fn get(&self, node: usize, measure: Centrality) -> Option<f32> {
+        let idx = self.measures.iter().position(|m| *m == measure)?;
+        Some(self.get_row(node)[idx])
+    }
+
+    /// Returns the value of the measure for each node, indexed by node (ready to build a
+    /// `ranking::Ranking`), or `None` if the measure was not requested.
+    pub fn get_column(&self, measure: Centrality) -> Option<Vec<f32>> {
+        let idx = self.measures.iter().position(|m| *m == measure)?;
+        Some(self.rows.iter().map(|row| row[idx]).collect())
+    }
+}
+
+/// Returns the table with the requested centrality measures of each node. The shortest
+/// paths from every node are computed once, and only if some requested measure needs
+/// them. The repeated measures get repeated columns.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::centrality::{compute, Centrality};
+/// use graphst::UGraph;
+/// // A path 0 - 1 - 2
+/// let g = UGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+/// let measures = [Centrality::Degree, Centrality::Harmonic, Centrality::Betweenness];
+/// let table = compute(&g, &measures);
+/// assert_eq!(table.get_row(1), &[1.0, 2.0, 1.0]);
+/// assert_eq!(table.get(0, Centrality::Harmonic), Some(1.5));
+/// assert_eq!(table.get_column(Centrality::Betweenness), Some(vec![0.0, 1.0, 0.0]));
+/// assert_eq!(table.get(0, Centrality::Closeness), None);
+/// ```
+pub fn compute<G>(g: &G, measures: &[Centrality]) -> CentralityTable
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    let needs_paths = measures.iter().any(|m| *m != Centrality::Degree);
+    let paths = if needs_paths {
+        shortest_path_measures(g)
+    } else {
+        PathMeasures::default()
+    };
+    let degree = if measures.contains(&Centrality::Degree) {
+        nx::degree_centrality(g)
+    } else {
+        vec![]
+    };
+    let rows = (0..n_nodes)
+        .map(|node| {
+            measures
+                .iter()
+                .map(|measure| match measure {
+                    Centrality::Degree => degree[node],
+                    Centrality::Closeness => paths.closeness[node],
+                    Centrality::Harmonic => paths.harmonic[node],
+                    Centrality::Betweenness => paths.betweenness[node],
+                })
+                .collect()
+        })
+        .collect();
+    CentralityTable {
+        measures: measures.to_vec(),
+        rows,
+    }
+}
+
+//--------- Private functions ---------
+
+#[derive(Default)]
+struct PathMeasures {
+    closeness: Vec<f32>,
+    harmonic: Vec<f32>,
+    betweenness: Vec<f32>,
+}
+
+// Runs Brandes' algorithm from every node, accumulating the distances to each node for
+// closeness and harmonic on the way
+fn shortest_path_measures<G>(g: &G) -> PathMeasures
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    // Sum of the distances from the nodes that reach each node, and how many they are
+    let mut dist_sums = vec![0.0; n_nodes];
+    let mut n_reaching = vec![0; n_nodes];
+    let mut harmonic = vec![0.0; n_nodes];
+    let mut betweenness = vec![0.0; n_nodes];
+    for src in 0..n_nodes {
+        let mut dist = vec![f32::INFINITY; n_nodes];
+        // sigma: Number of shortest paths from the source to each node
+        let mut sigma = vec![0.0f64; n_nodes];
+        let mut predecessors: Vec<Vec<usize>> = vec![vec![]; n_nodes];
+        let mut settled = vec![false; n_nodes];
+        let mut order = vec![];
+        let mut heap = BinaryHeap::new();
+        dist[src] = 0.0;
+        sigma[src] = 1.0;
+        heap.push(Reverse((FloatOrd(0.0), src)));
+        while let Some(Reverse((_, current))) = heap.pop() {
+            if settled[current] {
+                continue;
+            }
+            settled[current] = true;
+            order.push(current);
+            for n in g.get_out_neighbors_of(current) {
+                if let Some(weight) = g.get_edge(current, n) {
+                    let new_dist = dist[current] + weight;
+                    if settled[n] {
+                        continue;
+                    }
+                    if float::greater_than(dist[n], new_dist) {
+                        dist[n] = new_dist;
+                        sigma[n] = sigma[current];
+                        predecessors[n] = vec![current];
+                        heap.push(Reverse((FloatOrd(new_dist), n)));
+                    } else if float::approx_eq(dist[n], new_dist) {
+                        sigma[n] += sigma[current];
+                        predecessors[n].push(current);
+                    }
+                }
+            }
+        }
+        // The nodes are settled by increasing distance, so the dependencies are
+        // accumulated backwards
+        let mut delta = vec![0.0f64; n_nodes];
+        for node in order.iter().rev() {
+            for p in &predecessors[*node] {
+                delta[*p] += sigma[*p] / sigma[*node] * (1.0 + delta[*node]);
+            }
+            if *node != src {
+                betweenness[*node] += delta[*node];
+                dist_sums[*node] += dist[*node];
+                n_reaching[*node] += 1;
+                if dist[*node] > 0.0 {
+                    harmonic[*node] += 1.0 / dist[*node];
+                }
+            }
+        }
+    }
+    let closeness = (0..n_nodes)
+        .map(|node| {
+            if dist_sums[node] > 0.0 {
+                let reaching = n_reaching[node] as f32;
+                reaching / dist_sums[node] * reaching / (n_nodes - 1) as f32
+            } else {
+                0.0
+            }
+        })
+        .collect();
+    // Every pair of other nodes is counted in both directions, in undirected graphs from
+    // both ends of the path
+    let scale = if n_nodes > 2 {
+        1.0 / ((n_nodes - 1) * (n_nodes - 2)) as f64
+    } else {
+        0.0
+    };
+    PathMeasures {
+        closeness,
+        harmonic,
+        betweenness: betweenness
+            .into_iter()
+            .map(|b| (b * scale) as f32)
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::floyd_warshall;
+    use crate::DGraph;
+
+    #[test]
+    fn compute_matches_floyd_warshall_distances() {
+        let mut rng = crate::rng::Rng::new(21);
+        for _ in 0..20 {
+            let n_nodes = 2 + rng.gen_range(10);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(30) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                if node1 != node2 {
+                    adj_mat[node1][node2] = 1.0 + rng.gen_range(4) as f32;
+                }
+            }
+            let g = DGraph::from_adjacency_matrix(adj_mat);
+            let dist = floyd_warshall(&g);
+            let table = compute(&g, &[Centrality::Harmonic, Centrality::Closeness]);
+            for node in g.get_nodes() {
+                let reaching: Vec<f32> = (0..n_nodes)
+                    .filter(|n| *n != node && dist[*n][node].is_finite())
+                    .map(|n| dist[n][node])
+                    .collect();
+                let harmonic: f32 = reaching.iter().map(|d| 1.0 / d).sum();
+                let closeness = if reaching.is_empty() {
+                    0.0
+                } else {
+                    let r = reaching.len() as f32;
+                    r / reaching.iter().sum::<f32>() * r / (n_nodes - 1) as f32
+                };
+                assert!(float::approx_eq(table.get_row(node)[0], harmonic));
+                assert!(float::approx_eq(table.get_row(node)[1], closeness));
+            }
+        }
+    }
+
+    #[test]
+    fn betweenness_check_values() {
+        // Two shortest paths from 0 to 3, through 1 and through 2
+        let g = DGraph::from_edges(5, vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+        let betweenness = compute(&g, &[Centrality::Betweenness])
+            .get_column(Centrality::Betweenness)
+            .unwrap();
+        // Node 1 is in half of the paths 0 -> 3 and 0 -> 4, node 3 in 0, 1, 2 -> 4
+        let expected: Vec<f32> = vec![0.0, 1.0, 1.0, 3.0, 0.0]
+            .into_iter()
+            .map(|b| b / 12.0)
+            .collect();
+        assert_eq!(betweenness, expected);
+        let table = compute(&DGraph::from_edges(2, vec![]), &[Centrality::Betweenness]);
+        assert_eq!(
+            table.get_column(Centrality::Betweenness),
+            Some(vec![0.0, 0.0])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn get_row_panic_not_valid_node() {
+        compute(&DGraph::from_edges(2, vec![]), &[Centrality::Degree]).get_row(2);
+    }
+}
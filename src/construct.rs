@@ -0,0 +1,173 @@
+//! # Construct
+//!
+//! `construct` is a `graphst` module to build the graphs that model a structure of the
+//! input data, like the conflicts between the time intervals of a schedule.
+
+use crate::float::FloatOrd;
+use crate::{Graph, UGraph};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Returns the intersection graph of the intervals: the node `i` is the interval
+/// `intervals[i]` and two nodes are connected when their intervals overlap. The intervals
+/// are half-open `[start, end)`, so an interval that ends when another one starts doesn't
+/// overlap it (back-to-back meetings don't conflict).
+///
+/// # Arguments
+///
+/// * `intervals` - The `(start, end)` pairs of the intervals.
+///
+/// # Panics
+///
+/// * If some interval ends before it starts or has a NaN bound.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::construct::interval_graph;
+/// use graphst::Graph;
+/// let g = interval_graph(&[(0.0, 2.0), (1.0, 3.0), (2.0, 4.0)]);
+/// assert_eq!(g.get_edges(), vec![(0, 1, 1.0), (1, 2, 1.0)]);
+/// ```
+pub fn interval_graph(intervals: &[(f32, f32)]) -> UGraph {
+    check_intervals(intervals, "interval_graph");
+    let n_nodes = intervals.len();
+    let mut g = UGraph::from_adjacency_matrix(vec![vec![0.0; n_nodes]; n_nodes]);
+    // Sweep the intervals by start, keeping the ones that are still open
+    let mut open: Vec<usize> = vec![];
+    for idx in sorted_by_start(intervals) {
+        let (start, end) = intervals[idx];
+        open.retain(|other| intervals[*other].1 > start);
+        if start < end {
+            for other in &open {
+                g.add_edge(idx, *other);
+            }
+            open.push(idx);
+        }
+    }
+    g
+}
+
+/// Returns an optimal coloring of the interval graph of the intervals (see
+/// `interval_graph`): the color of each interval, such that the overlapping intervals
+/// get different colors. The number of colors is the maximum number of intervals that
+/// overlap at the same time, so it is the minimum number of rooms (or machines) to hold
+/// all the intervals. The intervals are processed by start time, and each one takes the
+/// lowest color released by the intervals that already ended.
+///
+/// # Arguments
+///
+/// * `intervals` - The `(start, end)` pairs of the intervals.
+///
+/// # Panics
+///
+/// * If some interval ends before it starts or has a NaN bound.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::construct::interval_coloring;
+/// let meetings = [(9.0, 10.5), (9.5, 11.0), (10.5, 12.0), (11.0, 12.0)];
+/// assert_eq!(interval_coloring(&meetings), vec![0, 1, 0, 1]); // 2 rooms
+/// ```
+pub fn interval_coloring(intervals: &[(f32, f32)]) -> Vec<usize> {
+    check_intervals(intervals, "interval_coloring");
+    let mut colors = vec![0; intervals.len()];
+    // busy: (end, color) of the open intervals, free: the released colors
+    let mut busy: BinaryHeap<Reverse<(FloatOrd, usize)>> = BinaryHeap::new();
+    let mut free: BinaryHeap<Reverse<usize>> = BinaryHeap::new();
+    let mut n_colors = 0;
+    for idx in sorted_by_start(intervals) {
+        let (start, end) = intervals[idx];
+        if start == end {
+            continue; // empty intervals don't overlap anything, they keep the color 0
+        }
+        while let Some(Reverse((FloatOrd(busy_end), color))) = busy.peek().copied() {
+            if busy_end > start {
+                break;
+            }
+            busy.pop();
+            free.push(Reverse(color));
+        }
+        colors[idx] = match free.pop() {
+            Some(Reverse(color)) => color,
+            None => {
+                n_colors += 1;
+                n_colors - 1
+            }
+        };
+        busy.push(Reverse((FloatOrd(end), colors[idx])));
+    }
+    colors
+}
+
+//--------- Private functions ---------
+
+fn check_intervals(intervals: &[(f32, f32)], function: &str) {
+    if let Some(interval) = intervals
+        .iter()
+        .find(|(start, end)| start.is_nan() || end.is_nan() || end < start)
+    {
+        panic!(
+            "[construct::{}] Error: The interval {:?} is not valid!",
+            function, interval
+        );
+    }
+}
+
+// Returns the indices of the intervals sorted by start (and by end for equal starts)
+fn sorted_by_start(intervals: &[(f32, f32)]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..intervals.len()).collect();
+    order.sort_by_key(|idx| (FloatOrd(intervals[*idx].0), FloatOrd(intervals[*idx].1)));
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    #[test]
+    fn interval_coloring_is_proper_and_optimal() {
+        let mut rng = Rng::new(3);
+        for _ in 0..50 {
+            let intervals: Vec<(f32, f32)> = (0..1 + rng.gen_range(15))
+                .map(|_| {
+                    let start = rng.gen_range(20) as f32;
+                    (start, start + rng.gen_range(6) as f32)
+                })
+                .collect();
+            let g = interval_graph(&intervals);
+            let colors = interval_coloring(&intervals);
+            for (node1, node2, _) in g.get_edges() {
+                assert_ne!(colors[node1], colors[node2]);
+            }
+            // The maximum overlap is reached at the start of some interval
+            let max_overlap = intervals
+                .iter()
+                .map(|(t, _)| {
+                    intervals
+                        .iter()
+                        .filter(|(start, end)| start <= t && t < end)
+                        .count()
+                })
+                .max()
+                .unwrap();
+            let n_colors = colors.iter().max().unwrap() + 1;
+            assert_eq!(n_colors, max_overlap.max(1));
+        }
+    }
+
+    #[test]
+    fn interval_graph_check_nested_and_empty_intervals() {
+        let g = interval_graph(&[(0.0, 10.0), (2.0, 3.0), (5.0, 5.0), (4.0, 6.0)]);
+        assert_eq!(g.get_neighbors_of(0), vec![1, 3]);
+        assert_eq!(g.get_neighbors_of(2), vec![]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn interval_graph_panic_reversed_interval() {
+        interval_graph(&[(0.0, 1.0), (3.0, 2.0)]);
+    }
+}
@@ -0,0 +1,368 @@
+use crate::edge_policy::EdgeMerger;
+use crate::{DGraph, DuplicatePolicy, Graph, GraphError, SelfLoopPolicy};
+use std::collections::HashSet;
+use std::fmt;
+
+/// The `DagGraph` struct is a `DGraph` that is guaranteed to be acyclic. Every new edge is
+/// checked before being added, and the edges that would create a cycle are rejected with
+/// a `GraphError::CycleDetected` error. The underlying `DGraph` is available (read-only)
+/// with `get_graph` to run the algorithms of the crate.
+///
+/// The nodes keep a topological order that is updated incrementally with the Pearce–Kelly
+/// algorithm: an edge that agrees with the order is added without any search, and
+/// otherwise only the nodes placed between its endpoints are visited and reordered.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{DagGraph, GraphError};
+/// let mut dag = DagGraph::with_nodes(3);
+/// dag.add_edge(0, 1).unwrap();
+/// dag.add_edge(1, 2).unwrap();
+/// let error = dag.add_edge(2, 0).unwrap_err();
+/// assert!(matches!(error, GraphError::CycleDetected { src: 2, dest: 0, .. }));
+/// assert!(error.to_string().starts_with("[DagGraph::add_weighted_edge] Adding the edge 2 -> 0"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DagGraph {
+    graph: DGraph,
+    order: Vec<usize>,             // position of each node in the topological order
+    predecessors: Vec<Vec<usize>>, // sources of the edges added to each node
+}
+
+impl DagGraph {
+    /// Creates an empty `DagGraph`.
+    pub fn new() -> DagGraph {
+        DagGraph::default()
+    }
+
+    /// Creates a `DagGraph` with `n_nodes` nodes and no edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_nodes` - An `usize` value with the number of nodes in the graph.
+    pub fn with_nodes(n_nodes: usize) -> DagGraph {
+        DagGraph {
+            graph: DGraph::from_adjacency_matrix(vec![vec![0.0; n_nodes]; n_nodes]),
+            order: (0..n_nodes).collect(),
+            predecessors: vec![vec![]; n_nodes],
+        }
+    }
+
+    /// Creates a `DagGraph` from the definition of the graph edges (with weight) and
+    /// the number of nodes. Returns an error if some edge is not valid or the edges
+    /// contain a cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_nodes` - An `usize` value with the number of nodes in the graph.
+    /// * `edges` - A vector of triplets with two `usize` values and a `f32`
+    ///   defining each edge (`(src, dest, weight)`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{DagGraph, GraphError};
+    /// let dag = DagGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 1.0)]);
+    /// assert!(dag.is_ok());
+    /// let dag = DagGraph::from_weighted_edges(2, vec![(0, 1, 2.0), (1, 0, 1.0)]);
+    /// assert!(matches!(dag, Err(GraphError::CycleDetected { src: 1, dest: 0, .. })));
+    /// ```
+    pub fn from_weighted_edges(
+        n_nodes: usize,
+        edges: Vec<(usize, usize, f32)>,
+    ) -> Result<DagGraph, GraphError> {
+        let mut dag = DagGraph::with_nodes(n_nodes);
+        for (src, dest, weight) in edges {
+            dag.add_weighted_edge(src, dest, weight)?;
+        }
+        Ok(dag)
+    }
+
+    /// Same as `from_weighted_edges` but handling the repeated edges and the self-loops
+    /// with the policies passed as parameters. A self-loop is a cycle, so with
+    /// `SelfLoopPolicy::Allow` it fails with a `GraphError::CycleDetected` error.
+    ///
+    /// # Errors
+    ///
+    /// * `GraphError::InvalidNode` if some edge has an invalid node value.
+    /// * `GraphError::CycleDetected` if the edges contain a cycle.
+    /// * `GraphError::DuplicateEdge` if some edge is repeated with `DuplicatePolicy::Error`.
+    /// * `GraphError::SelfLoop` if some edge is a self-loop with `SelfLoopPolicy::Error`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{DagGraph, DuplicatePolicy, Graph, SelfLoopPolicy};
+    /// let edges = vec![(0, 1, 2.0), (1, 1, 1.0), (0, 1, 3.0)];
+    /// let (min, ignore) = (DuplicatePolicy::Min, SelfLoopPolicy::Ignore);
+    /// let dag = DagGraph::from_weighted_edges_with(2, edges, min, ignore).unwrap();
+    /// assert_eq!(dag.get_graph().get_edges(), vec![(0, 1, 2.0)]);
+    /// ```
+    pub fn from_weighted_edges_with(
+        n_nodes: usize,
+        edges: Vec<(usize, usize, f32)>,
+        duplicates: DuplicatePolicy,
+        self_loops: SelfLoopPolicy,
+    ) -> Result<DagGraph, GraphError> {
+        let operation = "DagGraph::from_weighted_edges_with";
+        let mut dag = DagGraph::with_nodes(n_nodes);
+        let mut merger = EdgeMerger::new(operation, duplicates, self_loops);
+        for (src, dest, weight) in edges {
+            if let Some(node) = [src, dest].iter().find(|n| **n >= n_nodes) {
+                return Err(GraphError::InvalidNode {
+                    operation,
+                    node: *node,
+                    n_nodes,
+                });
+            }
+            if let Some(weight) = merger.merge(&dag.graph, src, dest, weight)? {
+                dag.add_weighted_edge(src, dest, weight)?;
+            }
+        }
+        Ok(dag)
+    }
+
+    /// Returns a reference to the underlying `DGraph`.
+    pub fn get_graph(&self) -> &DGraph {
+        &self.graph
+    }
+
+    /// Consumes the `DagGraph` and returns the underlying `DGraph`.
+    pub fn into_graph(self) -> DGraph {
+        self.graph
+    }
+
+    /// Adds a node to the graph without any edge and returns its index.
+    pub fn add_node(&mut self) -> usize {
+        let node = self.graph.add_node();
+        self.order.push(node); // the new node has no edges, so it can go last
+        self.predecessors.push(vec![]);
+        node
+    }
+
+    /// Sets a directed edge from the node `src` to the node `dest` with weight `1.0`.
+    /// Returns an error (and the graph is not modified) if some node is not valid or
+    /// the edge would create a cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - `usize` value of the source node.
+    /// * `dest` - `usize` value of the destination node.
+    pub fn add_edge(&mut self, src: usize, dest: usize) -> Result<(), GraphError> {
+        self.add_weighted_edge(src, dest, 1.0)
+    }
+
+    /// Sets a directed edge from the node `src` to the node `dest` with the weight
+    /// passed as a parameter. Returns an error (and the graph is not modified) if some
+    /// node is not valid or the edge would create a cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - `usize` value of the source node.
+    /// * `dest` - `usize` value of the destination node.
+    /// * `weight` - `f32` value of the edge weight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{DagGraph, Graph, GraphError};
+    /// let mut dag = DagGraph::with_nodes(2);
+    /// dag.add_weighted_edge(0, 1, 2.5).unwrap();
+    /// assert_eq!(dag.get_graph().get_edge(0, 1), Some(2.5));
+    /// assert_eq!(
+    ///     dag.add_weighted_edge(0, 2, 1.0),
+    ///     Err(GraphError::InvalidNode {
+    ///         operation: "DagGraph::add_weighted_edge",
+    ///         node: 2,
+    ///         n_nodes: 2
+    ///     })
+    /// );
+    /// ```
+    pub fn add_weighted_edge(
+        &mut self,
+        src: usize,
+        dest: usize,
+        weight: f32,
+    ) -> Result<(), GraphError> {
+        let n_nodes = self.graph.get_n_nodes();
+        if let Some(node) = [src, dest].iter().find(|n| **n >= n_nodes) {
+            return Err(GraphError::InvalidNode {
+                operation: "DagGraph::add_weighted_edge",
+                node: *node,
+                n_nodes,
+            });
+        }
+        if self.graph.get_edge(src, dest).is_none() {
+            if !self.reorder(src, dest) {
+                return Err(GraphError::CycleDetected {
+                    operation: "DagGraph::add_weighted_edge",
+                    src,
+                    dest,
+                    n_nodes,
+                });
+            }
+            if !self.predecessors[dest].contains(&src) {
+                self.predecessors[dest].push(src);
+            }
+        }
+        self.graph.add_weighted_edge(src, dest, weight);
+        Ok(())
+    }
+
+    //--------- Private functions ---------
+
+    // Updates the topological order to place `src` before `dest` (Pearce–Kelly). Returns
+    // false, without modifying the order, if `dest` reaches `src` (a node reaches itself)
+    fn reorder(&mut self, src: usize, dest: usize) -> bool {
+        let (lower, upper) = (self.order[dest], self.order[src]);
+        if lower > upper {
+            return true; // the edge already agrees with the order
+        }
+        // Nodes reached from `dest` that are not after `src` in the order
+        let mut forward = vec![dest];
+        let mut visited: HashSet<usize> = forward.iter().copied().collect();
+        let mut pos = 0;
+        while pos < forward.len() {
+            let node = forward[pos];
+            pos += 1;
+            if node == src {
+                return false;
+            }
+            for n in self.graph.get_successors_of(node) {
+                if self.order[n] <= upper && visited.insert(n) {
+                    forward.push(n);
+                }
+            }
+        }
+        // Nodes that reach `src` and are not before `dest` in the order. The predecessor
+        // lists can keep removed edges, so each one is checked in the graph
+        let mut backward = vec![src];
+        visited.insert(src);
+        let mut pos = 0;
+        while pos < backward.len() {
+            let node = backward[pos];
+            pos += 1;
+            for n in &self.predecessors[node] {
+                let present = self.graph.get_edge(*n, node).is_some();
+                if present && self.order[*n] >= lower && visited.insert(*n) {
+                    backward.push(*n);
+                }
+            }
+        }
+        // The backward nodes take the first positions of the affected nodes, followed by
+        // the forward nodes, both keeping their previous relative order
+        backward.sort_unstable_by_key(|n| self.order[*n]);
+        forward.sort_unstable_by_key(|n| self.order[*n]);
+        let mut positions: Vec<usize> = backward
+            .iter()
+            .chain(&forward)
+            .map(|n| self.order[*n])
+            .collect();
+        positions.sort_unstable();
+        for (node, position) in backward.into_iter().chain(forward).zip(positions) {
+            self.order[node] = position;
+        }
+        true
+    }
+}
+
+impl PartialEq for DagGraph {
+    /// Two `DagGraph` are equal if their graphs are equal, no matter the order in which
+    /// the edges were added.
+    fn eq(&self, other: &DagGraph) -> bool {
+        self.graph == other.graph
+    }
+}
+
+impl AsRef<DGraph> for DagGraph {
+    fn as_ref(&self) -> &DGraph {
+        &self.graph
+    }
+}
+
+impl fmt::Display for DagGraph {
+    /// Shows the info of the graph, same format as `DGraph`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.graph.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::bfs;
+    use crate::{DagGraph, Graph, GraphError};
+
+    #[test]
+    fn add_edge_rejects_self_loop() {
+        let mut dag = DagGraph::with_nodes(2);
+        assert_eq!(
+            dag.add_edge(1, 1),
+            Err(GraphError::CycleDetected {
+                operation: "DagGraph::add_weighted_edge",
+                src: 1,
+                dest: 1,
+                n_nodes: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn add_edge_rejects_long_cycle_without_modifying() {
+        let edges = vec![(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0)];
+        let mut dag = DagGraph::from_weighted_edges(4, edges).unwrap();
+        assert_eq!(
+            dag.add_edge(3, 0),
+            Err(GraphError::CycleDetected {
+                operation: "DagGraph::add_weighted_edge",
+                src: 3,
+                dest: 0,
+                n_nodes: 4,
+            })
+        );
+        assert_eq!(dag.get_graph().get_edge(3, 0), None);
+        assert_eq!(dag.add_edge(0, 3), Ok(())); // a shortcut is not a cycle
+    }
+
+    #[test]
+    fn add_weighted_edge_updates_existing_edge() {
+        let mut dag = DagGraph::from_weighted_edges(2, vec![(0, 1, 1.0)]).unwrap();
+        assert_eq!(dag.add_weighted_edge(0, 1, 3.0), Ok(()));
+        assert_eq!(dag.get_graph().get_edge(0, 1), Some(3.0));
+    }
+
+    #[test]
+    fn add_edge_keeps_topological_order() {
+        let mut rng = crate::rng::Rng::new(23);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(15);
+            let mut dag = DagGraph::with_nodes(n_nodes);
+            for _ in 0..rng.gen_range(60) {
+                if rng.gen_range(5) == 0 {
+                    dag.add_node();
+                }
+                let n_nodes = dag.get_graph().get_n_nodes();
+                let (src, dest) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                let reachable = bfs(dag.get_graph(), dest).hops[src].is_some();
+                assert_eq!(dag.add_edge(src, dest).is_err(), reachable);
+                // Every edge agrees with the order, and the order is a permutation
+                let mut positions = dag.order.clone();
+                positions.sort_unstable();
+                assert_eq!(positions, (0..n_nodes).collect::<Vec<_>>());
+                for (node1, node2, _) in dag.get_graph().get_edges() {
+                    assert!(dag.order[node1] < dag.order[node2]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn add_node_check_status() {
+        let mut dag = DagGraph::new();
+        let a = dag.add_node();
+        let b = dag.add_node();
+        assert_eq!((a, b), (0, 1));
+        assert_eq!(dag.add_edge(b, a), Ok(()));
+        assert_eq!(dag.into_graph().get_n_nodes(), 2);
+    }
+}
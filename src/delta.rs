@@ -0,0 +1,503 @@
+//! # Delta
+//!
+//! `delta` is a `graphst` module to record the changes of an evolving graph as an
+//! append-only stream of timestamped deltas and to replay them, so the experiments over
+//! evolving graphs can be reproduced from a file. Each line of a stream is a delta:
+//!
+//! ```text
+//! 0 add_node
+//! 5 add_edge 0 1 2.5
+//! 7 remove_edge 0 1
+//! 8 set_node_weight 0 0.5
+//! 9 remove_node 1
+//! ```
+//!
+//! The timestamps can't decrease along the stream. The `Graph` trait can't delete nodes,
+//! so `remove_node` removes all the edges of the node and keeps its index.
+
+use crate::float;
+use crate::graph::Graph;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+/// The `Delta` enum lists the changes of a delta stream.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Delta {
+    /// Adds a node at the end of the graph.
+    AddNode,
+    /// Removes all the edges of the node.
+    RemoveNode { node: usize },
+    /// Adds (or replaces) the edge with the weight.
+    AddEdge {
+        node1: usize,
+        node2: usize,
+        weight: f32,
+    },
+    /// Removes the edge, if it exists.
+    RemoveEdge { node1: usize, node2: usize },
+    /// Sets the weight of the node.
+    SetNodeWeight { node: usize, weight: f32 },
+}
+
+/// The `TimedDelta` struct is a line of a delta stream: a change and the time it happened.
+/// Its `Display` implementation writes the line (without the line break).
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimedDelta {
+    /// The timestamp of the change.
+    pub time: u64,
+    /// The change.
+    pub delta: Delta,
+}
+
+impl fmt::Display for TimedDelta {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.delta {
+            Delta::AddNode => write!(f, "{} add_node", self.time),
+            Delta::RemoveNode { node } => write!(f, "{} remove_node {}", self.time, node),
+            Delta::AddEdge {
+                node1,
+                node2,
+                weight,
+            } => write!(f, "{} add_edge {} {} {}", self.time, node1, node2, weight),
+            Delta::RemoveEdge { node1, node2 } => {
+                write!(f, "{} remove_edge {} {}", self.time, node1, node2)
+            }
+            Delta::SetNodeWeight { node, weight } => {
+                write!(f, "{} set_node_weight {} {}", self.time, node, weight)
+            }
+        }
+    }
+}
+
+/// The `DeltaError` struct is the error returned when a stream can't be replayed: the
+/// line of the failure (starting at `1`) and the reason.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeltaError {
+    /// The line of the stream where the failure was found.
+    pub line: usize,
+    /// The description of the failure.
+    pub message: String,
+}
+
+impl fmt::Display for DeltaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for DeltaError {}
+
+/// Applies the deltas of the stream read from `reader` to the graph, in order, and
+/// returns the number of deltas applied. The empty lines are ignored. If some line fails,
+/// the deltas of the previous lines stay applied.
+///
+/// # Errors
+///
+/// * If some line can't be read or is not a valid delta.
+/// * If some timestamp is lower than the previous one.
+/// * If some delta has a node that is not valid, or an edge with weight `0.0`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::delta::apply_deltas;
+/// use graphst::{Graph, UGraph};
+/// let stream = "0 add_node\n0 add_node\n3 add_edge 0 1 1.5\n";
+/// let mut g = UGraph::new();
+/// assert_eq!(apply_deltas(&mut g, stream.as_bytes()), Ok(3));
+/// assert_eq!(g.get_edge(1, 0), Some(1.5));
+/// let error = apply_deltas(&mut g, "4 remove_edge 0 2\n".as_bytes()).unwrap_err();
+/// assert_eq!(error.to_string(), "line 1: The node 2 is not valid!");
+/// ```
+pub fn apply_deltas<G, R>(g: &mut G, reader: R) -> Result<usize, DeltaError>
+where
+    G: Graph,
+    R: BufRead,
+{
+    let mut last_time = 0;
+    let mut n_applied = 0;
+    for (idx, line) in reader.lines().enumerate() {
+        let line_error = |message: String| DeltaError {
+            line: idx + 1,
+            message,
+        };
+        let line = line.map_err(|e| line_error(e.to_string()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let timed = parse_line(&line).map_err(line_error)?;
+        if timed.time < last_time {
+            return Err(line_error(format!(
+                "The time {} is lower than the previous time {}!",
+                timed.time, last_time
+            )));
+        }
+        apply(g, &timed.delta).map_err(line_error)?;
+        last_time = timed.time;
+        n_applied += 1;
+    }
+    Ok(n_applied)
+}
+
+/// Writes the deltas to `writer` as a delta stream, one line per delta.
+///
+/// # Errors
+///
+/// * If some line can't be written.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::delta::{record_deltas, Delta, TimedDelta};
+/// let deltas = vec![
+///     TimedDelta { time: 1, delta: Delta::AddNode },
+///     TimedDelta { time: 2, delta: Delta::RemoveNode { node: 0 } },
+/// ];
+/// let mut stream = vec![];
+/// record_deltas(&deltas, &mut stream).unwrap();
+/// assert_eq!(String::from_utf8(stream).unwrap(), "1 add_node\n2 remove_node 0\n");
+/// ```
+pub fn record_deltas<W>(deltas: &[TimedDelta], mut writer: W) -> io::Result<()>
+where
+    W: Write,
+{
+    for delta in deltas {
+        writeln!(writer, "{}", delta)?;
+    }
+    Ok(())
+}
+
+/// The `DeltaRecorder` struct wraps a graph (that implements `Graph`) to record its
+/// changes as deltas stamped with the current time, set with `set_time`. It implements
+/// `Graph`, so the code that builds or updates a graph records its changes without
+/// modifications, and the recorded stream replays them on an empty graph.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::delta::{apply_deltas, record_deltas, DeltaRecorder};
+/// use graphst::{DGraph, Graph};
+/// let mut recorder = DeltaRecorder::new(DGraph::new());
+/// recorder.add_nodes(2);
+/// recorder.set_time(10);
+/// recorder.add_weighted_edge(0, 1, 4.0);
+/// let mut stream = vec![];
+/// record_deltas(recorder.get_deltas(), &mut stream).unwrap();
+///
+/// let mut replayed = DGraph::new();
+/// apply_deltas(&mut replayed, stream.as_slice()).unwrap();
+/// assert_eq!(&replayed, recorder.get_graph());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeltaRecorder<G> {
+    graph: G,
+    time: u64,
+    deltas: Vec<TimedDelta>,
+}
+
+impl<G> DeltaRecorder<G>
+where
+    G: Graph,
+{
+    /// Creates a recorder over the graph passed as a parameter, at the time `0`. The
+    /// current contents of the graph are not recorded.
+    pub fn new(graph: G) -> DeltaRecorder<G> {
+        DeltaRecorder {
+            graph,
+            time: 0,
+            deltas: vec![],
+        }
+    }
+
+    /// Sets the time of the next changes.
+    ///
+    /// # Panics
+    ///
+    /// * If the time is lower than the current time, the streams are append-only.
+    pub fn set_time(&mut self, time: u64) {
+        if time < self.time {
+            panic!(
+                "[DeltaRecorder::set_time] Error: The time {} is lower than the current time {}!",
+                time, self.time
+            );
+        }
+        self.time = time;
+    }
+
+    /// Returns the current time.
+    pub fn get_time(&self) -> u64 {
+        self.time
+    }
+
+    /// Returns a reference to the underlying graph.
+    pub fn get_graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Returns the deltas recorded so far.
+    pub fn get_deltas(&self) -> &[TimedDelta] {
+        &self.deltas
+    }
+
+    /// Removes all the edges of the node and records it as a `remove_node` delta.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    pub fn remove_node(&mut self, node: usize) {
+        if let Err(message) = apply(&mut self.graph, &Delta::RemoveNode { node }) {
+            panic!("[DeltaRecorder::remove_node] Error: {}", message);
+        }
+        self.record(Delta::RemoveNode { node });
+    }
+
+    /// Returns the underlying graph and the recorded deltas, consuming the recorder.
+    pub fn into_parts(self) -> (G, Vec<TimedDelta>) {
+        (self.graph, self.deltas)
+    }
+
+    //--------- Private functions ---------
+
+    fn record(&mut self, delta: Delta) {
+        self.deltas.push(TimedDelta {
+            time: self.time,
+            delta,
+        });
+    }
+}
+
+impl<G> Graph for DeltaRecorder<G>
+where
+    G: Graph,
+{
+    fn get_n_nodes(&self) -> usize {
+        self.graph.get_n_nodes()
+    }
+
+    fn get_nodes(&self) -> Vec<usize> {
+        self.graph.get_nodes()
+    }
+
+    fn is_directed(&self) -> bool {
+        self.graph.is_directed()
+    }
+
+    fn get_edge(&self, node1: usize, node2: usize) -> Option<f32> {
+        self.graph.get_edge(node1, node2)
+    }
+
+    fn get_out_neighbors_of(&self, node: usize) -> Vec<usize> {
+        self.graph.get_out_neighbors_of(node)
+    }
+
+    fn get_edges(&self) -> Vec<(usize, usize, f32)> {
+        self.graph.get_edges()
+    }
+
+    fn get_node_weight(&self, node: usize) -> f32 {
+        self.graph.get_node_weight(node)
+    }
+
+    /// Sets the weight of the node, recorded as a `set_node_weight` delta.
+    fn set_node_weight(&mut self, node: usize, weight: f32) {
+        self.graph.set_node_weight(node, weight);
+        self.record(Delta::SetNodeWeight { node, weight });
+    }
+
+    fn add_node(&mut self) -> usize {
+        let node = self.graph.add_node();
+        self.record(Delta::AddNode);
+        node
+    }
+
+    fn add_edge(&mut self, node1: usize, node2: usize) {
+        self.add_weighted_edge(node1, node2, 1.0);
+    }
+
+    /// Sets the weight of the edge, recorded as an `add_edge` delta, or as a
+    /// `remove_edge` delta if the weight is `0.0`.
+    fn add_weighted_edge(&mut self, node1: usize, node2: usize, weight: f32) {
+        self.graph.add_weighted_edge(node1, node2, weight);
+        if float::is_zero(weight) {
+            self.record(Delta::RemoveEdge { node1, node2 });
+        } else {
+            self.record(Delta::AddEdge {
+                node1,
+                node2,
+                weight,
+            });
+        }
+    }
+}
+
+//--------- Private functions ---------
+
+fn parse_line(line: &str) -> Result<TimedDelta, String> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let time = parse(fields[0], "time")?;
+    let node = |idx: usize| parse::<usize>(fields[idx], "node");
+    let delta = match (fields.get(1).copied(), fields.len()) {
+        (Some("add_node"), 2) => Delta::AddNode,
+        (Some("remove_node"), 3) => Delta::RemoveNode { node: node(2)? },
+        (Some("add_edge"), 5) => Delta::AddEdge {
+            node1: node(2)?,
+            node2: node(3)?,
+            weight: parse(fields[4], "weight")?,
+        },
+        (Some("remove_edge"), 4) => Delta::RemoveEdge {
+            node1: node(2)?,
+            node2: node(3)?,
+        },
+        (Some("set_node_weight"), 4) => Delta::SetNodeWeight {
+            node: node(2)?,
+            weight: parse(fields[3], "weight")?,
+        },
+        _ => return Err("The delta is not valid!".to_string()),
+    };
+    Ok(TimedDelta { time, delta })
+}
+
+fn parse<T>(field: &str, name: &str) -> Result<T, String>
+where
+    T: std::str::FromStr,
+{
+    field
+        .parse()
+        .map_err(|_| format!("The {} {} is not valid!", name, field))
+}
+
+fn apply<G>(g: &mut G, delta: &Delta) -> Result<(), String>
+where
+    G: Graph,
+{
+    let nodes: &[usize] = match delta {
+        Delta::AddNode => &[],
+        Delta::RemoveNode { node } | Delta::SetNodeWeight { node, .. } => &[*node],
+        Delta::AddEdge { node1, node2, .. } | Delta::RemoveEdge { node1, node2 } => {
+            &[*node1, *node2]
+        }
+    };
+    if let Some(node) = nodes.iter().find(|n| **n >= g.get_n_nodes()) {
+        return Err(format!("The node {} is not valid!", node));
+    }
+    match *delta {
+        Delta::AddNode => {
+            g.add_node();
+        }
+        Delta::RemoveNode { node } => {
+            for n in g.get_nodes() {
+                if g.get_edge(node, n).is_some() {
+                    g.add_weighted_edge(node, n, 0.0);
+                }
+                if g.get_edge(n, node).is_some() {
+                    g.add_weighted_edge(n, node, 0.0);
+                }
+            }
+        }
+        Delta::AddEdge {
+            node1,
+            node2,
+            weight,
+        } => {
+            if float::is_zero(weight) {
+                return Err(format!("The weight {} is not valid!", weight));
+            }
+            g.add_weighted_edge(node1, node2, weight);
+        }
+        Delta::RemoveEdge { node1, node2 } => g.add_weighted_edge(node1, node2, 0.0),
+        Delta::SetNodeWeight { node, weight } => g.set_node_weight(node, weight),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DGraph, UGraph};
+
+    #[test]
+    fn replay_matches_recorded_graph() {
+        let mut rng = crate::rng::Rng::new(26);
+        for _ in 0..20 {
+            let mut recorder = DeltaRecorder::new(DGraph::new());
+            for _ in 0..rng.gen_range(60) {
+                recorder.set_time(recorder.get_time() + rng.gen_range(3) as u64);
+                let n_nodes = recorder.get_n_nodes();
+                match rng.gen_range(6) {
+                    0 => {
+                        recorder.add_node();
+                    }
+                    _ if n_nodes == 0 => {}
+                    1 => recorder.remove_node(rng.gen_range(n_nodes)),
+                    2 => recorder.add_weighted_edge(
+                        rng.gen_range(n_nodes),
+                        rng.gen_range(n_nodes),
+                        0.0,
+                    ),
+                    3 => recorder.set_node_weight(rng.gen_range(n_nodes), rng.next_f64() as f32),
+                    _ => recorder.add_weighted_edge(
+                        rng.gen_range(n_nodes),
+                        rng.gen_range(n_nodes),
+                        (rng.next_f64() as f32 + 0.1) * 10.0,
+                    ),
+                }
+            }
+            let mut stream = vec![];
+            record_deltas(recorder.get_deltas(), &mut stream).unwrap();
+            let mut replayed = DGraph::new();
+            let n_applied = apply_deltas(&mut replayed, stream.as_slice()).unwrap();
+            assert_eq!(n_applied, recorder.get_deltas().len());
+            assert_eq!(&replayed, recorder.get_graph());
+        }
+    }
+
+    #[test]
+    fn stream_round_trip_random_deltas() {
+        let mut rng = crate::rng::Rng::new(1014);
+        let deltas: Vec<TimedDelta> = (0..200)
+            .map(|time| {
+                let (node1, node2) = (rng.gen_range(50), rng.gen_range(50));
+                let weight = (rng.next_f64() as f32 - 0.5) * 1e3;
+                let delta = match rng.gen_range(5) {
+                    0 => Delta::AddNode,
+                    1 => Delta::RemoveNode { node: node1 },
+                    2 => Delta::AddEdge {
+                        node1,
+                        node2,
+                        weight,
+                    },
+                    3 => Delta::RemoveEdge { node1, node2 },
+                    _ => Delta::SetNodeWeight {
+                        node: node1,
+                        weight,
+                    },
+                };
+                TimedDelta { time, delta }
+            })
+            .collect();
+        let mut stream = vec![];
+        record_deltas(&deltas, &mut stream).unwrap();
+        let parsed: Vec<TimedDelta> = String::from_utf8(stream)
+            .unwrap()
+            .lines()
+            .map(|line| parse_line(line).unwrap())
+            .collect();
+        assert_eq!(parsed, deltas);
+    }
+
+    #[test]
+    fn apply_deltas_check_errors() {
+        let mut g = UGraph::new();
+        let error = apply_deltas(&mut g, "3 add_node\n\n2 add_node\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            (error.line, error.message.as_str()),
+            (3, "The time 2 is lower than the previous time 3!")
+        );
+        assert_eq!(g.get_n_nodes(), 1);
+        let error = apply_deltas(&mut g, "1 add_edge 0 0\n".as_bytes()).unwrap_err();
+        assert_eq!(error.message, "The delta is not valid!");
+        let error = apply_deltas(&mut g, "1 add_edge 0 0 0\n".as_bytes()).unwrap_err();
+        assert_eq!(error.message, "The weight 0 is not valid!");
+    }
+}
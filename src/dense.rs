@@ -0,0 +1,145 @@
+//! # Dense
+//!
+//! `dense` is a `graphst` module with the primitives used over dense rows, like the
+//! minimum scan of `dijkstra_dense` and `prim_mst`. With the `simd` feature enabled they
+//! use explicit SIMD instructions on `x86_64`, otherwise (or in other architectures) the
+//! scalar versions are used.
+
+/// Returns the index of the minimum value of `values` not marked in `visited`, or `None`
+/// if every value is visited or infinite. In case of a tie the first index is returned.
+/// The NaN values are skipped like the visited ones, in both the scalar and the SIMD
+/// versions.
+///
+/// # Panics
+///
+/// * If `values` and `visited` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::dense::row_min_scan;
+/// let dist = vec![0.0, 3.0, 1.0, 1.0, f32::INFINITY];
+/// let visited = vec![true, false, false, false, false];
+/// assert_eq!(row_min_scan(&dist, &visited), Some(2));
+/// ```
+pub fn row_min_scan(values: &[f32], visited: &[bool]) -> Option<usize> {
+    if values.len() != visited.len() {
+        panic!("[dense::row_min_scan] Error: The slices have different lengths!");
+    }
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    {
+        simd::row_min_scan(values, visited)
+    }
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    {
+        row_min_scan_scalar(values, visited)
+    }
+}
+
+/// Scalar version of `row_min_scan`, always available as a reference implementation.
+pub fn row_min_scan_scalar(values: &[f32], visited: &[bool]) -> Option<usize> {
+    let mut min_value = f32::INFINITY;
+    let mut min_idx = None;
+    for (idx, value) in values.iter().enumerate() {
+        if *value < min_value && !visited[idx] {
+            min_value = *value;
+            min_idx = Some(idx);
+        }
+    }
+    min_idx
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd {
+    // SSE2 is part of the x86_64 baseline, so these instructions are always available
+    use std::arch::x86_64::*;
+
+    const LANES: usize = 4;
+
+    pub(super) fn row_min_scan(values: &[f32], visited: &[bool]) -> Option<usize> {
+        // First pass: find the minimum value with SIMD. Second pass: find its first index.
+        let n_chunks = values.len() / LANES;
+        // SAFETY: SSE2 is always available on x86_64 and all the loads are unaligned
+        // loads of in-bounds chunks of 4 values.
+        let mut min_value = unsafe {
+            let inf = _mm_set1_ps(f32::INFINITY);
+            let zero = _mm_setzero_si128();
+            let mut best = inf;
+            for chunk in 0..n_chunks {
+                let start = chunk * LANES;
+                let vals = _mm_loadu_ps(values.as_ptr().add(start));
+                // Widen the 4 visited bytes (0 or 1) to 32 bit lanes and build the mask
+                let v = visited.as_ptr().add(start) as *const [u8; LANES];
+                let bytes = _mm_cvtsi32_si128(i32::from_ne_bytes(*v));
+                let wide = _mm_unpacklo_epi16(_mm_unpacklo_epi8(bytes, zero), zero);
+                let visited_mask = _mm_castsi128_ps(_mm_cmpgt_epi32(wide, zero));
+                // Visited and NaN values are replaced by infinity, `_mm_min_ps` would
+                // otherwise return the NaN of its second operand
+                let skip_mask = _mm_or_ps(visited_mask, _mm_cmpunord_ps(vals, vals));
+                let vals = _mm_or_ps(_mm_and_ps(skip_mask, inf), _mm_andnot_ps(skip_mask, vals));
+                best = _mm_min_ps(best, vals);
+            }
+            let mut lanes = [0.0f32; LANES];
+            _mm_storeu_ps(lanes.as_mut_ptr(), best);
+            lanes.iter().fold(f32::INFINITY, |a, b| a.min(*b))
+        };
+        // Remainder values that don't fill a chunk
+        for idx in n_chunks * LANES..values.len() {
+            if values[idx] < min_value && !visited[idx] {
+                min_value = values[idx];
+            }
+        }
+        if min_value == f32::INFINITY {
+            return None; // every value is visited or infinite
+        }
+        values
+            .iter()
+            .zip(visited)
+            .position(|(value, v)| *value == min_value && !v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_min_scan_matches_scalar() {
+        let values: Vec<f32> = (0..37).map(|i| ((i * 7919) % 23) as f32).collect();
+        let visited: Vec<bool> = (0..37).map(|i| i % 5 == 0).collect();
+        assert_eq!(
+            row_min_scan(&values, &visited),
+            row_min_scan_scalar(&values, &visited)
+        );
+    }
+
+    #[test]
+    fn row_min_scan_check_ties_and_empty_cases() {
+        let values = vec![2.0, 1.0, 5.0, 1.0, 1.0, 9.0, 1.0];
+        let visited = vec![false, true, false, false, false, false, false];
+        assert_eq!(row_min_scan(&values, &visited), Some(3));
+        assert_eq!(row_min_scan(&[f32::INFINITY; 6], &[false; 6]), None);
+        assert_eq!(row_min_scan(&[1.0; 5], &[true; 5]), None);
+        assert_eq!(row_min_scan(&[], &[]), None);
+    }
+
+    #[test]
+    fn row_min_scan_check_nan_skipped() {
+        // The NaN at 5 follows the minimum at 1 in the same SIMD lane
+        let mut values: Vec<f32> = (0..13).map(|i| (i + 1) as f32).collect();
+        for idx in [0, 5, 6, 11, 12].iter() {
+            values[*idx] = f32::NAN;
+        }
+        let visited = vec![false; 13];
+        assert_eq!(row_min_scan(&values, &visited), Some(1));
+        assert_eq!(row_min_scan_scalar(&values, &visited), Some(1));
+        assert_eq!(row_min_scan(&[f32::NAN; 8], &[false; 8]), None);
+        assert_eq!(row_min_scan_scalar(&[f32::NAN; 8], &[false; 8]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "different lengths")]
+    fn row_min_scan_panic_different_lengths() {
+        row_min_scan(&[1.0, 2.0], &[false]);
+    }
+}
@@ -1,12 +1,22 @@
-use crate::Graph;
+use crate::adj_index;
+use crate::edge_policy::EdgeMerger;
+use crate::float;
+use crate::graph;
+use crate::{DuplicatePolicy, Graph, GraphError, SelfLoopPolicy};
+use std::borrow::Cow;
 use std::fmt;
+use std::mem;
+use std::ops::Range;
 
 /// The `DGraph` struct provides the functionalities to create and manipulate `directed graphs`.
 /// It can use weighted edges or default edges (with weight `1.0`). The weights of the nodes are
 /// of type `f32`, and the nodes are referenced by `usize` values from `0` to `n_nodes-1`.
+#[derive(Clone, Default, PartialEq)]
 pub struct DGraph {
     n_nodes: usize,
     adj_mat: Vec<Vec<f32>>,
+    adj_list: Vec<Vec<usize>>, // sorted index of the present edges of each node
+    node_weights: Vec<f32>,    // `1.0` unless set with `set_node_weight`
 }
 
 impl DGraph {
@@ -22,6 +32,8 @@ impl DGraph {
         DGraph {
             n_nodes: 0,
             adj_mat: vec![],
+            adj_list: vec![],
+            node_weights: vec![],
         }
     }
 
@@ -31,7 +43,7 @@ impl DGraph {
     ///
     /// * `n_nodes` - An `usize` value with the number of nodes in the graph.
     /// * `edges` - A vector of tuples with two `usize` values defining each
-    ///             edge (`(src, dest)`).
+    ///   edge (`(src, dest)`).
     ///
     /// # Panics
     ///
@@ -55,7 +67,7 @@ impl DGraph {
                     edge
                 );
             }
-            if adj_mat[edge.0][edge.1] != 0.0 {
+            if !float::is_zero(adj_mat[edge.0][edge.1]) {
                 panic!(
                     "[DGraph::from_edges] Error: The edge ({})->({}) is repeated!",
                     edge.0, edge.1
@@ -64,7 +76,13 @@ impl DGraph {
                 adj_mat[edge.0][edge.1] = 1.0;
             }
         }
-        DGraph { n_nodes, adj_mat }
+        let adj_list = adj_index::build(&adj_mat);
+        DGraph {
+            n_nodes,
+            adj_mat,
+            adj_list,
+            node_weights: vec![1.0; n_nodes],
+        }
     }
 
     /// Creates a `DGraph` from the definition of the graph edges (with weight)
@@ -74,7 +92,7 @@ impl DGraph {
     ///
     /// * `n_nodes` - An `usize` value with the number of nodes in the graph.
     /// * `edges` - A vector of triplets with two `usize` values and a `f32`
-    ///             defining each edge (`(src, dest, weight)`).
+    ///   defining each edge (`(src, dest, weight)`).
     ///
     /// # Panics
     ///
@@ -98,7 +116,7 @@ impl DGraph {
                     edge
                 );
             }
-            if adj_mat[edge.0][edge.1] != 0.0 {
+            if !float::is_zero(adj_mat[edge.0][edge.1]) {
                 panic!(
                     "[DGraph::from_weighted_edges] Error: The edge ({})->({}) is repeated!",
                     edge.0, edge.1
@@ -107,7 +125,88 @@ impl DGraph {
                 adj_mat[edge.0][edge.1] = edge.2;
             }
         }
-        DGraph { n_nodes, adj_mat }
+        let adj_list = adj_index::build(&adj_mat);
+        DGraph {
+            n_nodes,
+            adj_mat,
+            adj_list,
+            node_weights: vec![1.0; n_nodes],
+        }
+    }
+
+    /// Same as `from_weighted_edges` but handling the repeated edges and the self-loops
+    /// with the policies passed as parameters, and returning an error instead of
+    /// panicking. The unweighted edge lists can be mapped to the weight `1.0`, and
+    /// `DuplicatePolicy::Sum` then counts the repetitions of each edge.
+    ///
+    /// # Errors
+    ///
+    /// * `GraphError::InvalidNode` if some edge has an invalid node value.
+    /// * `GraphError::DuplicateEdge` if some edge is repeated with `DuplicatePolicy::Error`.
+    /// * `GraphError::SelfLoop` if some edge is a self-loop with `SelfLoopPolicy::Error`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{DuplicatePolicy, Graph, GraphError, SelfLoopPolicy, DGraph};
+    /// let edges = vec![(0, 1, 2.0), (1, 0, 0.5), (2, 2, 3.0), (0, 1, 1.0)];
+    /// let (max, ignore) = (DuplicatePolicy::Max, SelfLoopPolicy::Ignore);
+    /// let g = DGraph::from_weighted_edges_with(3, edges.clone(), max, ignore).unwrap();
+    /// assert_eq!(g.get_edges(), vec![(0, 1, 2.0), (1, 0, 0.5)]);
+    /// let error = DGraph::from_weighted_edges_with(3, edges, max, SelfLoopPolicy::Error);
+    /// assert!(matches!(error, Err(GraphError::SelfLoop { node: 2, .. })));
+    /// ```
+    pub fn from_weighted_edges_with(
+        n_nodes: usize,
+        edges: Vec<(usize, usize, f32)>,
+        duplicates: DuplicatePolicy,
+        self_loops: SelfLoopPolicy,
+    ) -> Result<DGraph, GraphError> {
+        DGraph::from_merged_edges(
+            "DGraph::from_weighted_edges_with",
+            n_nodes,
+            edges,
+            duplicates,
+            self_loops,
+        )
+    }
+
+    /// Same as `from_edges` but handling the repeated edges and the self-loops with the
+    /// policies passed as parameters, and returning an error instead of panicking. The
+    /// edges have weight `1.0`, so `DuplicatePolicy::Sum` counts their repetitions.
+    ///
+    /// # Errors
+    ///
+    /// * `GraphError::InvalidNode` if some edge has an invalid node value.
+    /// * `GraphError::DuplicateEdge` if some edge is repeated with `DuplicatePolicy::Error`.
+    /// * `GraphError::SelfLoop` if some edge is a self-loop with `SelfLoopPolicy::Error`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{DuplicatePolicy, Graph, SelfLoopPolicy, DGraph};
+    /// let edges = vec![(0, 1), (0, 1), (1, 2), (2, 2)];
+    /// let (sum, ignore) = (DuplicatePolicy::Sum, SelfLoopPolicy::Ignore);
+    /// let g = DGraph::from_edges_with(3, edges, sum, ignore).unwrap();
+    /// assert_eq!(g.get_edge(0, 1), Some(2.0));
+    /// assert_eq!(g.get_edge(2, 2), None);
+    /// ```
+    pub fn from_edges_with(
+        n_nodes: usize,
+        edges: Vec<(usize, usize)>,
+        duplicates: DuplicatePolicy,
+        self_loops: SelfLoopPolicy,
+    ) -> Result<DGraph, GraphError> {
+        DGraph::from_merged_edges(
+            "DGraph::from_edges_with",
+            n_nodes,
+            edges
+                .into_iter()
+                .map(|(node1, node2)| (node1, node2, 1.0))
+                .collect(),
+            duplicates,
+            self_loops,
+        )
     }
 
     /// Creates a `DGraph` from an adjacency matrix. The `f32` values represent the weights
@@ -140,7 +239,45 @@ impl DGraph {
                 );
             }
         }
-        DGraph { n_nodes, adj_mat }
+        let adj_list = adj_index::build(&adj_mat);
+        DGraph {
+            n_nodes,
+            adj_mat,
+            adj_list,
+            node_weights: vec![1.0; n_nodes],
+        }
+    }
+
+    /// Creates a `DGraph` from a matrix where the value `no_edge` means that there is no
+    /// edge, e.g. `f32::INFINITY` for a distance matrix. `f32::NAN` is also valid as
+    /// `no_edge`. Any other value is the weight of an edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `mat` - A squared matrix of `f32` values.
+    /// * `no_edge` - The `f32` value that represents the absence of an edge.
+    ///
+    /// # Panics
+    ///
+    /// * If the matrix is not squared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, DGraph};
+    /// let inf = f32::INFINITY;
+    /// let g = DGraph::from_matrix_with(vec![vec![inf, 3.0], vec![3.0, inf]], inf);
+    /// assert_eq!(g.get_edge(0, 1), Some(3.0));
+    /// assert_eq!(g.get_edge(0, 0), None);
+    /// assert_eq!(g.to_matrix_with(inf), vec![vec![inf, 3.0], vec![3.0, inf]]);
+    /// ```
+    pub fn from_matrix_with(mut mat: Vec<Vec<f32>>, no_edge: f32) -> DGraph {
+        for value in mat.iter_mut().flatten() {
+            if *value == no_edge || (no_edge.is_nan() && value.is_nan()) {
+                *value = 0.0;
+            }
+        }
+        DGraph::from_adjacency_matrix(mat)
     }
 
     /// Returns a vector with the nodes that are successors of the node passed as a parameter.
@@ -170,12 +307,7 @@ impl DGraph {
                 node
             );
         }
-        self.adj_mat[node]
-            .iter()
-            .enumerate()
-            .filter(|(_, w)| **w != 0.0)
-            .map(|(idx, _)| idx)
-            .collect()
+        adj_index::present(&self.adj_list[node], &self.adj_mat[node])
     }
 
     /// Returns a vector with the nodes that are predecessors of the node passed as a parameter.
@@ -210,10 +342,109 @@ impl DGraph {
         self.adj_mat
             .iter()
             .enumerate()
-            .filter(|(_, w)| w[node] != 0.0)
+            .filter(|(_, w)| !float::is_zero(w[node]))
             .map(|(idx, _)| idx)
             .collect()
     }
+
+    /// Returns the row of the node in the adjacency matrix: the weight of the edge from
+    /// the node to each node, with `0.0` for the missing edges. These are the weights of
+    /// the out edges. As in `get_adjacency_matrix`, the weights that are zero within the
+    /// crate tolerance are returned as `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::DGraph;
+    /// let g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (2, 0, 0.5)]);
+    /// assert_eq!(*g.row(0), [0.0, 2.0, 0.0]);
+    /// let column: Vec<f32> = g.column_iter(0).collect();
+    /// assert_eq!(column, vec![0.0, 0.0, 0.5]);
+    /// ```
+    pub fn row(&self, node: usize) -> Cow<'_, [f32]> {
+        if node >= self.n_nodes {
+            panic!("[DGraph::row] Error: The node {} is not valid!", node);
+        }
+        adj_index::present_row(&self.adj_mat[node])
+    }
+
+    /// Returns an iterator over the column of the node in the adjacency matrix: the
+    /// weight of the edge from each node to the node, with `0.0` for the missing edges.
+    /// These are the weights of the in edges.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    pub fn column_iter(&self, node: usize) -> impl Iterator<Item = f32> + '_ {
+        if node >= self.n_nodes {
+            panic!(
+                "[DGraph::column_iter] Error: The node {} is not valid!",
+                node
+            );
+        }
+        self.adj_mat
+            .iter()
+            .map(move |row| adj_index::present_weight(row[node]))
+    }
+
+    /// Returns an estimation of the bytes used by the graph: the struct itself and the
+    /// allocated capacity of the adjacency matrix and the adjacency lists. The dense
+    /// adjacency matrix takes `4 * n_nodes^2` bytes, so it dominates on big graphs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::DGraph;
+    /// let g = DGraph::from_edges(100, vec![(0, 1)]);
+    /// assert!(g.memory_usage() >= 4 * 100 * 100);
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        mem::size_of::<DGraph>()
+            + graph::nested_vec_bytes(&self.adj_mat)
+            + graph::nested_vec_bytes(&self.adj_list)
+            + self.node_weights.capacity() * mem::size_of::<f32>()
+    }
+
+    /// Shrinks the capacity of the adjacency matrix and the adjacency lists to fit their
+    /// contents, releasing the memory reserved by previous insertions and removals.
+    pub fn shrink_to_fit(&mut self) {
+        self.adj_mat.iter_mut().for_each(|row| row.shrink_to_fit());
+        self.adj_mat.shrink_to_fit();
+        self.adj_list
+            .iter_mut()
+            .for_each(|list| list.shrink_to_fit());
+        self.adj_list.shrink_to_fit();
+        self.node_weights.shrink_to_fit();
+    }
+
+    //--------- Private functions ---------
+
+    // Builds the graph from the edges with the policies, naming `operation` in the errors
+    fn from_merged_edges(
+        operation: &'static str,
+        n_nodes: usize,
+        edges: Vec<(usize, usize, f32)>,
+        duplicates: DuplicatePolicy,
+        self_loops: SelfLoopPolicy,
+    ) -> Result<DGraph, GraphError> {
+        let mut g = DGraph::from_adjacency_matrix(vec![vec![0.0; n_nodes]; n_nodes]);
+        let mut merger = EdgeMerger::new(operation, duplicates, self_loops);
+        for (node1, node2, weight) in edges {
+            if let Some(node) = [node1, node2].iter().find(|n| **n >= n_nodes) {
+                return Err(GraphError::InvalidNode {
+                    operation,
+                    node: *node,
+                    n_nodes,
+                });
+            }
+            merger.add(&mut g, node1, node2, weight)?;
+        }
+        Ok(g)
+    }
 }
 
 impl Graph for DGraph {
@@ -249,9 +480,11 @@ impl Graph for DGraph {
         (0..self.n_nodes).collect()
     }
 
-    /// Returns a reference to the bidimensional vector of `f32` with the adjacency
-    /// matrix of the graph. The `f32` values are the weights of the edges, and a
-    /// value of `0.0` means that there is no edge between those nodes.
+    /// Returns the bidimensional vector of `f32` with the adjacency matrix of the graph.
+    /// The `f32` values are the weights of the edges, and a value of `0.0` means that
+    /// there is no edge between those nodes. The matrix is borrowed, unless it has
+    /// weights that are zero within the crate tolerance: a copy with those weights set
+    /// to `0.0` is returned instead, so the matrix always agrees with `get_edge`.
     ///
     /// # Examples
     ///
@@ -266,10 +499,18 @@ impl Graph for DGraph {
     ///     vec![0.0, 0.0, 1.0],
     ///     vec![0.0, 0.0, 1.0],
     /// ];
-    /// assert_eq!(g_adj_mat, &test_mat);
+    /// assert_eq!(*g_adj_mat, test_mat);
     /// ```
-    fn get_adjacency_matrix<'a>(&'a self) -> &'a Vec<Vec<f32>> {
-        &self.adj_mat
+    fn get_adjacency_matrix(&self) -> Cow<'_, Vec<Vec<f32>>> {
+        match adj_index::present_matrix(&self.adj_mat) {
+            Some(adj_mat) => Cow::Owned(adj_mat),
+            None => Cow::Borrowed(&self.adj_mat),
+        }
+    }
+
+    /// Returns `true`, the edges of a `DGraph` are directed.
+    fn is_directed(&self) -> bool {
+        true
     }
 
     /// Gets the weight of the edge from the node `src` to `dest`. If the graph is not weighted
@@ -312,14 +553,24 @@ impl Graph for DGraph {
                 dest
             );
         }
-        if self.adj_mat[src][dest] != 0.0 {
-            return Some(self.adj_mat[src][dest]);
+        if !float::is_zero(self.adj_mat[src][dest]) {
+            Some(self.adj_mat[src][dest])
         } else {
-            return None;
+            None
         }
     }
 
-    /// Adds a node to the graph without any edge.
+    /// Returns a sorted vector with the nodes reachable from `node` through one edge.
+    /// Same as `DGraph::get_successors_of`.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    fn get_out_neighbors_of(&self, node: usize) -> Vec<usize> {
+        self.get_successors_of(node)
+    }
+
+    /// Adds a node to the graph without any edge and returns its index.
     ///
     /// # Examples
     ///
@@ -331,12 +582,32 @@ impl Graph for DGraph {
     /// let g_nodes = g.get_nodes();
     /// assert_eq!(g_nodes, vec![0, 1]);
     /// ```
-    fn add_node(&mut self) {
+    fn add_node(&mut self) -> usize {
         for node in &mut self.adj_mat {
             node.push(0.0); // add a new value for setting the edges to the new node
         }
         self.n_nodes += 1;
         self.adj_mat.push(vec![0.0; self.n_nodes]); // add the new node edges vector
+        self.adj_list.push(vec![]);
+        self.node_weights.push(1.0);
+        self.n_nodes - 1
+    }
+
+    /// Adds `count` nodes to the graph without any edge and returns the range of their
+    /// indices. Each row of the adjacency matrix is resized once, instead of once per
+    /// node as with repeated calls to `add_node`.
+    fn add_nodes(&mut self, count: usize) -> Range<usize> {
+        let start = self.n_nodes;
+        self.n_nodes += count;
+        for row in &mut self.adj_mat {
+            row.reserve_exact(count);
+            row.resize(self.n_nodes, 0.0);
+        }
+        self.adj_mat.reserve_exact(count);
+        self.adj_mat.resize(self.n_nodes, vec![0.0; self.n_nodes]);
+        self.adj_list.resize(self.n_nodes, vec![]);
+        self.node_weights.resize(self.n_nodes, 1.0);
+        start..self.n_nodes
     }
 
     /// Sets a directed edge from the node `src` to the node `dest`.
@@ -375,6 +646,7 @@ impl Graph for DGraph {
             );
         }
         self.adj_mat[src][dest] = 1.0;
+        adj_index::update(&mut self.adj_list[src], dest, 1.0);
     }
 
     /// Sets a directed edge from the node `src` to the node `dest`.
@@ -414,6 +686,56 @@ impl Graph for DGraph {
             );
         }
         self.adj_mat[src][dest] = weight;
+        adj_index::update(&mut self.adj_list[src], dest, weight);
+    }
+
+    /// Returns the weight of the node, `1.0` if it was not set with `set_node_weight`.
+    ///
+    /// # Panics
+    ///
+    /// * If the node is not valid.
+    fn get_node_weight(&self, node: usize) -> f32 {
+        match self.node_weights.get(node) {
+            Some(weight) => *weight,
+            None => panic!(
+                "[DGraph::get_node_weight] Error: The node {} is not valid!",
+                node
+            ),
+        }
+    }
+
+    /// Sets the weight of the node passed as a parameter.
+    ///
+    /// # Panics
+    ///
+    /// * If the node is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, DGraph};
+    /// let mut g = DGraph::from_edges(2, vec![(0, 1)]);
+    /// g.set_node_weight(1, 4.5);
+    /// assert_eq!(g.get_node_weight(1), 4.5);
+    /// assert_eq!(g.get_node_weight(0), 1.0);
+    /// ```
+    fn set_node_weight(&mut self, node: usize, weight: f32) {
+        match self.node_weights.get_mut(node) {
+            Some(current) => *current = weight,
+            None => panic!(
+                "[DGraph::set_node_weight] Error: The node {} is not valid!",
+                node
+            ),
+        }
+    }
+}
+
+impl fmt::Debug for DGraph {
+    /// Shows the number of nodes and the edges of the graph. The alternate format
+    /// (`{:#?}`) shows the adjacency matrix instead, one row per line, for graphs with up
+    /// to 16 nodes.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        graph::fmt_debug(self, "DGraph", f)
     }
 }
 
@@ -421,11 +743,11 @@ impl fmt::Display for DGraph {
     /// Shows the info of the graph.
     /// The edges are represented in the format `src -(weigh)-> dest`.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Graph(edges=[\n")?;
+        writeln!(f, "Graph(edges=[")?;
         for (src, node) in self.adj_mat.iter().enumerate() {
             for (dest, weight) in node.iter().enumerate() {
-                if *weight != 0.0 {
-                    write!(f, "({})--{}->({}),\n", src, weight, dest)?;
+                if !float::is_zero(*weight) {
+                    writeln!(f, "({})--{}->({}),", src, weight, dest)?;
                 }
             }
         }
@@ -701,7 +1023,7 @@ mod tests {
             vec![0.0, 0.0, 1.0],
             vec![0.0, 0.0, 1.0],
         ];
-        assert_eq!(g_adj_mat, &test_mat);
+        assert_eq!(*g_adj_mat, test_mat);
     }
 
     #[test]
@@ -782,6 +1104,46 @@ mod tests {
         let _edge_0_3 = g.get_edge(0, 3).expect("The edge doesn't exist");
     }
 
+    #[test]
+    fn get_edge_check_near_zero_weight() {
+        let n_nodes = 2;
+        let adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+        let mut g = DGraph::from_adjacency_matrix(adj_mat);
+        g.add_weighted_edge(0, 1, 0.1 + 0.2 - 0.3); // accumulated error, not a real edge
+        assert_eq!(g.get_edge(0, 1), None);
+    }
+
+    #[test]
+    fn get_edges_check_values() {
+        let n_nodes = 3;
+        let edges = vec![(1, 0, 2.0), (2, 1, 1.5), (0, 1, 0.5)];
+        let g = DGraph::from_weighted_edges(n_nodes, edges);
+        assert_eq!(g.get_edges(), vec![(0, 1, 0.5), (1, 0, 2.0), (2, 1, 1.5)]);
+    }
+
+    #[test]
+    fn heaviest_and_lightest_neighbor_of_check_values() {
+        let n_nodes = 3;
+        let edges = vec![(0, 1, 2.0), (0, 2, 3.0), (2, 0, 9.0)];
+        let g = DGraph::from_weighted_edges(n_nodes, edges);
+        assert_eq!(g.heaviest_neighbor_of(0), Some((2, 3.0)));
+        assert_eq!(g.lightest_neighbor_of(0), Some((1, 2.0)));
+        assert_eq!(g.heaviest_neighbor_of(1), None); // only outgoing edges count
+    }
+
+    #[test]
+    fn adj_list_check_status_after_mutations() {
+        let n_nodes = 3;
+        let edges = vec![(0, 2), (1, 2)];
+        let mut g = DGraph::from_edges(n_nodes, edges);
+        g.add_edge(0, 1);
+        g.add_weighted_edge(1, 2, 0.0); // removes the edge
+        g.add_node();
+        g.add_weighted_edge(3, 0, 2.5);
+        assert_eq!(g.adj_list, vec![vec![1, 2], vec![], vec![], vec![0]]);
+        assert_eq!(g.get_successors_of(0), vec![1, 2]);
+    }
+
     #[test]
     fn add_node_check_status() {
         let n_nodes = 3;
@@ -797,6 +1159,20 @@ mod tests {
         assert_eq!(g_nodes, vec![0, 1, 2, 3, 4]);
     }
 
+    #[test]
+    fn shrink_to_fit_reduces_memory_usage() {
+        let mut g = DGraph::new();
+        for node in 0..50 {
+            g.add_node();
+            g.add_edge(node, 0);
+        }
+        let before = g.memory_usage();
+        g.shrink_to_fit();
+        assert!(g.memory_usage() < before);
+        assert!(g.memory_usage() >= 4 * 50 * 50);
+        assert_eq!(g.get_predecessors_of(0).len(), 50);
+    }
+
     #[test]
     fn add_edge_check_status() {
         let n_nodes = 3;
@@ -856,4 +1232,23 @@ mod tests {
         let mut g = DGraph::from_adjacency_matrix(adj_mat);
         g.add_weighted_edge(2, 3, 2.0);
     }
+
+    #[test]
+    fn constructor_from_matrix_with_nan_sentinel() {
+        let nan = f32::NAN;
+        let g = DGraph::from_matrix_with(vec![vec![nan, 1.5], vec![0.5, nan]], nan);
+        assert_eq!(g.get_edges(), vec![(0, 1, 1.5), (1, 0, 0.5)]);
+        let mat = g.to_matrix_with(-1.0);
+        assert_eq!(mat, vec![vec![-1.0, 1.5], vec![0.5, -1.0]]);
+    }
+
+    #[test]
+    fn debug_check_default_format() {
+        let g = DGraph::from_edges(2, vec![(1, 0)]);
+        assert_eq!(
+            format!("{:?}", g),
+            "DGraph { n_nodes: 2, edges: [(1, 0, 1.0)] }"
+        );
+        assert_eq!(g.clone(), g);
+    }
 }
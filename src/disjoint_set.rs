@@ -0,0 +1,186 @@
+/// The `DisjointSet` struct is a union-find over the elements `0` to `len-1`: it keeps a
+/// partition of the elements into disjoint sets, merged with `union` and identified by a
+/// representative element returned by `find`. With path compression and union by rank,
+/// any sequence of operations runs in almost linear time. It is the structure behind the
+/// component counts of the crate, like the connected components of a growing set of
+/// edges.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::DisjointSet;
+/// let mut sets = DisjointSet::new(5);
+/// assert!(sets.union(0, 1));
+/// assert!(sets.union(3, 4));
+/// assert!(!sets.union(1, 0)); // already in the same set
+/// assert!(sets.same_set(0, 1));
+/// assert!(!sets.same_set(1, 3));
+/// assert_eq!(sets.get_n_sets(), 3);
+/// assert_eq!(sets.get_set_size(4), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    n_sets: usize,
+}
+
+impl DisjointSet {
+    /// Creates a `DisjointSet` with `len` elements, each one in a set of its own.
+    pub fn new(len: usize) -> DisjointSet {
+        DisjointSet {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            size: vec![1; len],
+            n_sets: len,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn get_n_sets(&self) -> usize {
+        self.n_sets
+    }
+
+    /// Returns the representative of the set of the element, the same for all the
+    /// elements of the set until it is merged with another one. The path to the
+    /// representative is compressed on the way.
+    ///
+    /// # Panics
+    ///
+    /// * If the element is not valid.
+    pub fn find(&mut self, element: usize) -> usize {
+        self.check_element(element, "find");
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Point every element of the path directly to the root
+        let mut current = element;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// Merges the sets of the elements passed as parameters, attaching the tree of lower
+    /// rank below the other one. Returns `false` if they were already in the same set.
+    ///
+    /// # Panics
+    ///
+    /// * If some element is not valid.
+    pub fn union(&mut self, element1: usize, element2: usize) -> bool {
+        self.check_element(element1, "union");
+        self.check_element(element2, "union");
+        let (root1, root2) = (self.find(element1), self.find(element2));
+        if root1 == root2 {
+            return false;
+        }
+        let (child, root) = if self.rank[root1] < self.rank[root2] {
+            (root1, root2)
+        } else {
+            (root2, root1)
+        };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+        self.n_sets -= 1;
+        true
+    }
+
+    /// Returns `true` if the elements passed as parameters are in the same set.
+    ///
+    /// # Panics
+    ///
+    /// * If some element is not valid.
+    pub fn same_set(&mut self, element1: usize, element2: usize) -> bool {
+        self.find(element1) == self.find(element2)
+    }
+
+    /// Returns the number of elements of the set of the element.
+    ///
+    /// # Panics
+    ///
+    /// * If the element is not valid.
+    pub fn get_set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    //--------- Private functions ---------
+
+    fn check_element(&self, element: usize, method: &str) {
+        if element >= self.parent.len() {
+            panic!(
+                "[DisjointSet::{}] Error: The element {} is not valid!",
+                method, element
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_matches_naive_labels() {
+        let mut rng = crate::rng::Rng::new(35);
+        for _ in 0..30 {
+            let len = 1 + rng.gen_range(20);
+            let mut sets = DisjointSet::new(len);
+            // Naive partition: the label of the set of each element
+            let mut labels: Vec<usize> = (0..len).collect();
+            for _ in 0..rng.gen_range(30) {
+                let (a, b) = (rng.gen_range(len), rng.gen_range(len));
+                let (label_a, label_b) = (labels[a], labels[b]);
+                assert_eq!(sets.union(a, b), label_a != label_b);
+                labels
+                    .iter_mut()
+                    .filter(|l| **l == label_b)
+                    .for_each(|l| *l = label_a);
+                for element in 0..len {
+                    let expected = labels.iter().filter(|l| **l == labels[element]).count();
+                    assert_eq!(sets.get_set_size(element), expected);
+                    assert_eq!(sets.same_set(a, element), labels[a] == labels[element]);
+                }
+            }
+            let mut distinct = labels.clone();
+            distinct.sort_unstable();
+            distinct.dedup();
+            assert_eq!(sets.get_n_sets(), distinct.len());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn find_panic_not_valid_element() {
+        DisjointSet::new(2).find(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "[DisjointSet::union] Error: The element 2 is not valid!")]
+    fn union_panic_not_valid_first_element() {
+        DisjointSet::new(2).union(2, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[DisjointSet::union] Error: The element 3 is not valid!")]
+    fn union_panic_not_valid_second_element() {
+        DisjointSet::new(2).union(0, 3);
+    }
+}
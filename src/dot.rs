@@ -0,0 +1,161 @@
+//! # Dot
+//!
+//! `dot` is a `graphst` module to export graphs to the DOT language of Graphviz, to
+//! visualize them with tools like `dot -Tsvg`. The nodes are named by their index, and
+//! each edge is labeled with its weight.
+
+use crate::graph::Graph;
+use std::fmt::Write;
+
+// Colors of the clusters, reused when there are more groups than colors
+const PALETTE: [&str; 10] = [
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f",
+    "#bcbd22", "#17becf",
+];
+
+/// Returns the graph in the DOT language: a `graph` for undirected graphs and a `digraph`
+/// for directed graphs.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::dot::to_dot;
+/// use graphst::DGraph;
+/// let g = DGraph::from_weighted_edges(2, vec![(0, 1, 2.5)]);
+/// assert_eq!(to_dot(&g), "digraph {\n    0;\n    1;\n    0 -> 1 [label=\"2.5\"];\n}\n");
+/// ```
+pub fn to_dot<G>(g: &G) -> String
+where
+    G: Graph,
+{
+    let mut dot = header(g);
+    for node in g.get_nodes() {
+        writeln!(dot, "    {};", node).expect("Writing to a String can't fail");
+    }
+    write_edges(g, &mut dot);
+    dot.push_str("}\n");
+    dot
+}
+
+/// Same as `to_dot` but placing the nodes of each group in a Graphviz cluster, filled
+/// with a different color, to visualize the output of a community detection. The groups
+/// are given as the group of each node, and the clusters are sorted by group.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+/// * `groups` - A slice with the group of each node.
+///
+/// # Panics
+///
+/// * If `groups` doesn't have a value for each node.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::dot::to_dot_clustered;
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3)]);
+/// let dot = to_dot_clustered(&g, &[0, 0, 1, 1]);
+/// assert!(dot.contains("subgraph cluster_0 {"));
+/// assert!(dot.contains("subgraph cluster_1 {"));
+/// assert!(dot.contains("    1 -- 2 [label=\"1\"];"));
+/// ```
+pub fn to_dot_clustered<G>(g: &G, groups: &[usize]) -> String
+where
+    G: Graph,
+{
+    if groups.len() != g.get_n_nodes() {
+        panic!("[dot::to_dot_clustered] Error: The number of groups and nodes don't match!");
+    }
+    let mut sorted_groups = groups.to_vec();
+    sorted_groups.sort_unstable();
+    sorted_groups.dedup();
+    let mut dot = header(g);
+    for (idx, group) in sorted_groups.iter().enumerate() {
+        let color = PALETTE[idx % PALETTE.len()];
+        writeln!(dot, "    subgraph cluster_{} {{", group).expect("Writing to a String can't fail");
+        writeln!(dot, "        label=\"{}\";", group).expect("Writing to a String can't fail");
+        writeln!(dot, "        style=filled;").expect("Writing to a String can't fail");
+        writeln!(dot, "        color=\"{}40\";", color).expect("Writing to a String can't fail");
+        writeln!(dot, "        node [style=filled, fillcolor=\"{}\"];", color)
+            .expect("Writing to a String can't fail");
+        for node in g.get_nodes().into_iter().filter(|n| groups[*n] == *group) {
+            writeln!(dot, "        {};", node).expect("Writing to a String can't fail");
+        }
+        dot.push_str("    }\n");
+    }
+    write_edges(g, &mut dot);
+    dot.push_str("}\n");
+    dot
+}
+
+//--------- Private functions ---------
+
+pub(crate) fn header<G>(g: &G) -> String
+where
+    G: Graph,
+{
+    if g.is_directed() {
+        String::from("digraph {\n")
+    } else {
+        String::from("graph {\n")
+    }
+}
+
+fn write_edges<G>(g: &G, dot: &mut String)
+where
+    G: Graph,
+{
+    write_edges_with(g, dot, |_, _| String::new());
+}
+
+// Writes the edges with the extra attributes returned by `style` for each edge
+pub(crate) fn write_edges_with<G, F>(g: &G, dot: &mut String, style: F)
+where
+    G: Graph,
+    F: Fn(usize, usize) -> String,
+{
+    let edge_op = if g.is_directed() { "->" } else { "--" };
+    for (node1, node2, weight) in g.get_edges() {
+        writeln!(
+            dot,
+            "    {} {} {} [label=\"{}\"{}];",
+            node1,
+            edge_op,
+            node2,
+            weight,
+            style(node1, node2)
+        )
+        .expect("Writing to a String can't fail");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UGraph;
+
+    #[test]
+    fn to_dot_check_undirected() {
+        let g = UGraph::from_edges(3, vec![(0, 1), (2, 2)]);
+        let expected = "graph {\n    0;\n    1;\n    2;\n    0 -- 1 [label=\"1\"];\n    2 -- 2 [label=\"1\"];\n}\n";
+        assert_eq!(to_dot(&g), expected);
+    }
+
+    #[test]
+    fn to_dot_clustered_uses_distinct_colors() {
+        let g = UGraph::from_edges(3, vec![(0, 1)]);
+        let dot = to_dot_clustered(&g, &[7, 3, 7]);
+        let cluster_3 = dot.find("subgraph cluster_3").unwrap();
+        let cluster_7 = dot.find("subgraph cluster_7").unwrap();
+        assert!(cluster_3 < cluster_7);
+        assert!(dot.contains(PALETTE[0]) && dot.contains(PALETTE[1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "don't match")]
+    fn to_dot_clustered_panic_groups_length() {
+        to_dot_clustered(&UGraph::from_edges(2, vec![(0, 1)]), &[0]);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{float, Graph};
+use std::collections::HashMap;
+
+/// The `EdgeData` struct wraps a graph (that implements `Graph`) to store a value of a
+/// user-defined type `E` on each edge, next to its weight. A struct with several fields
+/// keeps all the attributes of an edge (capacity, cost, label...) in a single graph,
+/// without parallel maps kept in sync by hand. The edges of undirected graphs share the
+/// same value in both directions.
+///
+/// `EdgeData` also implements `Graph`, so the algorithms of the crate run on it using the
+/// weights. The edges added through the `Graph` methods get `E::default()`, and removing
+/// an edge (setting its weight to `0.0`) removes its value.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::dijkstra;
+/// use graphst::{EdgeData, Graph, UGraph};
+///
+/// #[derive(Clone, Debug, Default, PartialEq)]
+/// struct Road {
+///     capacity: u32,
+///     name: String,
+/// }
+///
+/// let mut g: EdgeData<UGraph, Road> = EdgeData::with_nodes(3);
+/// g.add_edge_with(0, 1, 2.5, Road { capacity: 100, name: "A1".to_string() });
+/// g.add_edge_with(1, 2, 1.0, Road { capacity: 40, name: "B2".to_string() });
+/// assert_eq!(g.get_edge_data(1, 0).unwrap().name, "A1");
+/// g.get_edge_data_mut(2, 1).unwrap().capacity -= 10;
+/// assert_eq!(g.get_edge_data(1, 2).unwrap().capacity, 30);
+/// assert_eq!(dijkstra(&g, 0), vec![0.0, 2.5, 3.5]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EdgeData<G, E> {
+    graph: G,
+    data: HashMap<(usize, usize), E>,
+}
+
+impl<G, E> EdgeData<G, E>
+where
+    G: Graph,
+{
+    /// Creates an `EdgeData` from a graph, where every existing edge gets `E::default()`.
+    pub fn from_graph(graph: G) -> EdgeData<G, E>
+    where
+        E: Default,
+    {
+        let mut g = EdgeData {
+            graph,
+            data: HashMap::new(),
+        };
+        for (node1, node2, _) in g.graph.get_edges() {
+            let key = g.key(node1, node2);
+            g.data.insert(key, E::default());
+        }
+        g
+    }
+
+    /// Creates an `EdgeData` with `n_nodes` nodes and no edges.
+    pub fn with_nodes(n_nodes: usize) -> EdgeData<G, E>
+    where
+        G: Default,
+    {
+        let mut graph = G::default();
+        graph.add_nodes(n_nodes);
+        EdgeData {
+            graph,
+            data: HashMap::new(),
+        }
+    }
+
+    /// Returns a reference to the underlying graph.
+    pub fn get_graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Adds an edge with the weight and the value passed as parameters between the nodes
+    /// passed as parameters, replacing the previous edge if it exists.
+    ///
+    /// # Panics
+    ///
+    /// * If some node is not valid or the weight is `0.0` (no edge).
+    pub fn add_edge_with(&mut self, node1: usize, node2: usize, weight: f32, value: E) {
+        if float::is_zero(weight) {
+            panic!(
+                "[EdgeData::add_edge_with] Error: The weight {} is not valid!",
+                weight
+            );
+        }
+        self.graph.add_weighted_edge(node1, node2, weight);
+        let key = self.key(node1, node2);
+        self.data.insert(key, value);
+    }
+
+    /// Returns the value of the edge between the nodes passed as parameters, or `None` if
+    /// there is no edge.
+    pub fn get_edge_data(&self, node1: usize, node2: usize) -> Option<&E> {
+        self.data.get(&self.key(node1, node2))
+    }
+
+    /// Returns a mutable reference to the value of the edge between the nodes passed as
+    /// parameters, or `None` if there is no edge.
+    pub fn get_edge_data_mut(&mut self, node1: usize, node2: usize) -> Option<&mut E> {
+        let key = self.key(node1, node2);
+        self.data.get_mut(&key)
+    }
+
+    /// Returns the edges of the graph with their values, as `(node1, node2, weight, value)`
+    /// tuples in the order of `get_edges`.
+    pub fn get_edges_with_data(&self) -> Vec<(usize, usize, f32, &E)> {
+        self.graph
+            .get_edges()
+            .into_iter()
+            .filter_map(|(node1, node2, weight)| {
+                self.get_edge_data(node1, node2)
+                    .map(|value| (node1, node2, weight, value))
+            })
+            .collect()
+    }
+
+    //--------- Private functions ---------
+
+    // The undirected edges are stored once, with the lowest node first
+    fn key(&self, node1: usize, node2: usize) -> (usize, usize) {
+        if self.graph.is_directed() || node1 <= node2 {
+            (node1, node2)
+        } else {
+            (node2, node1)
+        }
+    }
+}
+
+impl<G, E> Graph for EdgeData<G, E>
+where
+    G: Graph,
+    E: Default,
+{
+    fn get_n_nodes(&self) -> usize {
+        self.graph.get_n_nodes()
+    }
+
+    fn get_nodes(&self) -> Vec<usize> {
+        self.graph.get_nodes()
+    }
+
+    fn is_directed(&self) -> bool {
+        self.graph.is_directed()
+    }
+
+    fn get_edge(&self, node1: usize, node2: usize) -> Option<f32> {
+        self.graph.get_edge(node1, node2)
+    }
+
+    fn get_out_neighbors_of(&self, node: usize) -> Vec<usize> {
+        self.graph.get_out_neighbors_of(node)
+    }
+
+    fn get_edges(&self) -> Vec<(usize, usize, f32)> {
+        self.graph.get_edges()
+    }
+
+    fn get_node_weight(&self, node: usize) -> f32 {
+        self.graph.get_node_weight(node)
+    }
+
+    fn set_node_weight(&mut self, node: usize, weight: f32) {
+        self.graph.set_node_weight(node, weight);
+    }
+
+    fn add_node(&mut self) -> usize {
+        self.graph.add_node()
+    }
+
+    fn add_edge(&mut self, node1: usize, node2: usize) {
+        self.add_weighted_edge(node1, node2, 1.0);
+    }
+
+    /// Sets the weight of the edge, keeping its value if it already exists or adding
+    /// `E::default()` otherwise. A weight of `0.0` removes the edge and its value.
+    fn add_weighted_edge(&mut self, node1: usize, node2: usize, weight: f32) {
+        self.graph.add_weighted_edge(node1, node2, weight);
+        let key = self.key(node1, node2);
+        if self.graph.get_edge(node1, node2).is_some() {
+            self.data.entry(key).or_default();
+        } else {
+            self.data.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DGraph, EdgeData, Graph, UGraph};
+
+    #[test]
+    fn add_weighted_edge_keeps_and_removes_data() {
+        let mut g: EdgeData<DGraph, &str> = EdgeData::with_nodes(2);
+        g.add_edge_with(0, 1, 1.0, "a");
+        g.add_weighted_edge(0, 1, 3.0);
+        assert_eq!(g.get_edge_data(0, 1), Some(&"a"));
+        assert_eq!(g.get_edge_data(1, 0), None);
+        g.add_edge(1, 0);
+        assert_eq!(
+            g.get_edges_with_data(),
+            vec![(0, 1, 3.0, &"a"), (1, 0, 1.0, &"")]
+        );
+        g.add_weighted_edge(0, 1, 0.0);
+        assert_eq!(g.get_edge_data(0, 1), None);
+    }
+
+    #[test]
+    fn from_graph_check_undirected_keys() {
+        let g: EdgeData<UGraph, u32> =
+            EdgeData::from_graph(UGraph::from_edges(3, vec![(2, 0), (1, 2)]));
+        assert_eq!(g.get_edge_data(0, 2), Some(&0));
+        assert_eq!(g.get_edge_data(2, 1), Some(&0));
+        assert_eq!(g.get_edge_data(0, 1), None);
+    }
+}
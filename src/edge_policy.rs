@@ -0,0 +1,326 @@
+use crate::graph::Graph;
+use crate::GraphError;
+use std::collections::HashMap;
+
+/// The `DuplicatePolicy` enum lists how the constructors and readers that take a list of
+/// edges (the `_with` variants of the graph constructors, `text::from_text_with` and
+/// `import::from_weighted_id_edges_with`) handle an edge that appears more than once (in undirected graphs `(a, b)` and
+/// `(b, a)` are the same edge), which is common in real datasets. A merged weight of
+/// `0.0` (e.g. summing `1.0` and `-1.0`) removes the edge, but it still counts as seen, so
+/// its next appearance is merged as a repetition.
+///
+/// The delta streams (`delta::apply_deltas`) don't take the policies, because adding an
+/// edge that already exists is how a stream updates its weight, and neither do the
+/// `Hypergraph` builders, where each hyperedge is a set of nodes (without self-loops) and
+/// two hyperedges over the same nodes are different hyperedges.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Fails with a `GraphError::DuplicateEdge` error.
+    Error,
+    /// Keeps the weight of the first appearance.
+    KeepFirst,
+    /// Keeps the weight of the last appearance.
+    KeepLast,
+    /// Adds up the weights of all the appearances (with unit weights, the multiplicity).
+    Sum,
+    /// Keeps the lowest weight.
+    Min,
+    /// Keeps the highest weight.
+    Max,
+}
+
+impl DuplicatePolicy {
+    /// Returns the weight of an edge that already had the weight `old` when it appears
+    /// again with the weight `new`, or `None` with `DuplicatePolicy::Error`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::DuplicatePolicy;
+    /// assert_eq!(DuplicatePolicy::Sum.merge(2.0, 1.5), Some(3.5));
+    /// assert_eq!(DuplicatePolicy::KeepFirst.merge(2.0, 1.5), Some(2.0));
+    /// assert_eq!(DuplicatePolicy::Error.merge(2.0, 1.5), None);
+    /// ```
+    pub fn merge(self, old: f32, new: f32) -> Option<f32> {
+        match self {
+            DuplicatePolicy::Error => None,
+            DuplicatePolicy::KeepFirst => Some(old),
+            DuplicatePolicy::KeepLast => Some(new),
+            DuplicatePolicy::Sum => Some(old + new),
+            DuplicatePolicy::Min => Some(old.min(new)),
+            DuplicatePolicy::Max => Some(old.max(new)),
+        }
+    }
+}
+
+/// The `SelfLoopPolicy` enum lists how the constructors and readers that take a list of
+/// edges handle an edge from a node to itself. Some measures, like the clustering
+/// coefficient, or the minimum spanning trees are not defined for self-loops, so they can
+/// be dropped (or rejected) while the data is loaded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelfLoopPolicy {
+    /// Adds the self-loops like any other edge.
+    Allow,
+    /// Skips the self-loops. Their nodes are still added to the graph.
+    Ignore,
+    /// Fails with a `GraphError::SelfLoop` error.
+    Error,
+}
+
+// Adds the edges of a list to a graph, applying the self-loop policy and merging the
+// repeated edges with the duplicate policy. The merged weights are tracked apart from the
+// graph because a weight of 0.0 is not stored as an edge, and the policies must still
+// see it. The errors name the caller `operation`
+pub(crate) struct EdgeMerger {
+    operation: &'static str,
+    duplicates: DuplicatePolicy,
+    self_loops: SelfLoopPolicy,
+    seen: HashMap<(usize, usize), f32>,
+}
+
+impl EdgeMerger {
+    pub(crate) fn new(
+        operation: &'static str,
+        duplicates: DuplicatePolicy,
+        self_loops: SelfLoopPolicy,
+    ) -> EdgeMerger {
+        EdgeMerger {
+            operation,
+            duplicates,
+            self_loops,
+            seen: HashMap::new(),
+        }
+    }
+
+    // Returns the weight of the edge after merging it, or `None` if the self-loop policy
+    // skips it. The graph is only used for its size and direction
+    pub(crate) fn merge<G>(
+        &mut self,
+        g: &G,
+        node1: usize,
+        node2: usize,
+        weight: f32,
+    ) -> Result<Option<f32>, GraphError>
+    where
+        G: Graph,
+    {
+        if node1 == node2 {
+            match self.self_loops {
+                SelfLoopPolicy::Allow => (),
+                SelfLoopPolicy::Ignore => return Ok(None),
+                SelfLoopPolicy::Error => {
+                    return Err(GraphError::SelfLoop {
+                        operation: self.operation,
+                        node: node1,
+                        n_nodes: g.get_n_nodes(),
+                    })
+                }
+            }
+        }
+        let key = if g.is_directed() || node1 <= node2 {
+            (node1, node2)
+        } else {
+            (node2, node1)
+        };
+        let weight = match self.seen.get(&key) {
+            Some(old) => self
+                .duplicates
+                .merge(*old, weight)
+                .ok_or(GraphError::DuplicateEdge {
+                    operation: self.operation,
+                    node1,
+                    node2,
+                    n_nodes: g.get_n_nodes(),
+                })?,
+            None => weight,
+        };
+        self.seen.insert(key, weight);
+        Ok(Some(weight))
+    }
+
+    // Merges the edge and writes its weight to the graph
+    pub(crate) fn add<G>(
+        &mut self,
+        g: &mut G,
+        node1: usize,
+        node2: usize,
+        weight: f32,
+    ) -> Result<(), GraphError>
+    where
+        G: Graph,
+    {
+        if let Some(weight) = self.merge(g, node1, node2, weight)? {
+            g.add_weighted_edge(node1, node2, weight);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DGraph, DagGraph, UGraph};
+
+    #[test]
+    fn from_weighted_edges_with_check_policies() {
+        let edges = vec![(0, 1, 2.0), (2, 2, 1.0), (1, 0, 4.0), (0, 1, 3.0)];
+        let expected = [
+            (DuplicatePolicy::KeepFirst, 2.0),
+            (DuplicatePolicy::KeepLast, 3.0),
+            (DuplicatePolicy::Sum, 9.0),
+            (DuplicatePolicy::Min, 2.0),
+            (DuplicatePolicy::Max, 4.0),
+        ];
+        for (policy, weight) in expected.iter() {
+            let g =
+                UGraph::from_weighted_edges_with(3, edges.clone(), *policy, SelfLoopPolicy::Allow)
+                    .unwrap();
+            assert_eq!(g.get_edges(), vec![(0, 1, *weight), (2, 2, 1.0)]);
+        }
+        let g =
+            DGraph::from_weighted_edges_with(3, edges, DuplicatePolicy::Sum, SelfLoopPolicy::Allow)
+                .unwrap();
+        assert_eq!(g.get_edges(), vec![(0, 1, 5.0), (1, 0, 4.0), (2, 2, 1.0)]);
+    }
+
+    #[test]
+    fn from_weighted_edges_with_check_errors_and_removal() {
+        let edges = vec![(0, 1, 1.0), (0, 1, -1.0)];
+        let g =
+            DGraph::from_weighted_edges_with(2, edges, DuplicatePolicy::Sum, SelfLoopPolicy::Allow)
+                .unwrap();
+        assert_eq!(g.get_edges(), vec![]);
+        let error = UGraph::from_weighted_edges_with(
+            2,
+            vec![(0, 2, 1.0)],
+            DuplicatePolicy::Sum,
+            SelfLoopPolicy::Allow,
+        );
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "[UGraph::from_weighted_edges_with] The node 2 is not valid for a graph with 2 nodes"
+        );
+    }
+
+    #[test]
+    fn from_weighted_edges_with_check_zero_weights_count_as_seen() {
+        // The first appearance (or the merge of the first two) leaves no edge in the graph
+        let zero_first = vec![(0, 1, 0.0), (1, 0, 2.0)];
+        let error = UGraph::from_weighted_edges_with(
+            2,
+            zero_first.clone(),
+            DuplicatePolicy::Error,
+            SelfLoopPolicy::Allow,
+        );
+        assert!(matches!(
+            error,
+            Err(GraphError::DuplicateEdge {
+                node1: 1,
+                node2: 0,
+                ..
+            })
+        ));
+        let g = UGraph::from_weighted_edges_with(
+            2,
+            zero_first,
+            DuplicatePolicy::KeepFirst,
+            SelfLoopPolicy::Allow,
+        )
+        .unwrap();
+        assert_eq!(g.get_edges(), vec![]);
+        let edges = vec![(0, 1, 1.0), (0, 1, -1.0), (0, 1, 2.0)];
+        let expected = [
+            (DuplicatePolicy::Sum, vec![(0, 1, 2.0)]),
+            (DuplicatePolicy::Max, vec![(0, 1, 2.0)]),
+            (DuplicatePolicy::KeepFirst, vec![(0, 1, 1.0)]),
+        ];
+        for (policy, edges_after) in expected.iter() {
+            let g =
+                DGraph::from_weighted_edges_with(2, edges.clone(), *policy, SelfLoopPolicy::Allow)
+                    .unwrap();
+            assert_eq!(g.get_edges(), *edges_after);
+        }
+    }
+
+    #[test]
+    fn from_edges_with_and_dag_check_policies() {
+        let edges = vec![(0, 1), (1, 0), (1, 0)];
+        let g = DGraph::from_edges_with(
+            2,
+            edges.clone(),
+            DuplicatePolicy::Sum,
+            SelfLoopPolicy::Allow,
+        )
+        .unwrap();
+        assert_eq!(g.get_edges(), vec![(0, 1, 1.0), (1, 0, 2.0)]);
+        let error =
+            UGraph::from_edges_with(2, edges, DuplicatePolicy::Error, SelfLoopPolicy::Allow);
+        assert_eq!(
+            error,
+            Err(GraphError::DuplicateEdge {
+                operation: "UGraph::from_edges_with",
+                node1: 1,
+                node2: 0,
+                n_nodes: 2,
+            })
+        );
+        let edges = vec![(0, 1, 1.0), (0, 1, 2.0), (1, 1, 1.0)];
+        let dag = DagGraph::from_weighted_edges_with(
+            2,
+            edges.clone(),
+            DuplicatePolicy::Sum,
+            SelfLoopPolicy::Allow,
+        );
+        assert!(matches!(
+            dag,
+            Err(GraphError::CycleDetected {
+                src: 1,
+                dest: 1,
+                ..
+            })
+        ));
+        let dag = DagGraph::from_weighted_edges_with(
+            2,
+            edges,
+            DuplicatePolicy::Sum,
+            SelfLoopPolicy::Ignore,
+        )
+        .unwrap();
+        assert_eq!(dag.get_graph().get_edges(), vec![(0, 1, 3.0)]);
+    }
+
+    #[test]
+    fn from_weighted_edges_with_check_self_loops() {
+        let edges = vec![(0, 0, 1.0), (0, 1, 2.0), (1, 1, 3.0)];
+        let g = UGraph::from_weighted_edges_with(
+            2,
+            edges.clone(),
+            DuplicatePolicy::Error,
+            SelfLoopPolicy::Ignore,
+        )
+        .unwrap();
+        assert_eq!(g.get_edges(), vec![(0, 1, 2.0)]);
+        let g = DGraph::from_weighted_edges_with(
+            2,
+            edges.clone(),
+            DuplicatePolicy::Error,
+            SelfLoopPolicy::Allow,
+        )
+        .unwrap();
+        assert_eq!(g.get_edges(), edges);
+        let error = DGraph::from_weighted_edges_with(
+            2,
+            edges,
+            DuplicatePolicy::Error,
+            SelfLoopPolicy::Error,
+        );
+        assert_eq!(
+            error,
+            Err(GraphError::SelfLoop {
+                operation: "DGraph::from_weighted_edges_with",
+                node: 0,
+                n_nodes: 2,
+            })
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use std::error::Error;
+use std::fmt;
+
+/// The `GraphError` enum lists the errors returned by the fallible functions of the crate.
+/// It implements `std::error::Error`, so it can be propagated with `?` into boxed errors
+/// (or error types like `anyhow::Error`), and its `Display` message includes the context of
+/// the failure: every variant names the `operation` that failed, and the ones about a node
+/// or an edge also carry the number of nodes of the graph.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::try_dijkstra;
+/// use graphst::DGraph;
+/// let g = DGraph::from_edges(3, vec![(0, 1)]);
+/// let error = try_dijkstra(&g, 5).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "[algorithm::try_dijkstra] The node 5 is not valid for a graph with 3 nodes"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraphError {
+    /// The node is not valid for the graph of `n_nodes` nodes passed to `operation`.
+    InvalidNode {
+        operation: &'static str,
+        node: usize,
+        n_nodes: usize,
+    },
+    /// Adding the edge from `src` to `dest` would create a cycle.
+    CycleDetected {
+        operation: &'static str,
+        src: usize,
+        dest: usize,
+        n_nodes: usize,
+    },
+    /// The dependency graph has cycles, listed as sequences of nodes.
+    DependencyCycles {
+        operation: &'static str,
+        cycles: Vec<Vec<usize>>,
+    },
+    /// The edge from `src` to `dest` has a negative (or NaN) weight, which the algorithm
+    /// doesn't support.
+    NegativeWeight {
+        operation: &'static str,
+        src: usize,
+        dest: usize,
+        weight: f32,
+        n_nodes: usize,
+    },
+    /// There is a cycle with negative total weight reachable from the source, through
+    /// the node `node`.
+    NegativeCycle {
+        operation: &'static str,
+        node: usize,
+        n_nodes: usize,
+    },
+    /// The algorithm reached its limit of `limit` edge relaxations before converging.
+    IterationLimit {
+        operation: &'static str,
+        limit: usize,
+    },
+    /// The edge between `node1` and `node2` is repeated in a list of edges read with
+    /// `DuplicatePolicy::Error`.
+    DuplicateEdge {
+        operation: &'static str,
+        node1: usize,
+        node2: usize,
+        n_nodes: usize,
+    },
+    /// The edge from `node` to itself is in a list of edges read with
+    /// `SelfLoopPolicy::Error`.
+    SelfLoop {
+        operation: &'static str,
+        node: usize,
+        n_nodes: usize,
+    },
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GraphError::InvalidNode {
+                operation,
+                node,
+                n_nodes,
+            } => write!(
+                f,
+                "[{}] The node {} is not valid for a graph with {} nodes",
+                operation, node, n_nodes
+            ),
+            GraphError::CycleDetected {
+                operation,
+                src,
+                dest,
+                n_nodes,
+            } => write!(
+                f,
+                "[{}] Adding the edge {} -> {} would create a cycle in a graph with {} nodes",
+                operation, src, dest, n_nodes
+            ),
+            GraphError::DependencyCycles { operation, cycles } => {
+                write!(
+                    f,
+                    "[{}] The dependencies have {} cycles:",
+                    operation,
+                    cycles.len()
+                )?;
+                for cycle in cycles {
+                    write!(f, " {:?}", cycle)?;
+                }
+                Ok(())
+            }
+            GraphError::NegativeWeight {
+                operation,
+                src,
+                dest,
+                weight,
+                n_nodes,
+            } => write!(
+                f,
+                "[{}] The edge {} -> {} has the weight {}, which is not supported, in a graph \
+                 with {} nodes",
+                operation, src, dest, weight, n_nodes
+            ),
+            GraphError::NegativeCycle {
+                operation,
+                node,
+                n_nodes,
+            } => write!(
+                f,
+                "[{}] There is a negative cycle reachable from the source through the node {} \
+                 in a graph with {} nodes",
+                operation, node, n_nodes
+            ),
+            GraphError::IterationLimit { operation, limit } => write!(
+                f,
+                "[{}] The limit of {} edge relaxations was reached before converging",
+                operation, limit
+            ),
+            GraphError::DuplicateEdge {
+                operation,
+                node1,
+                node2,
+                n_nodes,
+            } => write!(
+                f,
+                "[{}] The edge ({}, {}) is repeated in a graph with {} nodes",
+                operation, node1, node2, n_nodes
+            ),
+            GraphError::SelfLoop {
+                operation,
+                node,
+                n_nodes,
+            } => write!(
+                f,
+                "[{}] The edge ({}, {}) is a self-loop in a graph with {} nodes",
+                operation, node, node, n_nodes
+            ),
+        }
+    }
+}
+
+impl Error for GraphError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_check_messages() {
+        let error = GraphError::DependencyCycles {
+            operation: "algorithm::resolve_dependencies",
+            cycles: vec![vec![0, 1], vec![2, 3]],
+        };
+        assert_eq!(
+            error.to_string(),
+            "[algorithm::resolve_dependencies] The dependencies have 2 cycles: [0, 1] [2, 3]"
+        );
+        let error = GraphError::CycleDetected {
+            operation: "DagGraph::add_weighted_edge",
+            src: 2,
+            dest: 0,
+            n_nodes: 3,
+        };
+        assert_eq!(
+            error.to_string(),
+            "[DagGraph::add_weighted_edge] Adding the edge 2 -> 0 would create a cycle in a \
+             graph with 3 nodes"
+        );
+        let error = GraphError::IterationLimit {
+            operation: "algorithm::spfa_with_limit",
+            limit: 5,
+        };
+        assert_eq!(
+            error.to_string(),
+            "[algorithm::spfa_with_limit] The limit of 5 edge relaxations was reached before \
+             converging"
+        );
+    }
+
+    #[test]
+    fn error_check_boxed_propagation() {
+        fn fails() -> Result<(), Box<dyn Error>> {
+            Err(GraphError::NegativeCycle {
+                operation: "algorithm::bellman_ford",
+                node: 4,
+                n_nodes: 5,
+            })?;
+            Ok(())
+        }
+        assert!(fails().unwrap_err().to_string().contains("node 4"));
+    }
+}
@@ -0,0 +1,244 @@
+//! # Float
+//!
+//! `float` is a `graphst` module that provides the epsilon-tolerant comparisons used
+//! for the `f32` edge weights. Accumulated floating point error can leave values like
+//! `1e-8` where an edge was removed, so the weights are never compared exactly against
+//! `0.0`. The tolerance is a crate-level setting that can be changed with `set_epsilon`.
+//!
+//! The setting is shared by the whole process: it decides which weights are edges in every
+//! graph, including the graphs that already exist and the ones used by other libraries
+//! that depend on `graphst`. The graphs keep the weights they were given and apply the
+//! tolerance when they are read, so changing it after building a graph is supported:
+//! the edges with weights between the old and the new tolerance appear or disappear in
+//! every query at once. The `_with` variants of the functions take the tolerance as a
+//! parameter instead, for the code that needs its own tolerance locally.
+//!
+//! The tolerance is absolute, so with the default the weights with a magnitude up to
+//! `DEFAULT_EPSILON` are not edges. Data with legitimately tiny weights should be
+//! rescaled, or use `set_epsilon(0.0)` to compare the weights exactly.
+
+use std::cmp;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Default tolerance used to compare the weights. It is absolute, so a weight of `1e-7`
+/// means no edge unless the tolerance is lowered.
+pub const DEFAULT_EPSILON: f32 = 1e-6;
+
+// The bits of `DEFAULT_EPSILON`, written out because `f32::to_bits` is not `const` in
+// the supported Rust versions
+const DEFAULT_EPSILON_BITS: u32 = 0x3586_37bd;
+
+// The epsilon is stored as the bits of the `f32` to be able to share it between threads
+static EPSILON_BITS: AtomicU32 = AtomicU32::new(DEFAULT_EPSILON_BITS);
+
+/// Returns the current tolerance used to compare the weights.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::float;
+/// assert_eq!(float::get_epsilon(), float::DEFAULT_EPSILON);
+/// ```
+pub fn get_epsilon() -> f32 {
+    f32::from_bits(EPSILON_BITS.load(Ordering::Relaxed))
+}
+
+/// Sets the tolerance used to compare the weights in the whole crate, for every thread.
+/// A tolerance of `0.0` makes the comparisons exact.
+///
+/// The graphs keep the weights they were given, so changing the tolerance after building
+/// a graph adds or removes the edges with weights between the old and the new tolerance
+/// in every query (`get_edge`, the neighbors, `get_edges` and the adjacency matrix).
+///
+/// # Arguments
+///
+/// * `epsilon` - A non-negative `f32` value with the new tolerance.
+///
+/// # Panics
+///
+/// * If `epsilon` is negative or `NaN`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::float;
+/// float::set_epsilon(1e-4);
+/// assert_eq!(float::get_epsilon(), 1e-4);
+/// ```
+pub fn set_epsilon(epsilon: f32) {
+    if epsilon.is_nan() || epsilon < 0.0 {
+        panic!(
+            "[float::set_epsilon] Error: The epsilon {} is not valid!",
+            epsilon
+        );
+    }
+    EPSILON_BITS.store(epsilon.to_bits(), Ordering::Relaxed);
+}
+
+/// Returns `true` if the weight is zero within the crate tolerance. A zero weight
+/// means that there is no edge.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::float;
+/// assert!(float::is_zero(0.0));
+/// assert!(float::is_zero(0.1 + 0.2 - 0.3));
+/// assert!(!float::is_zero(0.5));
+/// ```
+pub fn is_zero(value: f32) -> bool {
+    is_zero_with(value, get_epsilon())
+}
+
+/// Same as `is_zero` but using the tolerance passed as a parameter.
+pub fn is_zero_with(value: f32, epsilon: f32) -> bool {
+    value.abs() <= epsilon
+}
+
+/// Returns `true` if `a` and `b` are equal within the crate tolerance.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::float;
+/// assert!(float::approx_eq(0.1 + 0.2, 0.3));
+/// assert!(float::approx_eq(f32::INFINITY, f32::INFINITY));
+/// assert!(!float::approx_eq(1.0, 1.1));
+/// ```
+pub fn approx_eq(a: f32, b: f32) -> bool {
+    approx_eq_with(a, b, get_epsilon())
+}
+
+/// Same as `approx_eq` but using the tolerance passed as a parameter.
+pub fn approx_eq_with(a: f32, b: f32, epsilon: f32) -> bool {
+    a == b || (a - b).abs() <= epsilon // `a == b` handles the infinite values
+}
+
+/// Returns `true` if `a` is greater than `b` by more than the crate tolerance.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::float;
+/// assert!(float::greater_than(1.0, 0.5));
+/// assert!(!float::greater_than(0.3, 0.1 + 0.2));
+/// assert!(float::greater_than(f32::INFINITY, 1.0));
+/// ```
+pub fn greater_than(a: f32, b: f32) -> bool {
+    greater_than_with(a, b, get_epsilon())
+}
+
+/// Same as `greater_than` but using the tolerance passed as a parameter.
+pub fn greater_than_with(a: f32, b: f32, epsilon: f32) -> bool {
+    a > b && !approx_eq_with(a, b, epsilon)
+}
+
+/// Returns `true` if `a` is less than `b` by more than the crate tolerance.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::float;
+/// assert!(float::less_than(0.5, 1.0));
+/// assert!(!float::less_than(0.1 + 0.2, 0.3));
+/// ```
+pub fn less_than(a: f32, b: f32) -> bool {
+    greater_than(b, a)
+}
+
+/// Same as `less_than` but using the tolerance passed as a parameter.
+pub fn less_than_with(a: f32, b: f32, epsilon: f32) -> bool {
+    greater_than_with(b, a, epsilon)
+}
+
+/// The `FloatOrd` struct wraps a `f32` with the total order of `f32::total_cmp`, so the
+/// weights can be used as keys of ordered collections like `BinaryHeap`. Unlike the
+/// functions of this module the comparison is exact, without tolerance.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::float::FloatOrd;
+/// use std::cmp::Reverse;
+/// use std::collections::BinaryHeap;
+/// let mut heap = BinaryHeap::new();
+/// heap.push(Reverse(FloatOrd(2.5)));
+/// heap.push(Reverse(FloatOrd(0.5)));
+/// assert_eq!(heap.pop(), Some(Reverse(FloatOrd(0.5))));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FloatOrd(pub f32);
+
+impl PartialEq for FloatOrd {
+    fn eq(&self, other: &FloatOrd) -> bool {
+        self.cmp(other) == cmp::Ordering::Equal
+    }
+}
+
+impl Eq for FloatOrd {}
+
+impl PartialOrd for FloatOrd {
+    fn partial_cmp(&self, other: &FloatOrd) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FloatOrd {
+    fn cmp(&self, other: &FloatOrd) -> cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_epsilon_bits_matches_value() {
+        assert_eq!(f32::from_bits(DEFAULT_EPSILON_BITS), DEFAULT_EPSILON);
+    }
+
+    #[test]
+    fn is_zero_with_check_values() {
+        assert!(is_zero_with(0.0, 0.1));
+        assert!(is_zero_with(-0.05, 0.1));
+        assert!(!is_zero_with(0.2, 0.1));
+        assert!(!is_zero_with(f32::INFINITY, 0.1));
+    }
+
+    #[test]
+    fn approx_eq_with_check_values() {
+        assert!(approx_eq_with(1.0, 1.05, 0.1));
+        assert!(!approx_eq_with(1.0, 1.2, 0.1));
+        assert!(approx_eq_with(f32::INFINITY, f32::INFINITY, 0.1));
+        assert!(!approx_eq_with(f32::INFINITY, 1.0, 0.1));
+    }
+
+    #[test]
+    fn greater_and_less_than_with_check_values() {
+        assert!(greater_than_with(1.2, 1.0, 0.1));
+        assert!(!greater_than_with(1.05, 1.0, 0.1));
+        assert!(!greater_than_with(1.0, 1.2, 0.1));
+        assert!(less_than_with(1.0, 1.2, 0.1));
+        assert!(!less_than_with(1.0, 1.05, 0.1));
+    }
+
+    #[test]
+    fn default_epsilon_accumulated_error() {
+        let mut w = 0.0;
+        for _ in 0..10 {
+            w += 0.1;
+        }
+        for _ in 0..10 {
+            w -= 0.1;
+        }
+        assert!(w != 0.0); // sanity check of the accumulated error
+        assert!(is_zero_with(w, DEFAULT_EPSILON));
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn set_epsilon_panic_negative() {
+        set_epsilon(-1.0);
+    }
+}
@@ -0,0 +1,501 @@
+use crate::{float, DGraph};
+use std::fmt;
+
+/// The `FlowArc` struct holds the data of an arc of a `FlowNetwork`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlowArc {
+    /// The node where the arc starts.
+    pub src: usize,
+    /// The node where the arc ends.
+    pub dest: usize,
+    /// The maximum flow that the arc can carry.
+    pub capacity: f32,
+    /// The cost of each unit of flow sent through the arc.
+    pub cost: f32,
+    /// The flow currently sent through the arc.
+    pub flow: f32,
+}
+
+/// The `ResidualArc` struct is an arc of the residual graph of a `FlowNetwork`. A forward
+/// residual arc can push more flow through the arc `arc`, and a backward one can cancel
+/// flow of the arc `arc` (going from its `dest` to its `src` with the opposite cost).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ResidualArc {
+    /// The index of the arc of the network.
+    pub arc: usize,
+    /// `true` for forward residual arcs, `false` for backward residual arcs.
+    pub forward: bool,
+    /// The node where the residual arc starts.
+    pub src: usize,
+    /// The node where the residual arc ends.
+    pub dest: usize,
+    /// The flow that can be pushed through the residual arc.
+    pub capacity: f32,
+    /// The cost of each unit of flow pushed through the residual arc.
+    pub cost: f32,
+}
+
+/// The `FlowNetwork` struct provides a directed network where each arc stores its
+/// capacity, cost and current flow, instead of a single weight like `DGraph`. Parallel
+/// arcs are allowed, and each arc is referenced by the index returned when it is added.
+/// The nodes are referenced by `usize` values from `0` to `n_nodes-1`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FlowNetwork {
+    n_nodes: usize,
+    arcs: Vec<FlowArc>,
+    out_arcs: Vec<Vec<usize>>,
+    in_arcs: Vec<Vec<usize>>,
+}
+
+impl FlowNetwork {
+    /// Creates an empty `FlowNetwork`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::FlowNetwork;
+    /// let net = FlowNetwork::new();
+    /// ```
+    pub fn new() -> FlowNetwork {
+        FlowNetwork::with_nodes(0)
+    }
+
+    /// Creates a `FlowNetwork` with `n_nodes` nodes and no arcs.
+    pub fn with_nodes(n_nodes: usize) -> FlowNetwork {
+        FlowNetwork {
+            n_nodes,
+            arcs: vec![],
+            out_arcs: vec![vec![]; n_nodes],
+            in_arcs: vec![vec![]; n_nodes],
+        }
+    }
+
+    /// Creates a `FlowNetwork` from the number of nodes and the definition of the arcs
+    /// with their capacities. The cost of the arcs is `0.0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_nodes` - An `usize` value with the number of nodes in the network.
+    /// * `arcs` - A vector of `(src, dest, capacity)` triplets.
+    ///
+    /// # Panics
+    ///
+    /// * If some arc has an invalid node value or a negative capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::FlowNetwork;
+    /// let net = FlowNetwork::from_arcs(3, vec![(0, 1, 2.0), (1, 2, 1.0)]);
+    /// assert_eq!(net.get_n_arcs(), 2);
+    /// ```
+    pub fn from_arcs(n_nodes: usize, arcs: Vec<(usize, usize, f32)>) -> FlowNetwork {
+        let mut net = FlowNetwork::with_nodes(n_nodes);
+        for (src, dest, capacity) in arcs {
+            net.add_arc(src, dest, capacity);
+        }
+        net
+    }
+
+    /// Returns the number of nodes in the network.
+    pub fn get_n_nodes(&self) -> usize {
+        self.n_nodes
+    }
+
+    /// Returns the number of arcs in the network.
+    pub fn get_n_arcs(&self) -> usize {
+        self.arcs.len()
+    }
+
+    /// Returns the arcs of the network, where the index of each arc is its position.
+    pub fn get_arcs(&self) -> &[FlowArc] {
+        &self.arcs
+    }
+
+    /// Returns the arc with the index passed as a parameter.
+    ///
+    /// # Panics
+    ///
+    /// * If the arc index is not valid.
+    pub fn get_arc(&self, arc: usize) -> &FlowArc {
+        if arc >= self.arcs.len() {
+            panic!(
+                "[FlowNetwork::get_arc] Error: The arc {} is not valid!",
+                arc
+            );
+        }
+        &self.arcs[arc]
+    }
+
+    /// Returns the indices of the arcs that start at the node passed as a parameter.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    pub fn get_out_arcs_of(&self, node: usize) -> &[usize] {
+        if node >= self.n_nodes {
+            panic!(
+                "[FlowNetwork::get_out_arcs_of] Error: The node {} is not valid!",
+                node
+            );
+        }
+        &self.out_arcs[node]
+    }
+
+    /// Returns the indices of the arcs that end at the node passed as a parameter.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    pub fn get_in_arcs_of(&self, node: usize) -> &[usize] {
+        if node >= self.n_nodes {
+            panic!(
+                "[FlowNetwork::get_in_arcs_of] Error: The node {} is not valid!",
+                node
+            );
+        }
+        &self.in_arcs[node]
+    }
+
+    /// Adds a node to the network without any arc and returns its index.
+    pub fn add_node(&mut self) -> usize {
+        self.n_nodes += 1;
+        self.out_arcs.push(vec![]);
+        self.in_arcs.push(vec![]);
+        self.n_nodes - 1
+    }
+
+    /// Adds an arc with the capacity passed as a parameter and cost `0.0`, and returns
+    /// its index.
+    ///
+    /// # Panics
+    ///
+    /// * If some node is not valid or the capacity is negative.
+    pub fn add_arc(&mut self, src: usize, dest: usize, capacity: f32) -> usize {
+        self.add_arc_with_cost(src, dest, capacity, 0.0)
+    }
+
+    /// Adds an arc with the capacity and cost passed as parameters, and returns its
+    /// index. The arc starts without flow.
+    ///
+    /// # Panics
+    ///
+    /// * If some node is not valid or the capacity is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::FlowNetwork;
+    /// let mut net = FlowNetwork::with_nodes(2);
+    /// assert_eq!(net.add_arc_with_cost(0, 1, 5.0, 2.0), 0);
+    /// assert_eq!(net.get_arc(0).cost, 2.0);
+    /// ```
+    pub fn add_arc_with_cost(
+        &mut self,
+        src: usize,
+        dest: usize,
+        capacity: f32,
+        cost: f32,
+    ) -> usize {
+        if let Some(node) = [src, dest].iter().find(|n| **n >= self.n_nodes) {
+            panic!(
+                "[FlowNetwork::add_arc_with_cost] Error: The node {} is not valid!",
+                node
+            );
+        }
+        if capacity < 0.0 || capacity.is_nan() {
+            panic!(
+                "[FlowNetwork::add_arc_with_cost] Error: The capacity {} is not valid!",
+                capacity
+            );
+        }
+        self.arcs.push(FlowArc {
+            src,
+            dest,
+            capacity,
+            cost,
+            flow: 0.0,
+        });
+        let arc = self.arcs.len() - 1;
+        self.out_arcs[src].push(arc);
+        self.in_arcs[dest].push(arc);
+        arc
+    }
+
+    /// Sets the flow of the arc passed as a parameter.
+    ///
+    /// # Panics
+    ///
+    /// * If the arc index is not valid.
+    /// * If the flow is negative or exceeds the capacity of the arc.
+    pub fn set_flow(&mut self, arc: usize, flow: f32) {
+        if arc >= self.arcs.len() {
+            panic!(
+                "[FlowNetwork::set_flow] Error: The arc {} is not valid!",
+                arc
+            );
+        }
+        if float::less_than(flow, 0.0) || float::greater_than(flow, self.arcs[arc].capacity) {
+            panic!(
+                "[FlowNetwork::set_flow] Error: The flow {} is not valid for the arc {}!",
+                flow, arc
+            );
+        }
+        self.arcs[arc].flow = flow;
+    }
+
+    /// Removes the flow of every arc.
+    pub fn reset_flows(&mut self) {
+        for arc in &mut self.arcs {
+            arc.flow = 0.0;
+        }
+    }
+
+    /// Returns the excess of the node passed as a parameter: the flow that enters the
+    /// node minus the flow that leaves it. It is `0.0` for the nodes where the flow is
+    /// conserved.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    pub fn get_excess_of(&self, node: usize) -> f32 {
+        if node >= self.n_nodes {
+            panic!(
+                "[FlowNetwork::get_excess_of] Error: The node {} is not valid!",
+                node
+            );
+        }
+        let inflow: f32 = self.in_arcs[node].iter().map(|a| self.arcs[*a].flow).sum();
+        let outflow: f32 = self.out_arcs[node].iter().map(|a| self.arcs[*a].flow).sum();
+        inflow - outflow
+    }
+
+    /// Returns the total cost of the current flow, the sum of `flow * cost` of the arcs.
+    pub fn get_total_cost(&self) -> f32 {
+        self.arcs.iter().map(|arc| arc.flow * arc.cost).sum()
+    }
+
+    /// Returns the arcs of the residual graph that start at the node passed as a
+    /// parameter, only the ones with some residual capacity. The forward residual arcs go
+    /// first, in the order of `get_out_arcs_of`, followed by the backward ones.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::FlowNetwork;
+    /// let mut net = FlowNetwork::from_arcs(3, vec![(0, 1, 2.0), (1, 2, 1.0)]);
+    /// net.set_flow(0, 2.0);
+    /// let residual = net.get_residual_arcs_of(1);
+    /// assert_eq!(residual.len(), 2);
+    /// assert!(residual[0].forward && residual[0].dest == 2);
+    /// assert!(!residual[1].forward && residual[1].dest == 0 && residual[1].capacity == 2.0);
+    /// ```
+    pub fn get_residual_arcs_of(&self, node: usize) -> Vec<ResidualArc> {
+        if node >= self.n_nodes {
+            panic!(
+                "[FlowNetwork::get_residual_arcs_of] Error: The node {} is not valid!",
+                node
+            );
+        }
+        let forward = self.out_arcs[node].iter().map(|a| {
+            let arc = &self.arcs[*a];
+            ResidualArc {
+                arc: *a,
+                forward: true,
+                src: node,
+                dest: arc.dest,
+                capacity: arc.capacity - arc.flow,
+                cost: arc.cost,
+            }
+        });
+        let backward = self.in_arcs[node].iter().map(|a| {
+            let arc = &self.arcs[*a];
+            ResidualArc {
+                arc: *a,
+                forward: false,
+                src: node,
+                dest: arc.src,
+                capacity: arc.flow,
+                cost: -arc.cost,
+            }
+        });
+        forward
+            .chain(backward)
+            .filter(|r| float::greater_than(r.capacity, 0.0))
+            .collect()
+    }
+
+    /// Pushes `amount` units of flow through the residual arc passed as a parameter,
+    /// increasing the flow of the arc for forward residual arcs and decreasing it for
+    /// backward residual arcs.
+    ///
+    /// # Panics
+    ///
+    /// * If the arc index is not valid.
+    /// * If the resulting flow is negative or exceeds the capacity of the arc.
+    pub fn push_flow(&mut self, residual: &ResidualArc, amount: f32) {
+        if residual.arc >= self.arcs.len() {
+            panic!(
+                "[FlowNetwork::push_flow] Error: The arc {} is not valid!",
+                residual.arc
+            );
+        }
+        let arc = &mut self.arcs[residual.arc];
+        let flow = if residual.forward {
+            arc.flow + amount
+        } else {
+            arc.flow - amount
+        };
+        if float::less_than(flow, 0.0) || float::greater_than(flow, arc.capacity) {
+            panic!(
+                "[FlowNetwork::push_flow] Error: The amount {} exceeds the residual capacity!",
+                amount
+            );
+        }
+        // Snap to the bounds to avoid accumulating rounding errors
+        arc.flow = flow.max(0.0).min(arc.capacity);
+    }
+
+    /// Returns the sorted nodes reachable from `node` through residual arcs. After a
+    /// maximum flow, the nodes reachable from the source form the source side of a
+    /// minimum cut.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::FlowNetwork;
+    /// let mut net = FlowNetwork::from_arcs(3, vec![(0, 1, 2.0), (1, 2, 1.0)]);
+    /// net.set_flow(0, 1.0);
+    /// net.set_flow(1, 1.0);
+    /// assert_eq!(net.get_residual_reachable_from(0), vec![0, 1]);
+    /// ```
+    pub fn get_residual_reachable_from(&self, node: usize) -> Vec<usize> {
+        if node >= self.n_nodes {
+            panic!(
+                "[FlowNetwork::get_residual_reachable_from] Error: The node {} is not valid!",
+                node
+            );
+        }
+        let mut visited = vec![false; self.n_nodes];
+        visited[node] = true;
+        let mut stack = vec![node];
+        while let Some(current) = stack.pop() {
+            for residual in self.get_residual_arcs_of(current) {
+                if !visited[residual.dest] {
+                    visited[residual.dest] = true;
+                    stack.push(residual.dest);
+                }
+            }
+        }
+        (0..self.n_nodes).filter(|n| visited[*n]).collect()
+    }
+
+    /// Returns the residual graph as a `DGraph` where the weight of each edge is the
+    /// residual capacity between the nodes (summing parallel residual arcs). The costs
+    /// are not included, use `get_residual_arcs_of` to get them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{FlowNetwork, Graph};
+    /// let mut net = FlowNetwork::from_arcs(2, vec![(0, 1, 3.0)]);
+    /// net.set_flow(0, 1.0);
+    /// let residual = net.get_residual_graph();
+    /// assert_eq!(residual.get_edge(0, 1), Some(2.0));
+    /// assert_eq!(residual.get_edge(1, 0), Some(1.0));
+    /// ```
+    pub fn get_residual_graph(&self) -> DGraph {
+        let mut adj_mat = vec![vec![0.0; self.n_nodes]; self.n_nodes];
+        for (node, row) in adj_mat.iter_mut().enumerate() {
+            for residual in self.get_residual_arcs_of(node) {
+                row[residual.dest] += residual.capacity;
+            }
+        }
+        DGraph::from_adjacency_matrix(adj_mat)
+    }
+}
+
+impl fmt::Display for FlowNetwork {
+    /// Shows the info of the network.
+    /// The arcs are represented in the format `src->dest(flow/capacity, cost)`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "FlowNetwork(arcs=[")?;
+        for arc in &self.arcs {
+            writeln!(
+                f,
+                "{}->{}({}/{}, {}),",
+                arc.src, arc.dest, arc.flow, arc.capacity, arc.cost
+            )?;
+        }
+        write!(f, "], n_nodes={})", self.n_nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FlowNetwork;
+
+    #[test]
+    fn add_arc_allows_parallel_arcs() {
+        let mut net = FlowNetwork::with_nodes(2);
+        assert_eq!(net.add_arc(0, 1, 1.0), 0);
+        assert_eq!(net.add_arc(0, 1, 2.0), 1);
+        assert_eq!(net.get_out_arcs_of(0), &[0, 1]);
+        assert_eq!(net.get_in_arcs_of(1), &[0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity -1 is not valid")]
+    fn add_arc_panic_negative_capacity() {
+        FlowNetwork::from_arcs(2, vec![(0, 1, -1.0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn add_arc_panic_not_valid_node() {
+        FlowNetwork::from_arcs(2, vec![(0, 2, 1.0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "flow 3 is not valid")]
+    fn set_flow_panic_over_capacity() {
+        let mut net = FlowNetwork::from_arcs(2, vec![(0, 1, 2.0)]);
+        net.set_flow(0, 3.0);
+    }
+
+    #[test]
+    fn push_flow_check_excess_and_cost() {
+        let mut net = FlowNetwork::with_nodes(3);
+        net.add_arc_with_cost(0, 1, 2.0, 1.0);
+        net.add_arc_with_cost(1, 2, 2.0, 3.0);
+        for node in 0..2 {
+            let residual = net.get_residual_arcs_of(node)[0];
+            net.push_flow(&residual, 1.5);
+        }
+        assert_eq!(net.get_excess_of(0), -1.5);
+        assert_eq!(net.get_excess_of(1), 0.0);
+        assert_eq!(net.get_excess_of(2), 1.5);
+        assert_eq!(net.get_total_cost(), 6.0);
+        // Cancel part of the flow of the first arc
+        let backward = net.get_residual_arcs_of(1)[1];
+        assert_eq!((backward.forward, backward.cost), (false, -1.0));
+        net.push_flow(&backward, 0.5);
+        assert_eq!(net.get_arc(0).flow, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the residual capacity")]
+    fn push_flow_panic_exceeds_capacity() {
+        let mut net = FlowNetwork::from_arcs(2, vec![(0, 1, 2.0)]);
+        let residual = net.get_residual_arcs_of(0)[0];
+        net.push_flow(&residual, 2.5);
+    }
+}
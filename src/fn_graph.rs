@@ -0,0 +1,151 @@
+use crate::float;
+use crate::Graph;
+use std::fmt;
+
+/// The `FnGraph` struct implements `Graph` over a closure that returns the weight of the
+/// edge from `node1` to `node2` (`None` if there is no edge), so implicit graphs like
+/// grids or state spaces can be searched without building their adjacency matrix. The
+/// edges are computed on demand and the graph is directed: a symmetric closure behaves
+/// like an undirected graph for the searches.
+///
+/// The neighbors of a node are found by calling the closure with every other node, so
+/// each expansion costs `O(n)` calls.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::dijkstra;
+/// use graphst::FnGraph;
+/// // A 3x3 grid where moving right costs 1.0 and moving down costs 2.0
+/// let width = 3;
+/// let g = FnGraph::new(9, |node1: usize, node2: usize| {
+///     if node2 == node1 + 1 && node2 % width != 0 {
+///         Some(1.0)
+///     } else if node2 == node1 + width {
+///         Some(2.0)
+///     } else {
+///         None
+///     }
+/// });
+/// assert_eq!(dijkstra(&g, 0)[8], 6.0);
+/// ```
+#[derive(Clone)]
+pub struct FnGraph<F> {
+    n_nodes: usize,
+    weight_fn: F,
+}
+
+impl<F> FnGraph<F>
+where
+    F: Fn(usize, usize) -> Option<f32>,
+{
+    /// Creates a `FnGraph` with `n_nodes` nodes and the edges given by `weight_fn`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_nodes` - An `usize` value with the number of nodes in the graph.
+    /// * `weight_fn` - A closure that returns the weight of the edge from the first node
+    ///   to the second one, or `None` if there is no edge.
+    pub fn new(n_nodes: usize, weight_fn: F) -> FnGraph<F> {
+        FnGraph { n_nodes, weight_fn }
+    }
+}
+
+impl<F> Graph for FnGraph<F>
+where
+    F: Fn(usize, usize) -> Option<f32>,
+{
+    /// Returns the number of nodes in the graph.
+    fn get_n_nodes(&self) -> usize {
+        self.n_nodes
+    }
+
+    /// Returns a vector with the nodes (`usize` references) of the graph.
+    fn get_nodes(&self) -> Vec<usize> {
+        (0..self.n_nodes).collect()
+    }
+
+    /// Returns `true`, the closure defines the edges in each direction.
+    fn is_directed(&self) -> bool {
+        true
+    }
+
+    /// Returns the weight of the edge from `src` to `dest` given by the closure. The
+    /// edges with weight `0.0` are treated as missing, like in the other graphs.
+    ///
+    /// # Panics
+    ///
+    /// * If some node passed as a parameter is not valid.
+    fn get_edge(&self, src: usize, dest: usize) -> Option<f32> {
+        if let Some(node) = [src, dest].iter().find(|n| **n >= self.n_nodes) {
+            panic!("[FnGraph::get_edge] Error: The node {} is not valid!", node);
+        }
+        (self.weight_fn)(src, dest).filter(|w| !float::is_zero(*w))
+    }
+
+    /// Adds a node to the graph, the closure must handle the new node index.
+    fn add_node(&mut self) -> usize {
+        self.n_nodes += 1;
+        self.n_nodes - 1
+    }
+
+    /// The edges are defined by the closure, so they can't be added.
+    ///
+    /// # Panics
+    ///
+    /// * Always.
+    fn add_edge(&mut self, src: usize, dest: usize) {
+        self.add_weighted_edge(src, dest, 1.0);
+    }
+
+    /// The edges are defined by the closure, so they can't be added.
+    ///
+    /// # Panics
+    ///
+    /// * Always.
+    fn add_weighted_edge(&mut self, _src: usize, _dest: usize, _weight: f32) {
+        panic!("[FnGraph::add_weighted_edge] Error: The edges of a FnGraph can't be modified!");
+    }
+}
+
+impl<F> fmt::Debug for FnGraph<F> {
+    /// Shows the number of nodes, the edges are not computed.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FnGraph")
+            .field("n_nodes", &self.n_nodes)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FnGraph, Graph};
+
+    #[test]
+    fn get_edges_check_function_values() {
+        let g = FnGraph::new(3, |node1: usize, node2: usize| {
+            if node1 < node2 {
+                Some((node1 + node2) as f32)
+            } else {
+                None
+            }
+        });
+        assert_eq!(g.get_edges(), vec![(0, 1, 1.0), (0, 2, 2.0), (1, 2, 3.0)]);
+        assert_eq!(g.get_out_neighbors_of(0), vec![1, 2]);
+        assert_eq!(format!("{:?}", g), "FnGraph { n_nodes: 3, .. }");
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be modified")]
+    fn add_edge_panic_read_only() {
+        let mut g = FnGraph::new(2, |_, _| Some(1.0));
+        g.add_edge(0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn get_edge_panic_not_valid_node() {
+        let g = FnGraph::new(2, |_, _| Some(1.0));
+        g.get_edge(0, 2);
+    }
+}
@@ -0,0 +1,120 @@
+//! # Generate
+//!
+//! `generate` is a `graphst` module to create synthetic graphs, like the Kronecker graphs
+//! used as standard inputs by the Graph500 benchmark.
+
+use crate::rng::Rng;
+use crate::{Graph, UGraph};
+
+// Initiator probabilities of the Graph500 Kronecker generator (D = 1 - A - B - C)
+const KRONECKER_A: f64 = 0.57;
+const KRONECKER_B: f64 = 0.19;
+const KRONECKER_C: f64 = 0.19;
+
+/// Returns the edge list of a Kronecker (R-MAT) graph with `2^scale` nodes and
+/// `edgefactor * 2^scale` edges, using the Graph500 initiator probabilities. The node
+/// labels are randomly permuted. The list can contain self-loops and repeated edges.
+///
+/// # Arguments
+///
+/// * `scale` - The base 2 logarithm of the number of nodes.
+/// * `edgefactor` - The ratio between the number of edges and the number of nodes.
+/// * `seed` - The seed of the random generator.
+///
+/// # Examples
+///
+/// ```
+/// let edges = graphst::generate::kronecker_edges(4, 8, 1);
+/// assert_eq!(edges.len(), 16 * 8);
+/// assert!(edges.iter().all(|(a, b)| *a < 16 && *b < 16));
+/// ```
+pub fn kronecker_edges(scale: u32, edgefactor: usize, seed: u64) -> Vec<(usize, usize)> {
+    let n_nodes = 1usize << scale;
+    let mut rng = Rng::new(seed);
+    let mut edges = Vec::with_capacity(edgefactor * n_nodes);
+    for _ in 0..edgefactor * n_nodes {
+        let (mut src, mut dest) = (0, 0);
+        for bit in 0..scale {
+            // Choose the quadrant of the adjacency matrix at this level
+            let r = rng.next_f64();
+            let (src_bit, dest_bit) = if r < KRONECKER_A {
+                (0, 0)
+            } else if r < KRONECKER_A + KRONECKER_B {
+                (0, 1)
+            } else if r < KRONECKER_A + KRONECKER_B + KRONECKER_C {
+                (1, 0)
+            } else {
+                (1, 1)
+            };
+            src |= src_bit << bit;
+            dest |= dest_bit << bit;
+        }
+        edges.push((src, dest));
+    }
+    // Permute the labels so the high degree nodes are not clustered at low indices
+    let mut perm: Vec<usize> = (0..n_nodes).collect();
+    rng.shuffle(&mut perm);
+    edges
+        .into_iter()
+        .map(|(src, dest)| (perm[src], perm[dest]))
+        .collect()
+}
+
+/// Creates an undirected Kronecker graph with `2^scale` nodes from the edges generated by
+/// `kronecker_edges`, dropping the self-loops and the repeated edges. The graph uses a
+/// dense matrix, so `scale` should stay small (`2^scale` squared `f32` values).
+///
+/// # Arguments
+///
+/// * `scale` - The base 2 logarithm of the number of nodes.
+/// * `edgefactor` - The ratio between the number of generated edges and the number of nodes.
+/// * `seed` - The seed of the random generator.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::Graph;
+/// let g = graphst::generate::kronecker(6, 16, 42);
+/// assert_eq!(g.get_n_nodes(), 64);
+/// ```
+pub fn kronecker(scale: u32, edgefactor: usize, seed: u64) -> UGraph {
+    let n_nodes = 1usize << scale;
+    let mut g = UGraph::from_adjacency_matrix(vec![vec![0.0; n_nodes]; n_nodes]);
+    for (src, dest) in kronecker_edges(scale, edgefactor, seed) {
+        if src != dest {
+            g.add_edge(src, dest);
+        }
+    }
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kronecker_is_reproducible() {
+        assert_eq!(kronecker_edges(5, 4, 9), kronecker_edges(5, 4, 9));
+        assert_ne!(kronecker_edges(5, 4, 9), kronecker_edges(5, 4, 10));
+    }
+
+    #[test]
+    fn kronecker_has_no_self_loops() {
+        let g = kronecker(5, 8, 3);
+        assert!(g.get_edges().iter().all(|(a, b, _)| a != b));
+        assert!(!g.get_edges().is_empty());
+    }
+
+    #[test]
+    fn kronecker_degrees_are_skewed() {
+        let g = kronecker(8, 16, 5);
+        let mut degrees: Vec<usize> = g
+            .get_nodes()
+            .iter()
+            .map(|n| g.get_neighbors_of(*n).len())
+            .collect();
+        degrees.sort();
+        // The max degree is far above the median, as expected from a power-law like graph
+        assert!(degrees[degrees.len() - 1] > 4 * degrees[degrees.len() / 2]);
+    }
+}
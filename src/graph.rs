@@ -1,10 +1,532 @@
+use crate::float;
+use std::borrow::Cow;
+use std::fmt;
+use std::mem;
+use std::ops::Range;
+
 /// The `Graph` trait provides the basic common functions that every graph struct implements.
 pub trait Graph {
     fn get_n_nodes(&self) -> usize;
     fn get_nodes(&self) -> Vec<usize>;
-    fn get_adjacency_matrix(&self) -> &Vec<Vec<f32>>;
+    fn is_directed(&self) -> bool;
     fn get_edge(&self, node1: usize, node2: usize) -> Option<f32>;
-    fn add_node(&mut self);
+    fn add_node(&mut self) -> usize;
     fn add_edge(&mut self, node1: usize, node2: usize);
     fn add_weighted_edge(&mut self, node1: usize, node2: usize, weight: f32);
+
+    /// Adds `count` nodes without any edge and returns the range of their indices. By
+    /// default the nodes are added one by one with `add_node`; the matrix based graphs
+    /// grow their storage once for all the nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, UGraph};
+    /// let mut g = UGraph::from_edges(2, vec![(0, 1)]);
+    /// assert_eq!(g.add_nodes(3), 2..5);
+    /// assert_eq!(g.get_n_nodes(), 5);
+    /// ```
+    fn add_nodes(&mut self, count: usize) -> Range<usize> {
+        let start = self.get_n_nodes();
+        for _ in 0..count {
+            self.add_node();
+        }
+        start..start + count
+    }
+
+    /// Returns a sorted vector with the nodes reachable from `node` through one edge.
+    /// These are the neighbors for undirected graphs and the successors for directed graphs.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, DGraph};
+    /// let g = DGraph::from_edges(3, vec![(0, 2), (1, 0), (0, 1)]);
+    /// assert_eq!(g.get_out_neighbors_of(0), vec![1, 2]);
+    /// ```
+    fn get_out_neighbors_of(&self, node: usize) -> Vec<usize> {
+        if node >= self.get_n_nodes() {
+            panic!(
+                "[Graph::get_out_neighbors_of] Error: The node {} is not valid!",
+                node
+            );
+        }
+        self.get_nodes()
+            .into_iter()
+            .filter(|n| self.get_edge(node, *n).is_some())
+            .collect()
+    }
+
+    /// Returns a vector with the edges of the graph as `(node1, node2, weight)` triplets.
+    /// Undirected graphs return each edge only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, DGraph};
+    /// let g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (2, 0, 1.5)]);
+    /// assert_eq!(g.get_edges(), vec![(0, 1, 2.0), (2, 0, 1.5)]);
+    /// ```
+    fn get_edges(&self) -> Vec<(usize, usize, f32)> {
+        let mut edges = vec![];
+        for node1 in self.get_nodes() {
+            for node2 in self.get_out_neighbors_of(node1) {
+                if let Some(weight) = self.get_edge(node1, node2) {
+                    edges.push((node1, node2, weight));
+                }
+            }
+        }
+        edges
+    }
+
+    /// Returns the adjacency matrix of the graph, where a value of `0.0` means that there is
+    /// no edge between those nodes. By default the matrix is built from `get_edges`, so
+    /// graphs that don't store a dense matrix can still provide it. Matrix based graphs
+    /// return it borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, UGraph};
+    /// let g = UGraph::from_weighted_edges(2, vec![(0, 1, 2.0)]);
+    /// let adj_mat = g.get_adjacency_matrix();
+    /// assert_eq!(*adj_mat, vec![vec![0.0, 2.0], vec![2.0, 0.0]]);
+    /// ```
+    fn get_adjacency_matrix(&self) -> Cow<'_, Vec<Vec<f32>>> {
+        let n_nodes = self.get_n_nodes();
+        let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+        for (node1, node2, weight) in self.get_edges() {
+            adj_mat[node1][node2] = weight;
+            if !self.is_directed() {
+                adj_mat[node2][node1] = weight;
+            }
+        }
+        Cow::Owned(adj_mat)
+    }
+
+    /// Returns the number of parallel edges between `node1` and `node2` (from `node1` to
+    /// `node2` in directed graphs). Simple graphs return `0` or `1`; graphs with parallel
+    /// edges override it along with `edges_between`.
+    ///
+    /// # Panics
+    ///
+    /// * If some node is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, UGraph};
+    /// let g = UGraph::from_edges(3, vec![(0, 1)]);
+    /// assert_eq!(g.edge_multiplicity(1, 0), 1);
+    /// assert_eq!(g.edge_multiplicity(1, 2), 0);
+    /// ```
+    fn edge_multiplicity(&self, node1: usize, node2: usize) -> usize {
+        self.edges_between(node1, node2).len()
+    }
+
+    /// Returns all the parallel edges between `node1` and `node2` (from `node1` to `node2`
+    /// in directed graphs) as `(key, weight)` pairs, where the key tells apart the
+    /// parallel edges. Simple graphs have at most one edge, with key `0`.
+    ///
+    /// # Panics
+    ///
+    /// * If some node is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, DGraph};
+    /// let g = DGraph::from_weighted_edges(2, vec![(0, 1, 2.5)]);
+    /// assert_eq!(g.edges_between(0, 1), vec![(0, 2.5)]);
+    /// assert_eq!(g.edges_between(1, 0), vec![]);
+    /// ```
+    fn edges_between(&self, node1: usize, node2: usize) -> Vec<(usize, f32)> {
+        self.get_edge(node1, node2)
+            .map(|weight| vec![(0, weight)])
+            .unwrap_or_default()
+    }
+
+    /// Returns the adjacency matrix of the graph using `no_edge` as the value for the pairs
+    /// of nodes without an edge, e.g. `f32::INFINITY` for distance semantics. The diagonal
+    /// follows the same rule, so nodes without a self-loop get `no_edge` too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, DGraph};
+    /// let g = DGraph::from_weighted_edges(2, vec![(0, 1, 2.0)]);
+    /// let inf = f32::INFINITY;
+    /// assert_eq!(g.to_matrix_with(inf), vec![vec![inf, 2.0], vec![inf, inf]]);
+    /// ```
+    fn to_matrix_with(&self, no_edge: f32) -> Vec<Vec<f32>> {
+        let mut mat = self.get_adjacency_matrix().into_owned();
+        for value in mat.iter_mut().flatten() {
+            if float::is_zero(*value) {
+                *value = no_edge;
+            }
+        }
+        mat
+    }
+
+    /// Returns the edge with the minimum weight as a `(node1, node2, weight)` triplet, or
+    /// `None` if the graph has no edges. In case of a tie the first edge found is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, UGraph};
+    /// let g = UGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, -1.0)]);
+    /// assert_eq!(g.argmin_edge(), Some((1, 2, -1.0)));
+    /// ```
+    fn argmin_edge(&self) -> Option<(usize, usize, f32)> {
+        self.get_edges()
+            .into_iter()
+            .fold(None, |best, edge| match best {
+                Some((_, _, w)) if w <= edge.2 => best,
+                _ => Some(edge),
+            })
+    }
+
+    /// Returns the edge with the maximum weight as a `(node1, node2, weight)` triplet, or
+    /// `None` if the graph has no edges. In case of a tie the first edge found is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, UGraph};
+    /// let g = UGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, -1.0)]);
+    /// assert_eq!(g.argmax_edge(), Some((0, 1, 2.0)));
+    /// ```
+    fn argmax_edge(&self) -> Option<(usize, usize, f32)> {
+        self.get_edges()
+            .into_iter()
+            .fold(None, |best, edge| match best {
+                Some((_, _, w)) if w >= edge.2 => best,
+                _ => Some(edge),
+            })
+    }
+
+    /// Returns the minimum edge weight of the graph, or `None` if the graph has no edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, DGraph};
+    /// let g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 0.5)]);
+    /// assert_eq!(g.min_edge(), Some(0.5));
+    /// assert_eq!(DGraph::new().min_edge(), None);
+    /// ```
+    fn min_edge(&self) -> Option<f32> {
+        self.argmin_edge().map(|(_, _, weight)| weight)
+    }
+
+    /// Returns the maximum edge weight of the graph, or `None` if the graph has no edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, DGraph};
+    /// let g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 0.5)]);
+    /// assert_eq!(g.max_edge(), Some(2.0));
+    /// ```
+    fn max_edge(&self) -> Option<f32> {
+        self.argmax_edge().map(|(_, _, weight)| weight)
+    }
+
+    /// Returns the neighbor reached from `node` through the edge with the maximum
+    /// weight, as a `(neighbor, weight)` pair. Returns `None` if `node` has no edges.
+    /// For directed graphs only the outgoing edges are considered.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, UGraph};
+    /// let g = UGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (0, 2, 3.0)]);
+    /// assert_eq!(g.heaviest_neighbor_of(0), Some((2, 3.0)));
+    /// assert_eq!(g.heaviest_neighbor_of(1), Some((0, 2.0)));
+    /// ```
+    fn heaviest_neighbor_of(&self, node: usize) -> Option<(usize, f32)> {
+        if node >= self.get_n_nodes() {
+            panic!(
+                "[Graph::heaviest_neighbor_of] Error: The node {} is not valid!",
+                node
+            );
+        }
+        self.get_out_neighbors_of(node)
+            .into_iter()
+            .filter_map(|n| self.get_edge(node, n).map(|w| (n, w)))
+            .fold(None, |best, edge| match best {
+                Some((_, w)) if w >= edge.1 => best,
+                _ => Some(edge),
+            })
+    }
+
+    /// Returns the neighbor reached from `node` through the edge with the minimum
+    /// weight, as a `(neighbor, weight)` pair. Returns `None` if `node` has no edges.
+    /// For directed graphs only the outgoing edges are considered.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, UGraph};
+    /// let g = UGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (0, 2, 3.0)]);
+    /// assert_eq!(g.lightest_neighbor_of(0), Some((1, 2.0)));
+    /// ```
+    fn lightest_neighbor_of(&self, node: usize) -> Option<(usize, f32)> {
+        if node >= self.get_n_nodes() {
+            panic!(
+                "[Graph::lightest_neighbor_of] Error: The node {} is not valid!",
+                node
+            );
+        }
+        self.get_out_neighbors_of(node)
+            .into_iter()
+            .filter_map(|n| self.get_edge(node, n).map(|w| (n, w)))
+            .fold(None, |best, edge| match best {
+                Some((_, w)) if w <= edge.1 => best,
+                _ => Some(edge),
+            })
+    }
+
+    /// Returns the weight of the node, for the algorithms that put costs on the nodes
+    /// instead of (or besides) the edges, like node-weighted shortest paths or weighted
+    /// partitioning. By default every node weighs `1.0`, the same as an unweighted edge.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, UGraph};
+    /// let g = UGraph::from_edges(2, vec![(0, 1)]);
+    /// assert_eq!(g.get_node_weight(0), 1.0);
+    /// ```
+    fn get_node_weight(&self, node: usize) -> f32 {
+        if node >= self.get_n_nodes() {
+            panic!(
+                "[Graph::get_node_weight] Error: The node {} is not valid!",
+                node
+            );
+        }
+        1.0
+    }
+
+    /// Sets the weight of the node. Node weights are optional: the graphs that don't store
+    /// them keep this default, which panics, while `UGraph`, `DGraph` and the wrappers
+    /// around them support it.
+    ///
+    /// # Panics
+    ///
+    /// * If the graph doesn't store node weights, or the node is not valid.
+    fn set_node_weight(&mut self, node: usize, weight: f32) {
+        let _ = weight;
+        panic!(
+            "[Graph::set_node_weight] Error: The graph doesn't support node weights (node {})!",
+            node
+        );
+    }
+
+    /// Returns an iterator over the pairs of nodes without an edge, the edges of the
+    /// complement graph, without building it. The self-loops are skipped, and undirected
+    /// graphs yield each pair once as `(node1, node2)` with `node1 < node2`. The pairs are
+    /// yielded in lexicographic order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{DGraph, Graph, UGraph};
+    /// let g = UGraph::from_edges(3, vec![(0, 1), (2, 2)]);
+    /// assert_eq!(g.non_edges().collect::<Vec<_>>(), vec![(0, 2), (1, 2)]);
+    /// let g = DGraph::from_edges(2, vec![(0, 1)]);
+    /// assert_eq!(g.non_edges().collect::<Vec<_>>(), vec![(1, 0)]);
+    /// ```
+    fn non_edges(&self) -> NonEdges<'_, Self>
+    where
+        Self: Sized,
+    {
+        NonEdges {
+            g: self,
+            node1: 0,
+            node2: 0,
+        }
+    }
+}
+
+/// The `NonEdges` struct is the iterator returned by `Graph::non_edges`.
+#[derive(Debug)]
+pub struct NonEdges<'a, G> {
+    g: &'a G,
+    node1: usize,
+    node2: usize,
+}
+
+impl<'a, G> Iterator for NonEdges<'a, G>
+where
+    G: Graph,
+{
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        let n_nodes = self.g.get_n_nodes();
+        while self.node1 < n_nodes {
+            if self.node2 >= n_nodes {
+                self.node1 += 1;
+                // Undirected graphs only look at the pairs above the diagonal
+                self.node2 = if self.g.is_directed() {
+                    0
+                } else {
+                    self.node1 + 1
+                };
+                continue;
+            }
+            let pair = (self.node1, self.node2);
+            self.node2 += 1;
+            if pair.0 != pair.1 && self.g.get_edge(pair.0, pair.1).is_none() {
+                return Some(pair);
+            }
+        }
+        None
+    }
+}
+
+// Bytes allocated by a vector of vectors, counting the capacity of the inner vectors
+pub(crate) fn nested_vec_bytes<T>(v: &[Vec<T>]) -> usize {
+    mem::size_of_val(v)
+        + v.iter()
+            .map(|inner| inner.capacity() * mem::size_of::<T>())
+            .sum::<usize>()
+}
+
+// Maximum number of nodes to show the adjacency matrix in the alternate `Debug` format
+const DEBUG_MATRIX_MAX_NODES: usize = 16;
+
+// Shared `Debug` implementation of the graph types. The default format shows the edges,
+// and the alternate format (`{:#?}`) shows the adjacency matrix of the small graphs, one
+// row per line.
+pub(crate) fn fmt_debug<G>(g: &G, name: &str, f: &mut fmt::Formatter) -> fmt::Result
+where
+    G: Graph,
+{
+    if f.alternate() && g.get_n_nodes() <= DEBUG_MATRIX_MAX_NODES {
+        writeln!(f, "{} {{", name)?;
+        for row in g.get_adjacency_matrix().iter() {
+            writeln!(f, "    {:?},", row)?;
+        }
+        write!(f, "}}")
+    } else {
+        f.debug_struct(name)
+            .field("n_nodes", &g.get_n_nodes())
+            .field("edges", &g.get_edges())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Graph;
+    use std::borrow::Cow;
+
+    // Minimal undirected backend without a dense matrix, to test the default methods
+    struct EdgeListGraph {
+        n_nodes: usize,
+        edges: Vec<(usize, usize, f32)>,
+    }
+
+    impl Graph for EdgeListGraph {
+        fn get_n_nodes(&self) -> usize {
+            self.n_nodes
+        }
+        fn get_nodes(&self) -> Vec<usize> {
+            (0..self.n_nodes).collect()
+        }
+        fn is_directed(&self) -> bool {
+            false
+        }
+        fn get_edge(&self, node1: usize, node2: usize) -> Option<f32> {
+            self.edges
+                .iter()
+                .find(|(a, b, _)| (*a, *b) == (node1, node2) || (*a, *b) == (node2, node1))
+                .map(|(_, _, w)| *w)
+        }
+        fn get_edges(&self) -> Vec<(usize, usize, f32)> {
+            self.edges.clone()
+        }
+        fn add_node(&mut self) -> usize {
+            self.n_nodes += 1;
+            self.n_nodes - 1
+        }
+        fn add_edge(&mut self, node1: usize, node2: usize) {
+            self.add_weighted_edge(node1, node2, 1.0);
+        }
+        fn add_weighted_edge(&mut self, node1: usize, node2: usize, weight: f32) {
+            self.edges.push((node1, node2, weight));
+        }
+    }
+
+    #[test]
+    fn default_get_adjacency_matrix_check_values() {
+        let g = EdgeListGraph {
+            n_nodes: 3,
+            edges: vec![(0, 1, 2.0), (2, 1, 0.5)],
+        };
+        let adj_mat = g.get_adjacency_matrix();
+        assert!(matches!(adj_mat, Cow::Owned(_)));
+        let test_mat: Vec<Vec<f32>> = vec![
+            vec![0.0, 2.0, 0.0],
+            vec![2.0, 0.0, 0.5],
+            vec![0.0, 0.5, 0.0],
+        ];
+        assert_eq!(*adj_mat, test_mat);
+    }
+
+    #[test]
+    fn default_get_out_neighbors_of_check_values() {
+        let g = EdgeListGraph {
+            n_nodes: 3,
+            edges: vec![(0, 1, 2.0), (2, 1, 0.5)],
+        };
+        assert_eq!(g.get_out_neighbors_of(1), vec![0, 2]);
+        assert_eq!(g.get_node_weight(2), 1.0);
+        assert_eq!(g.heaviest_neighbor_of(1), Some((0, 2.0)));
+    }
+
+    #[test]
+    fn non_edges_matches_complement() {
+        let mut rng = crate::rng::Rng::new(32);
+        for _ in 0..20 {
+            let n_nodes = rng.gen_range(8);
+            let mut g = EdgeListGraph {
+                n_nodes,
+                edges: vec![],
+            };
+            for _ in 0..rng.gen_range(15) {
+                if n_nodes > 0 {
+                    g.add_edge(rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                }
+            }
+            let mut expected = vec![];
+            for node1 in 0..n_nodes {
+                for node2 in node1 + 1..n_nodes {
+                    if g.get_edge(node1, node2).is_none() {
+                        expected.push((node1, node2));
+                    }
+                }
+            }
+            assert_eq!(g.non_edges().collect::<Vec<_>>(), expected);
+        }
+    }
 }
@@ -0,0 +1,303 @@
+use crate::Graph;
+use std::fmt;
+
+/// The `Hypergraph` struct provides the functionalities to create and manipulate
+/// `hypergraphs`, where each hyperedge connects a set of nodes instead of a pair. The
+/// hyperedges have a `f32` weight (`1.0` by default), and the nodes are referenced by
+/// `usize` values from `0` to `n_nodes-1`. The hypergraph can be expanded into a
+/// `UGraph` or `DGraph` to use the algorithms of the crate.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Hypergraph {
+    n_nodes: usize,
+    hyperedges: Vec<(Vec<usize>, f32)>,
+}
+
+impl Hypergraph {
+    /// Creates an empty `Hypergraph`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::Hypergraph;
+    /// let h = Hypergraph::new();
+    /// ```
+    pub fn new() -> Hypergraph {
+        Hypergraph {
+            n_nodes: 0,
+            hyperedges: vec![],
+        }
+    }
+
+    /// Creates a `Hypergraph` from the number of nodes and the definition of the
+    /// hyperedges, each one with weight `1.0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_nodes` - An `usize` value with the number of nodes in the hypergraph.
+    /// * `hyperedges` - A vector with the nodes of each hyperedge.
+    ///
+    /// # Panics
+    ///
+    /// * If some hyperedge has an invalid node value or is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::Hypergraph;
+    /// let h = Hypergraph::from_hyperedges(4, vec![vec![0, 1, 2], vec![2, 3]]);
+    /// assert_eq!(h.get_n_hyperedges(), 2);
+    /// ```
+    pub fn from_hyperedges(n_nodes: usize, hyperedges: Vec<Vec<usize>>) -> Hypergraph {
+        Hypergraph::from_weighted_hyperedges(
+            n_nodes,
+            hyperedges.into_iter().map(|nodes| (nodes, 1.0)).collect(),
+        )
+    }
+
+    /// Creates a `Hypergraph` from the number of nodes and the definition of the
+    /// hyperedges with their weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_nodes` - An `usize` value with the number of nodes in the hypergraph.
+    /// * `hyperedges` - A vector of tuples with the nodes and the weight of each hyperedge.
+    ///
+    /// # Panics
+    ///
+    /// * If some hyperedge has an invalid node value or is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::Hypergraph;
+    /// let h = Hypergraph::from_weighted_hyperedges(3, vec![(vec![0, 1, 2], 0.5)]);
+    /// ```
+    pub fn from_weighted_hyperedges(
+        n_nodes: usize,
+        hyperedges: Vec<(Vec<usize>, f32)>,
+    ) -> Hypergraph {
+        let mut h = Hypergraph {
+            n_nodes,
+            hyperedges: vec![],
+        };
+        for (nodes, weight) in hyperedges {
+            h.add_weighted_hyperedge(nodes, weight);
+        }
+        h
+    }
+
+    /// Returns the number of nodes in the hypergraph.
+    pub fn get_n_nodes(&self) -> usize {
+        self.n_nodes
+    }
+
+    /// Returns the number of hyperedges in the hypergraph.
+    pub fn get_n_hyperedges(&self) -> usize {
+        self.hyperedges.len()
+    }
+
+    /// Returns the hyperedges of the hypergraph as `(nodes, weight)` tuples, where the
+    /// index of each hyperedge is its position.
+    pub fn get_hyperedges(&self) -> &[(Vec<usize>, f32)] {
+        &self.hyperedges
+    }
+
+    /// Returns the indices of the hyperedges that contain the node passed as a parameter.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::Hypergraph;
+    /// let h = Hypergraph::from_hyperedges(4, vec![vec![0, 1, 2], vec![2, 3]]);
+    /// assert_eq!(h.get_hyperedges_of(2), vec![0, 1]);
+    /// ```
+    pub fn get_hyperedges_of(&self, node: usize) -> Vec<usize> {
+        if node >= self.n_nodes {
+            panic!(
+                "[Hypergraph::get_hyperedges_of] Error: The node {} is not valid!",
+                node
+            );
+        }
+        self.hyperedges
+            .iter()
+            .enumerate()
+            .filter(|(_, (nodes, _))| nodes.contains(&node))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Adds a node to the hypergraph without any hyperedge and returns its index.
+    pub fn add_node(&mut self) -> usize {
+        self.n_nodes += 1;
+        self.n_nodes - 1
+    }
+
+    /// Adds a hyperedge with weight `1.0` and returns its index.
+    ///
+    /// # Panics
+    ///
+    /// * If the hyperedge has an invalid node value or is empty.
+    pub fn add_hyperedge(&mut self, nodes: Vec<usize>) -> usize {
+        self.add_weighted_hyperedge(nodes, 1.0)
+    }
+
+    /// Adds a hyperedge with the weight passed as a parameter and returns its index. The
+    /// repeated nodes of the hyperedge are removed.
+    ///
+    /// # Panics
+    ///
+    /// * If the hyperedge has an invalid node value or is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::Hypergraph;
+    /// let mut h = Hypergraph::from_hyperedges(3, vec![vec![0, 1]]);
+    /// assert_eq!(h.add_weighted_hyperedge(vec![2, 0, 1], 2.0), 1);
+    /// ```
+    pub fn add_weighted_hyperedge(&mut self, mut nodes: Vec<usize>, weight: f32) -> usize {
+        if nodes.is_empty() {
+            panic!("[Hypergraph::add_weighted_hyperedge] Error: The hyperedge is empty!");
+        }
+        if let Some(node) = nodes.iter().find(|n| **n >= self.n_nodes) {
+            panic!(
+                "[Hypergraph::add_weighted_hyperedge] Error: The node {} is not valid!",
+                node
+            );
+        }
+        nodes.sort_unstable();
+        nodes.dedup();
+        self.hyperedges.push((nodes, weight));
+        self.hyperedges.len() - 1
+    }
+
+    /// Returns the clique expansion of the hypergraph: a graph with the same nodes where
+    /// every pair of nodes of a hyperedge is connected. The weights of the hyperedges that
+    /// share a pair of nodes are summed. Directed graphs get the edges in both directions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, Hypergraph, UGraph};
+    /// let h = Hypergraph::from_hyperedges(4, vec![vec![0, 1, 2], vec![1, 2, 3]]);
+    /// let g: UGraph = h.to_clique_expansion();
+    /// assert_eq!(g.get_edge(1, 2), Some(2.0));
+    /// assert_eq!(g.get_edge(0, 3), None);
+    /// ```
+    pub fn to_clique_expansion<G>(&self) -> G
+    where
+        G: Graph + Default,
+    {
+        let mut g = G::default();
+        for _ in 0..self.n_nodes {
+            g.add_node();
+        }
+        for (nodes, weight) in &self.hyperedges {
+            for (i, node1) in nodes.iter().enumerate() {
+                for node2 in &nodes[i + 1..] {
+                    let current = g.get_edge(*node1, *node2).unwrap_or(0.0);
+                    g.add_weighted_edge(*node1, *node2, current + weight);
+                    if g.is_directed() {
+                        g.add_weighted_edge(*node2, *node1, current + weight);
+                    }
+                }
+            }
+        }
+        g
+    }
+
+    /// Returns the star expansion of the hypergraph: a graph with `n_nodes + n_hyperedges`
+    /// nodes where the hyperedge `e` becomes the node `n_nodes + e`, connected to each of
+    /// its nodes with the weight of the hyperedge. Directed graphs get the edges in both
+    /// directions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{DGraph, Graph, Hypergraph};
+    /// let h = Hypergraph::from_hyperedges(3, vec![vec![0, 1, 2]]);
+    /// let g: DGraph = h.to_star_expansion();
+    /// assert_eq!(g.get_n_nodes(), 4);
+    /// assert_eq!(g.get_successors_of(3), vec![0, 1, 2]);
+    /// assert_eq!(g.get_successors_of(0), vec![3]);
+    /// ```
+    pub fn to_star_expansion<G>(&self) -> G
+    where
+        G: Graph + Default,
+    {
+        let mut g = G::default();
+        for _ in 0..self.n_nodes + self.hyperedges.len() {
+            g.add_node();
+        }
+        for (idx, (nodes, weight)) in self.hyperedges.iter().enumerate() {
+            let hyperedge_node = self.n_nodes + idx;
+            for node in nodes {
+                g.add_weighted_edge(*node, hyperedge_node, *weight);
+                if g.is_directed() {
+                    g.add_weighted_edge(hyperedge_node, *node, *weight);
+                }
+            }
+        }
+        g
+    }
+}
+
+impl fmt::Display for Hypergraph {
+    /// Shows the info of the hypergraph.
+    /// The hyperedges are represented in the format `{nodes}--weight`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Hypergraph(hyperedges=[")?;
+        for (nodes, weight) in &self.hyperedges {
+            writeln!(f, "{:?}--{},", nodes, weight)?;
+        }
+        write!(f, "], n_nodes={})", self.n_nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DGraph, Graph, Hypergraph, UGraph};
+
+    #[test]
+    fn constructor_from_weighted_hyperedges_dedups_nodes() {
+        let h = Hypergraph::from_weighted_hyperedges(3, vec![(vec![2, 0, 2], 1.5)]);
+        assert_eq!(h.get_hyperedges(), &[(vec![0, 2], 1.5)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn constructor_from_hyperedges_panic_not_valid_node() {
+        let _h = Hypergraph::from_hyperedges(2, vec![vec![0, 2]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is empty")]
+    fn add_hyperedge_panic_empty() {
+        let mut h = Hypergraph::new();
+        h.add_node();
+        h.add_hyperedge(vec![]);
+    }
+
+    #[test]
+    fn to_clique_expansion_check_directed_weights() {
+        let h =
+            Hypergraph::from_weighted_hyperedges(3, vec![(vec![0, 1, 2], 2.0), (vec![0, 1], 0.5)]);
+        let g: DGraph = h.to_clique_expansion();
+        assert_eq!(g.get_edge(0, 1), Some(2.5));
+        assert_eq!(g.get_edge(1, 0), Some(2.5));
+        assert_eq!(g.get_edge(2, 1), Some(2.0));
+    }
+
+    #[test]
+    fn to_star_expansion_check_undirected() {
+        let h = Hypergraph::from_hyperedges(3, vec![vec![0, 1], vec![1, 2]]);
+        let g: UGraph = h.to_star_expansion();
+        assert_eq!(g.get_n_nodes(), 5);
+        assert_eq!(g.get_neighbors_of(1), vec![3, 4]);
+        assert_eq!(g.get_neighbors_of(4), vec![1, 2]);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::Graph;
+use std::hash::Hash;
+
+/// The `ImplicitGraph` trait describes a graph by the successors of each state, so the
+/// search algorithms can explore state spaces (puzzles, planning problems) that are too
+/// big to enumerate or that don't fit the indexed-node model. The states can be any
+/// hashable type. Every `Graph` is also an `ImplicitGraph` over its `usize` nodes.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::implicit_dijkstra;
+/// use graphst::ImplicitGraph;
+/// // Reach a number by doubling (cost 1.0) or adding one (cost 1.5)
+/// struct Numbers;
+/// impl ImplicitGraph for Numbers {
+///     type State = u32;
+///     fn successors(&self, n: &u32) -> Vec<(u32, f32)> {
+///         vec![(n * 2, 1.0), (n + 1, 1.5)]
+///     }
+/// }
+/// let (path, cost) = implicit_dijkstra(&Numbers, 1, |n| *n == 10).unwrap();
+/// assert_eq!(path, vec![1, 2, 4, 5, 10]);
+/// assert_eq!(cost, 4.5);
+/// ```
+pub trait ImplicitGraph {
+    /// The type of the states (nodes) of the graph.
+    type State: Clone + Eq + Hash;
+
+    /// Returns the states reachable from `state` through one edge, with the cost of each
+    /// edge.
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, f32)>;
+}
+
+impl<G> ImplicitGraph for G
+where
+    G: Graph,
+{
+    type State = usize;
+
+    fn successors(&self, node: &usize) -> Vec<(usize, f32)> {
+        self.get_out_neighbors_of(*node)
+            .into_iter()
+            .filter_map(|n| self.get_edge(*node, n).map(|w| (n, w)))
+            .collect()
+    }
+}
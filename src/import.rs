@@ -0,0 +1,195 @@
+//! # Import
+//!
+//! `import` is a `graphst` module to build graphs from data keyed by arbitrary string ids
+//! (user names, URLs...), keeping the mapping between the ids and the node indices.
+
+use crate::edge_policy::EdgeMerger;
+use crate::graph::Graph;
+use crate::{DuplicatePolicy, GraphError, SelfLoopPolicy};
+use std::collections::HashMap;
+
+/// The `IdMap` struct is a bidirectional mapping between string ids and node indices.
+/// The indices are assigned in order of insertion, from `0` to `len-1`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::import::IdMap;
+/// let mut ids = IdMap::new();
+/// assert_eq!(ids.insert("alice"), 0);
+/// assert_eq!(ids.insert("bob"), 1);
+/// assert_eq!(ids.insert("alice"), 0); // already mapped
+/// assert_eq!(ids.get_index("bob"), Some(1));
+/// assert_eq!(ids.get_id(0), Some("alice"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IdMap {
+    ids: Vec<String>,
+    indices: HashMap<String, usize>,
+}
+
+impl IdMap {
+    /// Creates an empty `IdMap`.
+    pub fn new() -> IdMap {
+        IdMap {
+            ids: vec![],
+            indices: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of ids in the map.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns `true` if the map has no ids.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Returns the index of the id, mapping it to the next index if it is new.
+    pub fn insert(&mut self, id: &str) -> usize {
+        if let Some(index) = self.indices.get(id) {
+            return *index;
+        }
+        self.ids.push(id.to_string());
+        self.indices.insert(id.to_string(), self.ids.len() - 1);
+        self.ids.len() - 1
+    }
+
+    /// Returns the index of the id, or `None` if it is not mapped.
+    pub fn get_index(&self, id: &str) -> Option<usize> {
+        self.indices.get(id).copied()
+    }
+
+    /// Returns the id of the index, or `None` if the index is not mapped.
+    pub fn get_id(&self, index: usize) -> Option<&str> {
+        self.ids.get(index).map(|id| id.as_str())
+    }
+
+    /// Returns the ids sorted by their index.
+    pub fn get_ids(&self) -> &[String] {
+        &self.ids
+    }
+}
+
+/// Builds a graph from edges between string ids, each one with weight `1.0`. The ids get
+/// the node indices in order of appearance. See `from_weighted_id_edges`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::import::from_id_edges;
+/// use graphst::{DGraph, Graph};
+/// let (g, ids): (DGraph, _) = from_id_edges(vec![("user123", "user456"), ("user456", "user789")]);
+/// assert_eq!(g.get_n_nodes(), 3);
+/// let src = ids.get_index("user456").unwrap();
+/// let dest = ids.get_index("user789").unwrap();
+/// assert_eq!(g.get_edge(src, dest), Some(1.0));
+/// ```
+pub fn from_id_edges<G, I, S>(edges: I) -> (G, IdMap)
+where
+    G: Graph + Default,
+    I: IntoIterator<Item = (S, S)>,
+    S: AsRef<str>,
+{
+    from_weighted_id_edges(edges.into_iter().map(|(id1, id2)| (id1, id2, 1.0)))
+}
+
+/// Builds a graph from weighted edges between string ids, and returns it with the
+/// mapping between the ids and the nodes. The ids get the node indices in order of
+/// appearance, and when an edge is repeated the last weight is kept (see
+/// `from_weighted_id_edges_with` for other policies).
+///
+/// # Examples
+///
+/// ```
+/// use graphst::import::from_weighted_id_edges;
+/// use graphst::{Graph, UGraph};
+/// let edges = vec![("madrid", "paris", 1270.0), ("paris", "berlin", 1050.0)];
+/// let (g, ids): (UGraph, _) = from_weighted_id_edges(edges);
+/// assert_eq!(ids.get_ids(), &["madrid", "paris", "berlin"]);
+/// assert_eq!(g.get_edge(2, 1), Some(1050.0));
+/// ```
+pub fn from_weighted_id_edges<G, I, S>(edges: I) -> (G, IdMap)
+where
+    G: Graph + Default,
+    I: IntoIterator<Item = (S, S, f32)>,
+    S: AsRef<str>,
+{
+    from_weighted_id_edges_with(edges, DuplicatePolicy::KeepLast, SelfLoopPolicy::Allow)
+        .expect("Keeping the last weight and the self-loops never fails")
+}
+
+/// Same as `from_weighted_id_edges` but handling the repeated edges and the self-loops with
+/// the policies passed as parameters. The ids of the ignored self-loops still get a node.
+///
+/// # Errors
+///
+/// * `GraphError::DuplicateEdge` if some edge is repeated with `DuplicatePolicy::Error`.
+/// * `GraphError::SelfLoop` if some edge is a self-loop with `SelfLoopPolicy::Error`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::import::from_weighted_id_edges_with;
+/// use graphst::{DGraph, DuplicatePolicy, Graph, SelfLoopPolicy};
+/// // Two transfers from alice to bob in a log of payments
+/// let edges = vec![("alice", "bob", 10.0), ("bob", "carol", 5.0), ("alice", "bob", 2.5)];
+/// let (sum, allow) = (DuplicatePolicy::Sum, SelfLoopPolicy::Allow);
+/// let (g, ids): (DGraph, _) = from_weighted_id_edges_with(edges, sum, allow).unwrap();
+/// assert_eq!(g.get_edge(ids.get_index("alice").unwrap(), 1), Some(12.5));
+/// ```
+pub fn from_weighted_id_edges_with<G, I, S>(
+    edges: I,
+    duplicates: DuplicatePolicy,
+    self_loops: SelfLoopPolicy,
+) -> Result<(G, IdMap), GraphError>
+where
+    G: Graph + Default,
+    I: IntoIterator<Item = (S, S, f32)>,
+    S: AsRef<str>,
+{
+    let mut ids = IdMap::new();
+    let mut g = G::default();
+    let mut merger = EdgeMerger::new(
+        "import::from_weighted_id_edges_with",
+        duplicates,
+        self_loops,
+    );
+    for (id1, id2, weight) in edges {
+        let node1 = ids.insert(id1.as_ref());
+        let node2 = ids.insert(id2.as_ref());
+        while g.get_n_nodes() < ids.len() {
+            g.add_node();
+        }
+        merger.add(&mut g, node1, node2, weight)?;
+    }
+    Ok((g, ids))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DGraph;
+
+    #[test]
+    fn from_id_edges_check_owned_ids_and_self_loops() {
+        let edges = vec![
+            (String::from("a"), String::from("a")),
+            (String::from("b"), String::from("a")),
+        ];
+        let (g, ids): (DGraph, _) = from_id_edges(edges);
+        assert_eq!(g.get_edges(), vec![(0, 0, 1.0), (1, 0, 1.0)]);
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids.get_index("c"), None);
+        assert_eq!(ids.get_id(2), None);
+    }
+
+    #[test]
+    fn from_weighted_id_edges_keeps_last_weight() {
+        let (g, ids): (DGraph, _) = from_weighted_id_edges(vec![("x", "y", 1.0), ("x", "y", 3.0)]);
+        assert_eq!(g.get_edges(), vec![(0, 1, 3.0)]);
+        assert!(!ids.is_empty());
+    }
+}
@@ -0,0 +1,267 @@
+//! # Layout
+//!
+//! `layout` is a `graphst` module with the coordinates used to draw graphs. The positions
+//! are `(x, y)` pairs indexed by node, and they can be exported to CSV or JSON to plot
+//! the graph in external tools like matplotlib or Observable.
+
+use std::f32::consts::PI;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The `ExportFormat` enum lists the file formats of `export`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One line per node with the header `node,label,x,y`.
+    Csv,
+    /// An array with one `{"node", "label", "x", "y"}` object per node.
+    Json,
+}
+
+/// Returns the positions of `n_nodes` nodes evenly spaced on the unit circle, starting at
+/// `(1.0, 0.0)` and going counterclockwise.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::layout::circular;
+/// let positions = circular(4);
+/// assert_eq!(positions[0], (1.0, 0.0));
+/// assert!((positions[1].1 - 1.0).abs() < 1e-6);
+/// ```
+pub fn circular(n_nodes: usize) -> Vec<(f32, f32)> {
+    (0..n_nodes)
+        .map(|node| {
+            let angle = 2.0 * PI * node as f32 / n_nodes as f32;
+            (angle.cos(), angle.sin())
+        })
+        .collect()
+}
+
+/// Returns the positions formatted as CSV or JSON, where the label of each node is its
+/// index, or `labels[node]` if labels are provided (e.g. the ids of an `import::IdMap`).
+/// The non-finite coordinates are written as empty CSV fields or JSON `null` values.
+///
+/// # Panics
+///
+/// * If the number of labels and positions is different.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::layout::{format_positions, ExportFormat};
+/// let positions = vec![(0.0, 1.5), (2.0, -1.0)];
+/// let labels = vec!["a".to_string(), "b,c".to_string()];
+/// let csv = format_positions(&positions, Some(&labels), ExportFormat::Csv);
+/// assert_eq!(csv, "node,label,x,y\n0,a,0,1.5\n1,\"b,c\",2,-1\n");
+/// let json = format_positions(&positions, None, ExportFormat::Json);
+/// assert_eq!(
+///     json,
+///     "[\n  {\"node\": 0, \"label\": \"0\", \"x\": 0, \"y\": 1.5},\n  \
+///      {\"node\": 1, \"label\": \"1\", \"x\": 2, \"y\": -1}\n]\n"
+/// );
+/// ```
+pub fn format_positions(
+    positions: &[(f32, f32)],
+    labels: Option<&[String]>,
+    format: ExportFormat,
+) -> String {
+    if let Some(labels) = labels {
+        if labels.len() != positions.len() {
+            panic!(
+                "[layout::format_positions] Error: There are {} labels for {} positions!",
+                labels.len(),
+                positions.len()
+            );
+        }
+    }
+    let label_of = |node: usize| match labels {
+        Some(labels) => labels[node].clone(),
+        None => node.to_string(),
+    };
+    let mut out = String::new();
+    match format {
+        ExportFormat::Csv => {
+            out.push_str("node,label,x,y\n");
+            for (node, (x, y)) in positions.iter().enumerate() {
+                out.push_str(&format!(
+                    "{},{},{},{}\n",
+                    node,
+                    csv_field(&label_of(node)),
+                    csv_number(*x),
+                    csv_number(*y)
+                ));
+            }
+        }
+        ExportFormat::Json => {
+            out.push_str("[\n");
+            for (node, (x, y)) in positions.iter().enumerate() {
+                let separator = if node + 1 < positions.len() { "," } else { "" };
+                out.push_str(&format!(
+                    "  {{\"node\": {}, \"label\": {}, \"x\": {}, \"y\": {}}}{}\n",
+                    node,
+                    json_string(&label_of(node)),
+                    json_number(*x),
+                    json_number(*y),
+                    separator
+                ));
+            }
+            out.push_str("]\n");
+        }
+    }
+    out
+}
+
+/// Writes the positions to the file `path` in the format passed as a parameter, with the
+/// node indices as labels (see `format_positions`).
+///
+/// # Errors
+///
+/// * If the file can't be written.
+///
+/// # Examples
+///
+/// ```no_run
+/// use graphst::layout::{circular, export, ExportFormat};
+/// export(&circular(10), ExportFormat::Csv, "positions.csv").unwrap();
+/// ```
+pub fn export<P>(positions: &[(f32, f32)], format: ExportFormat, path: P) -> io::Result<()>
+where
+    P: AsRef<Path>,
+{
+    fs::write(path, format_positions(positions, None, format))
+}
+
+/// Same as `export` but with a label for each node.
+///
+/// # Errors
+///
+/// * If the file can't be written.
+///
+/// # Panics
+///
+/// * If the number of labels and positions is different.
+pub fn export_with_labels<P>(
+    positions: &[(f32, f32)],
+    labels: &[String],
+    format: ExportFormat,
+    path: P,
+) -> io::Result<()>
+where
+    P: AsRef<Path>,
+{
+    fs::write(path, format_positions(positions, Some(labels), format))
+}
+
+//--------- Private functions ---------
+
+// Quotes the field if it has separators, quotes or line breaks
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn csv_number(value: f32) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        String::new()
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_number(value: f32) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_positions_escapes_labels_and_non_finite_values() {
+        let positions = vec![(f32::NAN, 1.0)];
+        let labels = vec!["say \"hi\"\n".to_string()];
+        assert_eq!(
+            format_positions(&positions, Some(&labels), ExportFormat::Csv),
+            "node,label,x,y\n0,\"say \"\"hi\"\"\n\",,1\n"
+        );
+        assert_eq!(
+            format_positions(&positions, Some(&labels), ExportFormat::Json),
+            "[\n  {\"node\": 0, \"label\": \"say \\\"hi\\\"\\n\", \"x\": null, \"y\": 1}\n]\n"
+        );
+    }
+
+    #[test]
+    fn format_positions_round_trip_random_positions() {
+        let mut rng = crate::rng::Rng::new(1014);
+        for _ in 0..20 {
+            let positions: Vec<(f32, f32)> = (0..1 + rng.gen_range(30))
+                .map(|_| {
+                    let x = (rng.next_f64() as f32 - 0.5) * 1e4;
+                    (x, x * rng.next_f64() as f32 / 3.0)
+                })
+                .collect();
+            let csv = format_positions(&positions, None, ExportFormat::Csv);
+            let from_csv: Vec<(f32, f32)> = csv
+                .lines()
+                .skip(1)
+                .map(|line| {
+                    let fields: Vec<&str> = line.split(',').collect();
+                    (fields[2].parse().unwrap(), fields[3].parse().unwrap())
+                })
+                .collect();
+            assert_eq!(from_csv, positions);
+            let json = format_positions(&positions, None, ExportFormat::Json);
+            let value_of = |line: &str, key: &str| -> f32 {
+                let start = line.find(key).unwrap() + key.len();
+                let end = line[start..].find([',', '}']).unwrap();
+                line[start..start + end].parse().unwrap()
+            };
+            let from_json: Vec<(f32, f32)> = json
+                .lines()
+                .filter(|line| line.contains("\"node\""))
+                .map(|line| (value_of(line, "\"x\": "), value_of(line, "\"y\": ")))
+                .collect();
+            assert_eq!(from_json, positions);
+        }
+    }
+
+    #[test]
+    fn export_writes_file() {
+        let path = std::env::temp_dir().join("graphst_layout_export_test.json");
+        export(&circular(2), ExportFormat::Json, &path).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(content.starts_with("[\n  {\"node\": 0, \"label\": \"0\", \"x\": 1, \"y\": 0}"));
+    }
+
+    #[test]
+    #[should_panic(expected = "labels for")]
+    fn format_positions_panic_wrong_number_of_labels() {
+        format_positions(&[(0.0, 0.0)], Some(&[]), ExportFormat::Csv);
+    }
+}
@@ -2,9 +2,26 @@
 //!
 //! `graphst` is a library to create and manipulate graphs. It also provides some
 //! implementations of popular graph algorithms.
+//!
+//! ## Thread safety
+//!
+//! The graph types are `Send + Sync`. All the query methods take `&self`, so a graph
+//! can be shared between threads for read-only analytics (see the `parallel` module),
+//! and the mutating methods take `&mut self`, so the compiler rejects concurrent
+//! modifications.
+
+pub mod float;
+
+mod adj_index;
+
+mod error;
+pub use error::GraphError; // Error type of the fallible functions
+
+mod edge_policy;
+pub use edge_policy::{DuplicatePolicy, SelfLoopPolicy}; // Handling of the repeated edges and self-loops of the edge lists
 
 mod graph;
-pub use graph::Graph; // Graph trait
+pub use graph::{Graph, NonEdges}; // Graph trait and the iterator of its non-edges
 
 mod ugraph;
 pub use ugraph::UGraph; // Undirected Graph
@@ -12,4 +29,73 @@ pub use ugraph::UGraph; // Undirected Graph
 mod dgraph;
 pub use dgraph::DGraph; // Directed Graph
 
+mod dag;
+pub use dag::DagGraph; // Directed Acyclic Graph
+
+mod hypergraph;
+pub use hypergraph::Hypergraph; // Hypergraph (hyperedges over node sets)
+
+mod disjoint_set;
+pub use disjoint_set::DisjointSet; // Union-find of disjoint sets
+
+mod stable;
+pub use stable::{NodeId, StableGraph}; // Graph wrapper with stable node ids
+
+mod edge_data;
+pub use edge_data::EdgeData; // Graph wrapper with a typed value on each edge
+
+mod flow;
+pub use flow::{FlowArc, FlowNetwork, ResidualArc}; // Flow Network (arcs with capacity, cost and flow)
+
+mod temporal;
+pub use temporal::TemporalGraph; // Temporal Graph (stream of timestamped edges)
+
+mod fn_graph;
+pub use fn_graph::FnGraph; // Implicit graph defined by a weight function
+
+mod implicit;
+pub use implicit::ImplicitGraph; // State space defined by the successors of each state
+
 pub mod algorithm;
+
+pub mod parallel;
+
+pub mod dense;
+
+pub mod backend;
+
+pub mod rng;
+
+pub mod generate;
+
+pub mod construct;
+
+pub mod benchmark;
+
+pub mod stats;
+
+pub mod centrality;
+
+pub mod ranking;
+
+pub mod ops;
+
+pub mod dot;
+
+pub mod layout;
+
+pub mod animate;
+
+pub mod path;
+
+pub mod import;
+
+pub mod sample;
+
+pub mod script;
+
+pub mod text;
+
+pub mod delta;
+
+pub mod nx;
@@ -1,7 +1,35 @@
 use graphst::algorithm::dijkstra;
-use graphst::{DGraph, UGraph};
+use graphst::{script, DGraph, UGraph};
+use std::{env, fs, process};
 
 fn main() {
+    let args: Vec<String> = env::args().collect();
+    match args.get(1).map(|arg| arg.as_str()) {
+        Some("run") if args.len() == 3 => run_script(&args[2]),
+        Some(_) => {
+            eprintln!("Usage: graphst [run <script.gst>]");
+            process::exit(2);
+        }
+        None => demo(),
+    }
+}
+
+// Runs the commands of a script file, see the `script` module for the format
+fn run_script(path: &str) {
+    let source = fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("Error: Can't read the file {}: {}", path, e);
+        process::exit(1);
+    });
+    match script::run(&source) {
+        Ok(output) => print!("{}", output),
+        Err(e) => {
+            eprintln!("Error: {}: {}", path, e);
+            process::exit(1);
+        }
+    }
+}
+
+fn demo() {
     // Test an undirected graph
     let n_nodes = 9;
     let edges = vec![
@@ -0,0 +1,276 @@
+//! # NX
+//!
+//! `nx` is a `graphst` module with functions named after their NetworkX counterparts,
+//! to ease the migration of Python code. They are thin wrappers over the rest of the
+//! crate, and the differences in semantics are noted in each function. The main one is
+//! that the nodes are always the `usize` values from `0` to `n_nodes-1`, instead of any
+//! hashable object.
+
+use crate::algorithm::{dijkstra, resolve_dependencies};
+use crate::graph::Graph;
+use crate::{float, ops, DGraph, GraphError};
+
+/// Same as `G.number_of_nodes()` in NetworkX.
+pub fn number_of_nodes<G>(g: &G) -> usize
+where
+    G: Graph,
+{
+    g.get_n_nodes()
+}
+
+/// Same as `G.number_of_edges()` in NetworkX, the edges of undirected graphs are counted
+/// once.
+pub fn number_of_edges<G>(g: &G) -> usize
+where
+    G: Graph,
+{
+    g.get_edges().len()
+}
+
+/// Same as `G.has_edge(u, v)` in NetworkX.
+///
+/// # Panics
+///
+/// * If some node is not valid.
+pub fn has_edge<G>(g: &G, u: usize, v: usize) -> bool
+where
+    G: Graph,
+{
+    g.get_edge(u, v).is_some()
+}
+
+/// Same as `G.neighbors(n)` in NetworkX: the neighbors of undirected graphs and the
+/// successors of directed graphs, sorted.
+///
+/// # Panics
+///
+/// * If the node passed as a parameter is not valid.
+pub fn neighbors<G>(g: &G, n: usize) -> Vec<usize>
+where
+    G: Graph,
+{
+    g.get_out_neighbors_of(n)
+}
+
+/// Same as `G.degree[n]` in NetworkX: the number of edges of the node, where self-loops
+/// count twice in undirected graphs, and the in-degree plus the out-degree in directed
+/// graphs.
+///
+/// # Panics
+///
+/// * If the node passed as a parameter is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{nx, DGraph};
+/// let g = DGraph::from_edges(3, vec![(0, 1), (1, 2), (2, 1)]);
+/// assert_eq!(nx::degree(&g, 1), 3);
+/// ```
+pub fn degree<G>(g: &G, n: usize) -> usize
+where
+    G: Graph,
+{
+    if n >= g.get_n_nodes() {
+        panic!("[nx::degree] Error: The node {} is not valid!", n);
+    }
+    g.get_edges()
+        .iter()
+        .map(|(node1, node2, _)| (*node1 == n) as usize + (*node2 == n) as usize)
+        .sum()
+}
+
+/// Same as `nx.density(G)`: the number of edges divided by the maximum number of edges
+/// without self-loops. Graphs with less than two nodes have density `0.0`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{nx, UGraph};
+/// let g = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(nx::density(&g), 0.5);
+/// ```
+pub fn density<G>(g: &G) -> f32
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes() as f32;
+    if n_nodes < 2.0 {
+        return 0.0;
+    }
+    let max_edges = if g.is_directed() {
+        n_nodes * (n_nodes - 1.0)
+    } else {
+        n_nodes * (n_nodes - 1.0) / 2.0
+    };
+    number_of_edges(g) as f32 / max_edges
+}
+
+/// Same as `nx.degree_centrality(G)`: the degree of each node (see `degree`) divided by
+/// `n - 1`, returned in a vector indexed by node instead of a dict. Graphs with less than
+/// two nodes have centrality `1.0`, like in NetworkX.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{nx, UGraph};
+/// let g = UGraph::from_edges(3, vec![(0, 1), (0, 2)]);
+/// assert_eq!(nx::degree_centrality(&g), vec![1.0, 0.5, 0.5]);
+/// ```
+pub fn degree_centrality<G>(g: &G) -> Vec<f32>
+where
+    G: Graph,
+{
+    let n_nodes = g.get_n_nodes();
+    if n_nodes < 2 {
+        return vec![1.0; n_nodes];
+    }
+    let mut degrees = vec![0; n_nodes];
+    for (node1, node2, _) in g.get_edges() {
+        degrees[node1] += 1;
+        degrees[node2] += 1;
+    }
+    degrees
+        .into_iter()
+        .map(|d| d as f32 / (n_nodes - 1) as f32)
+        .collect()
+}
+
+/// Same as `nx.shortest_path(G, source, target, weight="weight")`: the nodes of a
+/// shortest path from `source` to `target`, using the weights of the edges. Returns
+/// `None` if there is no path, instead of raising `NetworkXNoPath`.
+///
+/// # Panics
+///
+/// * If some node is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{nx, UGraph};
+/// let g = UGraph::from_weighted_edges(3, vec![(0, 1, 1.0), (1, 2, 1.0), (0, 2, 5.0)]);
+/// assert_eq!(nx::shortest_path(&g, 0, 2), Some(vec![0, 1, 2]));
+/// ```
+pub fn shortest_path<G>(g: &G, source: usize, target: usize) -> Option<Vec<usize>>
+where
+    G: Graph,
+{
+    check_nodes(g, source, target, "shortest_path");
+    let dist = dijkstra(g, source);
+    if dist[target] == f32::INFINITY {
+        return None;
+    }
+    // Walk back from the target through the nodes that are tight with the distances
+    let mut path = vec![target];
+    let mut current = target;
+    while current != source {
+        current = g
+            .get_nodes()
+            .into_iter()
+            .find(|p| {
+                dist[*p] < dist[current]
+                    && g.get_edge(*p, current)
+                        .is_some_and(|w| float::approx_eq(dist[*p] + w, dist[current]))
+            })
+            .expect("The distances of dijkstra are tight");
+        path.push(current);
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// Same as `nx.shortest_path_length(G, source, target, weight="weight")`. Returns `None`
+/// if there is no path, instead of raising `NetworkXNoPath`.
+///
+/// # Panics
+///
+/// * If some node is not valid.
+pub fn shortest_path_length<G>(g: &G, source: usize, target: usize) -> Option<f32>
+where
+    G: Graph,
+{
+    check_nodes(g, source, target, "shortest_path_length");
+    let dist = dijkstra(g, source)[target];
+    if dist == f32::INFINITY {
+        None
+    } else {
+        Some(dist)
+    }
+}
+
+/// Same as `G.subgraph(nodes)` in NetworkX, but the nodes are relabeled: the node
+/// `nodes[i]` becomes the node `i` of the subgraph. See `ops::induced_subgraph`.
+///
+/// # Panics
+///
+/// * If some node is not valid or is repeated.
+pub fn subgraph<G>(g: &G, nodes: &[usize]) -> G
+where
+    G: Graph + Default,
+{
+    ops::induced_subgraph(g, nodes)
+}
+
+/// Same as `nx.topological_sort(G)`, returning an error with the cycles of the graph
+/// instead of raising `NetworkXUnfeasible`. See `algorithm::resolve_dependencies`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{nx, DGraph};
+/// let g = DGraph::from_edges(3, vec![(2, 0), (0, 1)]);
+/// assert_eq!(nx::topological_sort(&g), Ok(vec![2, 0, 1]));
+/// ```
+pub fn topological_sort(g: &DGraph) -> Result<Vec<usize>, GraphError> {
+    resolve_dependencies(g).map(|batches| batches.into_iter().flatten().collect())
+}
+
+/// Same as `nx.is_directed_acyclic_graph(G)`.
+pub fn is_directed_acyclic_graph(g: &DGraph) -> bool {
+    resolve_dependencies(g).is_ok()
+}
+
+//--------- Private functions ---------
+
+fn check_nodes<G>(g: &G, source: usize, target: usize, caller: &str)
+where
+    G: Graph,
+{
+    if let Some(node) = [source, target].iter().find(|n| **n >= g.get_n_nodes()) {
+        panic!("[nx::{}] Error: The node {} is not valid!", caller, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UGraph;
+
+    #[test]
+    fn shortest_path_check_directed_and_unreachable() {
+        let g = DGraph::from_weighted_edges(4, vec![(0, 1, 2.0), (1, 2, 0.5), (2, 0, 1.0)]);
+        assert_eq!(shortest_path(&g, 1, 0), Some(vec![1, 2, 0]));
+        assert_eq!(shortest_path(&g, 0, 0), Some(vec![0]));
+        assert_eq!(shortest_path(&g, 0, 3), None);
+        assert_eq!(shortest_path_length(&g, 1, 0), Some(1.5));
+    }
+
+    #[test]
+    fn degree_check_self_loops() {
+        let g = UGraph::from_edges(2, vec![(0, 0), (0, 1)]);
+        assert_eq!(degree(&g, 0), 3);
+        assert_eq!(degree(&g, 1), 1);
+    }
+
+    #[test]
+    fn is_directed_acyclic_graph_check_values() {
+        assert!(is_directed_acyclic_graph(&DGraph::from_edges(
+            2,
+            vec![(0, 1)]
+        )));
+        assert!(!is_directed_acyclic_graph(&DGraph::from_edges(
+            2,
+            vec![(0, 1), (1, 0)]
+        )));
+    }
+}
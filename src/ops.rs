@@ -0,0 +1,478 @@
+//! # Ops
+//!
+//! `ops` is a `graphst` module with operations that build new graphs from existing ones,
+//! and compare them. The functions are generic over the graph type and return a graph of
+//! the same type, with the node weights carried over to the new nodes.
+
+use crate::float;
+use crate::graph::Graph;
+use crate::rng::Rng;
+use std::collections::BTreeSet;
+
+// Creates an empty graph of type `G` with `n_nodes` nodes
+fn empty_graph<G>(n_nodes: usize) -> G
+where
+    G: Graph + Default,
+{
+    let mut g = G::default();
+    g.add_nodes(n_nodes);
+    g
+}
+
+// Sets the weight of the node `node` of `to` (skipped for the default weight `1.0`, the
+// only one of the graphs without node weights)
+fn set_node_weight<G>(to: &mut G, node: usize, weight: f32)
+where
+    G: Graph,
+{
+    if weight != 1.0 {
+        to.set_node_weight(node, weight);
+    }
+}
+
+/// Projects the graph onto a new set of `new_n` nodes, mapping each node `n` to `f(n)`.
+/// The weights of the edges that end up connecting the same pair of nodes are summed,
+/// and the edges between nodes mapped together become self-loops. The node weights are
+/// summed in the same way (the new nodes without any node mapped to them keep the weight
+/// `1.0`). This generalizes the quotient graph for any grouping (e.g. routers grouped by
+/// datacenter).
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph` and `Default`.
+/// * `f` - The function that maps each node of `g` to a node of the new graph.
+/// * `new_n` - An `usize` value with the number of nodes of the new graph.
+///
+/// # Panics
+///
+/// * If `f` maps some node to a value not lower than `new_n`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{Graph, UGraph};
+/// use graphst::ops::map_nodes;
+/// let edges = vec![(0, 1, 1.0), (1, 2, 2.0), (2, 3, 3.0), (0, 3, 4.0)];
+/// let g = UGraph::from_weighted_edges(4, edges);
+/// // Nodes 0 and 1 go to the group 0, nodes 2 and 3 to the group 1
+/// let groups = map_nodes(&g, |n| n / 2, 2);
+/// assert_eq!(groups.get_edge(0, 1), Some(6.0));
+/// assert_eq!(groups.get_edge(0, 0), Some(1.0));
+/// assert_eq!(groups.get_edge(1, 1), Some(3.0));
+/// ```
+pub fn map_nodes<G, F>(g: &G, f: F, new_n: usize) -> G
+where
+    G: Graph + Default,
+    F: Fn(usize) -> usize,
+{
+    let mapping: Vec<usize> = g.get_nodes().into_iter().map(&f).collect();
+    if let Some(node) = mapping.iter().find(|n| **n >= new_n) {
+        panic!(
+            "[ops::map_nodes] Error: The mapped node {} is not valid!",
+            node
+        );
+    }
+    let mut mapped: G = empty_graph(new_n);
+    let mut node_weights = vec![None; new_n];
+    for (node, new) in mapping.iter().enumerate() {
+        let weight = node_weights[*new].unwrap_or(0.0) + g.get_node_weight(node);
+        node_weights[*new] = Some(weight);
+    }
+    for (new, weight) in node_weights.into_iter().enumerate() {
+        set_node_weight(&mut mapped, new, weight.unwrap_or(1.0));
+    }
+    for (node1, node2, weight) in g.get_edges() {
+        let (new1, new2) = (f(node1), f(node2));
+        let current = mapped.get_edge(new1, new2).unwrap_or(0.0);
+        mapped.add_weighted_edge(new1, new2, current + weight);
+    }
+    mapped
+}
+
+/// Returns the subgraph induced by the nodes passed as a parameter: the graph with those
+/// nodes and the edges between them. The node `nodes[i]` of `g` becomes the node `i` of
+/// the subgraph.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph` and `Default`.
+/// * `nodes` - A slice with the nodes of `g` to keep.
+///
+/// # Panics
+///
+/// * If some node is not valid or is repeated.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{Graph, UGraph};
+/// use graphst::ops::induced_subgraph;
+/// let g = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let sub = induced_subgraph(&g, &[3, 0, 1]);
+/// assert_eq!(sub.get_n_nodes(), 3);
+/// assert_eq!(sub.get_edges(), vec![(0, 1, 1.0), (1, 2, 1.0)]);
+/// ```
+pub fn induced_subgraph<G>(g: &G, nodes: &[usize]) -> G
+where
+    G: Graph + Default,
+{
+    let mut position = vec![None; g.get_n_nodes()];
+    for (idx, node) in nodes.iter().enumerate() {
+        if *node >= g.get_n_nodes() || position[*node].is_some() {
+            panic!(
+                "[ops::induced_subgraph] Error: The node {} is not valid!",
+                node
+            );
+        }
+        position[*node] = Some(idx);
+    }
+    let mut sub: G = empty_graph(nodes.len());
+    for (new, node) in nodes.iter().enumerate() {
+        set_node_weight(&mut sub, new, g.get_node_weight(*node));
+    }
+    for (node1, node2, weight) in g.get_edges() {
+        if let (Some(new1), Some(new2)) = (position[node1], position[node2]) {
+            sub.add_weighted_edge(new1, new2, weight);
+        }
+    }
+    sub
+}
+
+/// Returns a copy of the graph without the isolated nodes (the ones with no incident
+/// edges, where a self-loop counts as an edge), with the remaining nodes renumbered in
+/// order. It also returns the mappings between the indices: `old_to_new[n]` is the new
+/// index of the node `n` of `g` (`None` if it was removed), and `new_to_old[i]` is the
+/// node of `g` that became the node `i`. The graphs imported from sparse id spaces often
+/// have most of their nodes isolated.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{DGraph, Graph};
+/// use graphst::ops::compact;
+/// let g = DGraph::from_weighted_edges(6, vec![(4, 1, 2.0), (5, 5, 1.0)]);
+/// let (compacted, old_to_new, new_to_old) = compact(&g);
+/// assert_eq!(compacted.get_edges(), vec![(1, 0, 2.0), (2, 2, 1.0)]);
+/// assert_eq!(old_to_new, vec![None, Some(0), None, None, Some(1), Some(2)]);
+/// assert_eq!(new_to_old, vec![1, 4, 5]);
+/// ```
+pub fn compact<G>(g: &G) -> (G, Vec<Option<usize>>, Vec<usize>)
+where
+    G: Graph + Default,
+{
+    let mut incident = vec![false; g.get_n_nodes()];
+    for (node1, node2, _) in g.get_edges() {
+        incident[node1] = true;
+        incident[node2] = true;
+    }
+    let new_to_old: Vec<usize> = g
+        .get_nodes()
+        .into_iter()
+        .filter(|node| incident[*node])
+        .collect();
+    let mut old_to_new = vec![None; g.get_n_nodes()];
+    for (new, old) in new_to_old.iter().enumerate() {
+        old_to_new[*old] = Some(new);
+    }
+    (induced_subgraph(g, &new_to_old), old_to_new, new_to_old)
+}
+
+/// Returns the disjoint union of two graphs: a graph with the nodes and edges of `g1`
+/// followed by the ones of `g2`, where the node `n` of `g2` becomes the node `n + offset`.
+/// The offset (the number of nodes of `g1`) is returned with the graph.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{Graph, UGraph};
+/// use graphst::ops::disjoint_union;
+/// let g1 = UGraph::from_edges(2, vec![(0, 1)]);
+/// let g2 = UGraph::from_edges(3, vec![(0, 2)]);
+/// let (g, offset) = disjoint_union(&g1, &g2);
+/// assert_eq!(offset, 2);
+/// assert_eq!(g.get_n_nodes(), 5);
+/// assert_eq!(g.get_edges(), vec![(0, 1, 1.0), (2, 4, 1.0)]);
+/// ```
+pub fn disjoint_union<G>(g1: &G, g2: &G) -> (G, usize)
+where
+    G: Graph + Default,
+{
+    let offset = g1.get_n_nodes();
+    let mut union: G = empty_graph(offset + g2.get_n_nodes());
+    for node in g1.get_nodes() {
+        set_node_weight(&mut union, node, g1.get_node_weight(node));
+    }
+    for node in g2.get_nodes() {
+        set_node_weight(&mut union, node + offset, g2.get_node_weight(node));
+    }
+    for (node1, node2, weight) in g1.get_edges() {
+        union.add_weighted_edge(node1, node2, weight);
+    }
+    for (node1, node2, weight) in g2.get_edges() {
+        union.add_weighted_edge(node1 + offset, node2 + offset, weight);
+    }
+    (union, offset)
+}
+
+/// Returns a copy of the graph with the nodes randomly relabeled, and the permutation
+/// used, where the node `n` of `g` is the node `permutation[n]` of the copy. It allows
+/// sharing graphs without leaking the meaning of the order of the nodes. The same seed
+/// always gives the same permutation.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{Graph, UGraph};
+/// use graphst::ops::anonymize;
+/// let g = UGraph::from_weighted_edges(4, vec![(0, 1, 2.0), (1, 2, 3.0)]);
+/// let (anon, permutation) = anonymize(&g, 42);
+/// assert_eq!(anon.get_edge(permutation[1], permutation[2]), Some(3.0));
+/// assert_eq!(anon.get_edges().len(), 2);
+/// ```
+pub fn anonymize<G>(g: &G, seed: u64) -> (G, Vec<usize>)
+where
+    G: Graph + Default,
+{
+    let n_nodes = g.get_n_nodes();
+    let mut permutation: Vec<usize> = (0..n_nodes).collect();
+    Rng::new(seed).shuffle(&mut permutation);
+    let anon = map_nodes(g, |n| permutation[n], n_nodes);
+    (anon, permutation)
+}
+
+/// Returns a new graph with the weights of both graphs combined edge by edge with `f`,
+/// e.g. to merge several measurement layers of the same topology. `f` is called for every
+/// edge present in any of the graphs, with `0.0` as the weight of a missing edge, and the
+/// edges combined to `0.0` are not added. The node weights are combined with `f` too.
+///
+/// # Arguments
+///
+/// * `g1` - A graph that implements `Graph` and `Default`.
+/// * `g2` - A graph of the same type, kind and number of nodes as `g1`.
+/// * `f` - The function that combines the weights `(w1, w2)` of each edge.
+///
+/// # Panics
+///
+/// * If the graphs have a different kind (directed or undirected) or number of nodes.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{Graph, UGraph};
+/// use graphst::ops::combine;
+/// let morning = UGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 5.0)]);
+/// let evening = UGraph::from_weighted_edges(3, vec![(0, 1, 4.0), (0, 2, 1.0)]);
+/// let worst = combine(&morning, &evening, f32::max);
+/// assert_eq!(worst.get_edges(), vec![(0, 1, 4.0), (0, 2, 1.0), (1, 2, 5.0)]);
+/// let total = combine(&morning, &evening, |w1, w2| w1 + w2);
+/// assert_eq!(total.get_edge(0, 1), Some(6.0));
+/// ```
+pub fn combine<G, F>(g1: &G, g2: &G, f: F) -> G
+where
+    G: Graph + Default,
+    F: Fn(f32, f32) -> f32,
+{
+    if g1.is_directed() != g2.is_directed() || g1.get_n_nodes() != g2.get_n_nodes() {
+        panic!("[ops::combine] Error: The graphs don't have the same kind and number of nodes!");
+    }
+    let pairs: BTreeSet<(usize, usize)> = g1
+        .get_edges()
+        .into_iter()
+        .chain(g2.get_edges())
+        .map(|(node1, node2, _)| (node1, node2))
+        .collect();
+    let mut combined: G = empty_graph(g1.get_n_nodes());
+    for node in g1.get_nodes() {
+        let weight = f(g1.get_node_weight(node), g2.get_node_weight(node));
+        set_node_weight(&mut combined, node, weight);
+    }
+    for (node1, node2) in pairs {
+        let w1 = g1.get_edge(node1, node2).unwrap_or(0.0);
+        let w2 = g2.get_edge(node1, node2).unwrap_or(0.0);
+        let weight = f(w1, w2);
+        if !float::is_zero(weight) {
+            combined.add_weighted_edge(node1, node2, weight);
+        }
+    }
+    combined
+}
+
+/// Returns `true` if both graphs have the same kind (directed or undirected), the same
+/// number of nodes and the same edges, with weights equal within `epsilon`. A missing
+/// edge is compared as a weight of `0.0`, so an edge left with a tiny weight by the float
+/// jitter of an algorithm matches a missing edge.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::ops::approx_eq;
+/// use graphst::UGraph;
+/// let g1 = UGraph::from_weighted_edges(3, vec![(0, 1, 0.3), (1, 2, 1.000001)]);
+/// let g2 = UGraph::from_weighted_edges(3, vec![(0, 1, 0.3), (1, 2, 1.0)]);
+/// assert_ne!(g1, g2);
+/// assert!(approx_eq(&g1, &g2, 1e-6));
+/// assert!(!approx_eq(&g1, &UGraph::from_edges(3, vec![(0, 1)]), 1e-6));
+/// ```
+pub fn approx_eq<G1, G2>(g1: &G1, g2: &G2, epsilon: f32) -> bool
+where
+    G1: Graph,
+    G2: Graph,
+{
+    if g1.is_directed() != g2.is_directed() || g1.get_n_nodes() != g2.get_n_nodes() {
+        return false;
+    }
+    let (mat1, mat2) = (g1.get_adjacency_matrix(), g2.get_adjacency_matrix());
+    mat1.iter().zip(mat2.iter()).all(|(row1, row2)| {
+        row1.iter()
+            .zip(row2)
+            .all(|(w1, w2)| float::approx_eq_with(*w1, *w2, epsilon))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DGraph, UGraph};
+
+    #[test]
+    fn map_nodes_check_directed_sums() {
+        let edges = vec![(0, 2, 1.0), (1, 2, 2.0), (2, 0, 5.0), (1, 0, 0.5)];
+        let g = DGraph::from_weighted_edges(3, edges);
+        let mapped = map_nodes(&g, |n| if n == 2 { 1 } else { 0 }, 2);
+        assert_eq!(mapped.get_edge(0, 1), Some(3.0));
+        assert_eq!(mapped.get_edge(1, 0), Some(5.0));
+        assert_eq!(mapped.get_edge(0, 0), Some(0.5));
+        assert_eq!(mapped.get_edge(1, 1), None);
+    }
+
+    #[test]
+    fn approx_eq_check_kind_and_size() {
+        let g = DGraph::from_weighted_edges(2, vec![(0, 1, 2.0)]);
+        let tiny = DGraph::from_weighted_edges(2, vec![(0, 1, 2.0), (1, 0, 1e-4)]);
+        assert!(approx_eq(&g, &tiny, 1e-3));
+        assert!(!approx_eq(&g, &tiny, 1e-5));
+        assert!(!approx_eq(
+            &g,
+            &crate::UGraph::from_edges(2, vec![(0, 1)]),
+            1.0
+        ));
+        assert!(!approx_eq(&g, &DGraph::from_edges(3, vec![(0, 1)]), 1.0));
+    }
+
+    #[test]
+    fn combine_check_directed_min() {
+        let g1 = DGraph::from_weighted_edges(2, vec![(0, 1, 2.0), (1, 0, 3.0)]);
+        let g2 = DGraph::from_weighted_edges(2, vec![(0, 1, 1.0), (1, 1, 4.0)]);
+        let min = combine(&g1, &g2, f32::min);
+        assert_eq!(min.get_edges(), vec![(0, 1, 1.0)]);
+        let diff = combine(&g1, &g1, |w1, w2| w1 - w2);
+        assert_eq!(diff.get_edges(), vec![]);
+    }
+
+    #[test]
+    fn map_nodes_check_identity() {
+        let edges = vec![(0, 1, 1.0), (1, 2, 2.0)];
+        let g = DGraph::from_weighted_edges(3, edges);
+        let mapped = map_nodes(&g, |n| n, 3);
+        assert_eq!(mapped.get_edges(), g.get_edges());
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn map_nodes_panic_not_valid_mapping() {
+        let g = DGraph::from_edges(3, vec![(0, 1)]);
+        map_nodes(&g, |n| n, 2);
+    }
+
+    #[test]
+    fn disjoint_union_check_directed_and_empty() {
+        let g = DGraph::from_weighted_edges(2, vec![(1, 0, 2.0)]);
+        let (union, offset) = disjoint_union(&g, &g);
+        assert_eq!(offset, 2);
+        assert_eq!(union.get_edges(), vec![(1, 0, 2.0), (3, 2, 2.0)]);
+        let (union, offset) = disjoint_union(&DGraph::new(), &g);
+        assert_eq!(offset, 0);
+        assert_eq!(union.get_edges(), g.get_edges());
+    }
+
+    #[test]
+    fn anonymize_check_permutation_and_determinism() {
+        let edges: Vec<_> = (0..19).map(|n| (n, n + 1, n as f32 + 1.0)).collect();
+        let g = DGraph::from_weighted_edges(20, edges.clone());
+        let (anon, permutation) = anonymize(&g, 7);
+        let mut sorted = permutation.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+        assert_ne!(permutation, sorted);
+        for (node1, node2, weight) in edges {
+            assert_eq!(
+                anon.get_edge(permutation[node1], permutation[node2]),
+                Some(weight)
+            );
+        }
+        assert_eq!(anonymize(&g, 7).1, permutation);
+    }
+
+    #[test]
+    fn induced_subgraph_check_directed() {
+        let g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 3.0), (2, 1, 4.0)]);
+        let sub = induced_subgraph(&g, &[2, 1]);
+        assert_eq!(sub.get_edges(), vec![(0, 1, 4.0), (1, 0, 3.0)]);
+    }
+
+    #[test]
+    fn ops_check_node_weights_carried() {
+        let mut g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 3.0)]);
+        g.set_node_weight(0, 0.5);
+        g.set_node_weight(2, 4.0);
+        let node_weights = |g: &DGraph| -> Vec<f32> {
+            g.get_nodes()
+                .into_iter()
+                .map(|n| g.get_node_weight(n))
+                .collect()
+        };
+        assert_eq!(node_weights(&induced_subgraph(&g, &[2, 0])), vec![4.0, 0.5]);
+        assert_eq!(node_weights(&compact(&g).0), vec![0.5, 1.0, 4.0]);
+        let (anon, permutation) = anonymize(&g, 3);
+        assert_eq!(anon.get_node_weight(permutation[2]), 4.0);
+        assert_eq!(
+            node_weights(&map_nodes(&g, |n| n / 2, 3)),
+            vec![1.5, 4.0, 1.0]
+        );
+        let (union, _) = disjoint_union(&g, &DGraph::from_edges(1, vec![]));
+        assert_eq!(node_weights(&union), vec![0.5, 1.0, 4.0, 1.0]);
+        let combined = combine(&g, &DGraph::from_edges(3, vec![]), f32::max);
+        assert_eq!(node_weights(&combined), vec![1.0, 1.0, 4.0]);
+    }
+
+    #[test]
+    fn compact_check_edges_preserved() {
+        let mut rng = crate::rng::Rng::new(36);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(20);
+            let mut g = UGraph::from_edges(n_nodes, vec![]);
+            for _ in 0..rng.gen_range(8) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                g.add_weighted_edge(node1, node2, 1.0 + rng.gen_range(5) as f32);
+            }
+            let (compacted, old_to_new, new_to_old) = compact(&g);
+            assert_eq!(compacted.get_n_nodes(), new_to_old.len());
+            assert_eq!(compacted.get_edges().len(), g.get_edges().len());
+            for (node1, node2, weight) in g.get_edges() {
+                let (new1, new2) = (old_to_new[node1].unwrap(), old_to_new[node2].unwrap());
+                assert_eq!(compacted.get_edge(new1, new2), Some(weight));
+            }
+            for (new, old) in new_to_old.iter().enumerate() {
+                assert_eq!(old_to_new[*old], Some(new));
+                assert!(!compacted.get_neighbors_of(new).is_empty());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn induced_subgraph_panic_repeated_node() {
+        let g = DGraph::from_edges(3, vec![(0, 1)]);
+        induced_subgraph(&g, &[1, 1]);
+    }
+}
@@ -0,0 +1,137 @@
+//! # Parallel
+//!
+//! `parallel` is a `graphst` module with helpers to shard read-only work over the nodes
+//! of a graph between threads. The graph types are `Send + Sync` and all the read
+//! methods take `&self`, so a graph can be shared between threads while no thread
+//! modifies it.
+
+use crate::graph::Graph;
+use std::thread;
+
+// Returns the number of items that each thread has to process (0 if there are no items)
+pub(crate) fn chunk_size(n_items: usize) -> usize {
+    let n_threads = thread::available_parallelism().map_or(1, |n| n.get());
+    n_items.div_ceil(n_threads)
+}
+
+/// Calls `f` once for each node of the graph, splitting the nodes between the available
+/// threads. The order of the calls is not defined.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph` and `Sync`.
+/// * `f` - The function to call with each node.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::parallel::par_for_each_node;
+/// use graphst::{Graph, UGraph};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// let g = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3)]);
+/// let degree_sum = AtomicUsize::new(0);
+/// par_for_each_node(&g, |node| {
+///     degree_sum.fetch_add(g.get_neighbors_of(node).len(), Ordering::Relaxed);
+/// });
+/// assert_eq!(degree_sum.into_inner(), 6);
+/// ```
+pub fn par_for_each_node<G, F>(g: &G, f: F)
+where
+    G: Graph + Sync,
+    F: Fn(usize) + Sync,
+{
+    let nodes = g.get_nodes();
+    let chunk_size = chunk_size(nodes.len());
+    if chunk_size == 0 {
+        return; // empty graph
+    }
+    let f = &f;
+    thread::scope(|s| {
+        for chunk in nodes.chunks(chunk_size) {
+            s.spawn(move || chunk.iter().for_each(|node| f(*node)));
+        }
+    });
+}
+
+/// Computes `f` for each node of the graph splitting the nodes between the available
+/// threads, and returns the results in the order of `g.get_nodes()`.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph` and `Sync`.
+/// * `f` - The function to compute with each node.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::algorithm::dijkstra;
+/// use graphst::parallel::par_map_nodes;
+/// use graphst::UGraph;
+/// let g = UGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+/// let distances = par_map_nodes(&g, |node| dijkstra(&g, node));
+/// assert_eq!(distances[2], vec![2.0, 1.0, 0.0]);
+/// ```
+pub fn par_map_nodes<G, T, F>(g: &G, f: F) -> Vec<T>
+where
+    G: Graph + Sync,
+    T: Send,
+    F: Fn(usize) -> T + Sync,
+{
+    let nodes = g.get_nodes();
+    let chunk_size = chunk_size(nodes.len());
+    if chunk_size == 0 {
+        return vec![]; // empty graph
+    }
+    let f = &f;
+    thread::scope(|s| {
+        let handles: Vec<_> = nodes
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(move || chunk.iter().map(|node| f(*node)).collect::<Vec<T>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| {
+                h.join()
+                    .expect("[parallel::par_map_nodes] Error: A thread panicked!")
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DGraph, UGraph};
+    use std::sync::Mutex;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn graphs_are_send_and_sync() {
+        assert_send_sync::<UGraph>();
+        assert_send_sync::<DGraph>();
+    }
+
+    #[test]
+    fn par_for_each_node_visits_every_node_once() {
+        let n_nodes = 100;
+        let g = DGraph::from_adjacency_matrix(vec![vec![0.0; n_nodes]; n_nodes]);
+        let visited = Mutex::new(vec![0; n_nodes]);
+        par_for_each_node(&g, |node| visited.lock().unwrap()[node] += 1);
+        assert_eq!(visited.into_inner().unwrap(), vec![1; n_nodes]);
+    }
+
+    #[test]
+    fn par_map_nodes_keeps_the_order() {
+        let n_nodes = 50;
+        let g = UGraph::from_adjacency_matrix(vec![vec![0.0; n_nodes]; n_nodes]);
+        let doubled = par_map_nodes(&g, |node| node * 2);
+        assert_eq!(doubled, (0..n_nodes).map(|n| n * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn par_map_nodes_check_empty_graph() {
+        let g = UGraph::new();
+        assert_eq!(par_map_nodes(&g, |node| node), vec![]);
+    }
+}
@@ -0,0 +1,114 @@
+//! # Path
+//!
+//! `path` is a `graphst` module with utilities over paths given as sequences of nodes,
+//! to verify and score routes produced outside of the crate.
+
+use crate::graph::Graph;
+
+/// Returns `true` if the path is not empty, all its nodes are valid, and there is an edge
+/// between each pair of consecutive nodes. A path with a single valid node is valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{path, DGraph};
+/// let g = DGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+/// assert!(path::is_valid(&g, &[0, 1, 2]));
+/// assert!(!path::is_valid(&g, &[2, 1]));
+/// assert!(!path::is_valid(&g, &[0, 5]));
+/// ```
+pub fn is_valid<G>(g: &G, path: &[usize]) -> bool
+where
+    G: Graph,
+{
+    cost(g, path).is_some()
+}
+
+/// Returns the sum of the weights of the edges of the path, or `None` if the path is not
+/// valid (see `is_valid`). The cost of a path with a single node is `0.0`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{path, UGraph};
+/// let g = UGraph::from_weighted_edges(3, vec![(0, 1, 1.5), (1, 2, 2.0)]);
+/// assert_eq!(path::cost(&g, &[2, 1, 0]), Some(3.5));
+/// assert_eq!(path::cost(&g, &[0, 2]), None);
+/// ```
+pub fn cost<G>(g: &G, path: &[usize]) -> Option<f32>
+where
+    G: Graph,
+{
+    if path.is_empty() || path.iter().any(|n| *n >= g.get_n_nodes()) {
+        return None;
+    }
+    path.windows(2)
+        .map(|pair| g.get_edge(pair[0], pair[1]))
+        .sum()
+}
+
+/// Returns the path without the detours that come back to a previous node: the repeated
+/// consecutive nodes are merged and the cycles are cut out, so each node appears once.
+/// When a node is visited several times, the path jumps from its first visit to its
+/// last one.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::path;
+/// assert_eq!(path::simplify(&[0, 1, 1, 2, 1, 3]), vec![0, 1, 3]);
+/// assert_eq!(path::simplify(&[0, 1, 2, 0]), vec![0]);
+/// ```
+pub fn simplify(path: &[usize]) -> Vec<usize> {
+    let mut simplified = vec![];
+    let mut idx = 0;
+    while idx < path.len() {
+        let node = path[idx];
+        simplified.push(node);
+        // Continue from the last visit of the node
+        let last = path.iter().rposition(|n| *n == node).unwrap_or(idx);
+        idx = last + 1;
+    }
+    simplified
+}
+
+// Returns the path from the root of a tree of predecessors (a search tree) to `dest`,
+// both included, following the predecessors of the nodes back from `dest`
+pub(crate) fn from_predecessors(predecessors: &[Option<usize>], dest: usize) -> Vec<usize> {
+    let mut path = vec![dest];
+    while let Some(prev) = predecessors[path[path.len() - 1]] {
+        path.push(prev);
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UGraph;
+
+    #[test]
+    fn cost_check_single_node_and_empty_path() {
+        let g = UGraph::from_edges(2, vec![(0, 1)]);
+        assert_eq!(cost(&g, &[1]), Some(0.0));
+        assert_eq!(cost(&g, &[]), None);
+        assert!(!is_valid(&g, &[]));
+    }
+
+    #[test]
+    fn simplify_keeps_simple_paths() {
+        assert_eq!(simplify(&[3, 1, 2]), vec![3, 1, 2]);
+        assert_eq!(simplify(&[]), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn simplify_result_is_valid_for_valid_paths() {
+        let g = UGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 0), (0, 3)]);
+        let route = [1, 2, 0, 1, 2, 0, 3];
+        assert!(is_valid(&g, &route));
+        let simplified = simplify(&route);
+        assert_eq!(simplified, vec![1, 2, 0, 3]);
+        assert!(is_valid(&g, &simplified));
+    }
+}
@@ -0,0 +1,219 @@
+//! # Ranking
+//!
+//! `ranking` is a `graphst` module to rank the nodes of a graph by a score computed for
+//! each node, like the outputs of `nx::degree_centrality` or any other vector indexed by
+//! node. The ties are always ordered by increasing node, so the rankings are
+//! reproducible between runs.
+
+use std::cmp::Ordering;
+
+/// Returns the `k` nodes with the highest scores and their scores, by decreasing score
+/// and by increasing node on ties. Returns all the nodes if there are less than `k`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::ranking::top_k;
+/// let scores = vec![0.5, 1.0, 0.25, 1.0];
+/// assert_eq!(top_k(&scores, 3), vec![(1, 1.0), (3, 1.0), (0, 0.5)]);
+/// ```
+pub fn top_k(scores: &[f32], k: usize) -> Vec<(usize, f32)> {
+    let mut nodes: Vec<usize> = (0..scores.len()).collect();
+    let by_score = |a: &usize, b: &usize| descending(scores[*a], scores[*b]).then(a.cmp(b));
+    if k < nodes.len() {
+        // Only the first `k` nodes have to be sorted
+        nodes.select_nth_unstable_by(k, by_score);
+        nodes.truncate(k);
+    }
+    nodes.sort_unstable_by(by_score);
+    nodes.into_iter().map(|node| (node, scores[node])).collect()
+}
+
+/// The `Ranking` struct holds the scores of the nodes of a graph sorted by decreasing
+/// score, with the ties ordered by increasing node, to query the position and the
+/// percentile of each node.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::ranking::Ranking;
+/// use graphst::{nx, UGraph};
+/// let g = UGraph::from_edges(5, vec![(0, 1), (0, 2), (0, 3), (3, 4)]);
+/// let ranking = Ranking::new(nx::degree_centrality(&g));
+/// assert_eq!(ranking.get_order(), &[0, 3, 1, 2, 4]);
+/// assert_eq!(ranking.get_rank(3), 1);
+/// assert_eq!(ranking.percentile_of(0), 100.0);
+/// assert_eq!(ranking.percentile_of(1), 0.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ranking {
+    scores: Vec<f32>,
+    order: Vec<usize>,
+    ranks: Vec<usize>,
+}
+
+impl Ranking {
+    /// Creates a `Ranking` from the score of each node.
+    pub fn new(scores: Vec<f32>) -> Ranking {
+        let mut order: Vec<usize> = (0..scores.len()).collect();
+        // The sort is stable, so the ties keep the order of the nodes
+        order.sort_by(|a, b| descending(scores[*a], scores[*b]));
+        let mut ranks = vec![0; scores.len()];
+        for (rank, node) in order.iter().enumerate() {
+            ranks[*node] = rank;
+        }
+        Ranking {
+            scores,
+            order,
+            ranks,
+        }
+    }
+
+    /// Returns the number of ranked nodes.
+    pub fn get_n_nodes(&self) -> usize {
+        self.scores.len()
+    }
+
+    /// Returns the score of each node, indexed by node.
+    pub fn get_scores(&self) -> &[f32] {
+        &self.scores
+    }
+
+    /// Returns the nodes sorted by decreasing score.
+    pub fn get_order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Returns the position of the node in the ranking, where `0` is the highest score.
+    ///
+    /// # Panics
+    ///
+    /// * If the node is not valid.
+    pub fn get_rank(&self, node: usize) -> usize {
+        self.check_node(node, "get_rank");
+        self.ranks[node]
+    }
+
+    /// Returns the `k` nodes with the highest scores and their scores (see `top_k`).
+    pub fn top_k(&self, k: usize) -> Vec<(usize, f32)> {
+        self.order
+            .iter()
+            .take(k)
+            .map(|node| (*node, self.scores[*node]))
+            .collect()
+    }
+
+    /// Returns the percentage of the other nodes with a lower score than the node, from
+    /// `0.0` (the lowest score) to `100.0` (the highest). The nodes with the same score
+    /// have the same percentile, and a single node has `100.0`.
+    ///
+    /// # Panics
+    ///
+    /// * If the node is not valid.
+    pub fn percentile_of(&self, node: usize) -> f32 {
+        self.check_node(node, "percentile_of");
+        let n_nodes = self.scores.len();
+        if n_nodes == 1 {
+            return 100.0;
+        }
+        // The lower scores are after the last tie of the node in the order
+        let score = self.scores[node];
+        let n_higher_or_equal = self.order[self.ranks[node]..]
+            .iter()
+            .take_while(|n| self.scores[**n].total_cmp(&score) == Ordering::Equal)
+            .count()
+            + self.ranks[node];
+        (n_nodes - n_higher_or_equal) as f32 / (n_nodes - 1) as f32 * 100.0
+    }
+
+    /// Returns the score at the percentile `p` (in the range `[0, 100]`) of the scores,
+    /// interpolating linearly between the closest ranks like `stats::weight_percentile`.
+    /// Returns `None` if there are no nodes.
+    ///
+    /// # Panics
+    ///
+    /// * If the percentile is not in the range `[0, 100]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::ranking::Ranking;
+    /// let ranking = Ranking::new(vec![4.0, 1.0, 3.0, 2.0]);
+    /// assert_eq!(ranking.score_at_percentile(50.0), Some(2.5));
+    /// assert_eq!(ranking.score_at_percentile(100.0), Some(4.0));
+    /// ```
+    pub fn score_at_percentile(&self, p: f32) -> Option<f32> {
+        if !(0.0..=100.0).contains(&p) {
+            panic!(
+                "[Ranking::score_at_percentile] Error: The percentile {} is not valid!",
+                p
+            );
+        }
+        if self.order.is_empty() {
+            return None;
+        }
+        // The order is decreasing, so the ranks are counted from the end
+        let last = self.order.len() - 1;
+        let rank = p / 100.0 * last as f32;
+        let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+        let (low_score, high_score) = (
+            self.scores[self.order[last - low]],
+            self.scores[self.order[last - high]],
+        );
+        Some(low_score + (high_score - low_score) * (rank - low as f32))
+    }
+
+    //--------- Private functions ---------
+
+    fn check_node(&self, node: usize, method: &str) {
+        if node >= self.scores.len() {
+            panic!(
+                "[Ranking::{}] Error: The node {} is not valid!",
+                method, node
+            );
+        }
+    }
+}
+
+//--------- Private functions ---------
+
+fn descending(a: f32, b: f32) -> Ordering {
+    b.total_cmp(&a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_k_matches_ranking() {
+        let mut rng = crate::rng::Rng::new(18);
+        for _ in 0..30 {
+            let n_nodes = rng.gen_range(20);
+            // Few distinct scores, to have many ties
+            let scores: Vec<f32> = (0..n_nodes).map(|_| rng.gen_range(4) as f32).collect();
+            let ranking = Ranking::new(scores.clone());
+            let k = rng.gen_range(25);
+            assert_eq!(top_k(&scores, k), ranking.top_k(k));
+            for w in ranking.get_order().windows(2) {
+                let (a, b) = (scores[w[0]], scores[w[1]]);
+                assert!(a > b || (a == b && w[0] < w[1]));
+            }
+        }
+    }
+
+    #[test]
+    fn percentile_of_check_ties() {
+        let ranking = Ranking::new(vec![2.0, 1.0, 2.0, 0.0, 1.0]);
+        let percentiles: Vec<f32> = (0..5).map(|n| ranking.percentile_of(n)).collect();
+        assert_eq!(percentiles, vec![75.0, 25.0, 75.0, 0.0, 25.0]);
+        assert_eq!(ranking.get_rank(2), 1);
+        assert_eq!(Ranking::new(vec![]).score_at_percentile(10.0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn get_rank_panic_not_valid_node() {
+        Ranking::new(vec![1.0]).get_rank(1);
+    }
+}
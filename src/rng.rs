@@ -0,0 +1,90 @@
+//! # Rng
+//!
+//! `rng` is a `graphst` module with the small pseudo-random number generator used by the
+//! randomized functions of the crate (graph generators, sampling...). It is seeded
+//! explicitly, so the results can be reproduced. It is not suitable for cryptography.
+
+/// The `Rng` struct is a SplitMix64 pseudo-random number generator.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::rng::Rng;
+/// let mut rng = Rng::new(42);
+/// let x = rng.next_f64();
+/// assert!(x >= 0.0 && x < 1.0);
+/// assert_eq!(Rng::new(42).next_u64(), Rng::new(42).next_u64());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a `Rng` from a seed. The same seed always produces the same sequence.
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    /// Returns the next pseudo-random `u64` value.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a pseudo-random `f64` value in the range `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a pseudo-random `usize` value in the range `[0, n)`.
+    ///
+    /// # Panics
+    ///
+    /// * If `n` is `0`.
+    pub fn gen_range(&mut self, n: usize) -> usize {
+        if n == 0 {
+            panic!("[Rng::gen_range] Error: The range is empty!");
+        }
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// Shuffles the slice in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, values: &mut [T]) {
+        for i in (1..values.len()).rev() {
+            let j = self.gen_range(i + 1);
+            values.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rng;
+
+    #[test]
+    fn gen_range_check_bounds() {
+        let mut rng = Rng::new(7);
+        for n in 1..50 {
+            assert!(rng.gen_range(n) < n);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "range is empty")]
+    fn gen_range_panic_empty() {
+        Rng::new(7).gen_range(0);
+    }
+
+    #[test]
+    fn shuffle_is_a_permutation() {
+        let mut values: Vec<usize> = (0..20).collect();
+        Rng::new(3).shuffle(&mut values);
+        assert_ne!(values, (0..20).collect::<Vec<_>>());
+        values.sort();
+        assert_eq!(values, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,131 @@
+//! # Sample
+//!
+//! `sample` is a `graphst` module with the random sampling primitives of random walks and
+//! Monte-Carlo algorithms, drawing edges and neighbors with probability proportional to
+//! their weight. The randomness comes from an explicitly seeded `rng::Rng`.
+
+use crate::graph::Graph;
+use crate::rng::Rng;
+
+/// Draws an edge of the graph with probability proportional to its weight, as a
+/// `(node1, node2, weight)` triplet. Returns `None` if the graph has no edges. The
+/// undirected edges are drawn as returned by `get_edges` (once each).
+///
+/// # Panics
+///
+/// * If some edge has a negative or NaN weight.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::rng::Rng;
+/// use graphst::sample;
+/// use graphst::UGraph;
+/// let g = UGraph::from_weighted_edges(3, vec![(0, 1, 1.0), (1, 2, 3.0)]);
+/// let mut rng = Rng::new(7);
+/// let n_heavy = (0..1000)
+///     .filter(|_| sample::weighted_edge(&g, &mut rng) == Some((1, 2, 3.0)))
+///     .count();
+/// assert!(n_heavy > 700 && n_heavy < 800);
+/// ```
+pub fn weighted_edge<G>(g: &G, rng: &mut Rng) -> Option<(usize, usize, f32)>
+where
+    G: Graph,
+{
+    let edges = g.get_edges();
+    let weights: Vec<f32> = edges.iter().map(|(_, _, w)| *w).collect();
+    pick(&weights, rng, "weighted_edge").map(|idx| edges[idx])
+}
+
+/// Draws a neighbor of `node` (a successor for directed graphs) with probability
+/// proportional to the weight of the edge that joins them. Returns `None` if the node has
+/// no neighbors.
+///
+/// # Panics
+///
+/// * If the node passed as a parameter is not valid.
+/// * If some edge of the node has a negative or NaN weight.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::rng::Rng;
+/// use graphst::sample;
+/// use graphst::DGraph;
+/// let g = DGraph::from_weighted_edges(3, vec![(0, 1, 0.5), (0, 2, 0.5)]);
+/// let mut rng = Rng::new(1);
+/// let next = sample::weighted_neighbor(&g, 0, &mut rng).unwrap();
+/// assert!(next == 1 || next == 2);
+/// assert_eq!(sample::weighted_neighbor(&g, 2, &mut rng), None);
+/// ```
+pub fn weighted_neighbor<G>(g: &G, node: usize, rng: &mut Rng) -> Option<usize>
+where
+    G: Graph,
+{
+    let neighbors = g.get_out_neighbors_of(node);
+    let weights: Vec<f32> = neighbors
+        .iter()
+        .map(|n| g.get_edge(node, *n).unwrap_or(0.0))
+        .collect();
+    pick(&weights, rng, "weighted_neighbor").map(|idx| neighbors[idx])
+}
+
+//--------- Private functions ---------
+
+// Returns an index with probability proportional to its weight, or `None` if the
+// weights add up to zero
+fn pick(weights: &[f32], rng: &mut Rng, caller: &str) -> Option<usize> {
+    if let Some(weight) = weights.iter().find(|w| w.is_nan() || **w < 0.0) {
+        panic!(
+            "[sample::{}] Error: The weight {} is not valid!",
+            caller, weight
+        );
+    }
+    let total: f64 = weights.iter().map(|w| *w as f64).sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let target = rng.next_f64() * total;
+    let mut cumulative = 0.0;
+    for (idx, weight) in weights.iter().enumerate() {
+        cumulative += *weight as f64;
+        if target < cumulative {
+            return Some(idx);
+        }
+    }
+    // Rounding errors can leave the target at the end, take the last positive weight
+    weights.iter().rposition(|w| *w > 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UGraph;
+
+    #[test]
+    fn weighted_edge_check_empty_graph() {
+        let g = UGraph::from_edges(3, vec![]);
+        assert_eq!(weighted_edge(&g, &mut Rng::new(0)), None);
+    }
+
+    #[test]
+    fn weighted_neighbor_follows_weights() {
+        let g = UGraph::from_weighted_edges(4, vec![(0, 1, 1.0), (0, 2, 2.0), (0, 3, 7.0)]);
+        let mut rng = Rng::new(3);
+        let mut counts = [0; 4];
+        for _ in 0..10000 {
+            counts[weighted_neighbor(&g, 0, &mut rng).unwrap()] += 1;
+        }
+        assert_eq!(counts[0], 0);
+        assert!((900..1100).contains(&counts[1]));
+        assert!((1800..2200).contains(&counts[2]));
+        assert!((6700..7300).contains(&counts[3]));
+    }
+
+    #[test]
+    #[should_panic(expected = "weight -1 is not valid")]
+    fn weighted_edge_panic_negative_weight() {
+        let g = UGraph::from_weighted_edges(2, vec![(0, 1, -1.0)]);
+        weighted_edge(&g, &mut Rng::new(0));
+    }
+}
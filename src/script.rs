@@ -0,0 +1,234 @@
+//! # Script
+//!
+//! `script` is a `graphst` module to run graph experiments from a small text format,
+//! without writing Rust. A script has one command per line, with its arguments separated
+//! by spaces; the empty lines and the lines starting with `#` are ignored. The scripts
+//! can be run from the command line with `graphst run script.gst`.
+//!
+//! The commands are:
+//!
+//! * `graph undirected|directed [n_nodes]` - Starts a new empty graph (the initial graph
+//!   is undirected with no nodes).
+//! * `add_node [count]` - Adds one node, or `count` nodes.
+//! * `add_edge node1 node2 [weight]` - Adds an edge, with weight `1.0` by default.
+//! * `print` - Prints the graph.
+//! * `bfs src` - Prints the nodes in breadth-first order from `src`.
+//! * `dijkstra src` - Prints the shortest distance from `src` to each node.
+//! * `path src dest` - Prints a shortest path from `src` to `dest` and its cost.
+//! * `export dot path` - Writes the graph in the DOT language to the file `path`.
+
+use crate::algorithm::{bfs, dijkstra, dijkstra_with_paths};
+use crate::dot::to_dot;
+use crate::graph::Graph;
+use crate::{DGraph, UGraph};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+
+/// The `ScriptError` struct is the error returned when a script command fails: the line
+/// of the command (starting at `1`) and the reason.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScriptError {
+    /// The line of the script with the failed command.
+    pub line: usize,
+    /// The description of the failure.
+    pub message: String,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for ScriptError {}
+
+/// Runs the commands of the script and returns the text printed by them. The execution
+/// stops at the first failed command.
+///
+/// # Errors
+///
+/// * If a command is unknown, has wrong arguments, refers to a node that is not valid or
+///   can't write its output file.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::script::run;
+/// let script = "
+/// ## A triangle with a costly edge
+/// graph undirected 3
+/// add_edge 0 1
+/// add_edge 1 2 2.5
+/// add_edge 0 2 5
+/// dijkstra 0
+/// path 0 2
+/// ";
+/// assert_eq!(run(script).unwrap(), "dijkstra 0: [0.0, 1.0, 3.5]\npath 0 2: [0, 1, 2] (cost 3.5)\n");
+/// let error = run("add_edge 0 1").unwrap_err();
+/// assert_eq!(error.to_string(), "line 1: The node 0 is not valid!");
+/// ```
+pub fn run(script: &str) -> Result<String, ScriptError> {
+    let mut graph = ScriptGraph::Undirected(UGraph::new());
+    let mut output = String::new();
+    for (idx, line) in script.lines().enumerate() {
+        let args: Vec<&str> = line.split_whitespace().collect();
+        if args.is_empty() || args[0].starts_with('#') {
+            continue;
+        }
+        let result = match &mut graph {
+            ScriptGraph::Undirected(g) => execute(g, &args, &mut output),
+            ScriptGraph::Directed(g) => execute(g, &args, &mut output),
+        };
+        let error = |message| ScriptError {
+            line: idx + 1,
+            message,
+        };
+        if let Some(new_graph) = result.map_err(error)? {
+            graph = new_graph;
+        }
+    }
+    Ok(output)
+}
+
+//--------- Private functions ---------
+
+enum ScriptGraph {
+    Undirected(UGraph),
+    Directed(DGraph),
+}
+
+// Runs one command on the graph, returning the new graph for the `graph` command
+fn execute<G>(g: &mut G, args: &[&str], output: &mut String) -> Result<Option<ScriptGraph>, String>
+where
+    G: Graph + fmt::Display,
+{
+    let command = args[0];
+    match (command, args.len()) {
+        ("graph", 2) | ("graph", 3) => {
+            let n_nodes = if args.len() == 3 {
+                parse(args[2], "number of nodes")?
+            } else {
+                0
+            };
+            match args[1] {
+                "undirected" => Ok(Some(ScriptGraph::Undirected(new_graph(n_nodes)))),
+                "directed" => Ok(Some(ScriptGraph::Directed(new_graph(n_nodes)))),
+                kind => Err(format!("The graph kind {} is not valid!", kind)),
+            }
+        }
+        ("add_node", 1) | ("add_node", 2) => {
+            let count = if args.len() == 2 {
+                parse(args[1], "count")?
+            } else {
+                1
+            };
+            g.add_nodes(count);
+            Ok(None)
+        }
+        ("add_edge", 3) | ("add_edge", 4) => {
+            let node1 = parse_node(g, args[1])?;
+            let node2 = parse_node(g, args[2])?;
+            let weight = if args.len() == 4 {
+                parse(args[3], "weight")?
+            } else {
+                1.0
+            };
+            g.add_weighted_edge(node1, node2, weight);
+            Ok(None)
+        }
+        ("print", 1) => {
+            output.push_str(&format!("{}\n", g));
+            Ok(None)
+        }
+        ("bfs", 2) => {
+            let src = parse_node(g, args[1])?;
+            output.push_str(&format!("bfs {}: {:?}\n", src, bfs(g, src).order));
+            Ok(None)
+        }
+        ("dijkstra", 2) => {
+            let src = parse_node(g, args[1])?;
+            output.push_str(&format!("dijkstra {}: {:?}\n", src, dijkstra(g, src)));
+            Ok(None)
+        }
+        ("path", 3) => {
+            let src = parse_node(g, args[1])?;
+            let dest = parse_node(g, args[2])?;
+            let tree = dijkstra_with_paths(g, src);
+            let line = match tree.path_to(dest) {
+                Some(path) => format!("{:?} (cost {})", path, tree.distances[dest]),
+                None => "unreachable".to_string(),
+            };
+            output.push_str(&format!("path {} {}: {}\n", src, dest, line));
+            Ok(None)
+        }
+        ("export", 3) if args[1] == "dot" => {
+            fs::write(args[2], to_dot(g))
+                .map_err(|e| format!("Can't write the file {}: {}", args[2], e))?;
+            Ok(None)
+        }
+        ("graph", _)
+        | ("add_node", _)
+        | ("add_edge", _)
+        | ("print", _)
+        | ("bfs", _)
+        | ("dijkstra", _)
+        | ("path", _)
+        | ("export", _) => Err(format!("The arguments of {} are not valid!", command)),
+        _ => Err(format!("The command {} is not valid!", command)),
+    }
+}
+
+fn new_graph<G>(n_nodes: usize) -> G
+where
+    G: Graph + Default,
+{
+    let mut g = G::default();
+    g.add_nodes(n_nodes);
+    g
+}
+
+fn parse<T>(arg: &str, name: &str) -> Result<T, String>
+where
+    T: std::str::FromStr,
+{
+    arg.parse()
+        .map_err(|_| format!("The {} {} is not valid!", name, arg))
+}
+
+fn parse_node<G>(g: &G, arg: &str) -> Result<usize, String>
+where
+    G: Graph,
+{
+    let node = parse(arg, "node")?;
+    if node >= g.get_n_nodes() {
+        return Err(format!("The node {} is not valid!", node));
+    }
+    Ok(node)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_check_directed_graph() {
+        let script =
+            "graph directed\nadd_node 3\nadd_edge 0 1 2\nadd_edge 1 2\n\nbfs 0\npath 2 0\n";
+        assert_eq!(
+            run(script).unwrap(),
+            "bfs 0: [0, 1, 2]\npath 2 0: unreachable\n"
+        );
+    }
+
+    #[test]
+    fn run_check_errors() {
+        let error = run("graph undirected 2\n# comment\nadd_edge 0 x").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.message, "The node x is not valid!");
+        let error = run("dijkstra").unwrap_err();
+        assert_eq!(error.message, "The arguments of dijkstra are not valid!");
+        let error = run("shortest 0").unwrap_err();
+        assert_eq!(error.message, "The command shortest is not valid!");
+    }
+}
@@ -0,0 +1,277 @@
+use crate::Graph;
+
+/// The `NodeId` struct is a stable handle to a node of a `StableGraph`. It stays valid
+/// while the node exists, no matter which other nodes are added or removed. When a node is
+/// removed its slot can be reused, but the reused slot gets a new generation, so the old
+/// handles of the removed node are rejected instead of pointing to the new node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId {
+    index: usize,
+    generation: u32,
+}
+
+impl NodeId {
+    /// Returns the index of the node in the underlying graph.
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the generation of the slot of the node.
+    pub fn get_generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+/// The `StableGraph` struct wraps a graph (that implements `Graph`) to support node
+/// removals with stable `NodeId` handles (generational indices). Removing a node deletes
+/// its edges and leaves a hole in the underlying graph, which is reused by the next added
+/// node. The nodes of the underlying graph are never reshuffled, so the ids of the other
+/// nodes stay valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::{StableGraph, UGraph};
+/// let mut g: StableGraph<UGraph> = StableGraph::new();
+/// let a = g.add_node();
+/// let b = g.add_node();
+/// let c = g.add_node();
+/// g.add_edge(a, c);
+/// g.remove_node(b);
+/// assert!(!g.contains(b));
+/// assert_eq!(g.nodes().collect::<Vec<_>>(), vec![a, c]);
+/// assert_eq!(g.get_edge(a, c), Some(1.0));
+/// let d = g.add_node(); // reuses the slot of b
+/// assert_eq!(d.get_index(), b.get_index());
+/// assert!(!g.contains(b));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StableGraph<G> {
+    graph: G,
+    generations: Vec<u32>,
+    alive: Vec<bool>,
+    free: Vec<usize>,
+}
+
+impl<G> StableGraph<G>
+where
+    G: Graph,
+{
+    /// Creates an empty `StableGraph`.
+    pub fn new() -> StableGraph<G>
+    where
+        G: Default,
+    {
+        StableGraph::from_graph(G::default())
+    }
+
+    /// Creates a `StableGraph` from a graph, where every node of the graph is alive. The
+    /// ids of the nodes are in the order of the nodes of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{StableGraph, UGraph};
+    /// let g = StableGraph::from_graph(UGraph::from_edges(3, vec![(0, 1), (1, 2)]));
+    /// assert_eq!(g.get_n_nodes(), 3);
+    /// ```
+    pub fn from_graph(graph: G) -> StableGraph<G> {
+        let n_nodes = graph.get_n_nodes();
+        StableGraph {
+            graph,
+            generations: vec![0; n_nodes],
+            alive: vec![true; n_nodes],
+            free: vec![],
+        }
+    }
+
+    /// Returns the underlying graph, where the removed nodes are isolated nodes.
+    pub fn get_graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Returns the number of nodes alive in the graph.
+    pub fn get_n_nodes(&self) -> usize {
+        self.alive.len() - self.free.len()
+    }
+
+    /// Returns `true` if the node of the id passed as a parameter exists.
+    pub fn contains(&self, node: NodeId) -> bool {
+        node.index < self.alive.len()
+            && self.alive[node.index]
+            && self.generations[node.index] == node.generation
+    }
+
+    /// Returns an iterator over the ids of the nodes alive, skipping the holes left by the
+    /// removed nodes.
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..self.alive.len())
+            .filter(move |idx| self.alive[*idx])
+            .map(move |idx| self.node_id(idx))
+    }
+
+    /// Returns the id of the node alive in the slot `index` of the underlying graph, or
+    /// `None` if the slot is a hole.
+    pub fn get_node_id(&self, index: usize) -> Option<NodeId> {
+        if index < self.alive.len() && self.alive[index] {
+            Some(self.node_id(index))
+        } else {
+            None
+        }
+    }
+
+    /// Adds a node to the graph and returns its id. The slots of the removed nodes are
+    /// reused before growing the underlying graph.
+    pub fn add_node(&mut self) -> NodeId {
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                self.graph.add_node();
+                self.generations.push(0);
+                self.alive.push(false);
+                self.alive.len() - 1
+            }
+        };
+        self.alive[index] = true;
+        self.node_id(index)
+    }
+
+    /// Removes the node of the id passed as a parameter with all its edges. Returns
+    /// `false` if the node doesn't exist.
+    pub fn remove_node(&mut self, node: NodeId) -> bool {
+        if !self.contains(node) {
+            return false;
+        }
+        for n in self.graph.get_out_neighbors_of(node.index) {
+            self.graph.add_weighted_edge(node.index, n, 0.0);
+        }
+        if self.graph.is_directed() {
+            for n in self.graph.get_nodes() {
+                if self.graph.get_edge(n, node.index).is_some() {
+                    self.graph.add_weighted_edge(n, node.index, 0.0);
+                }
+            }
+        }
+        self.alive[node.index] = false;
+        self.generations[node.index] += 1;
+        self.free.push(node.index);
+        true
+    }
+
+    /// Returns the weight of the edge between the nodes passed as parameters, or `None` if
+    /// there is no edge.
+    ///
+    /// # Panics
+    ///
+    /// * If some node doesn't exist.
+    pub fn get_edge(&self, node1: NodeId, node2: NodeId) -> Option<f32> {
+        self.check_node(node1, "get_edge");
+        self.check_node(node2, "get_edge");
+        self.graph.get_edge(node1.index, node2.index)
+    }
+
+    /// Returns the ids of the nodes reachable from `node` through one edge.
+    ///
+    /// # Panics
+    ///
+    /// * If the node doesn't exist.
+    pub fn get_out_neighbors_of(&self, node: NodeId) -> Vec<NodeId> {
+        self.check_node(node, "get_out_neighbors_of");
+        self.graph
+            .get_out_neighbors_of(node.index)
+            .into_iter()
+            .map(|n| self.node_id(n))
+            .collect()
+    }
+
+    /// Adds an edge with weight `1.0` between the nodes passed as parameters.
+    ///
+    /// # Panics
+    ///
+    /// * If some node doesn't exist.
+    pub fn add_edge(&mut self, node1: NodeId, node2: NodeId) {
+        self.add_weighted_edge(node1, node2, 1.0);
+    }
+
+    /// Adds an edge with the weight passed as a parameter between the nodes passed as
+    /// parameters.
+    ///
+    /// # Panics
+    ///
+    /// * If some node doesn't exist.
+    pub fn add_weighted_edge(&mut self, node1: NodeId, node2: NodeId, weight: f32) {
+        self.check_node(node1, "add_weighted_edge");
+        self.check_node(node2, "add_weighted_edge");
+        self.graph
+            .add_weighted_edge(node1.index, node2.index, weight);
+    }
+
+    /// Removes the edge between the nodes passed as parameters, if it exists.
+    ///
+    /// # Panics
+    ///
+    /// * If some node doesn't exist.
+    pub fn remove_edge(&mut self, node1: NodeId, node2: NodeId) {
+        self.check_node(node1, "remove_edge");
+        self.check_node(node2, "remove_edge");
+        self.graph.add_weighted_edge(node1.index, node2.index, 0.0);
+    }
+
+    //--------- Private functions ---------
+
+    fn node_id(&self, index: usize) -> NodeId {
+        NodeId {
+            index,
+            generation: self.generations[index],
+        }
+    }
+
+    fn check_node(&self, node: NodeId, method: &str) {
+        if !self.contains(node) {
+            panic!(
+                "[StableGraph::{}] Error: The node {:?} is not valid!",
+                method, node
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DGraph, Graph, StableGraph};
+
+    #[test]
+    fn remove_node_deletes_directed_edges() {
+        let mut g = StableGraph::from_graph(DGraph::from_edges(3, vec![(0, 1), (1, 2), (2, 1)]));
+        let ids: Vec<_> = g.nodes().collect();
+        assert!(g.remove_node(ids[1]));
+        assert!(!g.remove_node(ids[1]));
+        assert_eq!(g.get_graph().get_edges(), vec![]);
+        assert_eq!(g.get_n_nodes(), 2);
+        assert_eq!(g.get_node_id(1), None);
+    }
+
+    #[test]
+    fn add_node_reuses_slots_with_new_generation() {
+        let mut g: StableGraph<DGraph> = StableGraph::new();
+        let a = g.add_node();
+        let b = g.add_node();
+        g.remove_node(a);
+        let c = g.add_node();
+        assert_eq!((c.get_index(), c.get_generation()), (0, 1));
+        assert_eq!(g.get_graph().get_n_nodes(), 2);
+        g.add_edge(c, b);
+        assert_eq!(g.get_out_neighbors_of(c), vec![b]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not valid")]
+    fn add_edge_panic_removed_node() {
+        let mut g: StableGraph<DGraph> = StableGraph::new();
+        let a = g.add_node();
+        let b = g.add_node();
+        g.remove_node(a);
+        g.add_node();
+        g.add_edge(a, b);
+    }
+}
@@ -0,0 +1,333 @@
+//! # Stats
+//!
+//! `stats` is a `graphst` module with statistics over the edge weights of a graph, useful
+//! to choose thresholds for sparsification or for the delta of delta-stepping. The edges
+//! of undirected graphs are counted once. It also summarizes a `TemporalGraph` over
+//! sliding time windows.
+
+use crate::graph::Graph;
+use crate::{DisjointSet, TemporalGraph};
+
+/// The `Histogram` struct holds the counts of the edge weights in equal width bins.
+/// The bin `i` covers the range `[bounds[i], bounds[i + 1])`, and the last bin also
+/// includes its upper bound.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+    /// The `bins + 1` boundaries of the bins.
+    pub bounds: Vec<f32>,
+    /// The number of edges in each bin.
+    pub counts: Vec<usize>,
+}
+
+/// Returns the histogram of the edge weights of the graph with `bins` equal width bins
+/// between the minimum and maximum weights. Returns `None` if the graph has no edges.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+/// * `bins` - An `usize` value with the number of bins.
+///
+/// # Panics
+///
+/// * If `bins` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::stats::weight_histogram;
+/// let edges = vec![(0, 1, 1.0), (1, 2, 2.0), (2, 3, 2.5), (3, 0, 3.0)];
+/// let g = graphst::UGraph::from_weighted_edges(4, edges);
+/// let hist = weight_histogram(&g, 2).unwrap();
+/// assert_eq!(hist.bounds, vec![1.0, 2.0, 3.0]);
+/// assert_eq!(hist.counts, vec![1, 3]);
+/// ```
+pub fn weight_histogram<G>(g: &G, bins: usize) -> Option<Histogram>
+where
+    G: Graph,
+{
+    if bins == 0 {
+        panic!("[stats::weight_histogram] Error: The number of bins must be positive!");
+    }
+    let weights = weights_of(g);
+    if weights.is_empty() {
+        return None;
+    }
+    let min = weights.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max = weights.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    let width = (max - min) / bins as f32;
+    let bounds: Vec<f32> = (0..=bins)
+        .map(|i| {
+            if i == bins {
+                max
+            } else {
+                min + width * i as f32
+            }
+        })
+        .collect();
+    let mut counts = vec![0; bins];
+    for w in weights {
+        let bin = if width > 0.0 {
+            (((w - min) / width) as usize).min(bins - 1)
+        } else {
+            bins - 1 // every weight is the same
+        };
+        counts[bin] += 1;
+    }
+    Some(Histogram { bounds, counts })
+}
+
+/// Returns the `p` percentile of the edge weights of the graph, interpolating linearly
+/// between the closest ranks. Returns `None` if the graph has no edges.
+///
+/// # Arguments
+///
+/// * `g` - A graph that implements `Graph`.
+/// * `p` - A `f32` value in the range `[0, 100]`.
+///
+/// # Panics
+///
+/// * If `p` is not in the range `[0, 100]`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::stats::weight_percentile;
+/// let edges = vec![(0, 1, 1.0), (1, 2, 2.0), (2, 3, 3.0), (3, 0, 4.0)];
+/// let g = graphst::DGraph::from_weighted_edges(4, edges);
+/// assert_eq!(weight_percentile(&g, 50.0), Some(2.5));
+/// assert_eq!(weight_percentile(&g, 100.0), Some(4.0));
+/// ```
+pub fn weight_percentile<G>(g: &G, p: f32) -> Option<f32>
+where
+    G: Graph,
+{
+    weight_percentiles(g, &[p]).map(|values| values[0])
+}
+
+/// Same as `weight_percentile` but computing several percentiles at once, sorting the
+/// weights only once.
+///
+/// # Panics
+///
+/// * If some percentile is not in the range `[0, 100]`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::stats::weight_percentiles;
+/// let edges = vec![(0, 1, 1.0), (1, 2, 2.0), (2, 0, 3.0)];
+/// let g = graphst::DGraph::from_weighted_edges(3, edges);
+/// assert_eq!(weight_percentiles(&g, &[0.0, 50.0, 100.0]), Some(vec![1.0, 2.0, 3.0]));
+/// ```
+pub fn weight_percentiles<G>(g: &G, ps: &[f32]) -> Option<Vec<f32>>
+where
+    G: Graph,
+{
+    if let Some(p) = ps.iter().find(|p| !(0.0..=100.0).contains(*p)) {
+        panic!(
+            "[stats::weight_percentiles] Error: The percentile {} is not valid!",
+            p
+        );
+    }
+    let mut weights = weights_of(g);
+    if weights.is_empty() {
+        return None;
+    }
+    weights.sort_by(|a, b| a.total_cmp(b));
+    let values = ps
+        .iter()
+        .map(|p| {
+            let rank = p / 100.0 * (weights.len() - 1) as f32;
+            let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+            weights[low] + (weights[high] - weights[low]) * (rank - low as f32)
+        })
+        .collect();
+    Some(values)
+}
+
+/// The `WindowStats` struct holds the statistics of the events of a `TemporalGraph` in
+/// the time window `[start, end)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowStats {
+    /// The first timestamp of the window.
+    pub start: u64,
+    /// The end of the window (excluded).
+    pub end: u64,
+    /// The number of events in the window.
+    pub n_events: usize,
+    /// The number of distinct edges in the window (the repeated events are counted once).
+    pub n_edges: usize,
+    /// The density of the edges of the window, without self-loops (see `nx::density`).
+    pub density: f32,
+    /// The number of (weakly) connected components of the window, where the nodes
+    /// without events are components of their own.
+    pub n_components: usize,
+}
+
+/// Returns the statistics of the events of the temporal graph over sliding windows of
+/// length `window`, moved by `step`. The first window starts at the first timestamp and
+/// the last one is the first that reaches the last timestamp. Returns an empty vector if
+/// the graph has no events.
+///
+/// # Arguments
+///
+/// * `tg` - A `TemporalGraph`.
+/// * `window` - An `u64` value with the length of the windows.
+/// * `step` - An `u64` value with the distance between the starts of two windows. If it
+///   is smaller than `window` the windows overlap.
+///
+/// # Panics
+///
+/// * If `window` or `step` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::stats::stats_over_windows;
+/// use graphst::TemporalGraph;
+/// let events = vec![(0, 1, 0), (1, 0, 5), (2, 3, 12), (1, 2, 15)];
+/// let tg = TemporalGraph::from_events(4, false, events);
+/// let stats = stats_over_windows(&tg, 10, 10);
+/// assert_eq!(stats.len(), 2);
+/// assert_eq!((stats[0].n_events, stats[0].n_edges, stats[0].n_components), (2, 1, 3));
+/// assert_eq!((stats[1].start, stats[1].n_edges, stats[1].n_components), (10, 2, 2));
+/// assert_eq!(stats[1].density, 2.0 / 6.0);
+/// ```
+pub fn stats_over_windows(tg: &TemporalGraph, window: u64, step: u64) -> Vec<WindowStats> {
+    if window == 0 || step == 0 {
+        panic!("[stats::stats_over_windows] Error: The window and the step must be positive!");
+    }
+    let (first, last) = match tg.get_time_span() {
+        Some(span) => span,
+        None => return vec![],
+    };
+    let n_nodes = tg.get_n_nodes();
+    let mut stats = vec![];
+    let mut start = first;
+    loop {
+        let end = start.saturating_add(window);
+        let events = tg.get_events_between(start, end);
+        let mut edges: Vec<(usize, usize)> = events
+            .iter()
+            .map(|(node1, node2, _)| {
+                if tg.is_directed() {
+                    (*node1, *node2)
+                } else {
+                    (*node1.min(node2), *node1.max(node2))
+                }
+            })
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+        let n_loops = edges.iter().filter(|(node1, node2)| node1 == node2).count();
+        let max_edges = match n_nodes {
+            0 | 1 => 0.0,
+            n if tg.is_directed() => (n * (n - 1)) as f32,
+            n => (n * (n - 1)) as f32 / 2.0,
+        };
+        let density = if max_edges > 0.0 {
+            (edges.len() - n_loops) as f32 / max_edges
+        } else {
+            0.0
+        };
+        stats.push(WindowStats {
+            start,
+            end,
+            n_events: events.len(),
+            n_edges: edges.len(),
+            density,
+            n_components: count_components(n_nodes, &edges),
+        });
+        if end > last {
+            break;
+        }
+        start = match start.checked_add(step) {
+            Some(next) if next <= last => next,
+            _ => break,
+        };
+    }
+    stats
+}
+
+fn weights_of<G>(g: &G) -> Vec<f32>
+where
+    G: Graph,
+{
+    g.get_edges().into_iter().map(|(_, _, w)| w).collect()
+}
+
+// Counts the connected components of the nodes joined by the edges
+fn count_components(n_nodes: usize, edges: &[(usize, usize)]) -> usize {
+    let mut components = DisjointSet::new(n_nodes);
+    for (node1, node2) in edges {
+        components.union(*node1, *node2);
+    }
+    components.get_n_sets()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DGraph, UGraph};
+
+    #[test]
+    fn weight_histogram_check_empty_and_constant_cases() {
+        let g = UGraph::from_adjacency_matrix(vec![vec![0.0; 2]; 2]);
+        assert_eq!(weight_histogram(&g, 3), None);
+        let g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 2.0)]);
+        let hist = weight_histogram(&g, 2).unwrap();
+        assert_eq!(hist.bounds, vec![2.0, 2.0, 2.0]);
+        assert_eq!(hist.counts, vec![0, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be positive")]
+    fn weight_histogram_panic_zero_bins() {
+        let g = UGraph::new();
+        weight_histogram(&g, 0);
+    }
+
+    #[test]
+    fn weight_percentiles_check_interpolation() {
+        let edges = vec![
+            (0, 1, 10.0),
+            (1, 2, 20.0),
+            (2, 3, 30.0),
+            (3, 4, 40.0),
+            (4, 0, 50.0),
+        ];
+        let g = DGraph::from_weighted_edges(5, edges);
+        assert_eq!(
+            weight_percentiles(&g, &[25.0, 90.0]),
+            Some(vec![20.0, 46.0])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn weight_percentile_panic_not_valid() {
+        let g = DGraph::from_weighted_edges(2, vec![(0, 1, 1.0)]);
+        weight_percentile(&g, 101.0);
+    }
+
+    #[test]
+    fn stats_over_windows_check_overlapping_directed() {
+        let events = vec![(0, 1, 100), (1, 0, 101), (0, 1, 102), (2, 2, 130)];
+        let tg = TemporalGraph::from_events(3, true, events);
+        let stats = stats_over_windows(&tg, 20, 10);
+        let starts: Vec<u64> = stats.iter().map(|s| s.start).collect();
+        assert_eq!(starts, vec![100, 110, 120]);
+        assert_eq!((stats[0].n_events, stats[0].n_edges), (3, 2));
+        assert_eq!(stats[0].density, 2.0 / 6.0);
+        assert_eq!((stats[1].n_edges, stats[1].n_components), (0, 3));
+        assert_eq!((stats[2].n_edges, stats[2].density), (1, 0.0));
+        assert!(stats_over_windows(&TemporalGraph::new(true), 10, 10).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "must be positive")]
+    fn stats_over_windows_panic_zero_step() {
+        let tg = TemporalGraph::from_events(2, false, vec![(0, 1, 0)]);
+        stats_over_windows(&tg, 10, 0);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::Graph;
+use std::fmt;
+
+/// The `TemporalGraph` struct stores a graph as a stream of timestamped edges (events),
+/// like the interactions parsed from a log. The nodes are referenced by `usize` values
+/// from `0` to `n_nodes-1` and the timestamps are `u64` values (e.g. seconds since the
+/// epoch). The events that happen in a time range can be collapsed into a `UGraph` or
+/// `DGraph` snapshot to use the algorithms of the crate.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TemporalGraph {
+    n_nodes: usize,
+    directed: bool,
+    events: Vec<(usize, usize, u64)>,
+}
+
+impl TemporalGraph {
+    /// Creates an empty `TemporalGraph`.
+    ///
+    /// # Arguments
+    ///
+    /// * `directed` - A `bool` value, `true` if the events go from a source to a
+    ///   destination.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::TemporalGraph;
+    /// let tg = TemporalGraph::new(false);
+    /// ```
+    pub fn new(directed: bool) -> TemporalGraph {
+        TemporalGraph {
+            n_nodes: 0,
+            directed,
+            events: vec![],
+        }
+    }
+
+    /// Creates a `TemporalGraph` from the number of nodes and the list of events.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_nodes` - An `usize` value with the number of nodes in the graph.
+    /// * `directed` - A `bool` value, `true` if the events go from a source to a
+    ///   destination.
+    /// * `events` - A vector of `(node1, node2, timestamp)` tuples.
+    ///
+    /// # Panics
+    ///
+    /// * If some event has an invalid node value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::TemporalGraph;
+    /// let tg = TemporalGraph::from_events(3, true, vec![(0, 1, 10), (1, 2, 15)]);
+    /// assert_eq!(tg.get_n_events(), 2);
+    /// ```
+    pub fn from_events(
+        n_nodes: usize,
+        directed: bool,
+        events: Vec<(usize, usize, u64)>,
+    ) -> TemporalGraph {
+        let mut tg = TemporalGraph {
+            n_nodes,
+            directed,
+            events: Vec::with_capacity(events.len()),
+        };
+        for (node1, node2, time) in events {
+            tg.add_event(node1, node2, time);
+        }
+        tg
+    }
+
+    /// Returns the number of nodes in the graph.
+    pub fn get_n_nodes(&self) -> usize {
+        self.n_nodes
+    }
+
+    /// Returns the number of events in the graph.
+    pub fn get_n_events(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if the events are directed.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Returns the events of the graph as `(node1, node2, timestamp)` tuples, sorted by
+    /// timestamp (the events with the same timestamp keep their insertion order).
+    pub fn get_events(&self) -> &[(usize, usize, u64)] {
+        &self.events
+    }
+
+    /// Returns the events with a timestamp in the range `[start, end)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::TemporalGraph;
+    /// let tg = TemporalGraph::from_events(3, false, vec![(0, 1, 5), (1, 2, 10), (0, 2, 20)]);
+    /// assert_eq!(tg.get_events_between(5, 20), &[(0, 1, 5), (1, 2, 10)]);
+    /// ```
+    pub fn get_events_between(&self, start: u64, end: u64) -> &[(usize, usize, u64)] {
+        let low = self.events.partition_point(|(_, _, t)| *t < start);
+        let high = self.events.partition_point(|(_, _, t)| *t < end).max(low);
+        &self.events[low..high]
+    }
+
+    /// Returns the first and last timestamps of the events, or `None` if there are no
+    /// events.
+    pub fn get_time_span(&self) -> Option<(u64, u64)> {
+        match (self.events.first(), self.events.last()) {
+            (Some((_, _, first)), Some((_, _, last))) => Some((*first, *last)),
+            _ => None,
+        }
+    }
+
+    /// Adds a node to the graph without any event and returns its index.
+    pub fn add_node(&mut self) -> usize {
+        self.n_nodes += 1;
+        self.n_nodes - 1
+    }
+
+    /// Adds an event between the nodes passed as parameters at the time `time`. The
+    /// events can be added in any order.
+    ///
+    /// # Panics
+    ///
+    /// * If some node passed as a parameter is not valid.
+    pub fn add_event(&mut self, node1: usize, node2: usize, time: u64) {
+        if let Some(node) = [node1, node2].iter().find(|n| **n >= self.n_nodes) {
+            panic!(
+                "[TemporalGraph::add_event] Error: The node {} is not valid!",
+                node
+            );
+        }
+        let pos = self.events.partition_point(|(_, _, t)| *t <= time);
+        self.events.insert(pos, (node1, node2, time));
+    }
+
+    /// Returns the graph with the events in the range `[start, end)`, where each edge has
+    /// the number of events between its nodes as weight. The directed temporal graphs
+    /// should be collapsed into a `DGraph`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, TemporalGraph, UGraph};
+    /// let tg = TemporalGraph::from_events(3, false, vec![(0, 1, 1), (1, 0, 2), (1, 2, 9)]);
+    /// let g: UGraph = tg.snapshot(0, 5);
+    /// assert_eq!(g.get_edges(), vec![(0, 1, 2.0)]);
+    /// ```
+    pub fn snapshot<G>(&self, start: u64, end: u64) -> G
+    where
+        G: Graph + Default,
+    {
+        let mut g = G::default();
+        for _ in 0..self.n_nodes {
+            g.add_node();
+        }
+        for (node1, node2, _) in self.get_events_between(start, end) {
+            let current = g.get_edge(*node1, *node2).unwrap_or(0.0);
+            g.add_weighted_edge(*node1, *node2, current + 1.0);
+        }
+        g
+    }
+}
+
+impl fmt::Display for TemporalGraph {
+    /// Shows the info of the temporal graph.
+    /// The events are represented in the format `node1--node2@timestamp` (`->` if
+    /// directed).
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let arrow = if self.directed { "->" } else { "--" };
+        writeln!(f, "TemporalGraph(events=[")?;
+        for (node1, node2, time) in &self.events {
+            writeln!(f, "{}{}{}@{},", node1, arrow, node2, time)?;
+        }
+        write!(f, "], n_nodes={})", self.n_nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DGraph, Graph, TemporalGraph};
+
+    #[test]
+    fn add_event_keeps_events_sorted() {
+        let mut tg = TemporalGraph::from_events(3, true, vec![(0, 1, 30), (1, 2, 10)]);
+        tg.add_event(2, 0, 20);
+        tg.add_event(0, 2, 10);
+        assert_eq!(
+            tg.get_events(),
+            &[(1, 2, 10), (0, 2, 10), (2, 0, 20), (0, 1, 30)]
+        );
+        assert_eq!(tg.get_time_span(), Some((10, 30)));
+        assert_eq!(tg.get_events_between(30, 10), &[]);
+    }
+
+    #[test]
+    fn snapshot_check_directed() {
+        let tg = TemporalGraph::from_events(3, true, vec![(0, 1, 1), (1, 0, 2), (0, 1, 3)]);
+        let g: DGraph = tg.snapshot(0, 10);
+        assert_eq!(g.get_edges(), vec![(0, 1, 2.0), (1, 0, 1.0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn add_event_panic_not_valid_node() {
+        let mut tg = TemporalGraph::new(false);
+        tg.add_node();
+        tg.add_event(0, 1, 0);
+    }
+}
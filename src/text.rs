@@ -0,0 +1,323 @@
+//! # Text
+//!
+//! `text` is a `graphst` module to save graphs in a plain text format and load them back.
+//! The first line of a document is the header `graphst <version>`, so the documents
+//! written by older versions of the crate keep loading when the format changes: each
+//! supported version has its own parser, and the documents are migrated to the current
+//! graph types while they are read.
+//!
+//! The current version (`2`) has the kind of graph and its number of nodes in the second
+//! line, followed by one `node` line per node with a weight other than `1.0` and one line
+//! per edge:
+//!
+//! ```text
+//! graphst 2
+//! undirected 3
+//! node 1 0.5
+//! 0 1 2.5
+//! 1 2 1
+//! ```
+//!
+//! The version `1` documents have no `node` lines, so their graphs are migrated with all
+//! the node weights set to `1.0`.
+
+use crate::edge_policy::EdgeMerger;
+use crate::graph::Graph;
+use crate::{DuplicatePolicy, SelfLoopPolicy};
+use std::error::Error;
+use std::fmt;
+
+/// The version of the format written by `to_text`.
+pub const FORMAT_VERSION: u32 = 2;
+
+/// The `TextError` struct is the error returned when a document can't be loaded: the
+/// line of the failure (starting at `1`) and the reason.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextError {
+    /// The line of the document where the failure was found.
+    pub line: usize,
+    /// The description of the failure.
+    pub message: String,
+}
+
+impl fmt::Display for TextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for TextError {}
+
+/// Returns the graph in the text format of the version `FORMAT_VERSION`. The weights (of
+/// the edges and the nodes) are written with the shortest representation that reads back
+/// to the same `f32`, so the documents load to an identical graph.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::text::to_text;
+/// use graphst::{DGraph, Graph};
+/// let mut g = DGraph::from_weighted_edges(3, vec![(0, 1, 2.5), (2, 0, 1.0)]);
+/// g.set_node_weight(2, 0.5);
+/// assert_eq!(to_text(&g), "graphst 2\ndirected 3\nnode 2 0.5\n0 1 2.5\n2 0 1\n");
+/// ```
+pub fn to_text<G>(g: &G) -> String
+where
+    G: Graph,
+{
+    let kind = if g.is_directed() {
+        "directed"
+    } else {
+        "undirected"
+    };
+    let mut text = format!("graphst {}\n{} {}\n", FORMAT_VERSION, kind, g.get_n_nodes());
+    for node in g.get_nodes() {
+        let weight = g.get_node_weight(node);
+        if weight != 1.0 {
+            text.push_str(&format!("node {} {}\n", node, weight));
+        }
+    }
+    for (node1, node2, weight) in g.get_edges() {
+        text.push_str(&format!("{} {} {}\n", node1, node2, weight));
+    }
+    text
+}
+
+/// Loads a graph from a document written by `to_text` with any supported version of the
+/// format. The empty lines are ignored, and when an edge is repeated the last weight is
+/// kept (see `from_text_with` for other policies).
+///
+/// # Errors
+///
+/// * If the header is missing or has a version that is not supported.
+/// * If the kind of graph of the document doesn't match the type `G`.
+/// * If some line is not valid, or an edge has a node that is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::text::{from_text, to_text};
+/// use graphst::{DGraph, UGraph};
+/// let g = UGraph::from_weighted_edges(3, vec![(0, 1, 0.1), (1, 2, 3.0)]);
+/// let loaded: UGraph = from_text(&to_text(&g)).unwrap();
+/// assert_eq!(loaded, g);
+/// let error = from_text::<DGraph>(&to_text(&g)).unwrap_err();
+/// assert_eq!(error.to_string(), "line 2: The graph kind undirected is not valid!");
+/// ```
+pub fn from_text<G>(text: &str) -> Result<G, TextError>
+where
+    G: Graph + Default,
+{
+    from_text_with(text, DuplicatePolicy::KeepLast, SelfLoopPolicy::Allow)
+}
+
+/// Same as `from_text` but handling the repeated edges and the self-loops with the policies
+/// passed as parameters, for the documents written by hand or by other tools.
+///
+/// # Errors
+///
+/// * The errors of `from_text`.
+/// * If some edge is repeated with `DuplicatePolicy::Error`.
+/// * If some edge is a self-loop with `SelfLoopPolicy::Error`.
+///
+/// # Examples
+///
+/// ```
+/// use graphst::text::from_text_with;
+/// use graphst::{DuplicatePolicy, Graph, SelfLoopPolicy, UGraph};
+/// let text = "graphst 2\nundirected 2\n0 1 3\n1 1 5\n1 0 2\n";
+/// let g: UGraph = from_text_with(text, DuplicatePolicy::Min, SelfLoopPolicy::Ignore).unwrap();
+/// assert_eq!(g.get_edges(), vec![(0, 1, 2.0)]);
+/// let error = from_text_with::<UGraph>(text, DuplicatePolicy::Min, SelfLoopPolicy::Error);
+/// assert_eq!(
+///     error.unwrap_err().to_string(),
+///     "line 4: [text::from_text_with] The edge (1, 1) is a self-loop in a graph with 2 nodes"
+/// );
+/// ```
+pub fn from_text_with<G>(
+    text: &str,
+    duplicates: DuplicatePolicy,
+    self_loops: SelfLoopPolicy,
+) -> Result<G, TextError>
+where
+    G: Graph + Default,
+{
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+    let (line, header) = lines.next().unwrap_or((1, ""));
+    let version = match header.strip_prefix("graphst ") {
+        Some(version) => parse(version, "version", line)?,
+        None => return Err(error(line, "The header is not valid!".to_string())),
+    };
+    match version {
+        1 => parse_v1(lines, duplicates, self_loops),
+        2 => parse_v2(lines, duplicates, self_loops),
+        _ => Err(error(
+            line,
+            format!("The version {} is not supported!", version),
+        )),
+    }
+}
+
+//--------- Private functions ---------
+
+// Version 1: the graph kind and the edges. The nodes keep the default weight
+fn parse_v1<'a, G, I>(
+    lines: I,
+    duplicates: DuplicatePolicy,
+    self_loops: SelfLoopPolicy,
+) -> Result<G, TextError>
+where
+    G: Graph + Default,
+    I: Iterator<Item = (usize, &'a str)>,
+{
+    parse_graph(lines, false, duplicates, self_loops)
+}
+
+// Version 2: the version 1 with the `node` lines of the node weights
+fn parse_v2<'a, G, I>(
+    lines: I,
+    duplicates: DuplicatePolicy,
+    self_loops: SelfLoopPolicy,
+) -> Result<G, TextError>
+where
+    G: Graph + Default,
+    I: Iterator<Item = (usize, &'a str)>,
+{
+    parse_graph(lines, true, duplicates, self_loops)
+}
+
+fn parse_graph<'a, G, I>(
+    mut lines: I,
+    node_weights: bool,
+    duplicates: DuplicatePolicy,
+    self_loops: SelfLoopPolicy,
+) -> Result<G, TextError>
+where
+    G: Graph + Default,
+    I: Iterator<Item = (usize, &'a str)>,
+{
+    let mut g = G::default();
+    let (line, kind_line) = lines
+        .next()
+        .ok_or_else(|| error(2, "The graph kind is missing!".to_string()))?;
+    let fields: Vec<&str> = kind_line.split_whitespace().collect();
+    if fields.len() != 2 {
+        return Err(error(line, "The graph kind is not valid!".to_string()));
+    }
+    let directed = match fields[0] {
+        "directed" => true,
+        "undirected" => false,
+        _ => return Err(error(line, "The graph kind is not valid!".to_string())),
+    };
+    if directed != g.is_directed() {
+        let message = format!("The graph kind {} is not valid!", fields[0]);
+        return Err(error(line, message));
+    }
+    g.add_nodes(parse(fields[1], "number of nodes", line)?);
+    let mut merger = EdgeMerger::new("text::from_text_with", duplicates, self_loops);
+    for (line, edge) in lines {
+        let fields: Vec<&str> = edge.split_whitespace().collect();
+        if node_weights && fields[0] == "node" {
+            if fields.len() != 3 {
+                return Err(error(line, "The node weight is not valid!".to_string()));
+            }
+            let node: usize = parse(fields[1], "node", line)?;
+            if node >= g.get_n_nodes() {
+                return Err(error(line, format!("The node {} is not valid!", node)));
+            }
+            g.set_node_weight(node, parse(fields[2], "weight", line)?);
+            continue;
+        }
+        if fields.len() != 3 {
+            return Err(error(line, "The edge is not valid!".to_string()));
+        }
+        let node1: usize = parse(fields[0], "node", line)?;
+        let node2: usize = parse(fields[1], "node", line)?;
+        let weight = parse(fields[2], "weight", line)?;
+        if let Some(node) = [node1, node2].iter().find(|n| **n >= g.get_n_nodes()) {
+            return Err(error(line, format!("The node {} is not valid!", node)));
+        }
+        merger
+            .add(&mut g, node1, node2, weight)
+            .map_err(|e| error(line, e.to_string()))?;
+    }
+    Ok(g)
+}
+
+fn parse<T>(field: &str, name: &str, line: usize) -> Result<T, TextError>
+where
+    T: std::str::FromStr,
+{
+    field
+        .parse()
+        .map_err(|_| error(line, format!("The {} {} is not valid!", name, field)))
+}
+
+fn error(line: usize, message: String) -> TextError {
+    TextError { line, message }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DGraph, UGraph};
+
+    #[test]
+    fn round_trip_random_graphs() {
+        let mut rng = crate::rng::Rng::new(31);
+        for _ in 0..30 {
+            let n_nodes = 1 + rng.gen_range(15);
+            let mut adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+            for _ in 0..rng.gen_range(40) {
+                let (node1, node2) = (rng.gen_range(n_nodes), rng.gen_range(n_nodes));
+                let weight = (rng.next_f64() as f32 - 0.5) * 1e3;
+                adj_mat[node1][node2] = weight;
+                adj_mat[node2][node1] = weight;
+            }
+            let node = rng.gen_range(n_nodes);
+            let node_weight = rng.next_f64() as f32;
+            let mut g = UGraph::from_adjacency_matrix(adj_mat.clone());
+            g.set_node_weight(node, node_weight);
+            assert_eq!(from_text::<UGraph>(&to_text(&g)), Ok(g));
+            let mut g = DGraph::from_adjacency_matrix(adj_mat);
+            g.set_node_weight(node, node_weight);
+            assert_eq!(from_text::<DGraph>(&to_text(&g)), Ok(g));
+        }
+    }
+
+    #[test]
+    fn from_text_check_version_1_migration() {
+        let v1 = "graphst 1\ndirected 3\n0 1 2.5\n2 0 1\n";
+        let g: DGraph = from_text(v1).unwrap();
+        assert_eq!(
+            g,
+            DGraph::from_weighted_edges(3, vec![(0, 1, 2.5), (2, 0, 1.0)])
+        );
+        assert_eq!(to_text(&g), v1.replace("graphst 1", "graphst 2"));
+        // The node lines are not part of the version 1
+        let error = from_text::<UGraph>("graphst 1\nundirected 2\nnode 0 0.5\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.message.as_str()),
+            (3, "The node node is not valid!")
+        );
+    }
+
+    #[test]
+    fn from_text_check_errors() {
+        let error = from_text::<UGraph>("graphst 3\nundirected 1\n").unwrap_err();
+        assert_eq!(error.message, "The version 3 is not supported!");
+        let error = from_text::<UGraph>("undirected 1\n").unwrap_err();
+        assert_eq!(error.message, "The header is not valid!");
+        let error = from_text::<UGraph>("graphst 1\nundirected 2\n\n0 2 1.0").unwrap_err();
+        assert_eq!(
+            (error.line, error.message.as_str()),
+            (4, "The node 2 is not valid!")
+        );
+        let error = from_text::<UGraph>("graphst 2\nundirected 2\nnode 2 0.5").unwrap_err();
+        assert_eq!(error.message, "The node 2 is not valid!");
+    }
+}
@@ -1,12 +1,22 @@
-use crate::Graph;
+use crate::adj_index;
+use crate::edge_policy::EdgeMerger;
+use crate::float;
+use crate::graph;
+use crate::{DuplicatePolicy, Graph, GraphError, SelfLoopPolicy};
+use std::borrow::Cow;
 use std::fmt;
+use std::mem;
+use std::ops::Range;
 
 /// The `UGraph` struct provides the functionalities to create and manipulate `undirected graphs`.
 /// It can use weighted edges or default edges (with weight `1.0`). The weights of the nodes are
 /// of type `f32`, and the nodes are referenced by `usize` values from `0` to `n_nodes-1`.
+#[derive(Clone, Default, PartialEq)]
 pub struct UGraph {
     n_nodes: usize,
     adj_mat: Vec<Vec<f32>>,
+    adj_list: Vec<Vec<usize>>, // sorted index of the present edges of each node
+    node_weights: Vec<f32>,    // `1.0` unless set with `set_node_weight`
 }
 
 impl UGraph {
@@ -22,6 +32,8 @@ impl UGraph {
         UGraph {
             n_nodes: 0,
             adj_mat: vec![],
+            adj_list: vec![],
+            node_weights: vec![],
         }
     }
 
@@ -32,7 +44,7 @@ impl UGraph {
     ///
     /// * `n_nodes` - An `usize` value with the number of nodes in the graph.
     /// * `edges` - A vector of tuples with two `usize` values defining each
-    ///             edge (`(node1, node2)`).
+    ///   edge (`(node1, node2)`).
     ///
     /// # Panics
     ///
@@ -57,7 +69,7 @@ impl UGraph {
                     edge
                 );
             }
-            if adj_mat[edge.0][edge.1] != 0.0 {
+            if !float::is_zero(adj_mat[edge.0][edge.1]) {
                 panic!(
                     "[UGraph::from_edges] Error: The edge ({})--({}) is repeated!",
                     edge.0, edge.1
@@ -68,7 +80,13 @@ impl UGraph {
                 adj_mat[edge.1][edge.0] = 1.0;
             }
         }
-        UGraph { n_nodes, adj_mat }
+        let adj_list = adj_index::build(&adj_mat);
+        UGraph {
+            n_nodes,
+            adj_mat,
+            adj_list,
+            node_weights: vec![1.0; n_nodes],
+        }
     }
 
     /// Creates a `UGraph` from the definition of the graph edges (with weight)
@@ -78,7 +96,7 @@ impl UGraph {
     ///
     /// * `n_nodes` - An `usize` value with the number of nodes in the Ugraph.
     /// * `edges` - A vector of triplets with two `usize` values and a `f32`
-    ///             defining each edge (`(node1, node2, weight)`).
+    ///   defining each edge (`(node1, node2, weight)`).
     ///
     /// # Panics
     ///
@@ -103,7 +121,7 @@ impl UGraph {
                     edge
                 );
             }
-            if adj_mat[edge.0][edge.1] != 0.0 {
+            if !float::is_zero(adj_mat[edge.0][edge.1]) {
                 panic!(
                     "[UGraph::from_weighted_edges] Error: The edge ({})--({}) is repeated!",
                     edge.0, edge.1
@@ -114,7 +132,88 @@ impl UGraph {
                 adj_mat[edge.1][edge.0] = edge.2;
             }
         }
-        UGraph { n_nodes, adj_mat }
+        let adj_list = adj_index::build(&adj_mat);
+        UGraph {
+            n_nodes,
+            adj_mat,
+            adj_list,
+            node_weights: vec![1.0; n_nodes],
+        }
+    }
+
+    /// Same as `from_weighted_edges` but handling the repeated edges and the self-loops
+    /// with the policies passed as parameters, and returning an error instead of
+    /// panicking. The unweighted edge lists can be mapped to the weight `1.0`, and
+    /// `DuplicatePolicy::Sum` then counts the repetitions of each edge.
+    ///
+    /// # Errors
+    ///
+    /// * `GraphError::InvalidNode` if some edge has an invalid node value.
+    /// * `GraphError::DuplicateEdge` if some edge is repeated with `DuplicatePolicy::Error`.
+    /// * `GraphError::SelfLoop` if some edge is a self-loop with `SelfLoopPolicy::Error`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{DuplicatePolicy, Graph, GraphError, SelfLoopPolicy, UGraph};
+    /// let edges = vec![(0, 1, 2.0), (1, 0, 0.5), (1, 2, 1.0)];
+    /// let allow = SelfLoopPolicy::Allow;
+    /// let g = UGraph::from_weighted_edges_with(3, edges.clone(), DuplicatePolicy::Sum, allow).unwrap();
+    /// assert_eq!(g.get_edge(0, 1), Some(2.5)); // (1, 0) is the same edge
+    /// let error = UGraph::from_weighted_edges_with(3, edges, DuplicatePolicy::Error, allow);
+    /// assert!(matches!(error, Err(GraphError::DuplicateEdge { node1: 1, node2: 0, .. })));
+    /// ```
+    pub fn from_weighted_edges_with(
+        n_nodes: usize,
+        edges: Vec<(usize, usize, f32)>,
+        duplicates: DuplicatePolicy,
+        self_loops: SelfLoopPolicy,
+    ) -> Result<UGraph, GraphError> {
+        UGraph::from_merged_edges(
+            "UGraph::from_weighted_edges_with",
+            n_nodes,
+            edges,
+            duplicates,
+            self_loops,
+        )
+    }
+
+    /// Same as `from_edges` but handling the repeated edges and the self-loops with the
+    /// policies passed as parameters, and returning an error instead of panicking. The
+    /// edges have weight `1.0`, so `DuplicatePolicy::Sum` counts their repetitions.
+    ///
+    /// # Errors
+    ///
+    /// * `GraphError::InvalidNode` if some edge has an invalid node value.
+    /// * `GraphError::DuplicateEdge` if some edge is repeated with `DuplicatePolicy::Error`.
+    /// * `GraphError::SelfLoop` if some edge is a self-loop with `SelfLoopPolicy::Error`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{DuplicatePolicy, Graph, SelfLoopPolicy, UGraph};
+    /// let edges = vec![(0, 1), (0, 1), (1, 2), (2, 2)];
+    /// let (sum, ignore) = (DuplicatePolicy::Sum, SelfLoopPolicy::Ignore);
+    /// let g = UGraph::from_edges_with(3, edges, sum, ignore).unwrap();
+    /// assert_eq!(g.get_edge(0, 1), Some(2.0));
+    /// assert_eq!(g.get_edge(2, 2), None);
+    /// ```
+    pub fn from_edges_with(
+        n_nodes: usize,
+        edges: Vec<(usize, usize)>,
+        duplicates: DuplicatePolicy,
+        self_loops: SelfLoopPolicy,
+    ) -> Result<UGraph, GraphError> {
+        UGraph::from_merged_edges(
+            "UGraph::from_edges_with",
+            n_nodes,
+            edges
+                .into_iter()
+                .map(|(node1, node2)| (node1, node2, 1.0))
+                .collect(),
+            duplicates,
+            self_loops,
+        )
     }
 
     /// Creates a `UGraph` from an adjacency matrix. The `f32` values represent the weights
@@ -151,14 +250,53 @@ impl UGraph {
                 );
             }
         }
-        let g = UGraph { n_nodes, adj_mat };
+        let adj_list = adj_index::build(&adj_mat);
+        let g = UGraph {
+            n_nodes,
+            adj_mat,
+            adj_list,
+            node_weights: vec![1.0; n_nodes],
+        };
         if !g.check_is_undirected() {
             panic!(
                 "[UGraph::from_adjacency_matrix] Error: The adjacency matrix provided is \
                 not a valid matrix for an undirected graph!"
             );
         }
-        return g;
+        g
+    }
+
+    /// Creates a `UGraph` from a matrix where the value `no_edge` means that there is no
+    /// edge, e.g. `f32::INFINITY` for a distance matrix. `f32::NAN` is also valid as
+    /// `no_edge`. Any other value is the weight of an edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `mat` - A squared matrix of `f32` values.
+    /// * `no_edge` - The `f32` value that represents the absence of an edge.
+    ///
+    /// # Panics
+    ///
+    /// * If the matrix is not squared.
+    /// * If the adjacency matrix is not valid for an undirected graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, UGraph};
+    /// let inf = f32::INFINITY;
+    /// let g = UGraph::from_matrix_with(vec![vec![inf, 3.0], vec![3.0, inf]], inf);
+    /// assert_eq!(g.get_edge(0, 1), Some(3.0));
+    /// assert_eq!(g.get_edge(0, 0), None);
+    /// assert_eq!(g.to_matrix_with(inf), vec![vec![inf, 3.0], vec![3.0, inf]]);
+    /// ```
+    pub fn from_matrix_with(mut mat: Vec<Vec<f32>>, no_edge: f32) -> UGraph {
+        for value in mat.iter_mut().flatten() {
+            if *value == no_edge || (no_edge.is_nan() && value.is_nan()) {
+                *value = 0.0;
+            }
+        }
+        UGraph::from_adjacency_matrix(mat)
     }
 
     /// Returns a vector with the nodes that are neighbors of the node passed as a parameter.
@@ -188,16 +326,108 @@ impl UGraph {
                 node
             );
         }
-        self.adj_mat[node]
+        adj_index::present(&self.adj_list[node], &self.adj_mat[node])
+    }
+
+    /// Returns the row of the node in the adjacency matrix: the weight of the edge from
+    /// the node to each node, with `0.0` for the missing edges. As in
+    /// `get_adjacency_matrix`, the weights that are zero within the crate tolerance are
+    /// returned as `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::UGraph;
+    /// let g = UGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (2, 0, 0.5)]);
+    /// assert_eq!(*g.row(0), [0.0, 2.0, 0.5]);
+    /// let column: Vec<f32> = g.column_iter(0).collect();
+    /// assert_eq!(column, vec![0.0, 2.0, 0.5]);
+    /// ```
+    pub fn row(&self, node: usize) -> Cow<'_, [f32]> {
+        if node >= self.n_nodes {
+            panic!("[UGraph::row] Error: The node {} is not valid!", node);
+        }
+        adj_index::present_row(&self.adj_mat[node])
+    }
+
+    /// Returns an iterator over the column of the node in the adjacency matrix: the
+    /// weight of the edge from each node to the node, with `0.0` for the missing edges.
+    /// The matrix is symmetric, so it has the same values as `row`.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    pub fn column_iter(&self, node: usize) -> impl Iterator<Item = f32> + '_ {
+        if node >= self.n_nodes {
+            panic!(
+                "[UGraph::column_iter] Error: The node {} is not valid!",
+                node
+            );
+        }
+        self.adj_mat
             .iter()
-            .enumerate()
-            .filter(|(_, w)| **w != 0.0)
-            .map(|(idx, _)| idx)
-            .collect()
+            .map(move |row| adj_index::present_weight(row[node]))
+    }
+
+    /// Returns an estimation of the bytes used by the graph: the struct itself and the
+    /// allocated capacity of the adjacency matrix and the adjacency lists. The dense
+    /// adjacency matrix takes `4 * n_nodes^2` bytes, so it dominates on big graphs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::UGraph;
+    /// let g = UGraph::from_edges(100, vec![(0, 1)]);
+    /// assert!(g.memory_usage() >= 4 * 100 * 100);
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        mem::size_of::<UGraph>()
+            + graph::nested_vec_bytes(&self.adj_mat)
+            + graph::nested_vec_bytes(&self.adj_list)
+            + self.node_weights.capacity() * mem::size_of::<f32>()
+    }
+
+    /// Shrinks the capacity of the adjacency matrix and the adjacency lists to fit their
+    /// contents, releasing the memory reserved by previous insertions and removals.
+    pub fn shrink_to_fit(&mut self) {
+        self.adj_mat.iter_mut().for_each(|row| row.shrink_to_fit());
+        self.adj_mat.shrink_to_fit();
+        self.adj_list
+            .iter_mut()
+            .for_each(|list| list.shrink_to_fit());
+        self.adj_list.shrink_to_fit();
+        self.node_weights.shrink_to_fit();
     }
 
     //--------- Private functions ---------
 
+    // Builds the graph from the edges with the policies, naming `operation` in the errors
+    fn from_merged_edges(
+        operation: &'static str,
+        n_nodes: usize,
+        edges: Vec<(usize, usize, f32)>,
+        duplicates: DuplicatePolicy,
+        self_loops: SelfLoopPolicy,
+    ) -> Result<UGraph, GraphError> {
+        let mut g = UGraph::from_adjacency_matrix(vec![vec![0.0; n_nodes]; n_nodes]);
+        let mut merger = EdgeMerger::new(operation, duplicates, self_loops);
+        for (node1, node2, weight) in edges {
+            if let Some(node) = [node1, node2].iter().find(|n| **n >= n_nodes) {
+                return Err(GraphError::InvalidNode {
+                    operation,
+                    node: *node,
+                    n_nodes,
+                });
+            }
+            merger.add(&mut g, node1, node2, weight)?;
+        }
+        Ok(g)
+    }
+
     fn check_is_undirected(&self) -> bool {
         for n in self.get_nodes() {
             for n2 in n..self.get_n_nodes() {
@@ -206,7 +436,7 @@ impl UGraph {
                 }
             }
         }
-        return true;
+        true
     }
 }
 
@@ -243,9 +473,11 @@ impl Graph for UGraph {
         (0..self.n_nodes).collect()
     }
 
-    /// Returns a reference to the bidimensional vector of `f32` with the adjacency
-    /// matrix of the graph. The `f32` values are the weights of the edges, and a
-    /// value of `0.0` means that there is no edge between those nodes.
+    /// Returns the bidimensional vector of `f32` with the adjacency matrix of the graph.
+    /// The `f32` values are the weights of the edges, and a value of `0.0` means that
+    /// there is no edge between those nodes. The matrix is borrowed, unless it has
+    /// weights that are zero within the crate tolerance: a copy with those weights set
+    /// to `0.0` is returned instead, so the matrix always agrees with `get_edge`.
     ///
     /// # Examples
     ///
@@ -260,10 +492,18 @@ impl Graph for UGraph {
     ///     vec![1.0, 0.0, 1.0],
     ///     vec![0.0, 1.0, 1.0],
     /// ];
-    /// assert_eq!(g_adj_mat, &test_mat);
+    /// assert_eq!(*g_adj_mat, test_mat);
     /// ```
-    fn get_adjacency_matrix<'a>(&'a self) -> &'a Vec<Vec<f32>> {
-        &self.adj_mat
+    fn get_adjacency_matrix(&self) -> Cow<'_, Vec<Vec<f32>>> {
+        match adj_index::present_matrix(&self.adj_mat) {
+            Some(adj_mat) => Cow::Owned(adj_mat),
+            None => Cow::Borrowed(&self.adj_mat),
+        }
+    }
+
+    /// Returns `false`, the edges of a `UGraph` are undirected.
+    fn is_directed(&self) -> bool {
+        false
     }
 
     /// Gets the weight of the edge connecting the nodes `node1` and `node2`. If the graph is
@@ -307,14 +547,24 @@ impl Graph for UGraph {
                 node2
             );
         }
-        if self.adj_mat[node1][node2] != 0.0 {
-            return Some(self.adj_mat[node1][node2]);
+        if !float::is_zero(self.adj_mat[node1][node2]) {
+            Some(self.adj_mat[node1][node2])
         } else {
-            return None;
+            None
         }
     }
 
-    /// Adds a node to the graph without any edge.
+    /// Returns a sorted vector with the nodes reachable from `node` through one edge.
+    /// Same as `UGraph::get_neighbors_of`.
+    ///
+    /// # Panics
+    ///
+    /// * If the node passed as a parameter is not valid.
+    fn get_out_neighbors_of(&self, node: usize) -> Vec<usize> {
+        self.get_neighbors_of(node)
+    }
+
+    /// Adds a node to the graph without any edge and returns its index.
     ///
     /// # Examples
     ///
@@ -326,12 +576,32 @@ impl Graph for UGraph {
     /// let g_nodes = g.get_nodes();
     /// assert_eq!(g_nodes, vec![0, 1]);
     /// ```
-    fn add_node(&mut self) {
+    fn add_node(&mut self) -> usize {
         for node in &mut self.adj_mat {
             node.push(0.0); // add a new value for setting the edges to the new node
         }
         self.n_nodes += 1;
         self.adj_mat.push(vec![0.0; self.n_nodes]); // add the new node edges vector
+        self.adj_list.push(vec![]);
+        self.node_weights.push(1.0);
+        self.n_nodes - 1
+    }
+
+    /// Adds `count` nodes to the graph without any edge and returns the range of their
+    /// indices. Each row of the adjacency matrix is resized once, instead of once per
+    /// node as with repeated calls to `add_node`.
+    fn add_nodes(&mut self, count: usize) -> Range<usize> {
+        let start = self.n_nodes;
+        self.n_nodes += count;
+        for row in &mut self.adj_mat {
+            row.reserve_exact(count);
+            row.resize(self.n_nodes, 0.0);
+        }
+        self.adj_mat.reserve_exact(count);
+        self.adj_mat.resize(self.n_nodes, vec![0.0; self.n_nodes]);
+        self.adj_list.resize(self.n_nodes, vec![]);
+        self.node_weights.resize(self.n_nodes, 1.0);
+        start..self.n_nodes
     }
 
     /// Sets an undirected edge between nodes `node1` and `node2`.
@@ -370,6 +640,8 @@ impl Graph for UGraph {
         }
         self.adj_mat[node1][node2] = 1.0;
         self.adj_mat[node2][node1] = 1.0;
+        adj_index::update(&mut self.adj_list[node1], node2, 1.0);
+        adj_index::update(&mut self.adj_list[node2], node1, 1.0);
     }
 
     /// Sets an undirected edge between nodes `node1` and `node2`.
@@ -410,6 +682,82 @@ impl Graph for UGraph {
         }
         self.adj_mat[node1][node2] = weight;
         self.adj_mat[node2][node1] = weight;
+        adj_index::update(&mut self.adj_list[node1], node2, weight);
+        adj_index::update(&mut self.adj_list[node2], node1, weight);
+    }
+
+    /// Returns a vector with the edges of the graph as `(node1, node2, weight)` triplets.
+    /// Each undirected edge is returned only once, with `node1 <= node2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, UGraph};
+    /// let n_nodes = 3;
+    /// let edges = vec![(1, 0, 2.0), (1, 2, 1.5), (2, 2, -0.5)];
+    /// let g = UGraph::from_weighted_edges(n_nodes, edges);
+    /// assert_eq!(g.get_edges(), vec![(0, 1, 2.0), (1, 2, 1.5), (2, 2, -0.5)]);
+    /// ```
+    fn get_edges(&self) -> Vec<(usize, usize, f32)> {
+        let mut edges = vec![];
+        for (node1, neighbors) in self.adj_list.iter().enumerate() {
+            for node2 in neighbors.iter().filter(|n| **n >= node1) {
+                let weight = self.adj_mat[node1][*node2];
+                if !float::is_zero(weight) {
+                    edges.push((node1, *node2, weight));
+                }
+            }
+        }
+        edges
+    }
+
+    /// Returns the weight of the node, `1.0` if it was not set with `set_node_weight`.
+    ///
+    /// # Panics
+    ///
+    /// * If the node is not valid.
+    fn get_node_weight(&self, node: usize) -> f32 {
+        match self.node_weights.get(node) {
+            Some(weight) => *weight,
+            None => panic!(
+                "[UGraph::get_node_weight] Error: The node {} is not valid!",
+                node
+            ),
+        }
+    }
+
+    /// Sets the weight of the node passed as a parameter.
+    ///
+    /// # Panics
+    ///
+    /// * If the node is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphst::{Graph, UGraph};
+    /// let mut g = UGraph::from_edges(2, vec![(0, 1)]);
+    /// g.set_node_weight(1, 4.5);
+    /// assert_eq!(g.get_node_weight(1), 4.5);
+    /// assert_eq!(g.get_node_weight(0), 1.0);
+    /// ```
+    fn set_node_weight(&mut self, node: usize, weight: f32) {
+        match self.node_weights.get_mut(node) {
+            Some(current) => *current = weight,
+            None => panic!(
+                "[UGraph::set_node_weight] Error: The node {} is not valid!",
+                node
+            ),
+        }
+    }
+}
+
+impl fmt::Debug for UGraph {
+    /// Shows the number of nodes and the edges of the graph. The alternate format
+    /// (`{:#?}`) shows the adjacency matrix instead, one row per line, for graphs with up
+    /// to 16 nodes.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        graph::fmt_debug(self, "UGraph", f)
     }
 }
 
@@ -417,11 +765,11 @@ impl fmt::Display for UGraph {
     /// Shows the info of the graph.
     /// The edges are represented in the format `node1 -(weigh)- node2`.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "UGraph(edges=[\n")?;
+        writeln!(f, "UGraph(edges=[")?;
         for (node1, node) in self.adj_mat.iter().enumerate() {
             for (node2, weight) in node.iter().enumerate() {
-                if *weight != 0.0 {
-                    write!(f, "({})--{}--({}),\n", node1, weight, node2)?;
+                if !float::is_zero(*weight) {
+                    writeln!(f, "({})--{}--({}),", node1, weight, node2)?;
                 }
             }
         }
@@ -649,7 +997,7 @@ mod tests {
             vec![1.0, 0.0, 1.0],
             vec![0.0, 1.0, 1.0],
         ];
-        assert_eq!(g_adj_mat, &test_mat);
+        assert_eq!(*g_adj_mat, test_mat);
     }
 
     #[test]
@@ -711,6 +1059,65 @@ mod tests {
         let _edge_0_3 = g.get_edge(0, 3).expect("The edge doesn't exist");
     }
 
+    #[test]
+    fn get_edge_check_near_zero_weight() {
+        let n_nodes = 2;
+        let adj_mat = vec![vec![0.0; n_nodes]; n_nodes];
+        let mut g = UGraph::from_adjacency_matrix(adj_mat);
+        g.add_weighted_edge(0, 1, 0.1 + 0.2 - 0.3); // accumulated error, not a real edge
+        assert_eq!(g.get_edge(0, 1), None);
+    }
+
+    #[test]
+    fn get_edges_check_values() {
+        let n_nodes = 3;
+        let edges = vec![(1, 0, 2.0), (2, 1, 1.5)];
+        let g = UGraph::from_weighted_edges(n_nodes, edges);
+        assert_eq!(g.get_edges(), vec![(0, 1, 2.0), (1, 2, 1.5)]);
+    }
+
+    #[test]
+    fn min_max_edge_check_values() {
+        let n_nodes = 4;
+        let edges = vec![(0, 1, 2.0), (1, 2, -1.0), (2, 3, 4.0), (3, 0, 4.0)];
+        let g = UGraph::from_weighted_edges(n_nodes, edges);
+        assert_eq!(g.min_edge(), Some(-1.0));
+        assert_eq!(g.max_edge(), Some(4.0));
+        assert_eq!(g.argmin_edge(), Some((1, 2, -1.0)));
+        assert_eq!(g.argmax_edge(), Some((0, 3, 4.0))); // first found in a tie
+        assert_eq!(UGraph::new().argmax_edge(), None);
+    }
+
+    #[test]
+    fn heaviest_and_lightest_neighbor_of_check_values() {
+        let n_nodes = 4;
+        let edges = vec![(0, 1, 2.0), (1, 2, -1.0), (1, 3, 4.0)];
+        let g = UGraph::from_weighted_edges(n_nodes, edges);
+        assert_eq!(g.heaviest_neighbor_of(1), Some((3, 4.0)));
+        assert_eq!(g.lightest_neighbor_of(1), Some((2, -1.0)));
+        assert_eq!(g.heaviest_neighbor_of(3), Some((1, 4.0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn heaviest_neighbor_of_panic_not_valid_node() {
+        let g = UGraph::from_edges(2, vec![(0, 1)]);
+        g.heaviest_neighbor_of(2);
+    }
+
+    #[test]
+    fn adj_list_check_status_after_mutations() {
+        let n_nodes = 3;
+        let edges = vec![(0, 2), (1, 2)];
+        let mut g = UGraph::from_edges(n_nodes, edges);
+        g.add_edge(0, 1);
+        g.add_weighted_edge(1, 2, 0.0); // removes the edge
+        g.add_node();
+        g.add_weighted_edge(3, 0, 2.5);
+        assert_eq!(g.adj_list, vec![vec![1, 2, 3], vec![0], vec![0], vec![0]]);
+        assert_eq!(g.get_neighbors_of(0), vec![1, 2, 3]);
+    }
+
     #[test]
     fn add_node_check_status() {
         let n_nodes = 3;
@@ -718,7 +1125,7 @@ mod tests {
         let mut g = UGraph::from_edges(n_nodes, edges);
         let g_nodes = g.get_nodes();
         assert_eq!(g_nodes, vec![0, 1, 2]);
-        g.add_node();
+        assert_eq!(g.add_node(), 3);
         let g_nodes = g.get_nodes();
         assert_eq!(g_nodes, vec![0, 1, 2, 3]);
         g.add_node();
@@ -726,6 +1133,20 @@ mod tests {
         assert_eq!(g_nodes, vec![0, 1, 2, 3, 4]);
     }
 
+    #[test]
+    fn add_nodes_matches_add_node() {
+        let mut g1 = UGraph::from_weighted_edges(3, vec![(0, 1, 2.0), (1, 2, 1.0)]);
+        let mut g2 = g1.clone();
+        assert_eq!(g1.add_nodes(4), 3..7);
+        for _ in 0..4 {
+            g2.add_node();
+        }
+        g1.add_edge(6, 0);
+        g2.add_edge(6, 0);
+        assert_eq!(g1, g2);
+        assert_eq!(g1.add_nodes(0), 7..7);
+    }
+
     #[test]
     fn add_edge_check_status() {
         let n_nodes = 3;
@@ -787,6 +1208,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn check_is_undirected_must_be_true() {
         let adj_mat: Vec<Vec<f32>> = vec![
             vec![0.0, 1.0, 0.0],
@@ -814,4 +1236,62 @@ mod tests {
         ];
         let _g = UGraph::from_adjacency_matrix(adj_mat);
     }
+
+    #[test]
+    #[should_panic(expected = "not a valid matrix for an undirected graph")]
+    fn check_is_undirected_panic_almost_symmetric() {
+        // The weights differ by less than the tolerance, but the edge would have two
+        // different weights depending on the direction
+        let adj_mat: Vec<Vec<f32>> = vec![vec![0.0, 1.0], vec![1.000_000_1, 0.0]];
+        let _g = UGraph::from_adjacency_matrix(adj_mat);
+    }
+
+    #[test]
+    fn clone_and_eq_check_values() {
+        let g = UGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+        let mut g2 = g.clone();
+        assert_eq!(g, g2);
+        g2.add_edge(0, 2);
+        assert_ne!(g, g2);
+        assert_eq!(UGraph::default(), UGraph::new());
+    }
+
+    #[test]
+    fn set_node_weight_check_new_nodes_and_eq() {
+        let mut g = UGraph::from_edges(2, vec![(0, 1)]);
+        let g2 = g.clone();
+        g.set_node_weight(0, 0.5);
+        assert_ne!(g, g2);
+        g.add_node();
+        g.add_nodes(2);
+        let weights: Vec<f32> = g
+            .get_nodes()
+            .iter()
+            .map(|n| g.get_node_weight(*n))
+            .collect();
+        assert_eq!(weights, vec![0.5, 1.0, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid")]
+    fn set_node_weight_panic_not_valid_node() {
+        let mut g = UGraph::from_edges(2, vec![(0, 1)]);
+        g.set_node_weight(2, 1.0);
+    }
+
+    #[test]
+    fn debug_check_formats() {
+        let g = UGraph::from_weighted_edges(2, vec![(0, 1, 2.0)]);
+        assert_eq!(
+            format!("{:?}", g),
+            "UGraph { n_nodes: 2, edges: [(0, 1, 2.0)] }"
+        );
+        assert_eq!(
+            format!("{:#?}", g),
+            "UGraph {\n    [0.0, 2.0],\n    [2.0, 0.0],\n}"
+        );
+        // Big graphs show the edges also in the alternate format
+        let g = UGraph::from_edges(17, vec![(0, 16)]);
+        assert!(format!("{:#?}", g).contains("edges"));
+    }
 }
@@ -0,0 +1,29 @@
+// Changing the crate tolerance affects the whole process, so these checks run in their
+// own test binary instead of next to the unit tests
+
+use graphst::{float, DGraph, Graph, UGraph};
+
+#[test]
+fn set_epsilon_keeps_index_and_get_edge_in_sync() {
+    let mut g = UGraph::from_edges(3, vec![(1, 2)]);
+    g.add_weighted_edge(0, 1, 1e-4);
+    let mut dg = DGraph::from_edges(3, vec![(1, 2)]);
+    dg.add_weighted_edge(0, 1, 1e-4);
+    float::set_epsilon(1e-3);
+    assert_eq!(g.get_edge(0, 1), None);
+    assert_eq!(g.get_neighbors_of(0), vec![]);
+    assert_eq!(g.get_edges(), vec![(1, 2, 1.0)]);
+    assert_eq!(dg.get_edge(0, 1), None);
+    assert_eq!(dg.get_successors_of(0), vec![]);
+    assert_eq!(dg.get_edges(), vec![(1, 2, 1.0)]);
+    // The matrix views agree with `get_edge`
+    assert_eq!(g.get_adjacency_matrix()[0], vec![0.0, 0.0, 0.0]);
+    assert_eq!(*g.row(1), [0.0, 0.0, 1.0]);
+    assert_eq!(dg.column_iter(1).collect::<Vec<f32>>(), vec![0.0, 0.0, 0.0]);
+    // The edge comes back with a lower tolerance
+    float::set_epsilon(float::DEFAULT_EPSILON);
+    assert_eq!(g.get_neighbors_of(0), vec![1]);
+    assert_eq!(dg.get_edges(), vec![(0, 1, 1e-4), (1, 2, 1.0)]);
+    assert_eq!(*g.row(1), [1e-4, 0.0, 1.0]);
+    assert_eq!(dg.get_adjacency_matrix()[0], vec![0.0, 1e-4, 0.0]);
+}